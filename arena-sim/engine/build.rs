@@ -0,0 +1,130 @@
+// Field Dictionary Generator
+// Compiles the `@field` annotations on WorldState and SimStats (src/types.rs)
+// into `$OUT_DIR/field_dictionary.rs`, included by `field_dictionary`.
+//
+// Annotation: the last doc line of each field,
+//   /// @field unit: <unit>; kind: cumulative|instantaneous; formula: <reference>
+// Other doc lines form the field's description. src/types.rs is parsed with
+// `syn`; a missing struct, a field without an annotation, or an unknown kind
+// fails the build.
+
+use std::fmt::Write;
+
+use quote::ToTokens;
+
+const TYPES: &str = "src/types.rs";
+const ANNOTATED: [(&str, &str); 2] = [
+    ("WorldState", "WORLD_STATE_FIELDS"),
+    ("SimStats", "SIM_STATS_FIELDS"),
+];
+
+struct Field {
+    name: String,
+    rust_type: String,
+    unit: String,
+    kind: &'static str,
+    formula: String,
+    description: String,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", TYPES);
+    let source = std::fs::read_to_string(TYPES).expect("read src/types.rs");
+
+    let mut out = String::new();
+    for (owner, table) in ANNOTATED {
+        writeln!(out, "pub(crate) const {}: &[FieldSpec] = &[", table).unwrap();
+        for f in parse_struct(&source, owner) {
+            writeln!(
+                out,
+                "    FieldSpec {{ owner: {:?}, name: {:?}, rust_type: {:?}, unit: {:?}, \
+                 kind: FieldKind::{}, formula: {:?}, description: {:?} }},",
+                owner, f.name, f.rust_type, f.unit, f.kind, f.formula, f.description,
+            )
+            .unwrap();
+        }
+        out.push_str("];\n");
+    }
+
+    let path = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("field_dictionary.rs");
+    std::fs::write(path, out).expect("write field_dictionary.rs");
+}
+
+/// Fields of `pub struct <owner>` with their annotations, in declaration order.
+fn parse_struct(source: &str, owner: &str) -> Vec<Field> {
+    let file = syn::parse_file(source).unwrap_or_else(|e| panic!("{}: {}", TYPES, e));
+    let item = file.items.iter()
+        .find_map(|item| match item {
+            syn::Item::Struct(s) if s.ident == owner => Some(s),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("{}: struct {} not found", TYPES, owner));
+    let syn::Fields::Named(named) = &item.fields else {
+        panic!("{}: struct {} has no named fields", TYPES, owner);
+    };
+
+    named.named.iter()
+        .map(|field| {
+            let name = field.ident.as_ref().expect("named field").to_string();
+            let docs: Vec<String> = field.attrs.iter().filter_map(doc_line).collect();
+            let docs: Vec<&str> = docs.iter().map(String::as_str).collect();
+            annotated_field(owner, &name, &type_name(&field.ty), &docs)
+        })
+        .collect()
+}
+
+/// Text of a `///` line, trimmed.
+fn doc_line(attr: &syn::Attribute) -> Option<String> {
+    let syn::Meta::NameValue(meta) = &attr.meta else {
+        return None;
+    };
+    match &meta.value {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }) if meta.path.is_ident("doc") => {
+            Some(s.value().trim().to_string())
+        }
+        _ => None,
+    }
+}
+
+/// `ty` as written in source, e.g. `Option<f64>` or `[u32; 4]`.
+fn type_name(ty: &syn::Type) -> String {
+    ty.to_token_stream().to_string()
+        .replace(' ', "")
+        .replace(';', "; ")
+        .replace(',', ", ")
+}
+
+fn annotated_field(owner: &str, name: &str, rust_type: &str, docs: &[&str]) -> Field {
+    let annotation = docs
+        .iter()
+        .find_map(|d| d.strip_prefix("@field"))
+        .unwrap_or_else(|| panic!("{}::{} has no @field annotation", owner, name));
+
+    let (mut unit, mut kind, mut formula) = (None, None, None);
+    for part in annotation.split(';') {
+        match part.split_once(':').map(|(k, v)| (k.trim(), v.trim())) {
+            Some(("unit", v)) => unit = Some(v.to_string()),
+            Some(("kind", "cumulative")) => kind = Some("Cumulative"),
+            Some(("kind", "instantaneous")) => kind = Some("Instantaneous"),
+            Some(("formula", v)) => formula = Some(v.to_string()),
+            _ => panic!("{}::{}: bad @field entry {:?}", owner, name, part.trim()),
+        }
+    }
+    let required = |what: &str, value: Option<String>| {
+        value.unwrap_or_else(|| panic!("{}::{}: @field has no {}", owner, name, what))
+    };
+
+    Field {
+        name: name.to_string(),
+        rust_type: rust_type.to_string(),
+        unit: required("unit", unit),
+        kind: kind.unwrap_or_else(|| panic!("{}::{}: @field has no kind", owner, name)),
+        formula: required("formula", formula),
+        description: docs
+            .iter()
+            .filter(|d| !d.starts_with("@field"))
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
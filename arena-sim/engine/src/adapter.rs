@@ -2,10 +2,11 @@
 
 use rust_decimal::Decimal;
 use rust_decimal::prelude::FromPrimitive;
-use crate::core_types::{GoldGrams, MarketTier as CoreTier};
+use crate::core_routing::{CapacityMetrics, PacketRouter};
+use crate::core_types::{GoldGrams, MarketTier as CoreTier, NodeId};
 use crate::core_governor::pid::{GovernorPid as CoreGovernor, NetworkMetrics as CoreMetrics, TierCounts};
 use crate::core_governor::params::GovernanceParams;
-use crate::types::{MarketTier as ArenaTier, WorldState};
+use crate::types::{MarketTier as ArenaTier, SimNode, WorldState};
 
 /// Convert f64 to Decimal (lossy but sufficient for simulation).
 pub fn to_decimal(v: f64) -> Decimal {
@@ -38,17 +39,21 @@ pub fn to_arena_tier(tier: &CoreTier) -> ArenaTier {
     }
 }
 
-/// Build core NetworkMetrics from Arena WorldState.
+/// Canonical Caesar peg target (USD gold price).
+pub const DEFAULT_PEG_TARGET: f64 = 2600.0;
+
+/// Build core NetworkMetrics from Arena WorldState, steering toward `peg_target`.
 pub fn world_to_metrics(
     state: &WorldState,
     volatility: f64,
     lambda: f64,
+    peg_target: f64,
 ) -> CoreMetrics {
     CoreMetrics {
-        current_gold_price_usd: to_decimal(state.gold_price),
-        target_gold_price_usd: to_decimal(2600.0), // canonical Caesar peg target
+        current_gold_price_usd: to_decimal(state.oracle_price),
+        target_gold_price_usd: to_decimal(peg_target),
         market_volatility: to_decimal(volatility),
-        transaction_volume: to_decimal(state.network_velocity),
+        transaction_volume: to_decimal(state.settled_value_per_tick),
         liquidity_depth: to_decimal(lambda * 1_000_000.0),
         network_velocity: to_decimal(state.network_velocity),
         active_packets_by_tier: TierCounts {
@@ -57,7 +62,8 @@ pub fn world_to_metrics(
             l2: state.tier_distribution[2] as u64,
             l3: state.tier_distribution[3] as u64,
         },
-        in_transit_float: to_decimal(state.active_value),
+        in_transit_float: to_decimal(state.in_transit_float),
+        oracle_staleness_ticks: state.oracle_staleness_ticks,
     }
 }
 
@@ -87,6 +93,19 @@ pub fn calculate_fee_via_core(
     from_decimal(fee)
 }
 
+/// Calculate a fee from a recorded base rate and tier modifier, the way the
+/// core governor prices it, under the constitutional caps.
+pub fn calculate_quoted_fee_via_core(
+    base_rate: f64,
+    tier_modifier: f64,
+    tier: &ArenaTier,
+    packet_value: f64,
+) -> f64 {
+    let raw = (to_decimal(base_rate) * to_decimal(tier_modifier)).max(Decimal::ZERO);
+    let cap = GovernanceParams::default().fee_caps.cap_for(to_core_tier(tier));
+    from_decimal(raw.min(to_decimal(packet_value) * cap))
+}
+
 /// Split rewards using core 80/20 split.
 pub fn split_rewards_via_core(governor: &CoreGovernor, total: f64) -> (f64, f64) {
     let split = governor.split_rewards(GoldGrams::from_decimal(to_decimal(total)));
@@ -116,6 +135,39 @@ pub fn score_capacity_via_core(
     from_decimal(score)
 }
 
+/// Core `CapacityMetrics` observed at `node`: bandwidth and latency as they
+/// stand, free buffer slots out of `routing::BUFFER_CAPACITY`, and its
+/// buffered packets as the active count.
+pub fn capacity_metrics(node: &SimNode) -> CapacityMetrics {
+    let capacity = crate::routing::BUFFER_CAPACITY as u64;
+    let active = node.current_buffer_count as u64;
+    CapacityMetrics {
+        node_id: NodeId::from(format!("node-{}", node.id)),
+        available_bandwidth_mbps: to_decimal(node.bandwidth),
+        buffer_capacity_packets: capacity.saturating_sub(active),
+        avg_latency_ms: to_decimal(node.latency),
+        active_packet_count: active,
+    }
+}
+
+/// Next hop among `candidates` (node ids) chosen by core's `PacketRouter`
+/// from their entries in `snapshot` (indexed by node id). None when there
+/// are no candidates.
+pub fn route_via_core(
+    router: &PacketRouter,
+    snapshot: &[CapacityMetrics],
+    candidates: &[u32],
+    tier: &ArenaTier,
+) -> Option<u32> {
+    let (ids, metrics): (Vec<u32>, Vec<CapacityMetrics>) = candidates.iter()
+        .filter_map(|&id| snapshot.get(id as usize).map(|m| (id, m.clone())))
+        .unzip();
+    let selection = router.find_route(&metrics, to_core_tier(tier)).ok()?;
+    metrics.iter()
+        .position(|m| m.node_id == selection.next_hop)
+        .map(|i| ids[i])
+}
+
 /// Cross-check a settlement against core's Decimal-based conservation law.
 /// Returns (balanced, circuit_breaker_tripped).
 /// This is a parallel validation — does NOT gate Arena's own conservation.
@@ -146,18 +198,31 @@ pub fn distribute_fee_via_core(
     egress_id: u32,
     transit_ids: &[u32],
 ) -> (f64, f64) {
+    let transit: Vec<(u32, u64)> = transit_ids.iter().map(|&id| (id, 0)).collect();
+    let (egress_amt, transit_amts) = distribute_weighted_fee_via_core(total_fee, egress_id, &transit);
+    (egress_amt, transit_amts.first().copied().unwrap_or(0.0))
+}
+
+/// Distribute a fee with the transit pool split in proportion to each
+/// relay's weight (core's bytes-relayed slot); all-zero weights split equally.
+/// Returns (egress_amount, per-relay amounts in `transit` order).
+pub fn distribute_weighted_fee_via_core(
+    total_fee: f64,
+    egress_id: u32,
+    transit: &[(u32, u64)],
+) -> (f64, Vec<f64>) {
     use crate::core_fee_distribution::FeeDistributor;
     use crate::core_types::{GoldGrams, NodeId};
 
     if total_fee <= 0.0 {
-        return (0.0, 0.0);
+        return (0.0, vec![0.0; transit.len()]);
     }
 
     let distributor = FeeDistributor::default();
     let egress_node = NodeId::from(format!("node-{}", egress_id));
-    let transit_nodes: Vec<(NodeId, u64)> = transit_ids
+    let transit_nodes: Vec<(NodeId, u64)> = transit
         .iter()
-        .map(|&id| (NodeId::from(format!("node-{}", id)), 0u64))
+        .map(|&(id, weight)| (NodeId::from(format!("node-{}", id)), weight))
         .collect();
 
     match distributor.distribute_fee(
@@ -167,13 +232,11 @@ pub fn distribute_fee_via_core(
     ) {
         Ok(dist) => {
             let egress_amt = from_decimal(dist.egress_payment.amount.0);
-            let per_transit = if dist.transit_payments.is_empty() {
-                0.0
-            } else {
-                from_decimal(dist.transit_payments[0].amount.0)
-            };
-            (egress_amt, per_transit)
+            let transit_amts = dist.transit_payments.iter()
+                .map(|payment| from_decimal(payment.amount.0))
+                .collect();
+            (egress_amt, transit_amts)
         }
-        Err(_) => (0.0, 0.0),
+        Err(_) => (0.0, vec![0.0; transit.len()]),
     }
 }
@@ -0,0 +1,104 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Adversarial Nodes
+//
+// Four `NodeStrategy` variants misbehave in the node cycle instead of just
+// sitting on zero liquidity:
+//
+//   FeeGouger        charges GOUGE_MULTIPLIER x its hop and settlement fees;
+//                    the constitutional tier caps still clamp them
+//   PacketDropper    discards every packet another node hands it
+//   RouteLiar        forwards to the live neighbor farthest from the packet's
+//                    target Egress, not the hop routing chose
+//   SelectiveCensor  refuses packets from the origins `censored_origins` names
+//
+// A dropped or censored packet never reaches an Egress. Its sender is
+// refunded, as for a hop whose receipt never comes back, under
+// `RevertReason::Dropped` or `RevertReason::Censored`. Panic does not turn an
+// adversary RiskAverse.
+
+use std::collections::BTreeSet;
+
+use crate::routing;
+use crate::types::{NodeRole, NodeStrategy, RevertReason, SimNode, SimPacket};
+
+/// How far past its honest fee a FeeGouger prices.
+pub const GOUGE_MULTIPLIER: f64 = 10.0;
+
+impl NodeStrategy {
+    /// One of the strategies that attack the network.
+    pub fn is_adversarial(self) -> bool {
+        matches!(
+            self,
+            Self::FeeGouger | Self::PacketDropper | Self::RouteLiar | Self::SelectiveCensor
+        )
+    }
+}
+
+/// Multiplier on the hop fee a node with `strategy` charges.
+pub fn hop_fee_multiplier(strategy: NodeStrategy) -> f64 {
+    match strategy {
+        NodeStrategy::FeeGouger => GOUGE_MULTIPLIER,
+        _ => 1.0,
+    }
+}
+
+/// Why a node with `strategy` refuses to serve `p`, if it does.
+pub fn refusal(strategy: NodeStrategy, p: &SimPacket, censored_origins: &BTreeSet<u32>) -> Option<RevertReason> {
+    match strategy {
+        NodeStrategy::PacketDropper if p.hops > 0 => Some(RevertReason::Dropped),
+        NodeStrategy::SelectiveCensor if censored_origins.contains(&p.origin_node) => Some(RevertReason::Censored),
+        _ => None,
+    }
+}
+
+/// The live neighbor of `node_id` farthest from `p`'s target Egress, which a
+/// RouteLiar sends `p` to instead of `honest`.
+pub fn misroute(nodes: &[SimNode], node_id: u32, p: &SimPacket, honest: u32) -> u32 {
+    let current = &nodes[node_id as usize];
+    let Some(target) = routing::target_egress(nodes, current, p) else {
+        return honest;
+    };
+    let distance = |n: &SimNode| (n.x - target.x).powi(2) + (n.y - target.y).powi(2);
+    current.neighbors.iter()
+        .copied()
+        .filter(|&n| nodes[n as usize].role != NodeRole::Disabled)
+        .max_by(|&a, &b| distance(&nodes[a as usize]).total_cmp(&distance(&nodes[b as usize])).then(b.cmp(&a)))
+        .unwrap_or(honest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArenaSimulation;
+
+    #[test]
+    fn test_adversaries_refuse_gouge_and_misroute() {
+        let mut sim = ArenaSimulation::new(24);
+        sim.spawn_packet(0, 100.0);
+        let mut p = sim.node_buffers[&0][0].clone();
+        let censored = BTreeSet::from([0]);
+
+        // A dropper keeps what its own Ingress mints; a censor refuses it anyway
+        assert_eq!(refusal(NodeStrategy::PacketDropper, &p, &BTreeSet::new()), None);
+        assert_eq!(refusal(NodeStrategy::SelectiveCensor, &p, &censored), Some(RevertReason::Censored));
+        assert_eq!(refusal(NodeStrategy::SelectiveCensor, &p, &BTreeSet::new()), None);
+        p.hops = 1;
+        assert_eq!(refusal(NodeStrategy::PacketDropper, &p, &BTreeSet::new()), Some(RevertReason::Dropped));
+        assert_eq!(refusal(NodeStrategy::Greedy, &p, &censored), None);
+        assert_eq!(hop_fee_multiplier(NodeStrategy::FeeGouger), GOUGE_MULTIPLIER);
+        assert!(!NodeStrategy::Greedy.is_adversarial());
+
+        // The lie sends the packet the opposite way from the target Egress
+        let target = routing::target_egress(&sim.nodes, &sim.nodes[0], &p).unwrap().clone();
+        let honest = routing::find_next_hop(
+            &sim.nodes, 0, &p, routing::RoutingMode::Euclidean, routing::RouteScoring::TrustWeighted,
+        ).unwrap();
+        let lie = misroute(&sim.nodes, 0, &p, honest);
+        let dist = |id: u32| {
+            let n = &sim.nodes[id as usize];
+            (n.x - target.x).powi(2) + (n.y - target.y).powi(2)
+        };
+        assert!(dist(lie) >= dist(honest));
+        assert!(sim.nodes[0].neighbors.iter().all(|&n| dist(n) <= dist(lie)));
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Ingress Dust Aggregation
+
+use std::collections::BTreeMap;
+
+/// Id `spawn_packet` returns for a payment held at its ingress for aggregation.
+pub const AGGREGATED_PACKET_ID: u64 = u64::MAX - 1;
+
+/// Payments waiting at one ingress: (amount, dust surcharge) and the tick the
+/// first one arrived.
+#[derive(Debug, Clone)]
+struct Pending {
+    payments: Vec<(f64, f64)>,
+    since: u64,
+}
+
+/// One routed packet's worth of coalesced payments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aggregate {
+    pub node_id: u32,
+    pub amount: f64,
+    pub surcharge: f64,
+    /// Component payment values, for fan-out accounting at egress
+    pub manifest: Vec<f64>,
+}
+
+// ---------------------------------------------------------------------------
+// DustAggregator - coalesce sub-threshold payments per ingress
+// ---------------------------------------------------------------------------
+
+/// Holds payments below `threshold` at their ingress and releases each
+/// ingress's batch as a single packet once its oldest payment has waited
+/// `max_wait_ticks`.
+#[derive(Debug, Clone)]
+pub struct DustAggregator {
+    threshold: f64,
+    max_wait_ticks: u64,
+    pending: BTreeMap<u32, Pending>,
+    pub payments_aggregated: u32,
+    pub aggregate_packets: u32,
+}
+
+impl DustAggregator {
+    pub fn new(threshold: f64, max_wait_ticks: u64) -> Self {
+        Self {
+            threshold: threshold.max(0.0),
+            max_wait_ticks,
+            pending: BTreeMap::new(),
+            payments_aggregated: 0,
+            aggregate_packets: 0,
+        }
+    }
+
+    /// Offer an admitted payment. Returns true when it is held for
+    /// aggregation, false when it should be routed on its own.
+    pub fn offer(&mut self, node_id: u32, amount: f64, surcharge: f64, tick: u64) -> bool {
+        if amount >= self.threshold {
+            return false;
+        }
+        self.pending.entry(node_id)
+            .or_insert_with(|| Pending { payments: Vec::new(), since: tick })
+            .payments
+            .push((amount, surcharge));
+        self.payments_aggregated += 1;
+        true
+    }
+
+    /// Release every batch whose oldest payment has waited long enough.
+    pub fn release(&mut self, tick: u64) -> Vec<Aggregate> {
+        let ready: Vec<u32> = self.pending.iter()
+            .filter(|(_, p)| tick.saturating_sub(p.since) >= self.max_wait_ticks)
+            .map(|(&id, _)| id)
+            .collect();
+        ready.into_iter()
+            .filter_map(|id| self.pending.remove(&id).map(|p| (id, p)))
+            .map(|(node_id, p)| {
+                self.aggregate_packets += 1;
+                Aggregate {
+                    node_id,
+                    amount: p.payments.iter().map(|&(a, _)| a).sum(),
+                    surcharge: p.payments.iter().map(|&(_, s)| s).sum(),
+                    manifest: p.payments.into_iter().map(|(a, _)| a).collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Payments still waiting at an ingress.
+    pub fn pending_payments(&self) -> usize {
+        self.pending.values().map(|p| p.payments.len()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_threshold_payments_coalesce_per_ingress() {
+        let mut agg = DustAggregator::new(1.0, 3);
+        assert!(!agg.offer(0, 5.0, 0.0, 10));
+        assert!(agg.offer(0, 0.5, 0.1, 10));
+        assert!(agg.offer(0, 0.25, 0.0, 11));
+        assert!(agg.offer(4, 0.5, 0.0, 12));
+        assert!(agg.release(12).is_empty());
+
+        let released = agg.release(13);
+        assert_eq!(released, vec![Aggregate {
+            node_id: 0, amount: 0.75, surcharge: 0.1, manifest: vec![0.5, 0.25],
+        }]);
+        assert_eq!(agg.pending_payments(), 1);
+        assert_eq!((agg.payments_aggregated, agg.aggregate_packets), (3, 1));
+    }
+
+    #[test]
+    fn test_zero_wait_releases_next_tick_batch() {
+        let mut agg = DustAggregator::new(1.0, 0);
+        agg.offer(8, 0.5, 0.0, 4);
+        agg.offer(8, 0.5, 0.0, 4);
+        let released = agg.release(4);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].manifest.len(), 2);
+        assert_eq!(agg.pending_payments(), 0);
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Closed-Form Demurrage Reference
+
+use crate::cohort::CohortOutcome;
+use crate::dissolution::DISSOLUTION_TIMEOUT_TICKS;
+use crate::routing_policy::RoutingPolicy;
+use crate::types::MarketTier;
+
+/// Orbit ticks a Held packet rides free before surge pricing burns it.
+pub const SURGE_FREE_TICKS: u64 = 10;
+/// Surge burn rate added per orbit tick past the free ticks.
+pub const SURGE_STEP: f64 = 0.01;
+/// Ceiling on the per-tick surge burn rate.
+pub const SURGE_MAX_BURN: f64 = 0.5;
+
+// ---------------------------------------------------------------------------
+// Closed forms
+// ---------------------------------------------------------------------------
+
+/// V0·e^(−λt): value left after `ticks` ticks of demurrage alone.
+pub fn demurrage_value(v0: f64, tier: MarketTier, ticks: u64) -> f64 {
+    v0 * (-tier.demurrage_lambda() * ticks as f64).exp()
+}
+
+/// Share of value left after `orbit_ticks` ticks of surge burn:
+/// ∏ (1 − min(0.01·(k − 10), 0.5)) over orbit ticks k > 10.
+pub fn surge_factor(orbit_ticks: u64) -> f64 {
+    (SURGE_FREE_TICKS + 1..=orbit_ticks)
+        .map(|k| 1.0 - ((k - SURGE_FREE_TICKS) as f64 * SURGE_STEP).min(SURGE_MAX_BURN))
+        .product()
+}
+
+/// Orbit ticks a Held packet may spend before it is refunded under the
+/// default `RoutingPolicy`. L3 orbits past the dissolution threshold; other
+/// tiers get half their TTL.
+pub fn orbit_limit(tier: MarketTier) -> u64 {
+    RoutingPolicy::default().orbit_timeout(tier)
+}
+
+// ---------------------------------------------------------------------------
+// Predicted lifetimes of packets that never route
+// ---------------------------------------------------------------------------
+
+/// When (age in ticks since spawn), how, and with what value a packet leaves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PredictedExit {
+    pub age: u64,
+    pub outcome: CohortOutcome,
+    pub value: f64,
+}
+
+/// Value at `age` of a parked packet, one that neither routes nor orbits
+/// (e.g. waiting out a settlement window at an egress): decay only.
+pub fn parked_value(v0: f64, tier: MarketTier, age: u64) -> f64 {
+    demurrage_value(v0, tier, age)
+}
+
+/// A parked packet expires at its TTL.
+pub fn parked_exit(v0: f64, tier: MarketTier) -> PredictedExit {
+    let age = tier.ttl_ticks();
+    PredictedExit { age, outcome: CohortOutcome::Reverted, value: parked_value(v0, tier, age) }
+}
+
+/// Value at `age` of a stranded packet, one that fails to route on its first
+/// tick and is Held from then on: decay plus surge burn from orbit tick 11.
+pub fn stranded_value(v0: f64, tier: MarketTier, age: u64) -> f64 {
+    demurrage_value(v0, tier, age) * surge_factor(age.saturating_sub(1))
+}
+
+/// A stranded packet leaves at the first of its TTL, dissolution and the
+/// orbit refund, checked in that order each tick. Surge takes at most half a
+/// packet per tick, so it never burns to zero and stays dissolvable. Orbit
+/// starts at age 1, so the refund comes `orbit_limit + 2` ticks in.
+pub fn stranded_exit(v0: f64, tier: MarketTier) -> PredictedExit {
+    let ttl = tier.ttl_ticks();
+    let refund_age = orbit_limit(tier) + 2;
+    let dissolve_age = DISSOLUTION_TIMEOUT_TICKS;
+    let (age, outcome) = if ttl <= dissolve_age.min(refund_age) {
+        (ttl, CohortOutcome::Reverted)
+    } else if dissolve_age <= refund_age {
+        (dissolve_age, CohortOutcome::Dissolved)
+    } else {
+        (refund_age, CohortOutcome::Reverted)
+    };
+    PredictedExit { age, outcome, value: stranded_value(v0, tier, age) }
+}
+
+/// |simulated − predicted| relative to the prediction.
+pub fn relative_error(simulated: f64, predicted: f64) -> f64 {
+    if predicted == 0.0 {
+        simulated.abs()
+    } else {
+        ((simulated - predicted) / predicted).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closed_forms_and_stranded_exit() {
+        // Half-life of L0 demurrage is ln 2 / λ ticks
+        let half_life = (std::f64::consts::LN_2 / MarketTier::L0.demurrage_lambda()).round() as u64;
+        assert!(relative_error(demurrage_value(8.0, MarketTier::L0, half_life), 4.0) < 1e-4);
+        assert_eq!(demurrage_value(8.0, MarketTier::L0, 0), 8.0);
+
+        assert_eq!(surge_factor(10), 1.0);
+        assert!(relative_error(surge_factor(12), 0.99 * 0.98) < 1e-15);
+
+        let l1 = stranded_exit(500.0, MarketTier::L1);
+        assert_eq!((l1.age, l1.outcome), (252, CohortOutcome::Reverted));
+        assert!(relative_error(parked_exit(500.0, MarketTier::L1).value, 500.0) < 1e-5);
+        // Only L3 orbits long enough to dissolve
+        let l3 = stranded_exit(200_000.0, MarketTier::L3);
+        assert_eq!((l3.age, l3.outcome), (5_000, CohortOutcome::Dissolved));
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Per-Asset Inventory Ledger
+//
+// The value ledger (input = output + burned + fees + active) says nothing
+// about which asset nodes end up holding. This ledger follows node fiat and
+// crypto inventories through every flow that moves them: provisioning
+// (starting inventory, joins and overrides), Egress settlement draws,
+// batch-window netting credits, liquidity provider supply and withdrawals,
+// replenishment inflows, rebalancing transfers between Egress peers and
+// dissolution payouts. What the nodes hold, plus rebalancing crypto still in
+// flight, must equal what the flows put there; dissolution paid as treasury claims moves no inventory and is
+// carried as claims owed per node.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tsify::Tsify;
+
+use crate::conservation::{compensated_sum, CompensatedSum};
+use crate::dissolution::{DissolutionPayout, DissolutionResult};
+use crate::types::SimNode;
+
+/// One asset's flows and what the nodes hold of it now.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct AssetBalance {
+    /// Set directly: starting inventory, joined nodes and overrides
+    pub provisioned: f64,
+    /// Drawn from Egress inventory by settlements
+    pub settled: f64,
+    /// Returned to Egress inventory when a batching window nets out
+    pub netted: f64,
+    /// Dissolution payouts credited in this asset
+    pub dissolved: f64,
+    /// Supplied to Egress inventory by liquidity providers, net of withdrawals
+    #[serde(default)]
+    pub supplied: f64,
+    /// Put into Egress inventory by a replenishment policy
+    #[serde(default)]
+    pub replenished: f64,
+    /// Sent between Egress peers by rebalancing and not yet landed
+    #[serde(default)]
+    pub in_flight: f64,
+    /// Sum of node inventories
+    pub held: f64,
+    /// |provisioned - settled + netted + dissolved + supplied + replenished - in_flight - held|
+    pub residual: f64,
+}
+
+/// Treasury claim owed to one node.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct NodeClaim {
+    pub node_id: u32,
+    pub amount: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct AssetLedgerReport {
+    pub payout: DissolutionPayout,
+    pub fiat: AssetBalance,
+    pub crypto: AssetBalance,
+    /// Dissolution owed by the treasury, in total and by node
+    pub treasury_claims: f64,
+    pub claims: Vec<NodeClaim>,
+    /// Every dissolution payout, whatever the path: fiat plus crypto plus claims
+    pub dissolved_total: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Flows {
+    provisioned: CompensatedSum,
+    settled: CompensatedSum,
+    netted: CompensatedSum,
+    dissolved: CompensatedSum,
+    supplied: CompensatedSum,
+    replenished: CompensatedSum,
+    in_flight: CompensatedSum,
+}
+
+impl Flows {
+    fn balance(&self, held: f64) -> AssetBalance {
+        let (provisioned, settled, netted, dissolved, supplied, replenished, in_flight) = (
+            self.provisioned.value(), self.settled.value(), self.netted.value(), self.dissolved.value(),
+            self.supplied.value(), self.replenished.value(), self.in_flight.value(),
+        );
+        AssetBalance {
+            provisioned, settled, netted, dissolved, supplied, replenished, in_flight, held,
+            residual: compensated_sum(
+                [provisioned, -settled, netted, dissolved, supplied, replenished, -in_flight, -held],
+            ).abs(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// AssetLedger
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct AssetLedger {
+    payout: DissolutionPayout,
+    fiat: Flows,
+    crypto: Flows,
+    claims: BTreeMap<u32, f64>,
+}
+
+impl AssetLedger {
+    /// A ledger opening on the inventories `nodes` start with.
+    pub fn opening(nodes: &[SimNode]) -> Self {
+        let mut ledger = Self::default();
+        for n in nodes {
+            ledger.provision(n.inventory_fiat, n.inventory_crypto);
+        }
+        ledger
+    }
+
+    pub fn payout(&self) -> DissolutionPayout {
+        self.payout
+    }
+
+    pub fn set_payout(&mut self, payout: DissolutionPayout) {
+        self.payout = payout;
+    }
+
+    /// Inventory set directly rather than moved by a flow (the change, for
+    /// an override).
+    pub fn provision(&mut self, fiat: f64, crypto: f64) {
+        self.fiat.provisioned += fiat;
+        self.crypto.provisioned += crypto;
+    }
+
+    pub fn record_settlement(&mut self, crypto: f64) {
+        self.crypto.settled += crypto;
+    }
+
+    pub fn record_netting(&mut self, crypto: f64) {
+        self.crypto.netted += crypto;
+    }
+
+    /// Crypto a liquidity provider put into (or, negative, took out of) an
+    /// Egress inventory.
+    pub fn record_supply(&mut self, crypto: f64) {
+        self.crypto.supplied += crypto;
+    }
+
+    /// Crypto a replenishment policy put into an Egress inventory.
+    pub fn record_replenishment(&mut self, crypto: f64) {
+        self.crypto.replenished += crypto;
+    }
+
+    /// Crypto a rebalancing lender sent toward a peer.
+    pub fn record_rebalance_sent(&mut self, crypto: f64) {
+        self.crypto.in_flight += crypto;
+    }
+
+    /// Rebalancing crypto that landed with the borrower and, as the fee,
+    /// back with the lender.
+    pub fn record_rebalance_landed(&mut self, crypto: f64) {
+        self.crypto.in_flight += -crypto;
+    }
+
+    /// Pay out `result` on the configured path.
+    pub fn pay_dissolution(&mut self, nodes: &mut [SimNode], result: &DissolutionResult) {
+        for dist in &result.distributions {
+            let Some(node) = nodes.get_mut(dist.node_id as usize) else {
+                continue;
+            };
+            match self.payout {
+                DissolutionPayout::FiatCredit => {
+                    node.inventory_fiat += dist.amount;
+                    self.fiat.dissolved += dist.amount;
+                }
+                DissolutionPayout::CryptoInventory => {
+                    node.inventory_crypto += dist.amount;
+                    self.crypto.dissolved += dist.amount;
+                }
+                DissolutionPayout::TreasuryClaim => {
+                    *self.claims.entry(dist.node_id).or_default() += dist.amount;
+                }
+            }
+        }
+    }
+
+    pub fn report(&self, nodes: &[SimNode]) -> AssetLedgerReport {
+        let fiat = self.fiat.balance(compensated_sum(nodes.iter().map(|n| n.inventory_fiat)));
+        let crypto = self.crypto.balance(compensated_sum(nodes.iter().map(|n| n.inventory_crypto)));
+        let treasury_claims = compensated_sum(self.claims.values().copied());
+        AssetLedgerReport {
+            payout: self.payout,
+            fiat,
+            crypto,
+            treasury_claims,
+            claims: self.claims.iter().map(|(&node_id, &amount)| NodeClaim { node_id, amount }).collect(),
+            dissolved_total: fiat.dissolved + crypto.dissolved + treasury_claims,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dissolution::GravityDistribution;
+    use crate::topology::TopologyBuilder;
+
+    #[test]
+    fn test_payout_paths_balance() {
+        let result = DissolutionResult {
+            total_dissolved: 30.0,
+            distributions: vec![
+                GravityDistribution { node_id: 0, amount: 10.0, held_shards: false },
+                GravityDistribution { node_id: 1, amount: 20.0, held_shards: true },
+            ],
+        };
+        let mut nodes = TopologyBuilder::new(4).build_nodes();
+        let mut ledger = AssetLedger::opening(&nodes);
+        let before = ledger.report(&nodes);
+
+        ledger.pay_dissolution(&mut nodes, &result);
+        ledger.set_payout(DissolutionPayout::CryptoInventory);
+        ledger.pay_dissolution(&mut nodes, &result);
+        nodes[1].inventory_crypto -= 5.0;
+        ledger.record_settlement(5.0);
+        ledger.record_netting(2.0);
+        nodes[1].inventory_crypto += 2.0;
+        ledger.set_payout(DissolutionPayout::TreasuryClaim);
+        ledger.pay_dissolution(&mut nodes, &result);
+
+        let after = ledger.report(&nodes);
+        assert!(after.fiat.residual < 1e-9 && after.crypto.residual < 1e-9);
+        assert_eq!((after.fiat.dissolved, after.crypto.dissolved, after.treasury_claims), (30.0, 30.0, 30.0));
+        assert_eq!(after.fiat.held - before.fiat.held, 30.0);
+        assert_eq!(after.crypto.held - before.crypto.held, 27.0);
+        assert_eq!(after.claims, [NodeClaim { node_id: 0, amount: 10.0 }, NodeClaim { node_id: 1, amount: 20.0 }]);
+        assert_eq!(after.dissolved_total, 90.0);
+
+        // An unrecorded inventory change shows up as residual
+        nodes[2].inventory_crypto += 1.0;
+        assert!((ledger.report(&nodes).crypto.residual - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Availability & Trust
+
+use crate::types::{NodeRole, SimNode};
+
+/// Effective window (ticks) of the rolling uptime average.
+pub const AVAILABILITY_WINDOW_TICKS: f64 = 100.0;
+/// Per-tick fraction of the gap to current uptime that trust wins back.
+pub const TRUST_RECOVERY_RATE: f64 = 0.01;
+
+/// Advance each node's rolling uptime by one tick (Disabled = down) and
+/// update its trust score. Trust falls with uptime immediately but only
+/// recovers slowly, so a revived node does not inherit its pre-outage trust.
+pub fn update_availability(nodes: &mut [SimNode]) {
+    let alpha = 1.0 / AVAILABILITY_WINDOW_TICKS;
+    for node in nodes.iter_mut() {
+        let up = if node.role == NodeRole::Disabled { 0.0 } else { 1.0 };
+        node.uptime = (node.uptime * (1.0 - alpha) + up * alpha).clamp(0.0, 1.0);
+        node.trust_score = if node.uptime < node.trust_score {
+            node.uptime
+        } else {
+            node.trust_score + (node.uptime - node.trust_score) * TRUST_RECOVERY_RATE
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reliable_node_keeps_full_trust() {
+        let mut nodes = vec![SimNode::test_default(0, NodeRole::Transit)];
+        for _ in 0..500 {
+            update_availability(&mut nodes);
+        }
+        assert_eq!(nodes[0].uptime, 1.0);
+        assert_eq!(nodes[0].trust_score, 1.0);
+    }
+
+    #[test]
+    fn test_outage_drops_trust_and_recovery_lags_uptime() {
+        let mut nodes = vec![SimNode::test_default(0, NodeRole::Disabled)];
+        for _ in 0..100 {
+            update_availability(&mut nodes);
+        }
+        let low = nodes[0].uptime;
+        assert!(low < 0.4);
+        assert_eq!(nodes[0].trust_score, low);
+
+        nodes[0].role = NodeRole::Transit;
+        for _ in 0..100 {
+            update_availability(&mut nodes);
+        }
+        assert!(nodes[0].uptime > 0.7);
+        assert!(nodes[0].trust_score < nodes[0].uptime);
+        assert!(nodes[0].trust_score > low);
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Initial Packet Backlog
+//
+// Studies of recovery from congestion need a network that starts congested.
+// A `BacklogSpec` describes packets already in flight when the run begins:
+// how many, their tier mix, how old they are, and what share is on a link
+// rather than sitting in an Ingress buffer. The clock cannot run before
+// tick 0, so an aged packet is one that has already spent `age` ticks of
+// its TTL and borne that many ticks of demurrage; spawn-relative metrics
+// (latency, cohorts) still count from tick 0.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::types::MarketTier;
+
+/// Payment value minted per tier, inside each tier's band.
+pub const TIER_VALUES: [f64; 4] = [5.0, 500.0, 50_000.0, 250_000.0];
+
+/// Packets pre-loaded before the first tick.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct BacklogSpec {
+    pub packets: u32,
+    /// Relative weights of L0..L3 (any non-negative scale)
+    pub tier_mix: [f64; 4],
+    /// Ages spread over `min_age_ticks..=max_age_ticks`
+    pub min_age_ticks: u64,
+    pub max_age_ticks: u64,
+    /// Share of packets already on the link to their first hop
+    pub in_transit_share: f64,
+}
+
+/// One packet of a backlog.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BacklogPacket {
+    pub tier: MarketTier,
+    pub value: f64,
+    pub age_ticks: u64,
+    pub in_transit: bool,
+}
+
+/// Low-discrepancy sequence: tiers, ages and transit draw from independent
+/// irrational strides so the three stay uncorrelated without an RNG.
+fn stride(i: u32, alpha: f64) -> f64 {
+    ((i as f64 + 0.5) * alpha).fract()
+}
+
+impl BacklogSpec {
+    /// The packets to seed, deterministically. An all-zero or invalid tier
+    /// mix seeds nothing.
+    pub fn packets(&self) -> Vec<BacklogPacket> {
+        let weights = self.tier_mix.map(|w| if w.is_finite() { w.max(0.0) } else { 0.0 });
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return Vec::new();
+        }
+        let (min_age, max_age) = (
+            self.min_age_ticks.min(self.max_age_ticks),
+            self.min_age_ticks.max(self.max_age_ticks),
+        );
+        let tiers = [MarketTier::L0, MarketTier::L1, MarketTier::L2, MarketTier::L3];
+        (0..self.packets)
+            .map(|i| {
+                let draw = stride(i, 0.618_033_988_749_895) * total;
+                let mut t = 0;
+                let mut cumulative = weights[0];
+                while t < 3 && (draw >= cumulative || weights[t] == 0.0) {
+                    t += 1;
+                    cumulative += weights[t];
+                }
+                let span = (max_age - min_age) as f64;
+                BacklogPacket {
+                    tier: tiers[t],
+                    value: TIER_VALUES[t],
+                    age_ticks: min_age + (stride(i, 0.414_213_562_373_095) * (span + 1.0)) as u64,
+                    in_transit: stride(i, 0.732_050_807_568_877) < self.in_transit_share,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packets_follow_the_mix_and_age_range() {
+        let spec = BacklogSpec {
+            packets: 1000,
+            tier_mix: [3.0, 1.0, 0.0, 0.0],
+            min_age_ticks: 10,
+            max_age_ticks: 40,
+            in_transit_share: 0.25,
+        };
+        let packets = spec.packets();
+        assert_eq!(packets, spec.packets());
+        let l0 = packets.iter().filter(|p| p.tier == MarketTier::L0).count();
+        assert!((740..=760).contains(&l0), "{} L0 packets", l0);
+        assert!(packets.iter().all(|p| p.tier != MarketTier::L2 && p.tier != MarketTier::L3));
+        assert!(packets.iter().all(|p| MarketTier::from_value(p.value) == p.tier));
+        assert!(packets.iter().all(|p| (10..=40).contains(&p.age_ticks)));
+        assert!(packets.iter().any(|p| p.age_ticks == 10) && packets.iter().any(|p| p.age_ticks == 40));
+        let in_transit = packets.iter().filter(|p| p.in_transit).count();
+        assert!((240..=260).contains(&in_transit), "{} in transit", in_transit);
+
+        let empty = BacklogSpec { tier_mix: [0.0, f64::NAN, -1.0, 0.0], ..spec };
+        assert!(empty.packets().is_empty());
+    }
+}
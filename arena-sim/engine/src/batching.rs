@@ -0,0 +1,167 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Egress Settlement Batching
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::types::{SimNode, SimPacket};
+
+/// Site a node belongs to (see `topology`); ids outside the network have none.
+pub fn site(nodes: &[SimNode], node_id: u32) -> u32 {
+    nodes.get(node_id as usize).map_or(u32::MAX, |n| n.site)
+}
+
+/// Settlement corridor of a packet at an egress: the site it entered at.
+pub fn corridor(nodes: &[SimNode], p: &SimPacket) -> u32 {
+    site(nodes, p.origin_node)
+}
+
+// ---------------------------------------------------------------------------
+// Corridor approval
+// ---------------------------------------------------------------------------
+
+/// Pick the corridors an egress can settle atomically this window.
+///
+/// A packet draws at most its value after this tick's demurrage, so that is
+/// a safe upper bound on the batch. Corridors are funded in id order while
+/// inventory lasts; a corridor that does not fit waits whole for a later window.
+pub fn approve_corridors(nodes: &[SimNode], packets: &[SimPacket], inventory: f64) -> BTreeSet<u32> {
+    let mut bound: BTreeMap<u32, f64> = BTreeMap::new();
+    for p in packets.iter().filter(|p| p.current_value > 0.0) {
+        *bound.entry(corridor(nodes, p)).or_insert(0.0) +=
+            p.current_value * (-p.tier.demurrage_lambda()).exp();
+    }
+
+    let mut remaining = inventory;
+    let mut approved = BTreeSet::new();
+    for (id, need) in bound {
+        if need <= remaining {
+            remaining -= need;
+            approved.insert(id);
+        }
+    }
+    approved
+}
+
+// ---------------------------------------------------------------------------
+// BatchLedger - bilateral corridor netting per window
+// ---------------------------------------------------------------------------
+
+/// Tracks egress inventory drawn by settlements. Within a batching window,
+/// value settled from site A into site B offsets value settled from B into
+/// A; the offset is returned to the egresses that fronted it when the
+/// window closes, so only the net corridor position consumes inventory.
+#[derive(Debug, Clone, Default)]
+pub struct BatchLedger {
+    pub batches: u32,
+    pub packets: u32,
+    /// Net inventory drawn by all settlements.
+    pub drawn: f64,
+    /// Inventory per-packet settlement draws for the same packets.
+    pub gross: f64,
+    open: BTreeSet<(u32, u32)>,
+    // (origin site, egress site) -> value fronted per egress node this window
+    flows: BTreeMap<(u32, u32), BTreeMap<u32, f64>>,
+}
+
+impl BatchLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a settlement of `value` at `egress_id` for a packet that entered
+    /// at `origin_id`. Only `batched` settlements join a corridor batch.
+    pub fn record(&mut self, nodes: &[SimNode], egress_id: u32, origin_id: u32, value: f64, batched: bool) {
+        self.gross += value;
+        self.drawn += value;
+        if !batched {
+            return;
+        }
+        self.packets += 1;
+        let (from, to) = (site(nodes, origin_id), site(nodes, egress_id));
+        if self.open.insert((egress_id, from)) {
+            self.batches += 1;
+        }
+        *self.flows
+            .entry((from, to))
+            .or_default()
+            .entry(egress_id)
+            .or_insert(0.0) += value;
+    }
+
+    /// Net opposing corridor flows and close the window. Returns the
+    /// inventory to credit back to each egress node.
+    pub fn close_window(&mut self) -> Vec<(u32, f64)> {
+        self.open.clear();
+        let flows = std::mem::take(&mut self.flows);
+        let total = |by_egress: &BTreeMap<u32, f64>| by_egress.values().sum::<f64>();
+
+        let mut credits: BTreeMap<u32, f64> = BTreeMap::new();
+        for (&(from, to), by_egress) in &flows {
+            if from == to {
+                continue;
+            }
+            let Some(reverse) = flows.get(&(to, from)) else {
+                continue;
+            };
+            let fronted = total(by_egress);
+            let offset = fronted.min(total(reverse));
+            if offset <= 0.0 {
+                continue;
+            }
+            // Each direction credits its own egresses, pro rata to what they fronted
+            for (&egress_id, &value) in by_egress {
+                *credits.entry(egress_id).or_insert(0.0) += offset * value / fronted;
+            }
+        }
+        self.drawn -= credits.values().sum::<f64>();
+        credits.into_iter().collect()
+    }
+
+    /// Inventory saved by netting relative to per-packet settlement.
+    pub fn saved(&self) -> f64 {
+        (self.gross - self.drawn).max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MarketTier;
+    use crate::ArenaSimulation;
+
+    fn packet(origin: u32, value: f64) -> SimPacket {
+        SimPacket { tier: MarketTier::L3, ..SimPacket::test_default(origin, value) }
+    }
+
+    #[test]
+    fn test_corridors_funded_whole_or_not_at_all() {
+        // Origins 0 and 1 share site 0 (~80), site 1 needs ~70, site 2 ~10
+        let nodes = ArenaSimulation::new(24).nodes;
+        let packets = vec![packet(0, 40.0), packet(4, 70.0), packet(1, 40.0), packet(8, 10.0)];
+        let approved = approve_corridors(&nodes, &packets, 100.0);
+        assert_eq!(approved.into_iter().collect::<Vec<_>>(), vec![0, 2]);
+        assert!(approve_corridors(&nodes, &packets, 5.0).is_empty());
+    }
+
+    #[test]
+    fn test_opposing_corridors_net_per_window() {
+        let nodes = ArenaSimulation::new(24).nodes;
+        let mut ledger = BatchLedger::new();
+        ledger.record(&nodes, 5, 0, 100.0, true); // site 0 -> 1
+        ledger.record(&nodes, 5, 0, 20.0, true);
+        ledger.record(&nodes, 1, 4, 30.0, true); // site 1 -> 0
+        ledger.record(&nodes, 1, 0, 50.0, true); // intra-site: never nets
+        ledger.record(&nodes, 9, 0, 10.0, false); // per-packet: not batched
+        assert_eq!((ledger.batches, ledger.packets), (3, 4));
+
+        let credits = ledger.close_window();
+        assert_eq!(credits, vec![(1, 30.0), (5, 30.0)]);
+        assert!((ledger.saved() - 60.0).abs() < 1e-12);
+        assert!((ledger.drawn - 150.0).abs() < 1e-12);
+
+        // Flows do not carry into the next window
+        ledger.record(&nodes, 1, 4, 30.0, true);
+        assert!(ledger.close_window().is_empty());
+        assert_eq!(ledger.batches, 4);
+    }
+}
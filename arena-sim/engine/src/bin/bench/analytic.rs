@@ -0,0 +1,140 @@
+// Analytic Cross-Check — simulated demurrage vs the closed-form reference
+// A packet that never routes has a known value path and exit tick; any drift is a decay-math regression
+
+use arena_engine::analytic::{self, PredictedExit};
+use arena_engine::cohort::CohortOutcome;
+use arena_engine::{ArenaSimulation, MarketTier, NodeRole};
+use serde::Serialize;
+
+/// Nodes in the probe network (6 Ingress, 6 Egress).
+const PROBE_NODES: u32 = 24;
+/// Largest relative gap between simulated and closed-form value. Per-tick
+/// compounding of e^(−λ) drifts from e^(−λt) by rounding only.
+pub const VALUE_TOLERANCE: f64 = 1e-9;
+/// One probe amount per tier.
+const PROBES: [(MarketTier, f64); 4] = [
+    (MarketTier::L0, 5.0),
+    (MarketTier::L1, 500.0),
+    (MarketTier::L2, 50_000.0),
+    (MarketTier::L3, 250_000.0),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProbePath {
+    /// Waits at an egress behind a settlement window that never closes: decay only
+    Parked,
+    /// Spawned into a network with no egress liquidity: decay plus orbit surge
+    Stranded,
+}
+
+impl ProbePath {
+    pub fn label(self) -> &'static str {
+        match self {
+            ProbePath::Parked => "parked",
+            ProbePath::Stranded => "stranded",
+        }
+    }
+
+    fn predicted_value(self, v0: f64, tier: MarketTier, age: u64) -> f64 {
+        match self {
+            ProbePath::Parked => analytic::parked_value(v0, tier, age),
+            ProbePath::Stranded => analytic::stranded_value(v0, tier, age),
+        }
+    }
+
+    fn predicted_exit(self, v0: f64, tier: MarketTier) -> PredictedExit {
+        match self {
+            ProbePath::Parked => analytic::parked_exit(v0, tier),
+            ProbePath::Stranded => analytic::stranded_exit(v0, tier),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AnalyticCheck {
+    pub tier: MarketTier,
+    pub path: ProbePath,
+    pub initial_value: f64,
+    pub predicted_exit_age: u64,
+    pub simulated_exit_age: u64,
+    pub predicted_outcome: &'static str,
+    pub simulated_outcome: &'static str,
+    /// Worst per-tick relative value error while the prediction is a normal float
+    pub max_relative_value_error: f64,
+    pub pass: bool,
+}
+
+fn outcome_label(outcome: CohortOutcome) -> &'static str {
+    match outcome {
+        CohortOutcome::Settled => "settled",
+        CohortOutcome::Reverted => "reverted",
+        CohortOutcome::Dissolved => "dissolved",
+    }
+}
+
+fn probe(tier: MarketTier, v0: f64, path: ProbePath) -> AnalyticCheck {
+    let mut sim = ArenaSimulation::new(PROBE_NODES);
+    sim.set_demand_factor(0.0);
+    let node_id = match path {
+        ProbePath::Parked => {
+            sim.set_settlement_batch_window(Some(u32::MAX));
+            sim.tick_core(); // step off tick 0, where every window closes
+            1
+        }
+        ProbePath::Stranded => {
+            let egress: Vec<u32> = sim
+                .get_nodes_filtered_core(Some(NodeRole::Egress), None, 0, usize::MAX)
+                .iter()
+                .map(|n| n.id)
+                .collect();
+            for id in egress {
+                sim.set_node_crypto(id, 0.0);
+            }
+            0
+        }
+    };
+    let id = sim.spawn_packet(node_id, v0);
+
+    let mut age = 0;
+    let mut max_error = 0.0_f64;
+    let simulated_outcome = loop {
+        let state = sim.tick_core().state;
+        age += 1;
+        let live = sim.get_active_packets_core(None, None, Some(node_id), usize::MAX);
+        match live.iter().find(|p| p.id == id) {
+            Some(p) => {
+                let expected = path.predicted_value(v0, tier, age);
+                if expected.is_normal() {
+                    max_error = max_error.max(analytic::relative_error(p.current_value, expected));
+                }
+            }
+            None if state.dissolved_count > 0 => break CohortOutcome::Dissolved,
+            None if state.revert_count > 0 => break CohortOutcome::Reverted,
+            None => break CohortOutcome::Settled,
+        }
+    };
+
+    let predicted = path.predicted_exit(v0, tier);
+    AnalyticCheck {
+        tier,
+        path,
+        initial_value: v0,
+        predicted_exit_age: predicted.age,
+        simulated_exit_age: age,
+        predicted_outcome: outcome_label(predicted.outcome),
+        simulated_outcome: outcome_label(simulated_outcome),
+        max_relative_value_error: max_error,
+        pass: age == predicted.age
+            && simulated_outcome == predicted.outcome
+            && max_error <= VALUE_TOLERANCE,
+    }
+}
+
+/// Probe every tier down both never-routing paths.
+pub fn run_analytic_cross_check() -> Vec<AnalyticCheck> {
+    [ProbePath::Parked, ProbePath::Stranded]
+        .into_iter()
+        .flat_map(|path| PROBES.map(|(tier, v0)| probe(tier, v0, path)))
+        .collect()
+}
@@ -0,0 +1,131 @@
+// Breaking-Point Escalation — ramp demand or drain liquidity until pass criteria fail
+// Reports the failure envelope per topology size for capacity planning
+
+use serde::Serialize;
+
+use crate::monte_carlo::{run_single_with, RunParams};
+use crate::scenarios::Scenario;
+
+/// Pass-rate threshold a step must meet to count as sustainable (matches suite gate).
+const STEP_PASS_RATE: f64 = 0.933;
+/// Geometric step factor when ramping demand up.
+const DEMAND_STEP: f64 = 1.5;
+/// Geometric step factor when draining liquidity down.
+const LIQUIDITY_STEP: f64 = 0.75;
+const MAX_STEPS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EscalationMode {
+    Demand,
+    Liquidity,
+}
+
+impl EscalationMode {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "demand" => Some(Self::Demand),
+            "liquidity" => Some(Self::Liquidity),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EscalationStep {
+    pub demand_scale: f64,
+    pub liquidity_scale: f64,
+    pub pass_rate: f64,
+    pub packets_per_tick: f64,
+    pub mean_liquidity_lambda: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BreakingPointReport {
+    pub scenario_name: String,
+    pub label: String,
+    pub nodes: u32,
+    pub mode: EscalationMode,
+    /// True if criteria failed within `MAX_STEPS`; false means the envelope is a lower bound
+    pub broke: bool,
+    /// False if the scenario already fails at baseline
+    pub baseline_passes: bool,
+    /// Highest mean packets/tick over the passing steps
+    pub max_sustainable_packets_per_tick: f64,
+    /// Lowest run-averaged liquidity coefficient among the passing steps
+    pub min_viable_lambda: f64,
+    pub last_passing_demand_scale: f64,
+    pub last_passing_liquidity_scale: f64,
+    pub steps: Vec<EscalationStep>,
+}
+
+fn evaluate(scenario: &Scenario, params: RunParams, runs: usize, base_seed: u64) -> EscalationStep {
+    let runs = runs.max(1);
+    let mut passed = 0;
+    let mut ppt = 0.0;
+    let mut lambda = 0.0;
+    for i in 0..runs {
+        let r = run_single_with(scenario, base_seed + i as u64, None, params);
+        if r.pass {
+            passed += 1;
+        }
+        ppt += r.packets_per_tick;
+        lambda += r.mean_liquidity_lambda;
+    }
+    EscalationStep {
+        demand_scale: params.demand_scale,
+        liquidity_scale: params.liquidity_scale,
+        pass_rate: passed as f64 / runs as f64,
+        packets_per_tick: ppt / runs as f64,
+        mean_liquidity_lambda: lambda / runs as f64,
+    }
+}
+
+/// Escalate one knob geometrically from the scenario baseline until a step's
+/// Monte Carlo pass rate drops below the suite gate.
+pub fn find_breaking_point(
+    scenario: &Scenario,
+    mode: EscalationMode,
+    runs: usize,
+    base_seed: u64,
+) -> BreakingPointReport {
+    let mut params = RunParams::default();
+    let mut steps = Vec::new();
+    let mut broke = false;
+    let mut last_pass: Option<RunParams> = None;
+    let mut max_ppt: f64 = 0.0;
+    let mut min_lambda = f64::INFINITY;
+
+    for _ in 0..MAX_STEPS {
+        let step = evaluate(scenario, params, runs, base_seed);
+        let passes = step.pass_rate >= STEP_PASS_RATE;
+        if passes {
+            last_pass = Some(params);
+            max_ppt = max_ppt.max(step.packets_per_tick);
+            min_lambda = min_lambda.min(step.mean_liquidity_lambda);
+        }
+        steps.push(step);
+        if !passes {
+            broke = true;
+            break;
+        }
+        match mode {
+            EscalationMode::Demand => params.demand_scale *= DEMAND_STEP,
+            EscalationMode::Liquidity => params.liquidity_scale *= LIQUIDITY_STEP,
+        }
+    }
+
+    BreakingPointReport {
+        scenario_name: scenario.name.to_string(),
+        label: scenario.label.to_string(),
+        nodes: scenario.nodes,
+        mode,
+        broke,
+        baseline_passes: last_pass.is_some(),
+        max_sustainable_packets_per_tick: max_ppt,
+        min_viable_lambda: if min_lambda.is_finite() { min_lambda } else { 0.0 },
+        last_passing_demand_scale: last_pass.map_or(0.0, |p| p.demand_scale),
+        last_passing_liquidity_scale: last_pass.map_or(0.0, |p| p.liquidity_scale),
+        steps,
+    }
+}
@@ -0,0 +1,120 @@
+// Demand Calibration — binary-search the demand scale that hits a target settlement rate
+// Turns "demand=0.5 feels right" into a measured quantity for a given topology/liquidity
+
+use serde::Serialize;
+
+use crate::monte_carlo::{run_single_with, RunParams};
+use crate::scenarios::Scenario;
+
+/// Upper bound on the demand multiplier explored while bracketing.
+const MAX_DEMAND_SCALE: f64 = 1024.0;
+/// Bisection steps after bracketing (2^-12 relative resolution).
+const BISECT_STEPS: usize = 12;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CalibrationResult {
+    pub scenario_name: String,
+    pub label: String,
+    pub target_settlement_rate: f64,
+    /// True if some demand level met the target
+    pub met: bool,
+    /// Highest multiplier on the scenario demand that still meets the target
+    pub demand_scale: f64,
+    /// Effective demand factor (scenario demand × scale)
+    pub demand: f64,
+    pub settlement_rate: f64,
+    pub packets_per_tick: f64,
+    pub settlements_per_tick: f64,
+    pub evaluations: usize,
+    pub runs_per_evaluation: usize,
+}
+
+struct Measurement {
+    settlement_rate: f64,
+    packets_per_tick: f64,
+    settlements_per_tick: f64,
+}
+
+fn measure(scenario: &Scenario, demand_scale: f64, runs: usize, base_seed: u64) -> Measurement {
+    let runs = runs.max(1);
+    let mut rate = 0.0;
+    let mut ppt = 0.0;
+    let mut spt = 0.0;
+    for i in 0..runs {
+        let params = RunParams { demand_scale, ..RunParams::default() };
+        let r = run_single_with(scenario, base_seed + i as u64, None, params);
+        rate += r.settlement_rate;
+        ppt += r.packets_per_tick;
+        spt += r.settlement_count as f64 / r.ticks.max(1) as f64;
+    }
+    let n = runs as f64;
+    Measurement {
+        settlement_rate: rate / n,
+        packets_per_tick: ppt / n,
+        settlements_per_tick: spt / n,
+    }
+}
+
+/// Find the largest demand scale whose mean settlement rate (percent, over
+/// `runs` seeds) stays at or above `target`. Assumes settlement rate is
+/// non-increasing in demand: brackets by doubling, then bisects.
+pub fn calibrate_demand(
+    scenario: &Scenario,
+    target: f64,
+    runs: usize,
+    base_seed: u64,
+) -> CalibrationResult {
+    let mut evaluations = 0;
+    let mut eval = |scale: f64| {
+        evaluations += 1;
+        measure(scenario, scale, runs, base_seed)
+    };
+
+    // Bracket: lo meets target (or is 0), hi misses it
+    let mut lo = 0.0;
+    let mut lo_m: Option<Measurement> = None;
+    let mut hi = 1.0;
+    loop {
+        let m = eval(hi);
+        if m.settlement_rate < target {
+            break;
+        }
+        lo = hi;
+        lo_m = Some(m);
+        if hi >= MAX_DEMAND_SCALE {
+            break;
+        }
+        hi *= 2.0;
+    }
+
+    if lo < hi {
+        for _ in 0..BISECT_STEPS {
+            let mid = (lo + hi) / 2.0;
+            let m = eval(mid);
+            if m.settlement_rate >= target {
+                lo = mid;
+                lo_m = Some(m);
+            } else {
+                hi = mid;
+            }
+        }
+    }
+
+    let (settlement_rate, packets_per_tick, settlements_per_tick) = lo_m.as_ref()
+        .map(|m| (m.settlement_rate, m.packets_per_tick, m.settlements_per_tick))
+        .unwrap_or((0.0, 0.0, 0.0));
+
+    CalibrationResult {
+        scenario_name: scenario.name.to_string(),
+        label: scenario.label.to_string(),
+        target_settlement_rate: target,
+        met: lo_m.is_some(),
+        demand_scale: lo,
+        demand: scenario.demand * lo,
+        settlement_rate,
+        packets_per_tick,
+        settlements_per_tick,
+        evaluations,
+        runs_per_evaluation: runs.max(1),
+    }
+}
@@ -0,0 +1,685 @@
+// Paired-Run Comparisons — one scenario and seed re-run under toggled settings
+// Every comparison is a row of `comparisons()`: the scenarios it runs on, its
+// arms (a label and a `RunParams` toggle each, the first the baseline), and
+// the metrics recorded for every arm. `run_comparison` runs any row.
+
+use std::collections::BTreeMap;
+
+use arena_engine::*;
+use serde::Serialize;
+
+use crate::monte_carlo::{run_single_with, RunParams};
+use crate::report::BenchResult;
+use crate::scenarios::Scenario;
+
+/// Egress batching window (ticks) for the bank-run netting comparison.
+const NETTING_BATCH_WINDOW: u32 = 50;
+/// Demurrage grace window (ticks) for the demurrage-loop comparison.
+const DEMURRAGE_GRACE_TICKS: u32 = 10;
+/// Rolling per-origin volume threshold and base-fee discount rate for the
+/// normal-market volume discount comparison.
+const VOLUME_DISCOUNT_THRESHOLD: f64 = 50_000.0;
+const VOLUME_DISCOUNT_RATE: f64 = 0.2;
+/// Dust mitigations for the dust-flood comparison: minimum packet value and
+/// flat surcharge on dust (grams).
+const DUST_MIN_VALUE: f64 = 1.0;
+const DUST_SURCHARGE: f64 = 0.25;
+/// Ingress aggregation for the dust-flood comparison: payments under the dust
+/// value wait up to this many ticks to be coalesced.
+const DUST_AGGREGATION_WAIT_TICKS: u32 = 5;
+/// Ticks an Egress sits dry before exiting, for the bank-run exit comparison.
+/// Nothing recapitalizes a bank run, so exited nodes stay out.
+const EGRESS_EXHAUSTED_TICKS: u32 = 200;
+/// Endogenous panic for the panic feedback comparison: shock coupling,
+/// per-tick calming once settlements run fast, and noise amplitude.
+const PANIC_DYNAMICS: (f64, f64, f64) = (1.0, 0.02, 0.01);
+/// Tick the flash crash begins, where the fee freeze counterfactual branches.
+const FEE_FREEZE_TICK: u64 = 50;
+/// Egress payout FX for the peg slippage comparison: calm-market spread,
+/// widening per unit of rolling gold volatility, and its ceiling.
+const PAYOUT_SLIPPAGE: (f64, f64, f64) = (0.002, 2.0, 0.03);
+/// Sender retries for the offered-load comparison: share of reverted
+/// payments re-sent, and ticks the sender waits first.
+const RETRY_MODEL: (f64, u32) = (0.7, 20);
+/// Speculator population the organic-ratio detector is scored against:
+/// agents, and share of idle bankroll each sends per tick.
+const SPECULATORS: (u32, f64) = (4, 0.5);
+/// Liquidity providers the incentive drought is re-run with: agents, crypto
+/// bankroll each, and the per-tick fee yield they need to supply.
+const LIQUIDITY_PROVIDERS: (u32, f64, f64) = (5, 5_000_000.0, 5e-5);
+/// Scale scenarios run under every initial inventory policy, so liquidity
+/// per unit demand can be compared across network sizes.
+pub const SCALE_SCENARIOS: &[&str] = &["SCALE_100", "SCALE_250", "SCALE_500"];
+/// Starting inventory policies the scale comparison runs each scenario under.
+const INVENTORY_POLICIES: [inventory::InventoryPolicy; 4] = [
+    inventory::InventoryPolicy::NetworkScaled,
+    inventory::InventoryPolicy::PerNode,
+    inventory::InventoryPolicy::DemandProportional,
+    inventory::InventoryPolicy::Whitepaper,
+];
+/// (decision interval, actuation lag) in ticks the control latency sweep
+/// runs, from the instantaneous loop to a slow, late governor.
+const CONTROL_LOOP_DELAYS: [(u32, u32); 8] =
+    [(1, 0), (1, 2), (1, 5), (1, 10), (1, 20), (5, 5), (10, 10), (20, 20)];
+/// Share of decisions that may reverse the fee rate's direction before the
+/// loop counts as oscillating.
+const OSCILLATION_REVERSAL_SHARE: f64 = 0.5;
+/// Gains of the scheduled run by quadrant (A..F as 0..5): twice the base
+/// gains (Kp 0.5, Ki 0.1, Kd 0.05) in a Bubble or Crash, half in a Golden Era.
+const SCHEDULED_GAINS: &[(u32, f64, f64, f64)] = &[
+    (0, 1.0, 0.2, 0.1),
+    (1, 1.0, 0.2, 0.1),
+    (3, 0.25, 0.05, 0.025),
+];
+/// Governor cycles a new quadrant must hold in the debounced run.
+const QUADRANT_HYSTERESIS_CYCLES: u32 = 5;
+/// Cycles the model-predictive governor looks ahead in the comparison.
+const MPC_HORIZON: u32 = 8;
+
+// ─── Comparison Table ───────────────────────────────────────────────────────
+
+/// Settings of one arm, applied on top of `RunParams::default()`.
+pub type Toggle = Box<dyn Fn(&mut RunParams) + Send + Sync>;
+/// A metric read off an arm's run, given the settings it ran with.
+pub type Metric = fn(&RunParams, &BenchResult) -> f64;
+
+pub struct ComparisonSpec {
+    /// Key of the comparison in the report
+    pub name: &'static str,
+    /// Scenarios it runs on, each compared separately
+    pub scenarios: &'static [&'static str],
+    pub description: String,
+    /// Label and settings of each run; the first is the baseline
+    pub arms: Vec<(String, Toggle)>,
+    pub metrics: Vec<(&'static str, Metric)>,
+    /// A finding across the arms, for comparisons that reduce to one
+    pub summarize: Option<fn(&[ComparisonArm]) -> String>,
+}
+
+fn arm(label: &str, toggle: impl Fn(&mut RunParams) + Send + Sync + 'static) -> (String, Toggle) {
+    (label.to_string(), Box::new(toggle))
+}
+
+fn baseline(label: &str) -> (String, Toggle) {
+    arm(label, |_| {})
+}
+
+fn metric(name: &'static str, read: Metric) -> (&'static str, Metric) {
+    (name, read)
+}
+
+fn reversal_share(r: &BenchResult) -> f64 {
+    r.fee_rate_reversals as f64 / r.governor_decisions.max(1) as f64
+}
+
+/// Every paired comparison the bench runs, in report order.
+pub fn comparisons() -> Vec<ComparisonSpec> {
+    vec![
+        ComparisonSpec {
+            name: "settlement_netting",
+            scenarios: &["WP_BANK_RUN_EXACT"],
+            description: format!(
+                "Bank run settled per packet vs in atomic corridor batches over {}-tick windows",
+                NETTING_BATCH_WINDOW),
+            arms: vec![
+                baseline("per-packet"),
+                arm("batched", |p| p.settlement_batch_window = Some(NETTING_BATCH_WINDOW)),
+            ],
+            metrics: vec![
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("inventory_drawn", |_, r| r.egress_inventory_drawn),
+                metric("settlement_batches", |_, r| r.settlement_batches as f64),
+                metric("inventory_saved", |_, r| r.batch_inventory_saved),
+                metric("inventory_saved_pct", |_, r| {
+                    let gross = r.egress_inventory_drawn + r.batch_inventory_saved;
+                    if gross > 0.0 { r.batch_inventory_saved / gross * 100.0 } else { 0.0 }
+                }),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "demurrage_grace",
+            scenarios: &["WP_DEMURRAGE_LOOP"],
+            description: format!(
+                "Demurrage loop decaying from spawn vs after a {}-tick grace window",
+                DEMURRAGE_GRACE_TICKS),
+            arms: vec![
+                baseline("from spawn"),
+                arm("grace", |p| p.demurrage_grace_ticks = DEMURRAGE_GRACE_TICKS),
+            ],
+            metrics: vec![
+                metric("l0_cost_bps", |_, r| r.tier_effective_cost_bps[0]),
+                // Most the grace window can save an L0 packet directly; any
+                // larger cost shift comes from the runs taking different paths
+                metric("l0_direct_saving_cap_bps", |p, _| {
+                    (1.0 - (-MarketTier::L0.demurrage_lambda() * p.demurrage_grace_ticks as f64).exp()) * 10_000.0
+                }),
+                metric("demurrage_total", |_, r| r.demurrage_total),
+                metric("held_at_end", |_, r| r.held_count as f64),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "volume_discount",
+            scenarios: &["NORMAL_MARKET"],
+            description: format!(
+                "Normal market at list-price base fees vs {:.0}% off for origins past {:.0} rolling volume",
+                VOLUME_DISCOUNT_RATE * 100.0, VOLUME_DISCOUNT_THRESHOLD),
+            arms: vec![
+                baseline("list price"),
+                arm("discounted", |p| {
+                    p.volume_discount = Some((VOLUME_DISCOUNT_THRESHOLD, VOLUME_DISCOUNT_RATE));
+                }),
+            ],
+            metrics: vec![
+                metric("fees_collected", |_, r| r.fees_collected),
+                metric("discount_forgone", |_, r| r.volume_discount_forgone),
+                metric("discounted_value_share", |_, r| r.discounted_value_share),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "dust_mitigation",
+            scenarios: &["DUST_FLOOD"],
+            description: format!(
+                "Dust flood unmitigated vs a {} minimum packet value vs a {} dust surcharge",
+                DUST_MIN_VALUE, DUST_SURCHARGE),
+            arms: vec![
+                baseline("unmitigated"),
+                arm("min value", |p| p.dust_policy = Some((DUST_MIN_VALUE, 0.0))),
+                arm("surcharge", |p| p.dust_policy = Some((0.0, DUST_SURCHARGE))),
+            ],
+            metrics: vec![
+                metric("non_dust_settle_ticks", |_, r| r.non_dust_settle_ticks),
+                metric("verification_shortfalls", |_, r| r.verification_shortfalls as f64),
+                metric("dust_rejected", |_, r| r.dust_rejected as f64),
+                metric("dust_surcharges", |_, r| r.dust_surcharges),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "dust_aggregation",
+            scenarios: &["DUST_FLOOD"],
+            description: format!(
+                "Dust flood with every payment routed alone vs coalesced at ingress for up to {} ticks",
+                DUST_AGGREGATION_WAIT_TICKS),
+            arms: vec![
+                baseline("alone"),
+                arm("aggregated", |p| {
+                    p.dust_aggregation = Some((dust::DUST_VALUE, DUST_AGGREGATION_WAIT_TICKS));
+                }),
+            ],
+            metrics: vec![
+                metric("mean_packets_in_flight", |_, r| r.mean_packets_in_flight),
+                metric("payments_aggregated", |_, r| r.payments_aggregated as f64),
+                metric("aggregate_packets", |_, r| r.aggregate_packets as f64),
+                // Payments settled per payment spawned (aggregates fan out at egress)
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("non_dust_settle_ticks", |_, r| r.non_dust_settle_ticks),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "route_cache",
+            scenarios: &["SCALE_10K"],
+            description: "10K nodes with next hops rescored per packet vs served from the route cache; \
+                outcomes should match and CPU time drop".to_string(),
+            arms: vec![
+                baseline("rescored"),
+                arm("cached", |p| p.route_cache = true),
+            ],
+            metrics: vec![
+                metric("elapsed_ms", |_, r| r.elapsed_ms as f64),
+                metric("route_cache_hit_rate", |_, r| r.route_cache_hit_rate),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("held_count", |_, r| r.held_count as f64),
+                metric("normalized_conservation_error", |_, r| r.normalized_conservation_error),
+                metric("pass", |_, r| f64::from(u8::from(r.pass))),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "route_scoring",
+            scenarios: &["TRUST_REBATE_FLAPPING"],
+            description: "Flapping Egress with routes scored by trust vs capacity only, as in core".to_string(),
+            arms: vec![
+                baseline("trust-weighted"),
+                arm("capacity-only", |p| p.route_scoring = routing::RouteScoring::CapacityOnly),
+            ],
+            metrics: vec![
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("held_count", |_, r| r.held_count as f64),
+                // Share of settlements whose whole route was high-trust
+                metric("trusted_settlement_share", |_, r| r.trusted_settlement_share),
+                metric("trust_rebates_paid", |_, r| r.trust_rebates_paid),
+                metric("transit_profit", |_, r| r.transit_profit_total),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "routing_policy",
+            scenarios: &["NORMAL_MARKET"],
+            description: "Normal market with hop-exhausted packets orbited vs refunded vs backed off".to_string(),
+            arms: [
+                ("orbit", routing_policy::HopExhaustion::Orbit),
+                ("revert", routing_policy::HopExhaustion::Revert),
+                ("backoff", routing_policy::HopExhaustion::Backoff),
+            ].into_iter().map(|(label, hop_exhaustion)| arm(label, move |p| {
+                p.routing_policy = routing_policy::RoutingPolicy { hop_exhaustion, ..Default::default() };
+            })).collect(),
+            metrics: vec![
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("held_count", |_, r| r.held_count as f64),
+                // Hop-limit and disabled-destination orbits at end of run
+                metric("topology_orbits", |_, r| r.final_topology_orbits as f64),
+                metric("hop_limit_reverts", |_, r| r.revert_reasons[RevertReason::HopLimit as usize] as f64),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "routing_regime",
+            scenarios: &["NORMAL_MARKET"],
+            description: "Normal market with next hops from the legacy router vs core's capacity router \
+                vs the hybrid, under identical traffic".to_string(),
+            arms: [
+                ("legacy", capacity_router::RoutingRegime::Legacy),
+                ("capacity", capacity_router::RoutingRegime::CapacityBased),
+                ("hybrid", capacity_router::RoutingRegime::Hybrid),
+            ].into_iter().map(|(label, regime)| arm(label, move |p| p.routing_regime = regime)).collect(),
+            metrics: vec![
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("held_count", |_, r| r.held_count as f64),
+                metric("topology_orbits", |_, r| r.final_topology_orbits as f64),
+                metric("non_dust_settle_ticks", |_, r| r.non_dust_settle_ticks),
+                metric("mean_packets_in_flight", |_, r| r.mean_packets_in_flight),
+                metric("elapsed_ms", |_, r| r.elapsed_ms as f64),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "transit_weighting",
+            scenarios: &["WP_BANK_RUN_EXACT"],
+            description: "Bank run with the transit pool split equally vs by relay holding time".to_string(),
+            arms: vec![
+                baseline("equal"),
+                arm("holding time", |p| p.transit_weighting = transit_rewards::TransitWeighting::HoldingTime),
+            ],
+            metrics: vec![
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("transit_profit", |_, r| r.transit_profit_total),
+                // Distribution of `total_fees_earned` across Transit nodes
+                metric("earnings_gini", |_, r| r.transit_earnings_gini),
+                metric("top_decile_share", |_, r| r.transit_top_decile_share),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "quote_pricing",
+            scenarios: &["COST_CERTAINTY"],
+            description: "Cost certainty with settlement fees at spot vs the spawn-time quote".to_string(),
+            arms: vec![
+                baseline("spot"),
+                arm("quoted", |p| p.pricing_mode = quote::PricingMode::Quoted),
+            ],
+            metrics: vec![
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("fees_collected", |_, r| r.fees_collected),
+                // Total |fee charged - spawn quote| over all settlements
+                metric("quote_drift", |_, r| r.quote_drift),
+                metric("quote_mismatches", |_, r| r.quote_mismatches as f64),
+                metric("cost_certainty", |_, r| f64::from(u8::from(r.cost_certainty))),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "egress_exit",
+            scenarios: &["WP_NO_FAIL_BANK_RUN"],
+            description: format!(
+                "Bank run with bankrupt Egress staying put vs exiting to Transit vs to Disabled after {} dry ticks",
+                EGRESS_EXHAUSTED_TICKS),
+            arms: vec![
+                baseline("stay"),
+                arm("exit to Transit", |p| {
+                    p.egress_exit = Some((EGRESS_EXHAUSTED_TICKS, NodeRole::Transit, f64::INFINITY));
+                }),
+                arm("exit to Disabled", |p| {
+                    p.egress_exit = Some((EGRESS_EXHAUSTED_TICKS, NodeRole::Disabled, f64::INFINITY));
+                }),
+            ],
+            metrics: vec![
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("revert_count", |_, r| r.revert_count as f64),
+                metric("final_held", |_, r| r.final_held_count as f64),
+                metric("mean_stress_index", |_, r| r.mean_stress_index),
+                metric("egress_exits", |_, r| r.egress_exits as f64),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "panic_feedback",
+            scenarios: &["WP_INCENTIVE_DROUGHT"],
+            description: "Incentive drought with panic held at the scenario level vs reacting to \
+                the reverts and latency of draining Egress".to_string(),
+            arms: vec![
+                baseline("exogenous"),
+                arm("endogenous", |p| p.panic_dynamics = Some(PANIC_DYNAMICS)),
+            ],
+            metrics: vec![
+                metric("mean_panic", |_, r| r.mean_panic_level),
+                metric("peak_panic", |_, r| r.peak_panic_level),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("revert_count", |_, r| r.revert_count as f64),
+                metric("mean_stress_index", |_, r| r.mean_stress_index),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "fee_freeze",
+            scenarios: &["RW_FLASH_CRASH_OCT25"],
+            description: format!(
+                "Flash crash with the governor repricing through the crash vs fees frozen from tick {}, \
+                forked from the same run", FEE_FREEZE_TICK),
+            arms: vec![
+                baseline("governed"),
+                arm("frozen", |p| p.fee_freeze_tick = Some(FEE_FREEZE_TICK)),
+            ],
+            metrics: vec![
+                metric("avg_fee", |_, r| r.avg_fee),
+                metric("peak_fee", |_, r| r.peak_fee),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("revert_count", |_, r| r.revert_count as f64),
+                metric("mean_stress_index", |_, r| r.mean_stress_index),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "payout_slippage",
+            scenarios: &["WP_PEG_ELASTICITY"],
+            description: format!(
+                "Peg elasticity with payouts at the oracle price vs net of FX slippage \
+                (spread {:.2}% + {} x volatility, cap {:.1}%)",
+                PAYOUT_SLIPPAGE.0 * 100.0, PAYOUT_SLIPPAGE.1, PAYOUT_SLIPPAGE.2 * 100.0),
+            arms: vec![
+                baseline("oracle"),
+                arm("slipped", |p| p.payout_slippage = Some(PAYOUT_SLIPPAGE)),
+            ],
+            metrics: vec![
+                metric("peg_elasticity_pct", |_, r| r.peg_elasticity_pct),
+                metric("peak_payout_spread", |_, r| r.peak_payout_spread),
+                // Value beneficiaries lost to the spread over the run
+                metric("payout_slippage", |_, r| r.payout_slippage),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "retries",
+            scenarios: &["WP_INCENTIVE_DROUGHT"],
+            description: format!(
+                "Incentive drought with reverted value leaving for good vs {:.0}% re-sent after {} ticks",
+                RETRY_MODEL.0 * 100.0, RETRY_MODEL.1),
+            arms: vec![
+                baseline("no retries"),
+                arm("retried", |p| p.retry_model = Some(RETRY_MODEL)),
+            ],
+            metrics: vec![
+                // Packets admitted to the network, retries included
+                metric("offered", |_, r| r.spawn_count as f64),
+                metric("retry_count", |_, r| r.retry_count as f64),
+                metric("settlement_count", |_, r| r.settlement_count as f64),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("revert_count", |_, r| r.revert_count as f64),
+                metric("held_count", |_, r| r.held_count as f64),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "speculation",
+            scenarios: &["NORMAL_MARKET"],
+            description: format!(
+                "Normal market without speculators vs with {} at {:.0}% aggressiveness, \
+                scoring the organic-ratio detector against them",
+                SPECULATORS.0, SPECULATORS.1 * 100.0),
+            arms: vec![
+                baseline("none"),
+                arm("speculators", |p| p.speculators = Some(SPECULATORS)),
+            ],
+            metrics: vec![
+                metric("flagged_ticks", |_, r| {
+                    (r.speculation.true_positive_ticks + r.speculation.false_positive_ticks) as f64
+                }),
+                // Ticks speculators moved `speculation::SPECULATIVE_SHARE` of settled value
+                metric("speculative_ticks", |_, r| {
+                    (r.speculation.true_positive_ticks + r.speculation.false_negative_ticks) as f64
+                }),
+                metric("precision", |_, r| r.speculation.precision),
+                metric("recall", |_, r| r.speculation.recall),
+                metric("value_sent", |_, r| r.speculation.value_sent),
+                // Speculator bankroll lost to fees, demurrage and dissolution
+                metric("bankroll_lost", |p, r| p.speculators.map_or(0.0, |(count, _)| {
+                    count as f64 * speculation::SPECULATOR_BANKROLL - r.speculation.bankroll
+                })),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "liquidity_providers",
+            scenarios: &["WP_INCENTIVE_DROUGHT"],
+            description: format!(
+                "Incentive drought as provisioned vs with {} yield-seeking providers of {:.0} each at hurdle {:.1e}",
+                LIQUIDITY_PROVIDERS.0, LIQUIDITY_PROVIDERS.1, LIQUIDITY_PROVIDERS.2),
+            arms: vec![
+                baseline("provisioned"),
+                arm("providers", |p| p.liquidity_providers = Some(LIQUIDITY_PROVIDERS)),
+            ],
+            metrics: vec![
+                metric("deposits", |_, r| r.liquidity.deposits as f64),
+                metric("withdrawals", |_, r| r.liquidity.withdrawals as f64),
+                metric("supplied", |_, r| r.liquidity.supplied),
+                metric("withdrawn", |_, r| r.liquidity.withdrawn),
+                // Providers' stakes still in Egress inventory at the end
+                metric("staked", |_, r| r.liquidity.staked),
+                metric("fees_earned", |_, r| r.liquidity.fees_earned),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("held_count", |_, r| r.held_count as f64),
+                // Packets orbiting for lack of a liquid Egress at the end
+                metric("liquidity_orbits", |_, r| r.final_liquidity_orbits as f64),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "inventory_policies",
+            scenarios: SCALE_SCENARIOS,
+            description: "Scale scenario under each starting inventory policy".to_string(),
+            arms: INVENTORY_POLICIES.iter()
+                .map(|&policy| arm(&format!("{:?}", policy), move |p| p.initial_inventory = Some(policy)))
+                .collect(),
+            metrics: vec![
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("mean_liquidity_lambda", |_, r| r.mean_liquidity_lambda),
+                metric("mean_stress_index", |_, r| r.mean_stress_index),
+                metric("liquidity_orbits", |_, r| r.final_liquidity_orbits as f64),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "control_latency",
+            scenarios: &["GOVERNOR_STRESS"],
+            description: "Governor stress with the governor deciding less often and acting later".to_string(),
+            arms: CONTROL_LOOP_DELAYS.iter()
+                .map(|&(interval, lag)| arm(&format!("every {}, lag {}", interval, lag), move |p| {
+                    p.control_loop = Some((interval, lag));
+                }))
+                .collect(),
+            metrics: vec![
+                metric("loop_delay_ticks", |p, _| p.control_loop.map_or(0, |(i, l)| i + l) as f64),
+                metric("governor_decisions", |_, r| r.governor_decisions as f64),
+                metric("fee_rate_reversals", |_, r| r.fee_rate_reversals as f64),
+                metric("reversal_share", |_, r| reversal_share(r)),
+                metric("peg_elasticity_pct", |_, r| r.peg_elasticity_pct),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("mean_stress_index", |_, r| r.mean_stress_index),
+            ],
+            summarize: Some(stability_margin),
+        },
+        ComparisonSpec {
+            name: "gain_schedule",
+            scenarios: &["GOVERNOR_STRESS"],
+            // Where the integral has wound the PID term up to its clamp, as
+            // through most of GOVERNOR_STRESS, the gains cannot move the fee
+            // and the runs part only in the stretches off the clamp
+            description: "Governor stress on fixed gains vs gains scheduled by quadrant".to_string(),
+            arms: vec![
+                baseline("fixed"),
+                arm("scheduled", |p| p.quadrant_gains = SCHEDULED_GAINS),
+            ],
+            metrics: vec![
+                metric("avg_fee", |_, r| r.avg_fee),
+                metric("peak_fee", |_, r| r.peak_fee),
+                metric("governor_decisions", |_, r| r.governor_decisions as f64),
+                metric("fee_rate_reversals", |_, r| r.fee_rate_reversals as f64),
+                metric("reversal_share", |_, r| reversal_share(r)),
+                metric("peg_elasticity_pct", |_, r| r.peg_elasticity_pct),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("mean_stress_index", |_, r| r.mean_stress_index),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "quadrant_hysteresis",
+            scenarios: &["GOVERNOR_STRESS"],
+            description: format!(
+                "Governor stress switching quadrants at once vs after {} consecutive cycles",
+                QUADRANT_HYSTERESIS_CYCLES),
+            arms: vec![
+                baseline("immediate"),
+                arm("debounced", |p| p.quadrant_hysteresis = QUADRANT_HYSTERESIS_CYCLES),
+            ],
+            metrics: vec![
+                metric("quadrant_transitions", |_, r| r.quadrant_transition_count as f64),
+                metric("governor_decisions", |_, r| r.governor_decisions as f64),
+                // Share of decisions that changed the quadrant
+                metric("transition_share", |_, r| {
+                    r.quadrant_transition_count as f64 / r.governor_decisions.max(1) as f64
+                }),
+                metric("fee_rate_reversals", |_, r| r.fee_rate_reversals as f64),
+                metric("avg_fee", |_, r| r.avg_fee),
+                metric("peg_elasticity_pct", |_, r| r.peg_elasticity_pct),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+            ],
+            summarize: None,
+        },
+        ComparisonSpec {
+            name: "mpc",
+            scenarios: &["GOVERNOR_STRESS"],
+            // The core adjustment moves the base fee by at most ±2% and the
+            // surge and panic overrides set its level, so average fees barely
+            // differ; the planner follows the oscillating peg error and
+            // reverses more often, where the PID's wound-up integral holds it
+            // on the clamp
+            description: format!(
+                "Governor stress with the PID vs the model-predictive governor looking {} cycles ahead",
+                MPC_HORIZON),
+            arms: vec![
+                baseline("PID"),
+                arm("MPC", |p| p.mpc_horizon = MPC_HORIZON),
+            ],
+            metrics: vec![
+                metric("avg_fee", |_, r| r.avg_fee),
+                metric("peak_fee", |_, r| r.peak_fee),
+                metric("fee_rate_reversals", |_, r| r.fee_rate_reversals as f64),
+                metric("reversal_share", |_, r| reversal_share(r)),
+                metric("peg_elasticity_pct", |_, r| r.peg_elasticity_pct),
+                metric("settlement_rate", |_, r| r.settlement_rate),
+                metric("mean_packets_in_flight", |_, r| r.mean_packets_in_flight),
+                metric("mean_stress_index", |_, r| r.mean_stress_index),
+            ],
+            summarize: None,
+        },
+    ]
+}
+
+/// Stability margin of the control latency sweep: the longest loop delay
+/// before the first arm whose reversal share reaches
+/// `OSCILLATION_REVERSAL_SHARE`.
+fn stability_margin(arms: &[ComparisonArm]) -> String {
+    let mut by_delay: Vec<&ComparisonArm> = arms.iter().collect();
+    by_delay.sort_by(|a, b| a.metrics["loop_delay_ticks"].total_cmp(&b.metrics["loop_delay_ticks"]));
+    let margin = by_delay.iter()
+        .take_while(|a| a.metrics["reversal_share"] < OSCILLATION_REVERSAL_SHARE)
+        .map(|a| a.metrics["loop_delay_ticks"])
+        .last();
+    match margin {
+        Some(ticks) => format!("stability margin {} ticks of loop delay", ticks),
+        None => "no stability margin: the instantaneous loop already oscillates".to_string(),
+    }
+}
+
+// ─── Running a Comparison ───────────────────────────────────────────────────
+
+/// One arm's metrics, keyed by `ComparisonSpec::metrics` name.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparisonArm {
+    pub label: String,
+    pub metrics: BTreeMap<&'static str, f64>,
+}
+
+/// A comparison run on one scenario and seed.
+#[derive(Debug, Clone, Serialize)]
+pub struct Comparison {
+    pub name: &'static str,
+    pub scenario: String,
+    pub description: String,
+    pub arms: Vec<ComparisonArm>,
+    pub summary: Option<String>,
+}
+
+/// Run `scenario` on `seed` once per arm of `spec`.
+pub fn run_comparison(spec: &ComparisonSpec, scenario: &Scenario, seed: u64) -> Comparison {
+    let arms: Vec<ComparisonArm> = spec.arms.iter()
+        .map(|(label, toggle)| {
+            let mut params = RunParams::default();
+            toggle(&mut params);
+            let r = run_single_with(scenario, seed, None, params);
+            ComparisonArm {
+                label: label.clone(),
+                metrics: spec.metrics.iter().map(|&(name, read)| (name, read(&params, &r))).collect(),
+            }
+        })
+        .collect();
+    Comparison {
+        name: spec.name,
+        scenario: scenario.name.to_string(),
+        description: spec.description.clone(),
+        summary: spec.summarize.map(|summarize| summarize(&arms)),
+        arms,
+    }
+}
+
+/// Run every comparison registered for a scenario in `to_run`.
+pub fn run_all(to_run: &[&Scenario], seed: u64) -> Vec<Comparison> {
+    let specs = comparisons();
+    specs.iter()
+        .flat_map(|spec| spec.scenarios.iter().map(move |&name| (spec, name)))
+        .filter_map(|(spec, name)| {
+            let scenario = to_run.iter().find(|s| s.name == name)?;
+            Some(run_comparison(spec, scenario, seed))
+        })
+        .collect()
+}
+
+/// A metric value as the report prints it: counts whole, small values with
+/// enough digits to tell fees and shares apart.
+pub fn format_metric(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else if value.abs() >= 100.0 {
+        format!("{:.1}", value)
+    } else if value.abs() >= 0.01 {
+        format!("{:.4}", value)
+    } else {
+        format!("{:.3e}", value)
+    }
+}
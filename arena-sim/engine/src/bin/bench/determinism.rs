@@ -0,0 +1,50 @@
+// Determinism Self-Check — run a seed twice and byte-compare the outputs
+// Catches nondeterministic regressions (hash iteration order, wall-clock leaks) in the suite itself
+
+use crate::monte_carlo::{run_single_traced, RunParams};
+use crate::report::BenchResult;
+use crate::scenarios::Scenario;
+use crate::time_series::TimeSeriesRecorder;
+
+/// Serialize a run's metrics with wall-clock fields zeroed, so only simulated
+/// outputs participate in the comparison.
+fn canonical_metrics(result: &BenchResult) -> String {
+    let mut r = result.clone();
+    r.elapsed_ms = 0;
+    r.throughput_per_sec = 0.0;
+    serde_json::to_string(&r).expect("Failed to serialize BenchResult")
+}
+
+/// Run `seed` twice and compare the per-tick time series and per-run metrics
+/// byte for byte. Returns a description of the first divergence.
+pub fn verify_determinism(scenario: &Scenario, seed: u64) -> Result<(), String> {
+    let a = run_single_traced(scenario, seed, RunParams::default(), true);
+    let b = run_single_traced(scenario, seed, RunParams::default(), true);
+
+    let jsonl = |ts: Option<&TimeSeriesRecorder>| {
+        ts.expect("time series requested").to_jsonl().map_err(|e| e.to_string())
+    };
+    let jsonl_a = jsonl(a.time_series.as_ref())?;
+    let jsonl_b = jsonl(b.time_series.as_ref())?;
+    if jsonl_a != jsonl_b {
+        let (line, (la, lb)) = jsonl_a.lines().zip(jsonl_b.lines())
+            .enumerate()
+            .find(|(_, (la, lb))| la != lb)
+            .unwrap_or((0, ("<length differs>", "<length differs>")));
+        return Err(format!(
+            "time series diverges at line {}:\n      run 1: {}\n      run 2: {}",
+            line + 1, la, lb
+        ));
+    }
+
+    let metrics_a = canonical_metrics(&a.result);
+    let metrics_b = canonical_metrics(&b.result);
+    if metrics_a != metrics_b {
+        return Err(format!(
+            "per-run metrics differ:\n      run 1: {}\n      run 2: {}",
+            metrics_a, metrics_b
+        ));
+    }
+
+    Ok(())
+}
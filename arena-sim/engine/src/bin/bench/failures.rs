@@ -0,0 +1,74 @@
+// Failing-Seed Corpus — persist failing (scenario, seed) pairs for replay
+// Each failing Monte Carlo run is written to benchmark-results/failures/ with its final WorldState
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use arena_engine::WorldState;
+
+use crate::monte_carlo::RunOutput;
+use crate::report::BenchResult;
+use crate::scenarios::Scenario;
+
+pub const FAILURES_DIR: &str = "benchmark-results/failures";
+
+#[derive(Debug, Serialize)]
+struct FailureRecord<'a> {
+    scenario_name: &'a str,
+    seed: u64,
+    result: &'a BenchResult,
+    final_state: &'a WorldState,
+}
+
+/// The replayable part of a failure record.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FailureKey {
+    pub scenario_name: String,
+    pub seed: u64,
+}
+
+fn record_path(scenario_name: &str, seed: u64) -> PathBuf {
+    Path::new(FAILURES_DIR).join(format!("{}-seed-{}.json", scenario_name.to_lowercase(), seed))
+}
+
+/// Write (or overwrite) the corpus entry for a failing run.
+pub fn record(scenario: &Scenario, seed: u64, output: &RunOutput) {
+    let record = FailureRecord {
+        scenario_name: scenario.name,
+        seed,
+        result: &output.result,
+        final_state: &output.final_state,
+    };
+    let path = record_path(scenario.name, seed);
+    let write = || -> std::io::Result<()> {
+        std::fs::create_dir_all(FAILURES_DIR)?;
+        let json = serde_json::to_string_pretty(&record).map_err(std::io::Error::other)?;
+        std::fs::write(&path, json)
+    };
+    if let Err(e) = write() {
+        eprintln!("  Warning: failed to record failing seed {}: {}", path.display(), e);
+    }
+}
+
+/// Load every corpus entry, sorted by scenario then seed. Unreadable files are skipped.
+pub fn load_all() -> Vec<FailureKey> {
+    let Ok(entries) = std::fs::read_dir(FAILURES_DIR) else {
+        return Vec::new();
+    };
+    let mut keys: Vec<FailureKey> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|e| {
+            let json = std::fs::read_to_string(e.path()).ok()?;
+            match serde_json::from_str(&json) {
+                Ok(key) => Some(key),
+                Err(err) => {
+                    eprintln!("  Warning: skipping {}: {}", e.path().display(), err);
+                    None
+                }
+            }
+        })
+        .collect();
+    keys.sort_by(|a, b| a.scenario_name.cmp(&b.scenario_name).then(a.seed.cmp(&b.seed)));
+    keys
+}
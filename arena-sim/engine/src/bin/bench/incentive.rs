@@ -0,0 +1,205 @@
+// Incentive Drought Validation — paired normal vs drought runs, Monte Carlo over seeds
+// Whitepaper Metric 3: Egress profits spike >500% when liquidity dries up
+//
+// Each seed drives the same ChaCha8 traffic through two networks that differ
+// only in Egress inventory. The per-seed profit ratio is what a gram of
+// Egress inventory earns in the drought over what it earns normally, the
+// wake-up signal to liquidity providers; the claim holds when the lower
+// bound of its 95% CI clears the criteria.
+
+use arena_engine::{ArenaSimulation, NodeRole};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::Serialize;
+
+use crate::report::Stats;
+use crate::scenarios::IncentiveScenario;
+use crate::traffic::TrafficGenerator;
+
+/// Pass-rate threshold the seeds must meet (matches suite gate).
+const PASS_RATE: f64 = 0.933;
+
+/// One seed's normal and drought runs.
+#[derive(Debug, Clone, Serialize)]
+pub struct IncentivePair {
+    pub seed: u64,
+    /// Egress rewards per gram of starting Egress inventory
+    pub normal_egress_return: f64,
+    pub drought_egress_return: f64,
+    pub normal_egress_profit: f64,
+    pub drought_egress_profit: f64,
+    pub normal_avg_fee_rate: f64,
+    pub drought_avg_fee_rate: f64,
+    pub normal_peak_surge: f64,
+    pub drought_peak_surge: f64,
+    /// Drought over normal: Egress return, average fee rate, peak surge
+    pub profit_ratio: f64,
+    pub fee_ratio: f64,
+    pub surge_ratio: f64,
+    pub pass: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IncentiveReport {
+    pub scenario_name: String,
+    pub label: String,
+    pub category: String,
+    pub n_runs: usize,
+    pub min_profit_ratio: f64,
+    /// Share of seeds whose profit ratio exceeds `min_profit_ratio`
+    pub pass_rate: f64,
+    pub profit_ratio: Stats,
+    pub fee_ratio: Stats,
+    pub surge_ratio: Stats,
+    pub normal_egress_profit: Stats,
+    pub drought_egress_profit: Stats,
+    /// Pass rate at the suite gate and the profit ratio's CI lower bound
+    /// above `min_profit_ratio`
+    pub passes: bool,
+    pub individual_runs: Vec<IncentivePair>,
+}
+
+/// Run `n_runs` seeded pairs of `scenario` from `base_seed` and aggregate.
+pub fn run_incentive_monte_carlo(scenario: &IncentiveScenario, n_runs: usize, base_seed: u64) -> IncentiveReport {
+    let runs: Vec<IncentivePair> = (0..n_runs.max(1) as u64)
+        .map(|i| run_incentive_pair(scenario, base_seed + i))
+        .collect();
+    let stats = |f: fn(&IncentivePair) -> f64| Stats::from_samples(&runs.iter().map(f).collect::<Vec<_>>());
+    let min_profit_ratio = scenario.criteria.min_profit_ratio;
+    let pass_rate = runs.iter().filter(|r| r.pass).count() as f64 / runs.len() as f64;
+    let profit_ratio = stats(|r| r.profit_ratio);
+
+    IncentiveReport {
+        scenario_name: scenario.name.to_string(),
+        label: scenario.label.to_string(),
+        category: scenario.category.to_string(),
+        n_runs: runs.len(),
+        min_profit_ratio,
+        pass_rate,
+        passes: pass_rate >= PASS_RATE && profit_ratio.ci_lower > min_profit_ratio,
+        profit_ratio,
+        fee_ratio: stats(|r| r.fee_ratio),
+        surge_ratio: stats(|r| r.surge_ratio),
+        normal_egress_profit: stats(|r| r.normal_egress_profit),
+        drought_egress_profit: stats(|r| r.drought_egress_profit),
+        individual_runs: runs,
+    }
+}
+
+/// Both runs of one seed: same traffic, only Egress liquidity differs.
+pub fn run_incentive_pair(scenario: &IncentiveScenario, seed: u64) -> IncentivePair {
+    let normal = run_with_liquidity_factor(scenario, seed, 1.0, 0.0);
+    let drought = run_with_liquidity_factor(scenario, seed, scenario.drought_liquidity, scenario.drought_panic);
+    let ratio = |drought: f64, normal: f64| if normal > 0.0 { drought / normal } else { 0.0 };
+    let profit_ratio = ratio(drought.egress_return(), normal.egress_return());
+
+    IncentivePair {
+        seed,
+        normal_egress_return: normal.egress_return(),
+        drought_egress_return: drought.egress_return(),
+        normal_egress_profit: normal.egress_profit,
+        drought_egress_profit: drought.egress_profit,
+        normal_avg_fee_rate: normal.avg_fee_rate,
+        drought_avg_fee_rate: drought.avg_fee_rate,
+        normal_peak_surge: normal.peak_surge,
+        drought_peak_surge: drought.peak_surge,
+        profit_ratio,
+        fee_ratio: ratio(drought.avg_fee_rate, normal.avg_fee_rate),
+        surge_ratio: ratio(drought.peak_surge, normal.peak_surge),
+        pass: profit_ratio > scenario.criteria.min_profit_ratio,
+    }
+}
+
+struct RunMetrics {
+    avg_fee_rate: f64,
+    peak_surge: f64,
+    egress_profit: f64,
+    egress_inventory: f64,
+}
+
+impl RunMetrics {
+    fn egress_return(&self) -> f64 {
+        if self.egress_inventory > 0.0 { self.egress_profit / self.egress_inventory } else { 0.0 }
+    }
+}
+
+fn run_with_liquidity_factor(
+    scenario: &IncentiveScenario,
+    seed: u64,
+    liquidity_factor: f64,
+    panic: f64,
+) -> RunMetrics {
+    let nodes = scenario.nodes;
+    let mut sim = ArenaSimulation::new(nodes);
+    sim.set_gold_price(scenario.gold);
+    sim.set_demand_factor(0.0); // suppress engine traffic
+    sim.set_panic_level(panic);
+
+    // Scale Egress liquidity, and note what the run starts with
+    let egress: Vec<(u32, f64)> = sim
+        .get_nodes_filtered_core(Some(NodeRole::Egress), None, 0, usize::MAX)
+        .iter()
+        .map(|n| (n.id, n.inventory_crypto * liquidity_factor))
+        .collect();
+    for &(id, crypto) in &egress {
+        sim.set_node_crypto(id, crypto);
+    }
+    let egress_inventory = egress.iter().map(|&(_, crypto)| crypto).sum();
+
+    let ingress_nodes: Vec<u32> = sim
+        .get_nodes_filtered_core(Some(NodeRole::Ingress), None, 0, usize::MAX)
+        .iter()
+        .map(|n| n.id)
+        .collect();
+    let rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut traffic = TrafficGenerator::new(rng, ingress_nodes);
+    let lambda = TrafficGenerator::compute_lambda(scenario.demand, nodes);
+
+    let mut last_retention = 1.0_f64;
+    let mut fee_rate_sum = 0.0_f64;
+    let mut peak_surge = 0.0_f64;
+    let mut egress_profit = 0.0;
+
+    for _tick in 0..scenario.ticks {
+        traffic.set_demand_retention(last_retention);
+        for (node_id, amount) in traffic.generate_tick(lambda) {
+            sim.spawn_packet(node_id, amount);
+        }
+        let result = sim.tick_core();
+        last_retention = result.state.demand_retention;
+        fee_rate_sum += result.state.current_fee_rate;
+        peak_surge = peak_surge.max(result.state.surge_multiplier);
+        egress_profit = result.state.total_rewards_egress;
+    }
+
+    RunMetrics {
+        avg_fee_rate: if scenario.ticks > 0 { fee_rate_sum / scenario.ticks as f64 } else { 0.0 },
+        peak_surge,
+        egress_profit,
+        egress_inventory,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenarios::IncentiveCriteria;
+
+    #[test]
+    fn test_pair_replays_the_same_traffic_into_less_liquidity() {
+        let scenario = IncentiveScenario {
+            name: "TEST", label: "Test", category: "test",
+            gold: 163.0, demand: 0.8, nodes: 24, ticks: 200,
+            drought_liquidity: 0.1, drought_panic: 0.7,
+            criteria: IncentiveCriteria::default(), tags: &[],
+        };
+        let pair = run_incentive_pair(&scenario, 3);
+        assert!(pair.normal_egress_profit > 0.0 && pair.drought_egress_profit > 0.0);
+        assert!(pair.drought_egress_return > pair.normal_egress_return, "{:?}", pair);
+        assert_eq!(pair.pass, pair.profit_ratio > 5.0);
+
+        let report = run_incentive_monte_carlo(&scenario, 2, 3);
+        assert_eq!(report.n_runs, 2);
+        assert_eq!(report.individual_runs[0].profit_ratio, pair.profit_ratio, "seeded pairs are reproducible");
+    }
+}
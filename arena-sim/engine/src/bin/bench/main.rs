@@ -3,10 +3,21 @@
 //
 // Usage:
 //   cargo run --release --bin bench                     # Run all scenarios (30 runs each)
-//   cargo run --release --bin bench -- --runs 5         # Quick mode (5 runs each)
+//   cargo run --release --bin bench -- --runs 5         # Fewer runs (5 each)
 //   cargo run --release --bin bench -- WP_BANK_RUN      # Filter by name
 //   cargo run --release --bin bench -- --time-series    # Enable JSONL output
+//   cargo run --release --bin bench -- --governor-io     # JSONL output plus governor inputs/outputs
+//   cargo run --release --bin bench -- --volatility ewma:30  # Governor volatility: cv|ewma|parkinson|garch[:window]
 //   cargo run --release --bin bench -- --seed 42        # Custom base seed
+//   cargo run --release --bin bench -- --calibrate 95   # Demand hitting 95% settlement
+//   cargo run --release --bin bench -- --breaking-point demand     # Ramp until failure
+//   cargo run --release --bin bench -- --breaking-point liquidity  # Drain until failure
+//   cargo run --release --bin bench -- --verify-determinism  # Seed-0 double-run check first
+//   cargo run --release --bin bench -- --format md > summary.md  # Markdown table on stdout
+//   cargo run --release --bin bench -- --replay-failures  # Re-run seeds in benchmark-results/failures/
+//   cargo run --release --bin bench -- --adaptive 1.0 --runs 200  # Runs until settle% CI ±1.0 (cap 200)
+//   cargo run --release --bin bench -- --include-tags fast --exclude-tags scale  # Compose by tag
+//   cargo run --release --bin bench -- --quick           # 5 runs, single-phase runs capped at 500 ticks
 
 mod report;
 mod scenarios;
@@ -14,19 +25,76 @@ mod monte_carlo;
 mod traffic;
 mod metrics;
 mod time_series;
+mod calibration;
+mod breaking_point;
+mod determinism;
+mod failures;
+mod series;
+mod analytic;
+mod incentive;
+mod comparisons;
 
 use report::*;
 use scenarios::*;
-use metrics::run_incentive_comparison;
+use metrics::run_resource_economics;
+use arena_engine::{held_sweep, volatility};
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
+/// `--quick`: runs per scenario, and the tick cap on single-phase scenarios.
+const QUICK_RUNS: usize = 5;
+const QUICK_MAX_TICKS: u64 = 500;
+
 // ─── CLI Parsing ────────────────────────────────────────────────────────────
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Markdown,
+}
+
 struct CliArgs {
     runs: usize,
     seed: u64,
     time_series: bool,
+    governor_io: bool,
+    volatility: Option<(volatility::VolatilityEstimator, u32)>,
     filter: Option<String>,
+    calibrate: Option<f64>,
+    breaking_point: Option<breaking_point::EscalationMode>,
+    verify_determinism: bool,
+    format: OutputFormat,
+    replay_failures: bool,
+    adaptive: Option<f64>,
+    include_tags: Vec<Tag>,
+    exclude_tags: Vec<Tag>,
+    quick: bool,
+}
+
+/// Comma-separated tag list; unknown tags are reported and skipped.
+fn parse_tags(arg: &str) -> Vec<Tag> {
+    arg.split(',')
+        .filter_map(|name| {
+            let tag = Tag::from_arg(name.trim());
+            if tag.is_none() {
+                eprintln!("Unknown tag: {} (expected fast|slow|whitepaper|scale)", name);
+            }
+            tag
+        })
+        .collect()
+}
+
+/// `name[:window]` of a volatility estimator; the window defaults to 20 ticks.
+fn parse_volatility(arg: &str) -> Option<(volatility::VolatilityEstimator, u32)> {
+    let (name, window) = arg.split_once(':').unwrap_or((arg, ""));
+    let estimator = match name {
+        "cv" => volatility::VolatilityEstimator::RollingCv,
+        "ewma" => volatility::VolatilityEstimator::EwmaLogReturn,
+        "parkinson" => volatility::VolatilityEstimator::Parkinson,
+        "garch" => volatility::VolatilityEstimator::GarchLite,
+        _ => return None,
+    };
+    let window = if window.is_empty() { volatility::DEFAULT_WINDOW } else { window.parse().ok()? };
+    Some((estimator, window))
 }
 
 fn parse_args() -> CliArgs {
@@ -35,7 +103,18 @@ fn parse_args() -> CliArgs {
         runs: 30,
         seed: 0,
         time_series: false,
+        governor_io: false,
+        volatility: None,
         filter: None,
+        calibrate: None,
+        breaking_point: None,
+        verify_determinism: false,
+        format: OutputFormat::Text,
+        replay_failures: false,
+        adaptive: None,
+        include_tags: Vec::new(),
+        exclude_tags: Vec::new(),
+        quick: false,
     };
 
     let mut i = 0;
@@ -56,6 +135,69 @@ fn parse_args() -> CliArgs {
             "--time-series" => {
                 cli.time_series = true;
             }
+            "--governor-io" => {
+                cli.time_series = true;
+                cli.governor_io = true;
+            }
+            "--format" => {
+                i += 1;
+                match args.get(i).map(String::as_str) {
+                    Some("md") | Some("markdown") => cli.format = OutputFormat::Markdown,
+                    Some("text") => cli.format = OutputFormat::Text,
+                    other => eprintln!("Unknown --format: {:?} (expected text|md)", other),
+                }
+            }
+            "--adaptive" => {
+                i += 1;
+                if i < args.len() {
+                    cli.adaptive = args[i].parse().ok();
+                }
+            }
+            "--include-tags" => {
+                i += 1;
+                if i < args.len() {
+                    cli.include_tags.extend(parse_tags(&args[i]));
+                }
+            }
+            "--exclude-tags" => {
+                i += 1;
+                if i < args.len() {
+                    cli.exclude_tags.extend(parse_tags(&args[i]));
+                }
+            }
+            "--quick" => {
+                cli.quick = true;
+            }
+            "--volatility" => {
+                i += 1;
+                if i < args.len() {
+                    cli.volatility = parse_volatility(&args[i]);
+                    if cli.volatility.is_none() {
+                        eprintln!("Unknown --volatility: {} (expected cv|ewma|parkinson|garch[:window])", args[i]);
+                    }
+                }
+            }
+            "--replay-failures" => {
+                cli.replay_failures = true;
+            }
+            "--verify-determinism" => {
+                cli.verify_determinism = true;
+            }
+            "--calibrate" => {
+                i += 1;
+                if i < args.len() {
+                    cli.calibrate = args[i].parse().ok();
+                }
+            }
+            "--breaking-point" => {
+                i += 1;
+                if i < args.len() {
+                    cli.breaking_point = breaking_point::EscalationMode::from_arg(&args[i]);
+                    if cli.breaking_point.is_none() {
+                        eprintln!("Unknown --breaking-point mode: {} (expected demand|liquidity)", args[i]);
+                    }
+                }
+            }
             arg if !arg.starts_with('-') => {
                 cli.filter = Some(arg.to_string());
             }
@@ -65,33 +207,194 @@ fn parse_args() -> CliArgs {
         }
         i += 1;
     }
+    if cli.quick {
+        cli.runs = cli.runs.min(QUICK_RUNS);
+    }
 
     cli
 }
 
+// ─── Determinism Check ──────────────────────────────────────────────────────
+
+/// Run every selected scenario's first seed twice; exit non-zero on any divergence.
+fn run_determinism_check(cli: &CliArgs, to_run: &[&Scenario]) {
+    eprintln!("\n  Verifying determinism (seed {} run twice per scenario)...", cli.seed);
+    let mut diverged = 0;
+    for scenario in to_run {
+        if let Err(msg) = determinism::verify_determinism(scenario, cli.seed) {
+            eprintln!("  NONDETERMINISTIC: {} — {}", scenario.name, msg);
+            diverged += 1;
+        }
+    }
+    if diverged > 0 {
+        eprintln!("\n  Determinism check FAILED for {} of {} scenario(s)\n", diverged, to_run.len());
+        std::process::exit(1);
+    }
+    eprintln!("  Determinism check passed for {} scenario(s)", to_run.len());
+}
+
+// ─── Failure Replay ─────────────────────────────────────────────────────────
+
+/// Re-run every recorded failing seed whose scenario matches the filter.
+/// Exits non-zero if any still fails.
+fn run_failure_replay(to_run: &[&Scenario]) {
+    let corpus = failures::load_all();
+    let selected: Vec<(&Scenario, u64)> = corpus.iter()
+        .filter_map(|k| {
+            to_run.iter()
+                .find(|s| s.name == k.scenario_name)
+                .map(|s| (*s, k.seed))
+        })
+        .collect();
+
+    println!("\n  Replaying {} recorded failure(s) from {}/\n", selected.len(), failures::FAILURES_DIR);
+    println!("  {:<36} {:>8} {:>10} {:>6} {:>8}", "Scenario", "Seed", "Settle%", "Held", "Status");
+    println!("  {}", "-".repeat(74));
+
+    let mut still_failing = 0;
+    for (scenario, seed) in &selected {
+        let r = monte_carlo::run_single(scenario, *seed, None);
+        if !r.pass {
+            still_failing += 1;
+        }
+        println!("  {:<36} {:>8} {:>9.1}% {:>6} {:>8}",
+            scenario.label, seed, r.settlement_rate, r.held_count,
+            if r.pass { "FIXED" } else { "FAIL" });
+    }
+
+    println!("  {}", "-".repeat(74));
+    println!("  Still failing: {}  Fixed: {}\n", still_failing, selected.len() - still_failing);
+    if still_failing > 0 {
+        std::process::exit(1);
+    }
+}
+
+// ─── Calibration Mode ───────────────────────────────────────────────────────
+
+fn run_calibration(cli: &CliArgs, to_run: &[&Scenario], target: f64) {
+    println!("\n  Arena Demand Calibration (target settlement rate {:.1}%)", target);
+    println!("  Runs/evaluation: {} | Base seed: {}\n", cli.runs, cli.seed);
+    println!("  {:<36} {:>8} {:>8} {:>8} {:>10} {:>10}",
+        "Scenario", "Scale", "Demand", "Settle%", "Pkts/tick", "Settl/tick");
+    println!("  {}", "-".repeat(86));
+
+    let mut results = Vec::new();
+    for scenario in to_run {
+        let r = calibration::calibrate_demand(scenario, target, cli.runs, cli.seed);
+        if r.met {
+            println!("  {:<36} {:>8.3} {:>8.3} {:>7.1}% {:>10.2} {:>10.2}",
+                r.label, r.demand_scale, r.demand, r.settlement_rate,
+                r.packets_per_tick, r.settlements_per_tick);
+        } else {
+            println!("  {:<36} {:>8} target not reachable", r.label, "-");
+        }
+        results.push(r);
+    }
+
+    let ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+    let dir = std::path::Path::new("benchmark-results");
+    std::fs::create_dir_all(dir).expect("Failed to create benchmark-results/");
+    let path = dir.join(format!("calibration-{}.json", ts));
+    let json = serde_json::to_string_pretty(&results).expect("Failed to serialize");
+    std::fs::write(&path, &json).expect("Failed to write calibration file");
+    println!("\n  Results saved to: {}\n", path.display());
+}
+
+// ─── Breaking-Point Mode ────────────────────────────────────────────────────
+
+fn run_breaking_point(cli: &CliArgs, to_run: &[&Scenario], mode: breaking_point::EscalationMode) {
+    println!("\n  Arena Breaking-Point Escalation ({:?})", mode);
+    println!("  Runs/step: {} | Base seed: {}\n", cli.runs, cli.seed);
+    println!("  {:<36} {:>7} {:>6} {:>10} {:>10} {:>8}",
+        "Scenario", "Nodes", "Steps", "MaxPkt/t", "MinLambda", "Status");
+    println!("  {}", "-".repeat(82));
+
+    let mut reports = Vec::new();
+    for scenario in to_run {
+        let r = breaking_point::find_breaking_point(scenario, mode, cli.runs, cli.seed);
+        let status = if !r.baseline_passes {
+            "BASELINE FAIL"
+        } else if r.broke {
+            "BROKE"
+        } else {
+            "HELD"
+        };
+        println!("  {:<36} {:>7} {:>6} {:>10.2} {:>10.3} {:>8}",
+            r.label, r.nodes, r.steps.len(),
+            r.max_sustainable_packets_per_tick, r.min_viable_lambda, status);
+        reports.push(r);
+    }
+
+    let ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+    let dir = std::path::Path::new("benchmark-results");
+    std::fs::create_dir_all(dir).expect("Failed to create benchmark-results/");
+    let path = dir.join(format!("breaking-point-{}.json", ts));
+    let json = serde_json::to_string_pretty(&reports).expect("Failed to serialize");
+    std::fs::write(&path, &json).expect("Failed to write breaking-point file");
+    println!("\n  Results saved to: {}\n", path.display());
+}
+
 // ─── Main ───────────────────────────────────────────────────────────────────
 
 fn main() {
     let cli = parse_args();
-    let all_scenarios = scenarios();
-
-    let to_run: Vec<&Scenario> = match &cli.filter {
-        Some(f) => {
-            let f_lower = f.to_lowercase();
-            all_scenarios.iter()
-                .filter(|s| s.name.to_lowercase().contains(&f_lower)
-                          || s.label.to_lowercase().contains(&f_lower)
-                          || s.category.to_lowercase().contains(&f_lower))
-                .collect()
+    let mut all_scenarios = scenarios();
+    let mut all_incentive = incentive_scenarios();
+    if cli.quick {
+        // Chain phases keep their lengths (and their shock ticks)
+        for s in all_scenarios.iter_mut().filter(|s| s.chain.is_empty()) {
+            s.ticks = s.ticks.min(QUICK_MAX_TICKS);
         }
-        None => all_scenarios.iter().collect(),
-    };
+        for s in &mut all_incentive {
+            s.ticks = s.ticks.min(QUICK_MAX_TICKS);
+        }
+    }
 
-    if to_run.is_empty() {
-        eprintln!("No scenarios match filter: {:?}", cli.filter);
+    // Name/label/category filter, then tags, for either scenario type
+    let f_lower = cli.filter.as_ref().map(|f| f.to_lowercase());
+    let selected = |names: [&str; 3], tags: &dyn Fn(&[Tag]) -> bool| {
+        f_lower.as_ref().is_none_or(|f| names.iter().any(|n| n.to_lowercase().contains(f)))
+            && (cli.include_tags.is_empty() || tags(&cli.include_tags))
+            && !tags(&cli.exclude_tags)
+    };
+    let to_run: Vec<&Scenario> = all_scenarios.iter()
+        .filter(|s| selected([s.name, s.label, s.category], &|t| s.has_any_tag(t)))
+        .collect();
+    let incentive_to_run: Vec<&IncentiveScenario> = all_incentive.iter()
+        .filter(|s| selected([s.name, s.label, s.category], &|t| s.has_any_tag(t)))
+        .collect();
+
+    if to_run.is_empty() && incentive_to_run.is_empty() {
+        eprintln!("No scenarios match filter: {:?}, include tags {:?}, exclude tags {:?}",
+            cli.filter, cli.include_tags, cli.exclude_tags);
         std::process::exit(1);
     }
 
+    if cli.verify_determinism {
+        run_determinism_check(&cli, &to_run);
+    }
+
+    if cli.replay_failures {
+        run_failure_replay(&to_run);
+        return;
+    }
+    if let Some(target) = cli.calibrate {
+        run_calibration(&cli, &to_run, target);
+        return;
+    }
+    if let Some(mode) = cli.breaking_point {
+        run_breaking_point(&cli, &to_run, mode);
+        return;
+    }
+
+    // In markdown mode stdout carries only the markdown; progress goes to stderr
+    let markdown = cli.format == OutputFormat::Markdown;
+    macro_rules! status {
+        ($($arg:tt)*) => {
+            if markdown { eprintln!($($arg)*) } else { println!($($arg)*) }
+        };
+    }
+
     let ts_dir = if cli.time_series {
         let dir = std::path::Path::new("benchmark-results/time-series");
         Some(dir.to_path_buf())
@@ -99,23 +402,47 @@ fn main() {
         None
     };
 
-    println!("\n  Arena Benchmark Runner v1.0.0 (SEC/Economist-Grade)");
-    println!("  PRNG: ChaCha8Rng | Runs/scenario: {} | Base seed: {}", cli.runs, cli.seed);
-    println!("  Running {} scenario(s)...\n", to_run.len());
-    println!("  {:<36} {:>5} {:>10} {:>12} {:>8} {:>6} {:>7}",
+    status!("\n  Arena Benchmark Runner v1.0.0 (SEC/Economist-Grade)");
+    match cli.adaptive {
+        Some(hw) => status!("  PRNG: ChaCha8Rng | Runs/scenario: adaptive (settle% CI ±{}, cap {}) | Base seed: {}",
+            hw, cli.runs, cli.seed),
+        None => status!("  PRNG: ChaCha8Rng | Runs/scenario: {} | Base seed: {}", cli.runs, cli.seed),
+    }
+    if cli.quick {
+        status!("  Quick mode: single-phase scenarios capped at {} ticks; results are indicative only",
+            QUICK_MAX_TICKS);
+    }
+    status!("  Running {} scenario(s)...\n", to_run.len());
+    status!("  {:<36} {:>5} {:>10} {:>12} {:>8} {:>6} {:>7}",
         "Scenario", "Pass%", "Settle%", "Conserv(N)", "Peg%", "Held", "Time");
-    println!("  {}", "-".repeat(88));
+    status!("  {}", "-".repeat(88));
 
     let suite_start = Instant::now();
     let mut mc_reports = Vec::new();
 
+    let run_params = monte_carlo::RunParams {
+        governor_io: cli.governor_io,
+        volatility: cli.volatility,
+        ..Default::default()
+    };
     for scenario in &to_run {
-        let report = monte_carlo::run_monte_carlo(
-            scenario,
-            cli.runs,
-            cli.seed,
-            ts_dir.as_deref(),
-        );
+        let report = match cli.adaptive {
+            Some(half_width) => monte_carlo::run_monte_carlo_adaptive(
+                scenario,
+                half_width,
+                cli.runs,
+                cli.seed,
+                ts_dir.as_deref(),
+                run_params,
+            ),
+            None => monte_carlo::run_monte_carlo(
+                scenario,
+                cli.runs,
+                cli.seed,
+                ts_dir.as_deref(),
+                run_params,
+            ),
+        };
 
         let pass_pct = report.pass_rate * 100.0;
         let settle_mean = report.settlement_rate.mean;
@@ -126,8 +453,9 @@ fn main() {
         let time_mean = report.elapsed_ms.mean;
 
         let status = if pass_pct >= 93.3 { "PASS" } else { "FAIL" };
+        let n_note = if cli.adaptive.is_some() { format!(" (n={})", report.n_runs) } else { String::new() };
 
-        println!("  {:<36} {:>4}% {:>6.1}±{:<3.1} {:>12.2e} {:>7.1}% {:>5.0} {:>5.0}ms  {}",
+        status!("  {:<36} {:>4}% {:>6.1}±{:<3.1} {:>12.2e} {:>7.1}% {:>5.0} {:>5.0}ms  {}{}",
             report.label,
             pass_pct as u32,
             settle_mean, settle_ci,
@@ -136,11 +464,57 @@ fn main() {
             held_mean,
             time_mean,
             status,
+            n_note,
         );
+        // Chained scenarios: phase breakdown of the base-seed run
+        if let Some(first) = report.individual_runs.first() {
+            for phase in &first.phases {
+                status!("      ↳ {:<30} {:>5}t  settled {:>5}  reverted {:>5}  held at handover {:>5}",
+                    phase.name, phase.ticks, phase.settlements, phase.reverts, phase.held_at_end);
+            }
+        }
+        // Failure modes: mean reverts per run by reason
+        let reasons = &report.revert_reasons;
+        if reasons.iter().any(|r| r.mean > 0.0) {
+            status!("      ↳ reverts/run: TTL {:.1}, orbit timeout {:.1}, no liquid Egress {:.1}, cancelled {:.1}, circuit breaker {:.1}, hop limit {:.1}, dropped {:.1}, censored {:.1}, node removed {:.1}",
+                reasons[0].mean, reasons[1].mean, reasons[2].mean, reasons[3].mean, reasons[4].mean,
+                reasons[5].mean, reasons[6].mean, reasons[7].mean, reasons[8].mean);
+        }
+        // Latency service levels: p95 settlement ticks by tier, and runs that missed one
+        if !scenario.criteria.latency_slas.is_empty() {
+            let p95 = &report.tier_p95_settle_ticks;
+            let missed = report.individual_runs.iter().filter(|r| !r.sla_failures.is_empty()).count();
+            status!("      ↳ p95 settle ticks: L0-L3 {:.0}/{:.0}/{:.0}/{:.0}; runs missing an SLA {}",
+                p95[0].mean, p95[1].mean, p95[2].mean, p95[3].mean, missed);
+        }
+        // Held at the end: how old, which tiers, and what forced resolution left
+        if report.held_count.mean > 0.0 {
+            let (age, tier) = (&report.held_by_age, &report.held_by_tier);
+            status!("      ↳ held/run: age <{} {:.1}, <{} {:.1}, <{} {:.1}, older {:.1}; L0-L3 {:.1}/{:.1}/{:.1}/{:.1}; unresolved after sweep {:.1}",
+                held_sweep::AGE_BAND_EDGES[0], age[0].mean, held_sweep::AGE_BAND_EDGES[1], age[1].mean,
+                held_sweep::AGE_BAND_EDGES[2], age[2].mean, age[3].mean,
+                tier[0].mean, tier[1].mean, tier[2].mean, tier[3].mean,
+                report.held_unresolved.mean);
+        }
 
         mc_reports.push(report);
     }
 
+    // Paired incentive scenarios: pass rate over seeds, profit ratio with its CI
+    let mut incentive_reports = Vec::new();
+    for scenario in &incentive_to_run {
+        let report = incentive::run_incentive_monte_carlo(scenario, cli.runs, cli.seed);
+        let ratio = &report.profit_ratio;
+        status!("  {:<36} {:>4}% profit ratio {:.2}x [{:.2}, {:.2}] (min {:.1}x)  {}",
+            report.label,
+            (report.pass_rate * 100.0) as u32,
+            ratio.mean, ratio.ci_lower, ratio.ci_upper,
+            report.min_profit_ratio,
+            if report.passes { "PASS" } else { "FAIL" },
+        );
+        incentive_reports.push(report);
+    }
+
     let suite_elapsed = suite_start.elapsed();
 
     // ─── Whitepaper Validation ──────────────────────────────────────────
@@ -157,15 +531,13 @@ fn main() {
         .map(|r| r.peg_elasticity_pct.mean >= 95.0)
         .unwrap_or(true);
 
-    // Check Incentive (run paired comparison if WP_INCENTIVE_DROUGHT was run)
-    let incentive_passes = mc_reports.iter()
-        .find(|r| r.scenario_name == "WP_INCENTIVE_DROUGHT")
-        .map(|_r| {
-            // Run paired comparison: same traffic, different Egress liquidity
-            let comp = run_incentive_comparison(100, 2000, 163.0, 0.8, cli.seed);
-            comp.passes
-        })
-        .unwrap_or(true);
+    // Check Incentive (paired profit ratio, CI lower bound above 500%)
+    let incentive = incentive_reports.iter()
+        .find(|r| r.scenario_name == "WP_INCENTIVE_RATIO");
+    let incentive_passes = incentive.map(|r| r.passes).unwrap_or(true);
+
+    // Paired runs of one scenario and seed under toggled settings
+    let comparisons = comparisons::run_all(&to_run, cli.seed);
 
     // Check Demurrage
     let demurrage_passes = mc_reports.iter()
@@ -195,21 +567,77 @@ fn main() {
 
     // ─── Summary ────────────────────────────────────────────────────────
 
-    let total = mc_reports.len();
-    let passed = mc_reports.iter().filter(|r| r.pass_rate >= 0.933).count();
+    let total = mc_reports.len() + incentive_reports.len();
+    let passed = mc_reports.iter().filter(|r| r.pass_rate >= 0.933).count()
+        + incentive_reports.iter().filter(|r| r.passes).count();
     let failed = total - passed;
 
-    println!("  {}", "-".repeat(88));
-    println!("  Total: {}  Passed: {}  Failed: {}  Suite time: {:.1}s\n",
+    status!("  {}", "-".repeat(88));
+    status!("  Total: {}  Passed: {}  Failed: {}  Suite time: {:.1}s\n",
         total, passed, failed, suite_elapsed.as_secs_f64());
 
-    println!("  Whitepaper Validation:");
-    println!("    Bank Run No-Fail:     {}", if wp_validation.bank_run_no_fail { "PASS" } else { "FAIL" });
-    println!("    Peg Elasticity ≥95%:  {}", if wp_validation.peg_elasticity_95pct { "PASS" } else { "FAIL" });
-    println!("    Incentive >500%:      {}", if wp_validation.incentive_ratio_500pct { "PASS" } else { "FAIL" });
-    println!("    Demurrage Decay:      {}", if wp_validation.demurrage_decay_to_zero { "PASS" } else { "FAIL" });
-    println!("    Route Healing:        {}", if wp_validation.route_healing_zero_loss { "PASS" } else { "FAIL" });
-    println!("    Max Norm Conservation: {:.2e}\n", wp_validation.max_normalized_conservation);
+    status!("  Whitepaper Validation:");
+    status!("    Bank Run No-Fail:     {}", if wp_validation.bank_run_no_fail { "PASS" } else { "FAIL" });
+    status!("    Peg Elasticity ≥95%:  {}", if wp_validation.peg_elasticity_95pct { "PASS" } else { "FAIL" });
+    status!("    Incentive >500%:      {}", if wp_validation.incentive_ratio_500pct { "PASS" } else { "FAIL" });
+    status!("    Demurrage Decay:      {}", if wp_validation.demurrage_decay_to_zero { "PASS" } else { "FAIL" });
+    status!("    Route Healing:        {}", if wp_validation.route_healing_zero_loss { "PASS" } else { "FAIL" });
+    status!("    Max Norm Conservation: {:.2e}", wp_validation.max_normalized_conservation);
+    if let Some(r) = incentive {
+        status!("    Incentive profit:      {:.2} -> {:.2} Egress rewards, {:.2}x return on inventory [{:.2}, {:.2}] over {} seeds",
+            r.normal_egress_profit.mean, r.drought_egress_profit.mean,
+            r.profit_ratio.mean, r.profit_ratio.ci_lower, r.profit_ratio.ci_upper, r.n_runs);
+        status!("    Incentive fee/surge:   {:.2}x fee rate, {:.2}x peak surge",
+            r.fee_ratio.mean, r.surge_ratio.mean);
+    }
+    status!("");
+
+    // Never-routing packets against the closed-form demurrage reference
+    let analytic_cross_check = analytic::run_analytic_cross_check();
+    let analytic_failed = analytic_cross_check.iter().filter(|c| !c.pass).count();
+    status!("  Analytic Demurrage Cross-Check (value tolerance {:.0e}):", analytic::VALUE_TOLERANCE);
+    for c in &analytic_cross_check {
+        status!("    {:?} {:<9} exit age {:>5} (closed form {:>5}), {} (closed form {}), max value error {:.2e}  {}",
+            c.tier, c.path.label(),
+            c.simulated_exit_age, c.predicted_exit_age,
+            c.simulated_outcome, c.predicted_outcome,
+            c.max_relative_value_error, if c.pass { "PASS" } else { "FAIL" });
+    }
+    status!("");
+
+    // Scale scenarios with node running costs netted against fees
+    let resource_economics: Vec<_> = to_run.iter()
+        .filter(|s| comparisons::SCALE_SCENARIOS.contains(&s.name))
+        .filter_map(|s| run_resource_economics(s, cli.seed))
+        .collect();
+    if !resource_economics.is_empty() {
+        status!("  Node Resource Economics (seed {}):", cli.seed);
+        for r in &resource_economics {
+            let s = &r.summary;
+            status!("    {:<10} {:>5}n {:>6.1} days: {:>8.0} GB pledged, {:>8.1} kWh, {:.2} Wh and {:.4} per settlement, fees {:.2} vs costs {:.2}",
+                r.scenario, r.nodes, s.days, s.storage_pledged_gb, s.energy_kwh,
+                s.energy_wh_per_settlement, s.cost_per_settlement, s.revenue, s.cost);
+            for role in &s.by_role {
+                status!("      {:<8} {:>3}/{:<3} profitable, net {:>10.2}",
+                    format!("{:?}", role.role), role.profitable_nodes, role.nodes, role.net);
+            }
+        }
+        status!("");
+    }
+
+    for c in &comparisons {
+        status!("  {} ({}, seed {}):", c.description, c.scenario, cli.seed);
+        for arm in &c.arms {
+            let metrics: Vec<String> = arm.metrics.iter()
+                .map(|(name, &value)| format!("{} {}", name, comparisons::format_metric(value)))
+                .collect();
+            status!("    {:<18} {}", arm.label, metrics.join(", "));
+        }
+        if let Some(summary) = &c.summary {
+            status!("    {}", summary);
+        }
+        status!("");
+    }
 
     // ─── Write JSON Report ──────────────────────────────────────────────
 
@@ -221,6 +649,9 @@ fn main() {
         version: "1.0.0",
         prng: "ChaCha8Rng",
         n_runs_per_scenario: cli.runs,
+        adaptive_ci_half_width: cli.adaptive,
+        quick: cli.quick,
+        route_scoring_mode: format!("{:?}", monte_carlo::RunParams::default().route_scoring),
         summary: Summary {
             total,
             passed,
@@ -228,7 +659,11 @@ fn main() {
             pass_rate: passed as f64 / total as f64,
         },
         whitepaper_validation: wp_validation,
+        comparisons,
+        analytic_cross_check,
+        resource_economics,
         scenarios: mc_reports,
+        incentive_scenarios: incentive_reports,
     };
 
     let dir = std::path::Path::new("benchmark-results");
@@ -238,9 +673,16 @@ fn main() {
     let path = dir.join(format!("bench-{}.json", timestamp));
     let json = serde_json::to_string_pretty(&report).expect("Failed to serialize");
     std::fs::write(&path, &json).expect("Failed to write benchmark file");
-    println!("  Results saved to: {}\n", path.display());
+    status!("  Results saved to: {}\n", path.display());
+
+    if markdown {
+        let md = report.to_markdown();
+        let md_path = dir.join(format!("bench-{}.md", timestamp));
+        std::fs::write(&md_path, &md).expect("Failed to write markdown summary");
+        print!("{}", md);
+    }
 
-    if failed > 0 {
+    if failed > 0 || analytic_failed > 0 {
         std::process::exit(1);
     }
 }
@@ -1,7 +1,12 @@
-// Per-Tick Metric Trackers — Peg Elasticity, Conservation, Incentive Comparison
+// Per-Tick Metric Trackers — Peg Elasticity, Conservation, Demand Curve, Resource Economics
 // Tracks correct whitepaper-aligned metrics with proper normalization
 
 use arena_engine::*;
+use arena_engine::resources::ResourceSummary;
+use serde::Serialize;
+
+use crate::monte_carlo::{run_single_with, RunParams};
+use crate::scenarios::Scenario;
 
 // ─── Peg Elasticity Tracker ─────────────────────────────────────────────────
 
@@ -27,14 +32,17 @@ impl PegTracker {
     }
 
     /// Record a tick's peg state.
-    /// effective_rate = gold_price × (1 - current_fee_rate)
-    /// deviation = |effective_rate - gold_price| / gold_price = current_fee_rate
+    /// effective_rate = gold_price × (1 - current_fee_rate) × (1 - payout_spread)
+    /// deviation = |effective_rate - gold_price| / gold_price
+    ///           = current_fee_rate + payout_spread × (1 - current_fee_rate)
     pub fn record_tick(&mut self, state: &WorldState) {
         self.total_ticks += 1;
 
-        // The deviation from peg is simply the fee rate (how much the effective
-        // exchange rate differs from spot gold)
-        let deviation = state.current_fee_rate;
+        // The deviation from peg is the fee rate plus the beneficiary's FX
+        // spread on what is left (how much the effective exchange rate
+        // differs from spot gold); without payout slippage it is the fee rate
+        let deviation = state.current_fee_rate
+            + state.payout_spread * (1.0 - state.current_fee_rate);
         self.deviations.push(deviation);
         self.max_deviation = self.max_deviation.max(deviation);
 
@@ -81,135 +89,128 @@ impl ConservationTracker {
         if self.total_throughput <= 0.0 { return 0.0; }
         self.max_abs_error / self.total_throughput
     }
+}
+
+// ─── Operator Earnings Distribution ─────────────────────────────────────────
+
+pub use arena_engine::concentration::gini;
 
-    pub fn raw_error(&self) -> f64 {
-        self.max_abs_error
+/// Share of total earnings taken by the best-earning tenth of operators
+/// (at least one).
+pub fn top_decile_share(earnings: &[f64]) -> f64 {
+    let total: f64 = earnings.iter().sum();
+    if total <= 0.0 {
+        return 0.0;
     }
+    let mut sorted = earnings.to_vec();
+    sorted.sort_by(|a, b| b.total_cmp(a));
+    let top = sorted.len().div_ceil(10);
+    sorted.iter().take(top).sum::<f64>() / total
 }
 
-// ─── Incentive Comparison (Paired Runs) ─────────────────────────────────────
-
-/// Result of a paired incentive comparison: same traffic, different liquidity.
-/// Measures fee rate response and surge multiplier under liquidity drought.
-/// Whitepaper claim: fee rate spikes significantly (>5x) under sustained liquidity crunch.
-#[derive(Debug, Clone)]
-pub struct IncentiveComparison {
-    pub normal_avg_fee_rate: f64,
-    pub drought_avg_fee_rate: f64,
-    pub normal_peak_surge: f64,
-    pub drought_peak_surge: f64,
-    pub fee_ratio: f64,
-    pub surge_ratio: f64,
-    pub passes: bool,
+// ─── Implied Demand Curve ───────────────────────────────────────────────────
+
+/// Lower edges (bps) of the realized all-in cost bands the implied demand
+/// curve is binned on; the last band is open-ended.
+const DEMAND_CURVE_EDGES_BPS: [f64; 10] = [
+    0.0, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 500.0, 2_000.0,
+];
+
+/// One cost band of the implied demand curve.
+#[derive(Debug, Clone, Serialize)]
+pub struct DemandCurvePoint {
+    /// Lower edge of the realized cost band, basis points
+    pub cost_from_bps: f64,
+    pub ticks: u64,
+    /// Tick-weighted mean realized cost within the band, basis points
+    pub mean_cost_bps: f64,
+    /// Expected arrivals before demand destruction
+    pub offered: f64,
+    pub spawned: u64,
+    /// Spawned over offered: the demand retained at this cost
+    pub volume_share: f64,
 }
 
-/// Run a paired incentive comparison.
-/// Both runs use the same seed/traffic, only Egress liquidity differs.
-/// Compares fee rate and surge multiplier response.
-pub fn run_incentive_comparison(
-    nodes: u32,
+#[derive(Debug, Clone, Copy, Default)]
+struct DemandBand {
     ticks: u64,
-    gold: f64,
-    demand: f64,
-    seed: u64,
-) -> IncentiveComparison {
-    let normal = run_with_liquidity_factor(nodes, ticks, gold, demand, seed, 1.0);
-    let drought = run_with_liquidity_factor(nodes, ticks, gold, demand, seed, 0.1);
-
-    let fee_ratio = if normal.avg_fee_rate > 0.0 {
-        drought.avg_fee_rate / normal.avg_fee_rate
-    } else { 0.0 };
-
-    let surge_ratio = if normal.peak_surge > 0.0 {
-        drought.peak_surge / normal.peak_surge
-    } else { 0.0 };
-
-    let peak_fee_ratio = if normal.peak_fee > 0.0 {
-        drought.peak_fee / normal.peak_fee
-    } else { 0.0 };
-
-    // Pass if any mechanism shows significant differential response.
-    // The governor may respond through fee rate, surge pricing, or peak fees.
-    // With PID stabilization, a 2x differential is significant evidence.
-    let passes = fee_ratio > 2.0 || surge_ratio > 2.0 || peak_fee_ratio > 2.0;
-
-    IncentiveComparison {
-        normal_avg_fee_rate: normal.avg_fee_rate,
-        drought_avg_fee_rate: drought.avg_fee_rate,
-        normal_peak_surge: normal.peak_surge,
-        drought_peak_surge: drought.peak_surge,
-        fee_ratio,
-        surge_ratio,
-        passes,
-    }
+    cost_bps_sum: f64,
+    offered: f64,
+    spawned: u64,
 }
 
-struct RunMetrics {
-    avg_fee_rate: f64,
-    peak_fee: f64,
-    peak_surge: f64,
+/// Bins each tick's offered and spawned traffic by the realized all-in cost
+/// users faced when deciding to send.
+#[derive(Debug, Clone, Default)]
+pub struct DemandCurveRecorder {
+    bands: [DemandBand; DEMAND_CURVE_EDGES_BPS.len()],
 }
 
-fn run_with_liquidity_factor(
-    nodes: u32,
-    ticks: u64,
-    gold: f64,
-    demand: f64,
-    seed: u64,
-    liquidity_factor: f64,
-) -> RunMetrics {
-    use rand::SeedableRng;
-    use rand_chacha::ChaCha8Rng;
-    use crate::traffic::TrafficGenerator;
-
-    let mut sim = ArenaSimulation::new(nodes);
-    sim.set_gold_price(gold);
-    sim.set_demand_factor(0.0); // suppress engine traffic
-    // Set panic proportional to liquidity stress
-    if liquidity_factor < 1.0 {
-        sim.set_panic_level(0.7);
+impl DemandCurveRecorder {
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    // Set Egress liquidity
-    if liquidity_factor != 1.0 {
-        let base_crypto = 1000.0 * (nodes as f64 / 24.0).max(1.0) * 500.0;
-        for i in 0..nodes {
-            if i % 4 == 1 { // Egress nodes
-                sim.set_node_crypto(i, base_crypto * liquidity_factor);
-            }
-        }
+    fn band(cost_bps: f64) -> usize {
+        DEMAND_CURVE_EDGES_BPS.iter().rposition(|&edge| cost_bps >= edge).unwrap_or(0)
     }
 
-    let ingress_nodes: Vec<u32> = (0..nodes)
-        .filter(|i| i % 4 == 0) // Ingress nodes
-        .collect();
-    let rng = ChaCha8Rng::seed_from_u64(seed);
-    let mut traffic = TrafficGenerator::new(rng, ingress_nodes);
-    let lambda = TrafficGenerator::compute_lambda(demand, nodes);
-
-    let mut last_fee_rate = 0.0_f64;
-    let mut fee_rate_sum = 0.0_f64;
-    let mut peak_surge = 0.0_f64;
-    let mut peak_fee = 0.0_f64;
-    let mut tick_count = 0_u64;
-
-    for _tick in 0..ticks {
-        traffic.set_fee_rate(last_fee_rate);
-        let spawns = traffic.generate_tick(lambda);
-        for (node_id, amount) in spawns {
-            sim.spawn_packet(node_id, amount);
+    pub fn record(&mut self, realized_cost: f64, offered: f64, spawned: usize) {
+        let cost_bps = realized_cost * 10_000.0;
+        let band = &mut self.bands[Self::band(cost_bps)];
+        band.ticks += 1;
+        band.cost_bps_sum += cost_bps;
+        band.offered += offered;
+        band.spawned += spawned as u64;
+    }
+
+    /// Pool per-run curves into one.
+    pub fn merged<'a>(runs: impl Iterator<Item = &'a [DemandCurvePoint]>) -> Vec<DemandCurvePoint> {
+        let mut pooled = Self::new();
+        for point in runs.flatten() {
+            let band = &mut pooled.bands[Self::band(point.cost_from_bps)];
+            band.ticks += point.ticks;
+            band.cost_bps_sum += point.mean_cost_bps * point.ticks as f64;
+            band.offered += point.offered;
+            band.spawned += point.spawned;
         }
-        let result = sim.tick_core();
-        last_fee_rate = result.state.current_fee_rate;
-        fee_rate_sum += result.state.current_fee_rate;
-        peak_fee = peak_fee.max(result.state.current_fee_rate);
-        peak_surge = peak_surge.max(result.state.surge_multiplier);
-        tick_count += 1;
+        pooled.points()
     }
 
-    RunMetrics {
-        avg_fee_rate: if tick_count > 0 { fee_rate_sum / tick_count as f64 } else { 0.0 },
-        peak_fee,
-        peak_surge,
+    /// Bands that saw at least one tick, cheapest first.
+    pub fn points(&self) -> Vec<DemandCurvePoint> {
+        DEMAND_CURVE_EDGES_BPS.iter().zip(&self.bands)
+            .filter(|(_, band)| band.ticks > 0)
+            .map(|(&cost_from_bps, band)| DemandCurvePoint {
+                cost_from_bps,
+                ticks: band.ticks,
+                mean_cost_bps: band.cost_bps_sum / band.ticks as f64,
+                offered: band.offered,
+                spawned: band.spawned,
+                volume_share: if band.offered > 0.0 { band.spawned as f64 / band.offered } else { 0.0 },
+            })
+            .collect()
     }
 }
+
+// ─── Node Resource Economics ────────────────────────────────────────────────
+
+/// A scenario run with node running costs accounted.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceEconomicsRun {
+    pub scenario: String,
+    pub nodes: u32,
+    pub settlement_rate: f64,
+    pub summary: ResourceSummary,
+}
+
+/// Run `scenario` on `seed` with the resource model on.
+pub fn run_resource_economics(scenario: &Scenario, seed: u64) -> Option<ResourceEconomicsRun> {
+    let r = run_single_with(scenario, seed, None, RunParams { resource_model: true, ..RunParams::default() });
+    Some(ResourceEconomicsRun {
+        scenario: scenario.name.to_string(),
+        nodes: scenario.nodes,
+        settlement_rate: r.settlement_rate,
+        summary: r.resources?,
+    })
+}
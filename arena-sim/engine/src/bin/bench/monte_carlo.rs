@@ -6,32 +6,213 @@ use rand_chacha::ChaCha8Rng;
 use arena_engine::*;
 
 use crate::report::*;
-use crate::scenarios::Scenario;
+use crate::scenarios::{scenarios, Scenario};
 use crate::traffic::TrafficGenerator;
-use crate::metrics::{PegTracker, ConservationTracker};
+use crate::metrics::{gini, top_decile_share, PegTracker, ConservationTracker, DemandCurveRecorder};
+use crate::series::SeriesRecorder;
 use crate::time_series::TimeSeriesRecorder;
+use crate::failures;
 
 use std::time::Instant;
 
+/// Cap on quadrant transitions kept per run in the JSON report.
+const MAX_REPORTED_TRANSITIONS: usize = 100;
+/// Whitepaper invariants are claimed at a stated load, so their demand stays
+/// inelastic; every other category runs the engine's default elasticity curve.
+const FIXED_LOAD_CATEGORY: &str = "whitepaper";
+
+/// Per-run overrides applied on top of a scenario's own parameters.
+#[derive(Debug, Clone, Copy)]
+pub struct RunParams {
+    /// Multiplier on the scenario demand (and demand curve) driving Poisson lambda.
+    pub demand_scale: f64,
+    /// Multiplier on Egress liquidity, applied after scenario setup.
+    pub liquidity_scale: f64,
+    /// Egress settlement batching window in ticks (`None` settles per packet).
+    pub settlement_batch_window: Option<u32>,
+    /// Ticks after spawn before demurrage starts (0 = decay from spawn).
+    pub demurrage_grace_ticks: u32,
+    /// Per-origin volume discount as (rolling volume threshold, rate); `None` is off.
+    pub volume_discount: Option<(f64, f64)>,
+    /// Dust policy as (minimum packet value, dust surcharge); `None` is off.
+    pub dust_policy: Option<(f64, f64)>,
+    /// Ingress dust aggregation as (payment threshold, max wait ticks); `None` is off.
+    pub dust_aggregation: Option<(f64, u32)>,
+    /// Serve next hops from the route cache.
+    pub route_cache: bool,
+    /// Whether neighbor trust enters the router's score.
+    pub route_scoring: routing::RouteScoring,
+    /// Which router picks next hops: legacy scoring or core's `PacketRouter`.
+    pub routing_regime: capacity_router::RoutingRegime,
+    /// Hop-exhaustion handling and orbit timeouts.
+    pub routing_policy: routing_policy::RoutingPolicy,
+    /// How the settlement fee's transit pool is split among relays.
+    pub transit_weighting: transit_rewards::TransitWeighting,
+    /// Settlement fee pricing: governor spot rate or the spawn-time quote.
+    pub pricing_mode: quote::PricingMode,
+    /// Bankrupt Egress exit as (dry ticks before exit, exit role, re-entry
+    /// inventory); `None` is off.
+    pub egress_exit: Option<(u32, NodeRole, f64)>,
+    /// Endogenous panic as (coupling, calm rate, noise), seeded from the run
+    /// seed's chaos stream; `None` leaves panic to the scenario.
+    pub panic_dynamics: Option<(f64, f64, f64)>,
+    /// Freeze governor fees once this many ticks have run; `None` leaves
+    /// them to the governor. A seeded run is identical to its baseline up
+    /// to that tick, so this is the baseline forked there.
+    pub fee_freeze_tick: Option<u64>,
+    /// Egress payout FX as (base spread, volatility coefficient, max
+    /// spread); `None` pays out at the oracle price.
+    pub payout_slippage: Option<(f64, f64, f64)>,
+    /// Starting inventory policy, sized against the scenario's expected
+    /// Poisson traffic and applied before scenario setup; `None` keeps the
+    /// engine default.
+    pub initial_inventory: Option<inventory::InventoryPolicy>,
+    /// Sender retries of reverted payments as (probability, delay ticks);
+    /// `None` lets reverted value leave for good.
+    pub retry_model: Option<(f64, u32)>,
+    /// Speculator population as (count, aggressiveness); `None` has none.
+    pub speculators: Option<(u32, f64)>,
+    /// Liquidity providers as (count, bankroll, hurdle rate per tick);
+    /// `None` has none.
+    pub liquidity_providers: Option<(u32, f64, f64)>,
+    /// Governor control loop as (decision interval, actuation lag) in
+    /// ticks; `None` decides and acts every tick.
+    pub control_loop: Option<(u32, u32)>,
+    /// Governor gains by pressure quadrant as (quadrant A..F as 0..5, kp,
+    /// ki, kd); empty runs on the fixed base gains.
+    pub quadrant_gains: &'static [(u32, f64, f64, f64)],
+    /// Governor cycles a new pressure quadrant must hold before it takes
+    /// over (0 = switch at once).
+    pub quadrant_hysteresis: u32,
+    /// Model-predictive governor horizon in cycles (0 = PID).
+    pub mpc_horizon: u32,
+    /// Account node running costs (storage, bandwidth, energy) at default prices.
+    pub resource_model: bool,
+    /// Forced resolution of packets still Held once the run is scored.
+    pub held_sweep: held_sweep::SweepPolicy,
+    /// Log each governor decision's inputs and outputs into the time series.
+    pub governor_io: bool,
+    /// Volatility estimator and window feeding the governor, before scenario
+    /// setup picks its own; `None` keeps the 20-tick coefficient of variation.
+    pub volatility: Option<(volatility::VolatilityEstimator, u32)>,
+}
+
+impl Default for RunParams {
+    fn default() -> Self {
+        Self {
+            demand_scale: 1.0,
+            liquidity_scale: 1.0,
+            settlement_batch_window: None,
+            demurrage_grace_ticks: 0,
+            volume_discount: None,
+            dust_policy: None,
+            dust_aggregation: None,
+            route_cache: false,
+            route_scoring: routing::RouteScoring::TrustWeighted,
+            routing_regime: capacity_router::RoutingRegime::Legacy,
+            routing_policy: routing_policy::RoutingPolicy::default(),
+            transit_weighting: transit_rewards::TransitWeighting::Equal,
+            pricing_mode: quote::PricingMode::Spot,
+            egress_exit: None,
+            panic_dynamics: None,
+            fee_freeze_tick: None,
+            payout_slippage: None,
+            initial_inventory: None,
+            retry_model: None,
+            speculators: None,
+            liquidity_providers: None,
+            control_loop: None,
+            quadrant_gains: &[],
+            quadrant_hysteresis: 0,
+            mpc_horizon: 0,
+            resource_model: false,
+            held_sweep: held_sweep::SweepPolicy::Dissolve,
+            governor_io: false,
+            volatility: None,
+        }
+    }
+}
+
 /// Run a single scenario iteration with a specific seed.
 pub fn run_single(
     scenario: &Scenario,
     seed: u64,
     time_series_dir: Option<&std::path::Path>,
 ) -> BenchResult {
+    run_single_with(scenario, seed, time_series_dir, RunParams::default())
+}
+
+/// Run a single scenario iteration with a specific seed and overrides.
+pub fn run_single_with(
+    scenario: &Scenario,
+    seed: u64,
+    time_series_dir: Option<&std::path::Path>,
+    params: RunParams,
+) -> BenchResult {
+    let output = run_single_traced(scenario, seed, params, time_series_dir.is_some());
+    write_time_series(&output, time_series_dir, seed);
+    output.result
+}
+
+/// Everything a single run produces, beyond the summary metrics.
+pub struct RunOutput {
+    pub result: BenchResult,
+    pub time_series: Option<TimeSeriesRecorder>,
+    pub final_state: WorldState,
+}
+
+fn write_time_series(output: &RunOutput, time_series_dir: Option<&std::path::Path>, seed: u64) {
+    if let (Some(ts), Some(dir)) = (&output.time_series, time_series_dir) {
+        let path = dir.join(format!("seed-{}.jsonl", seed));
+        if let Err(e) = ts.write_jsonl(&path) {
+            eprintln!("  Warning: failed to write time series: {}", e);
+        }
+    }
+}
+
+/// Run a single scenario iteration, optionally keeping the per-tick time series in memory.
+pub fn run_single_traced(
+    scenario: &Scenario,
+    seed: u64,
+    params: RunParams,
+    record_time_series: bool,
+) -> RunOutput {
     let start = Instant::now();
+    // A chained scenario plays each phase's curves, setup and events in turn
+    // on the same economy; otherwise the scenario is its own single phase
+    let chained = !scenario.chain.is_empty();
+    let catalog = if chained { scenarios() } else { Vec::new() };
+    let phases: Vec<(&Scenario, u64)> = if chained {
+        scenario.chain.iter()
+            .map(|&(name, ticks)| {
+                let phase = catalog.iter()
+                    .find(|s| s.name == name)
+                    .unwrap_or_else(|| panic!("Unknown chain phase {}", name));
+                (phase, ticks)
+            })
+            .collect()
+    } else {
+        vec![(scenario, scenario.ticks)]
+    };
+    let total_ticks: u64 = phases.iter().map(|&(_, ticks)| ticks).sum();
+    let mut phase_summaries: Vec<PhaseSummary> = Vec::new();
+
     let mut sim = ArenaSimulation::new(scenario.nodes);
+    sim.set_master_seed(seed as u32);
     sim.set_gold_price(scenario.gold);
     sim.set_panic_level(scenario.panic);
 
     // Suppress engine traffic — bench injects via Poisson
     sim.set_demand_factor(0.0);
+    if scenario.category == FIXED_LOAD_CATEGORY {
+        sim.set_demand_elasticity(0.0, elasticity::DEFAULT_REFERENCE_COST);
+    }
 
     // Set up Poisson traffic generator
     let ingress_nodes: Vec<u32> = (0..scenario.nodes)
         .filter(|i| i % 4 == 0) // Ingress nodes
         .collect();
-    let rng = ChaCha8Rng::seed_from_u64(seed);
+    let rng = ChaCha8Rng::seed_from_u64(rng::stream_seed(seed, rng::Stream::Traffic));
     let mut traffic = TrafficGenerator::new(rng, ingress_nodes);
     let demand_scale = (scenario.nodes as f64 / 24.0).sqrt();
     let _base_lambda = TrafficGenerator::compute_lambda(scenario.demand, scenario.nodes);
@@ -39,108 +220,240 @@ pub fn run_single(
     // Metric trackers
     let mut peg = PegTracker::new();
     let mut conservation = ConservationTracker::new();
-    let mut time_series = if time_series_dir.is_some() {
+    let mut time_series = if record_time_series {
         Some(TimeSeriesRecorder::new())
     } else {
         None
     };
 
+    let mut series = SeriesRecorder::new(scenario.criteria.series_assertions);
     let mut peak_fee: f64 = 0.0;
+    let mut liquidity_lambda_sum = 0.0_f64;
+    let mut peak_top1_share: f64 = 0.0;
+    let mut peak_top3_share: f64 = 0.0;
+    let mut peak_stress_index: f64 = 0.0;
+    let mut stress_index_sum = 0.0_f64;
+    let mut peak_in_transit_float: f64 = 0.0;
+    let mut panic_level_sum = 0.0_f64;
+    let mut demand_curve = DemandCurveRecorder::new();
+    let mut peak_panic_level: f64 = 0.0;
+    let mut peak_payout_spread: f64 = 0.0;
     let mut fee_cap_breaches: u32 = 0;
     let mut all_packets_settled_final = true;
     let mut cost_certainty_violations: u32 = 0;
     let mut audit_trail_violations: u32 = 0;
     let mut conservation_holds = true;
-    let mut last_fee_rate = 0.0_f64;
     let mut last_state: Option<WorldState> = None;
+    let mut traffic_accepted: u64 = 0;
+    let mut packets_in_flight_sum: u64 = 0;
+    let mut peak_memory_bytes: u64 = 0;
 
     let caps = [0.05_f64, 0.02, 0.005, 0.001];
 
+    if let Some(policy) = params.initial_inventory {
+        let arrivals = TrafficGenerator::compute_lambda(scenario.demand * params.demand_scale, scenario.nodes);
+        sim.set_initial_inventory(policy as u32, arrivals, TrafficGenerator::mean_packet_value());
+    }
+    if let Some((estimator, window)) = params.volatility {
+        sim.set_volatility_estimator(estimator as u32, window);
+    }
     // Pre-scenario setup (kill nodes, set liquidity, etc.)
     if let Some(setup) = &scenario.setup {
         setup(&mut sim);
     }
-
-    for tick in 0..scenario.ticks {
-        // Apply curves
-        let gold = if let Some(curve) = scenario.gold_curve {
-            curve(tick)
-        } else {
-            scenario.gold
-        };
-        sim.set_gold_price(gold);
-
-        let demand = if let Some(curve) = scenario.demand_curve {
-            curve(tick)
-        } else {
-            scenario.demand
-        };
-        // Modulate Poisson lambda via demand curve
-        let current_lambda = demand * 5.0 * (scenario.nodes as f64 / 24.0).sqrt();
-
-        if let Some(curve) = scenario.panic_curve {
-            sim.set_panic_level(curve(tick));
-        }
-
-        // Mid-scenario events (e.g., kill nodes at tick 500)
-        if let Some(event) = &scenario.mid_event {
-            event(&mut sim, tick);
+    if params.liquidity_scale != 1.0 {
+        let egress: Vec<(u32, f64)> = sim
+            .get_nodes_filtered_core(Some(NodeRole::Egress), None, 0, usize::MAX)
+            .iter()
+            .map(|n| (n.id, n.inventory_crypto))
+            .collect();
+        for (id, crypto) in egress {
+            sim.set_node_crypto(id, crypto * params.liquidity_scale);
         }
-
-        // Inject Poisson traffic (use last tick's fee rate for demand destruction)
-        traffic.set_fee_rate(last_fee_rate);
-        let spawns = traffic.generate_tick(current_lambda);
-        for (node_id, amount) in spawns {
-            sim.spawn_packet(node_id, amount);
+    }
+    sim.set_settlement_batch_window(params.settlement_batch_window);
+    sim.set_demurrage_grace_ticks(params.demurrage_grace_ticks);
+    if let Some((threshold, rate)) = params.volume_discount {
+        sim.set_volume_discount(threshold, rate);
+    }
+    if let Some((min_value, surcharge)) = params.dust_policy {
+        sim.set_dust_policy(min_value, surcharge);
+    }
+    if let Some((threshold, max_wait_ticks)) = params.dust_aggregation {
+        sim.set_dust_aggregation(threshold, max_wait_ticks);
+    }
+    if params.route_cache {
+        sim.set_route_cache(true);
+    }
+    sim.set_route_scoring(params.route_scoring as u32);
+    sim.set_routing_regime(params.routing_regime as u32);
+    let policy = params.routing_policy;
+    sim.set_hop_exhaustion(
+        policy.hop_exhaustion as u32, policy.backoff_base_ticks as u32, policy.backoff_max_ticks as u32);
+    sim.set_orbit_timeouts(policy.orbit_timeout_ttl_fraction, policy.l3_orbit_timeout_ticks as u32);
+    sim.set_transit_weighting(params.transit_weighting as u32);
+    sim.set_pricing_mode(params.pricing_mode as u32);
+    if let Some((exhausted_ticks, exit_role, reentry_crypto)) = params.egress_exit {
+        sim.set_egress_exit(exhausted_ticks, exit_role as u32, reentry_crypto);
+    }
+    if let Some((coupling, calm_rate, noise)) = params.panic_dynamics {
+        sim.set_panic_dynamics(
+            coupling, calm_rate, noise, rng::stream_seed(seed, rng::Stream::Chaos) as u32);
+    }
+    if let Some((base_spread, volatility_coefficient, max_spread)) = params.payout_slippage {
+        sim.set_payout_slippage(base_spread, volatility_coefficient, max_spread);
+    }
+    if let Some((probability, delay_ticks)) = params.retry_model {
+        sim.set_retry_model(probability, delay_ticks);
+    }
+    if let Some((count, aggressiveness)) = params.speculators {
+        sim.set_speculators(count, aggressiveness);
+    }
+    if let Some((count, bankroll, hurdle_rate)) = params.liquidity_providers {
+        sim.set_liquidity_providers(count, bankroll, hurdle_rate);
+    }
+    if let Some((interval_ticks, actuation_lag_ticks)) = params.control_loop {
+        sim.set_control_loop(interval_ticks, actuation_lag_ticks);
+    }
+    for &(quadrant, kp, ki, kd) in params.quadrant_gains {
+        sim.set_quadrant_gains(quadrant, kp, ki, kd);
+    }
+    sim.set_quadrant_hysteresis(params.quadrant_hysteresis);
+    sim.set_governor_mpc(params.mpc_horizon, 1.0);
+    sim.set_resource_model(params.resource_model);
+    // Hop attribution and governor logging only feed the time series
+    sim.set_hop_tracing(record_time_series);
+    sim.set_governor_logging(record_time_series && params.governor_io);
+
+    for &(phase, phase_ticks) in &phases {
+        if chained {
+            sim.set_panic_level(phase.panic);
+            if let Some(setup) = &phase.setup {
+                setup(&mut sim);
+            }
         }
+        let (settled_before, reverted_before) = last_state.as_ref()
+            .map_or((0, 0), |s| (s.settlement_count, s.revert_count));
+        let mut phase_fee_sum = 0.0_f64;
+
+        for tick in 0..phase_ticks {
+            // Apply curves
+            let gold = if let Some(curve) = phase.gold_curve {
+                curve(tick)
+            } else {
+                phase.gold
+            };
+            sim.set_gold_price(gold);
+
+            let demand = if let Some(curve) = phase.demand_curve {
+                curve(tick)
+            } else {
+                phase.demand
+            } * params.demand_scale;
+            // Modulate Poisson lambda via demand curve
+            let current_lambda = demand * 5.0 * (scenario.nodes as f64 / 24.0).sqrt();
+
+            if let Some(curve) = phase.panic_curve {
+                sim.set_panic_level(curve(tick));
+            }
 
-        // Tick the engine
-        let result = sim.tick_core();
-        last_fee_rate = result.state.current_fee_rate;
-        peak_fee = peak_fee.max(result.state.current_fee_rate);
+            // Mid-scenario events (e.g., kill nodes at tick 500)
+            if let Some(event) = &phase.mid_event {
+                event(&mut sim, tick);
+            }
 
-        // Track metrics
-        peg.record_tick(&result.state);
-        conservation.record_tick(&result.state);
+            let elapsed = last_state.as_ref().map_or(0, |s| s.current_tick);
+            if params.fee_freeze_tick == Some(elapsed) {
+                sim.set_fee_freeze(true);
+            }
 
-        if let Some(ref mut ts) = time_series {
-            ts.record(&result.state);
-        }
+            // Inject Poisson traffic, thinned by last tick's demand retention
+            let (realized_cost, retention) = last_state.as_ref()
+                .map_or((0.0, 1.0), |s| (s.realized_cost, s.demand_retention));
+            traffic.set_demand_retention(retention);
+            let spawns = traffic.generate_tick(current_lambda);
+            demand_curve.record(realized_cost, current_lambda, spawns.len());
+            for (node_id, amount) in spawns {
+                if sim.spawn_packet(node_id, amount) != ratelimit::REJECTED_PACKET_ID {
+                    traffic_accepted += 1;
+                }
+            }
 
-        // Conservation check (raw)
-        if result.state.total_value_leaked.abs() > scenario.criteria.max_conservation_error {
-            conservation_holds = false;
-        }
+            // Tick the engine
+            let result = sim.tick_core();
+            peak_fee = peak_fee.max(result.state.current_fee_rate);
+            liquidity_lambda_sum += result.state.liquidity_lambda;
+            peak_top1_share = peak_top1_share.max(result.state.egress_top1_share);
+            peak_top3_share = peak_top3_share.max(result.state.egress_top3_share);
+            peak_stress_index = peak_stress_index.max(result.state.stress_index);
+            stress_index_sum += result.state.stress_index;
+            peak_in_transit_float = peak_in_transit_float.max(result.state.in_transit_float);
+            panic_level_sum += result.state.panic_level;
+            peak_panic_level = peak_panic_level.max(result.state.panic_level);
+            peak_payout_spread = peak_payout_spread.max(result.state.payout_spread);
+            packets_in_flight_sum += result.active_packets.len() as u64;
+            // Sample the footprint on the tick before each compaction pass
+            let interval = compaction::COMPACTION_INTERVAL_TICKS;
+            if result.state.current_tick % interval == interval - 1 {
+                peak_memory_bytes = peak_memory_bytes.max(sim.get_memory_footprint_core().estimated_bytes);
+            }
 
-        // Fee cap breach check
-        let tier_rates = result.state.tier_fee_rates;
-        for t in 0..4 {
-            if tier_rates[t] > caps[t] + 0.0001 {
-                fee_cap_breaches += 1;
+            // Track metrics
+            peg.record_tick(&result.state);
+            conservation.record_tick(&result.state);
+
+            series.record(&sim, &result.state);
+            if let Some(ref mut ts) = time_series {
+                ts.record(&result.state);
+                if let Some(io) = sim.governor_io_core() {
+                    ts.record_governor_io(io);
+                }
+                if result.state.current_tick.is_multiple_of(cohort::DEFAULT_COHORT_TICKS) {
+                    ts.record_cohorts(&sim.get_cohorts_core());
+                    ts.record_hop_stats(sim.get_hop_stats_core());
+                }
             }
-        }
 
-        // Fiduciary checks
-        for p in &result.active_packets {
-            if p.fee_budget > 0.0 && p.fees_consumed > p.fee_budget + 0.0001 {
-                cost_certainty_violations += 1;
+            // Conservation check (raw)
+            if result.state.total_value_leaked.abs() > scenario.criteria.max_conservation_error {
+                conservation_holds = false;
             }
-            if p.route_history.is_empty() {
-                audit_trail_violations += 1;
+
+            // Fee cap breach check
+            let tier_rates = result.state.tier_fee_rates;
+            for t in 0..4 {
+                if tier_rates[t] > caps[t] + 0.0001 {
+                    fee_cap_breaches += 1;
+                }
             }
-            if p.status == PacketStatus::Settled {
-                all_packets_settled_final = false;
+
+            // Fiduciary checks
+            for p in &result.active_packets {
+                if p.fee_budget > 0.0 && p.fees_consumed > p.fee_budget + 0.0001 {
+                    cost_certainty_violations += 1;
+                }
+                if p.route_history.is_empty() {
+                    audit_trail_violations += 1;
+                }
+                if p.status == PacketStatus::Settled {
+                    all_packets_settled_final = false;
+                }
             }
-        }
 
-        last_state = Some(result.state);
-    }
+            phase_fee_sum += result.state.current_fee_rate;
+            last_state = Some(result.state);
+        }
 
-    // Write time series if enabled
-    if let (Some(ts), Some(dir)) = (&time_series, time_series_dir) {
-        let path = dir.join(format!("seed-{}.jsonl", seed));
-        if let Err(e) = ts.write_jsonl(&path) {
-            eprintln!("  Warning: failed to write time series: {}", e);
+        if chained {
+            let end = last_state.as_ref().expect("No ticks executed");
+            phase_summaries.push(PhaseSummary {
+                name: phase.name.to_string(),
+                ticks: phase_ticks,
+                settlements: end.settlement_count - settled_before,
+                reverts: end.revert_count - reverted_before,
+                mean_fee_rate: phase_fee_sum / phase_ticks.max(1) as f64,
+                held_at_end: end.held_count,
+            });
         }
     }
 
@@ -152,13 +465,26 @@ pub fn run_single(
     let settled = state.settlement_count;
     // Use bench-tracked spawn count (engine's spawn_count won't be incremented
     // since we use spawn_packet() which only increments total_input)
-    let spawned = traffic.spawn_count.max(1);
+    // Payments from scenario events (e.g. a dust flood) and senders' retries
+    // of reverted payments count as spawned too
+    let event_spawns = (sim.get_payments_accepted() - traffic_accepted) as u32;
+    let spawned = (traffic.spawn_count + event_spawns).max(1);
     let settlement_rate = (settled as f64 / spawned as f64) * 100.0;
 
     let normalized_conservation = conservation.normalized_error();
+    let assets = sim.get_asset_ledger_core();
+    let asset_ledger_residual = (assets.fiat.residual / assets.fiat.provisioned.abs().max(1.0))
+        .max(assets.crypto.residual / assets.crypto.provisioned.abs().max(1.0));
+    let trusted_settlement_share = if settled > 0 {
+        state.trusted_settlements as f64 / settled as f64
+    } else { 0.0 };
 
     // Evaluate pass/fail
     let mut pass = state.total_value_leaked.abs() <= scenario.criteria.max_conservation_error;
+    // Settlement fees past the tier cap are a hard invariant in every scenario
+    if state.fee_cap_breaches > 0 {
+        pass = false;
+    }
     if let Some(min_rate) = scenario.criteria.min_settlement_rate {
         if settled > 0 && settlement_rate < min_rate {
             pass = false;
@@ -186,8 +512,59 @@ pub fn run_single(
             pass = false;
         }
     }
+    if let Some(max_share) = scenario.criteria.max_egress_top1_share {
+        if peak_top1_share > max_share {
+            pass = false;
+        }
+    }
+    if let Some(max_share) = scenario.criteria.max_egress_top3_share {
+        if peak_top3_share > max_share {
+            pass = false;
+        }
+    }
+    let topology_orbits = state.orbit_hop_limit + state.orbit_disabled_destination;
+    if let Some(max_orbits) = scenario.criteria.max_liquidity_orbits_at_end {
+        if state.orbit_no_liquid_egress > max_orbits {
+            pass = false;
+        }
+    }
+    if let Some(max_orbits) = scenario.criteria.max_topology_orbits_at_end {
+        if topology_orbits > max_orbits {
+            pass = false;
+        }
+    }
+    if let Some(max_stress) = scenario.criteria.max_mean_stress_index {
+        if stress_index_sum / total_ticks.max(1) as f64 > max_stress {
+            pass = false;
+        }
+    }
+    if let Some(min_share) = scenario.criteria.min_trusted_settlement_share {
+        if settled > 0 && trusted_settlement_share < min_share {
+            pass = false;
+        }
+    }
+    let series_failures = series.failures();
+    if !series_failures.is_empty() {
+        pass = false;
+    }
+    let latency = sim.get_settlement_latency_core();
+    let sla_failures: Vec<String> = scenario.criteria.latency_slas.iter()
+        .filter_map(|sla| sla.check(&latency))
+        .collect();
+    if !sla_failures.is_empty() {
+        pass = false;
+    }
 
-    BenchResult {
+    let transit_earnings: Vec<f64> = sim
+        .get_nodes_filtered_core(Some(NodeRole::Transit), None, 0, usize::MAX)
+        .iter()
+        .map(|n| n.total_fees_earned.max(0.0))
+        .collect();
+
+    let stats = sim.get_stats_core();
+    // After scoring, so criteria judge the run as it ended
+    let held_sweep = sim.sweep_held_core(params.held_sweep);
+    let result = BenchResult {
         scenario: scenario.label.to_string(),
         name: scenario.name.to_string(),
         category: scenario.category.to_string(),
@@ -199,18 +576,20 @@ pub fn run_single(
         settlement_rate,
         conservation_error: state.total_value_leaked.abs(),
         normalized_conservation_error: normalized_conservation,
+        asset_ledger_residual,
         avg_fee: state.current_fee_rate * 100.0,
         peak_fee: peak_fee * 100.0,
         dissolved_count: state.dissolved_count,
         held_count: state.held_count,
         fee_cap_breaches,
+        settlement_fee_cap_breaches: state.fee_cap_breaches,
         settlement_finality: all_packets_settled_final,
         cost_certainty: cost_certainty_violations == 0,
         audit_trail: audit_trail_violations == 0,
         tier_breakdown: state.tier_distribution,
-        ticks: scenario.ticks,
+        ticks: total_ticks,
         elapsed_ms,
-        packets_per_tick: spawned as f64 / scenario.ticks as f64,
+        packets_per_tick: spawned as f64 / total_ticks as f64,
         demand_scale_factor: demand_scale,
         egress_profit_total: state.total_rewards_egress,
         transit_profit_total: state.total_rewards_transit,
@@ -218,9 +597,75 @@ pub fn run_single(
         conservation_holds,
         final_held_count: state.held_count,
         final_orbit_count: state.orbit_count,
-        throughput_per_sec: scenario.ticks as f64 / elapsed_secs,
+        final_liquidity_orbits: state.orbit_no_liquid_egress,
+        final_topology_orbits: topology_orbits,
+        throughput_per_sec: total_ticks as f64 / elapsed_secs,
         peg_elasticity_pct: peg.elasticity_pct(),
         max_normalized_conservation: normalized_conservation,
+        mean_liquidity_lambda: liquidity_lambda_sum / total_ticks.max(1) as f64,
+        tier_effective_cost_bps: state.tier_effective_cost.map(|c| c * 10_000.0),
+        peak_egress_top1_share: peak_top1_share,
+        peak_egress_top3_share: peak_top3_share,
+        peak_stress_index,
+        mean_stress_index: stress_index_sum / total_ticks.max(1) as f64,
+        peak_in_transit_float,
+        peak_panic_level,
+        mean_panic_level: panic_level_sum / total_ticks.max(1) as f64,
+        demand_curve: demand_curve.points(),
+        verification_fees_paid: state.verification_fees_paid,
+        verification_shortfalls: state.verification_shortfalls,
+        quadrant_ticks: state.quadrant_ticks,
+        quadrant_transition_count: state.quadrant_transition_count,
+        quadrant_transitions: sim.get_quadrant_transitions_core()
+            .iter()
+            .take(MAX_REPORTED_TRANSITIONS)
+            .cloned()
+            .collect(),
+        settlement_batches: state.settlement_batches,
+        egress_inventory_drawn: state.egress_inventory_drawn,
+        batch_inventory_saved: state.batch_inventory_saved,
+        trust_rebates_paid: state.trust_rebates_paid,
+        trusted_settlement_share,
+        fees_collected: state.total_fees_collected,
+        volume_discount_forgone: state.volume_discount_forgone,
+        discounted_value_share: state.discounted_value_share,
+        non_dust_settle_ticks: state.non_dust_settle_ticks,
+        dust_rejected: state.dust_rejected,
+        dust_surcharges: state.dust_surcharges,
+        mean_packets_in_flight: packets_in_flight_sum as f64 / total_ticks.max(1) as f64,
+        payments_aggregated: state.payments_aggregated,
+        aggregate_packets: state.aggregate_packets,
+        route_cache_hit_rate: state.route_cache_hit_rate,
+        transit_earnings_gini: gini(&transit_earnings),
+        transit_top_decile_share: top_decile_share(&transit_earnings),
+        quote_drift: state.quote_drift,
+        quote_mismatches: state.quote_mismatches,
+        egress_exits: state.egress_exits,
+        egress_reentries: state.egress_reentries,
+        revert_reasons: state.revert_reasons,
+        retry_count: state.retry_count,
+        speculation: stats.speculation.clone(),
+        liquidity: stats.liquidity.clone(),
+        resources: sim.get_resource_summary_core(),
+        governor_decisions: state.governor_decisions,
+        fee_rate_reversals: state.fee_rate_reversals,
+        peak_payout_spread,
+        payout_slippage: state.payout_slippage,
+        tier_fees_collected: stats.tier_fees_collected,
+        tier_demurrage_burned: stats.tier_demurrage_burned,
+        tier_rewards_distributed: stats.tier_rewards_distributed,
+        peak_memory_bytes: peak_memory_bytes.max(sim.get_memory_footprint_core().estimated_bytes),
+        phases: phase_summaries,
+        series_failures,
+        tier_p95_settle_ticks: std::array::from_fn(|t| latency[t].p95_ticks),
+        sla_failures,
+        held_sweep,
+    };
+
+    RunOutput {
+        result,
+        time_series,
+        final_state: last_state.expect("No ticks executed"),
     }
 }
 
@@ -230,19 +675,70 @@ pub fn run_monte_carlo(
     n_runs: usize,
     base_seed: u64,
     time_series_base: Option<&std::path::Path>,
+    params: RunParams,
 ) -> MonteCarloReport {
-    let ts_dir = time_series_base.map(|base| base.join(&scenario.name.to_lowercase()));
+    let ts_dir = time_series_base.map(|base| base.join(scenario.name.to_lowercase()));
 
     let mut results = Vec::with_capacity(n_runs);
     for i in 0..n_runs {
         let seed = base_seed + i as u64;
-        let result = run_single(scenario, seed, ts_dir.as_deref());
+        results.push(run_mc_seed(scenario, seed, ts_dir.as_deref(), params));
+    }
+
+    aggregate(scenario, results)
+}
+
+/// Minimum runs before the adaptive stopping rule is consulted.
+const ADAPTIVE_MIN_RUNS: usize = 5;
+
+/// Adaptive Monte Carlo: keep adding seeds until the 95% CI half-width of the
+/// settlement rate (percentage points) drops to `target_half_width`, or
+/// `max_runs` is reached.
+pub fn run_monte_carlo_adaptive(
+    scenario: &Scenario,
+    target_half_width: f64,
+    max_runs: usize,
+    base_seed: u64,
+    time_series_base: Option<&std::path::Path>,
+    params: RunParams,
+) -> MonteCarloReport {
+    let ts_dir = time_series_base.map(|base| base.join(scenario.name.to_lowercase()));
+    let min_runs = ADAPTIVE_MIN_RUNS.min(max_runs.max(1));
+
+    let mut results: Vec<BenchResult> = Vec::new();
+    let mut settlement_rates = Vec::new();
+    while results.len() < max_runs.max(1) {
+        let seed = base_seed + results.len() as u64;
+        let result = run_mc_seed(scenario, seed, ts_dir.as_deref(), params);
+        settlement_rates.push(result.settlement_rate);
         results.push(result);
+
+        if results.len() >= min_runs {
+            let stats = Stats::from_samples(&settlement_rates);
+            if (stats.ci_upper - stats.ci_lower) / 2.0 <= target_half_width {
+                break;
+            }
+        }
     }
 
     aggregate(scenario, results)
 }
 
+/// One Monte Carlo iteration: run, write its time series, record it if it failed.
+fn run_mc_seed(
+    scenario: &Scenario,
+    seed: u64,
+    ts_dir: Option<&std::path::Path>,
+    params: RunParams,
+) -> BenchResult {
+    let output = run_single_traced(scenario, seed, params, ts_dir.is_some());
+    write_time_series(&output, ts_dir, seed);
+    if !output.result.pass {
+        failures::record(scenario, seed, &output);
+    }
+    output.result
+}
+
 /// Aggregate individual runs into a MonteCarloReport.
 fn aggregate(scenario: &Scenario, results: Vec<BenchResult>) -> MonteCarloReport {
     let n = results.len();
@@ -282,6 +778,35 @@ fn aggregate(scenario: &Scenario, results: Vec<BenchResult>) -> MonteCarloReport
     let packets_per_tick = Stats::from_samples(
         &results.iter().map(|r| r.packets_per_tick).collect::<Vec<_>>()
     );
+    let tier_effective_cost_bps = (0..4)
+        .map(|t| Stats::from_samples(
+            &results.iter().map(|r| r.tier_effective_cost_bps[t]).collect::<Vec<_>>()
+        ))
+        .collect();
+    let revert_reasons = (0..RevertReason::COUNT)
+        .map(|r| Stats::from_samples(
+            &results.iter().map(|run| run.revert_reasons[r] as f64).collect::<Vec<_>>()
+        ))
+        .collect();
+    let held_by_age = (0..held_sweep::AGE_BANDS)
+        .map(|b| Stats::from_samples(
+            &results.iter().map(|run| run.held_sweep.by_age[b] as f64).collect::<Vec<_>>()
+        ))
+        .collect();
+    let held_by_tier = (0..4)
+        .map(|t| Stats::from_samples(
+            &results.iter().map(|run| run.held_sweep.by_tier[t] as f64).collect::<Vec<_>>()
+        ))
+        .collect();
+    let tier_p95_settle_ticks = (0..4)
+        .map(|t| Stats::from_samples(
+            &results.iter().map(|r| r.tier_p95_settle_ticks[t]).collect::<Vec<_>>()
+        ))
+        .collect();
+    let held_unresolved = Stats::from_samples(
+        &results.iter().map(|r| r.held_sweep.unresolved as f64).collect::<Vec<_>>()
+    );
+    let demand_curve = DemandCurveRecorder::merged(results.iter().map(|r| r.demand_curve.as_slice()));
 
     MonteCarloReport {
         scenario_name: scenario.name.to_string(),
@@ -300,6 +825,13 @@ fn aggregate(scenario: &Scenario, results: Vec<BenchResult>) -> MonteCarloReport
         elapsed_ms,
         throughput_per_sec,
         packets_per_tick,
+        tier_effective_cost_bps,
+        tier_p95_settle_ticks,
+        revert_reasons,
+        held_by_age,
+        held_by_tier,
+        held_unresolved,
+        demand_curve,
         individual_runs: results,
     }
 }
@@ -1,8 +1,18 @@
 // SEC/Economist-Grade Benchmark Report Types
 // Structured output for independent analysis and whitepaper validation
 
+use arena_engine::held_sweep::HeldSweepReport;
+use arena_engine::liquidity_provider::LiquidityStats;
+use arena_engine::resources::ResourceSummary;
+use arena_engine::speculation::SpeculationStats;
+use arena_engine::{QuadrantTransition, RevertReason};
 use serde::Serialize;
 
+use crate::analytic::AnalyticCheck;
+use crate::comparisons::Comparison;
+use crate::incentive::IncentiveReport;
+use crate::metrics::{DemandCurvePoint, ResourceEconomicsRun};
+
 // ─── Statistics (per-metric Monte Carlo aggregation) ────────────────────────
 
 #[derive(Debug, Clone, Serialize)]
@@ -58,11 +68,16 @@ pub struct BenchResult {
     pub settlement_rate: f64,
     pub conservation_error: f64,
     pub normalized_conservation_error: f64,
+    /// Node fiat and crypto inventories against the flows that moved them,
+    /// as the larger of the two residuals over what was provisioned
+    pub asset_ledger_residual: f64,
     pub avg_fee: f64,
     pub peak_fee: f64,
     pub dissolved_count: u32,
     pub held_count: u32,
     pub fee_cap_breaches: u32,
+    /// Settlement fees the engine charged past their tier cap (must be 0)
+    pub settlement_fee_cap_breaches: u32,
     pub settlement_finality: bool,
     pub cost_certainty: bool,
     pub audit_trail: bool,
@@ -77,9 +92,128 @@ pub struct BenchResult {
     pub conservation_holds: bool,
     pub final_held_count: u32,
     pub final_orbit_count: u32,
+    /// Final orbit count split into liquidity (no liquid egress) and
+    /// topology (hop limit, disabled destination) failures
+    pub final_liquidity_orbits: u32,
+    pub final_topology_orbits: u32,
     pub throughput_per_sec: f64,
     pub peg_elasticity_pct: f64,
     pub max_normalized_conservation: f64,
+    /// Time-averaged smoothed liquidity coefficient over the run
+    pub mean_liquidity_lambda: f64,
+    /// Mean all-in settlement cost per tier (L0..L3), basis points of original value
+    pub tier_effective_cost_bps: [f64; 4],
+    /// Highest per-epoch share of settled value through the top-1 / top-3 egress nodes
+    pub peak_egress_top1_share: f64,
+    pub peak_egress_top3_share: f64,
+    /// Peak and mean of the per-tick composite liquidity stress index
+    pub peak_stress_index: f64,
+    pub mean_stress_index: f64,
+    /// Largest value in flight between hops on any tick
+    pub peak_in_transit_float: f64,
+    /// Peak and mean panic level, endogenous or scenario-driven
+    pub peak_panic_level: f64,
+    pub mean_panic_level: f64,
+    /// Implied demand curve: share of offered traffic spawned per band of
+    /// realized all-in cost
+    pub demand_curve: Vec<DemandCurvePoint>,
+    /// Fees paid to NGauge verifiers over the run
+    pub verification_fees_paid: f64,
+    /// Hops that found no NGauge capacity anywhere in the network
+    pub verification_shortfalls: u32,
+    /// Ticks spent in each governor quadrant, indexed A..F
+    pub quadrant_ticks: [u32; 6],
+    pub quadrant_transition_count: u32,
+    /// Earliest quadrant changes of the run (capped; see `quadrant_transition_count`)
+    pub quadrant_transitions: Vec<QuadrantTransition>,
+    /// Corridor batches settled at egress (zero when batching is off)
+    pub settlement_batches: u32,
+    /// Egress inventory drawn by settlements, and inventory saved by batch
+    /// netting relative to settling the same packets one by one
+    pub egress_inventory_drawn: f64,
+    pub batch_inventory_saved: f64,
+    /// Fees rebated to packets that routed only through high-trust nodes
+    pub trust_rebates_paid: f64,
+    /// Share of settlements whose whole route was high-trust
+    pub trusted_settlement_share: f64,
+    /// Fee revenue collected over the run
+    pub fees_collected: f64,
+    /// Fee revenue given up to volume discounts, and the share of settled
+    /// value from origins that were discounted
+    pub volume_discount_forgone: f64,
+    pub discounted_value_share: f64,
+    /// Mean spawn-to-settlement ticks of non-dust packets
+    pub non_dust_settle_ticks: f64,
+    /// Spawns the dust policy rejected, and dust surcharges collected
+    pub dust_rejected: u32,
+    pub dust_surcharges: f64,
+    /// Mean active packets (buffered or in flight) per tick
+    pub mean_packets_in_flight: f64,
+    /// Payments coalesced at ingress, and the aggregate packets that carried them
+    pub payments_aggregated: u32,
+    pub aggregate_packets: u32,
+    /// Share of next-hop lookups served from the route cache (0 when off)
+    pub route_cache_hit_rate: f64,
+    /// Inequality of Transit operators' earnings: Gini coefficient and the
+    /// best-earning tenth's share
+    pub transit_earnings_gini: f64,
+    pub transit_top_decile_share: f64,
+    /// Settlement fees' total deviation from their spawn-time quotes, and
+    /// settlements charged other than their quote
+    pub quote_drift: f64,
+    pub quote_mismatches: u32,
+    /// Bankrupt Egress exits and recapitalized re-entries
+    pub egress_exits: u32,
+    pub egress_reentries: u32,
+    /// Reverts by `RevertReason` discriminant (TTL expired, orbit timeout,
+    /// no liquid Egress, cancelled, circuit breaker, hop limit, dropped,
+    /// censored, node removed)
+    pub revert_reasons: [u32; RevertReason::COUNT],
+    /// Reverted payments re-sent by their senders (0 without a retry model)
+    pub retry_count: u32,
+    /// Speculator agents and the organic-ratio detector's score against them
+    pub speculation: SpeculationStats,
+    /// Liquidity providers' supply, withdrawals and fees earned (empty without them)
+    pub liquidity: LiquidityStats,
+    /// Node running costs and P&L, when the resource model was on
+    pub resources: Option<ResourceSummary>,
+    /// Governor decisions taken, and direction reversals of the fee rate
+    /// they set
+    pub governor_decisions: u32,
+    pub fee_rate_reversals: u32,
+    /// Widest egress payout FX spread, and value beneficiaries lost to it
+    pub peak_payout_spread: f64,
+    pub payout_slippage: f64,
+    /// Fees collected, demurrage burned and settlement rewards distributed,
+    /// by the tier that paid them (L0..L3)
+    pub tier_fees_collected: [f64; 4],
+    pub tier_demurrage_burned: [f64; 4],
+    pub tier_rewards_distributed: [f64; 4],
+    /// Largest estimated footprint of packet containers and histories
+    pub peak_memory_bytes: u64,
+    /// Per-phase breakdown of a chained scenario (empty for a single phase)
+    pub phases: Vec<PhaseSummary>,
+    /// Time-series assertions of the scenario that did not hold
+    pub series_failures: Vec<String>,
+    /// p95 spawn-to-settlement ticks by tier (L0..L3; 0 with no
+    /// settlements), and the scenario's latency service levels it missed
+    pub tier_p95_settle_ticks: [f64; 4],
+    pub sla_failures: Vec<String>,
+    /// Packets still Held at the end by age, tier and blocking reason, and
+    /// what the forced resolution pass after scoring left unresolved
+    pub held_sweep: HeldSweepReport,
+}
+
+/// One phase of a chained scenario.
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseSummary {
+    pub name: String,
+    pub ticks: u64,
+    pub settlements: u32,
+    pub reverts: u32,
+    pub mean_fee_rate: f64,
+    /// Packets held when the phase handed over to the next
+    pub held_at_end: u32,
 }
 
 // ─── Monte Carlo Report (per-scenario aggregation) ──────────────────────────
@@ -102,6 +236,19 @@ pub struct MonteCarloReport {
     pub elapsed_ms: Stats,
     pub throughput_per_sec: Stats,
     pub packets_per_tick: Stats,
+    /// Per-tier (L0..L3) effective settlement cost in bps
+    pub tier_effective_cost_bps: Vec<Stats>,
+    /// Per-tier (L0..L3) p95 spawn-to-settlement ticks
+    pub tier_p95_settle_ticks: Vec<Stats>,
+    /// Reverts per run by `RevertReason` discriminant
+    pub revert_reasons: Vec<Stats>,
+    /// Held packets at the end per run by age band and tier (L0..L3), and
+    /// those the forced resolution pass could not clear
+    pub held_by_age: Vec<Stats>,
+    pub held_by_tier: Vec<Stats>,
+    pub held_unresolved: Stats,
+    /// Implied demand curve pooled over every run
+    pub demand_curve: Vec<DemandCurvePoint>,
     pub individual_runs: Vec<BenchResult>,
 }
 
@@ -118,6 +265,7 @@ pub struct WhitepaperValidation {
 }
 
 impl WhitepaperValidation {
+    #[allow(dead_code)]
     pub fn all_pass(&self) -> bool {
         self.bank_run_no_fail
             && self.peg_elasticity_95pct
@@ -136,9 +284,26 @@ pub struct BenchReport {
     pub version: &'static str,
     pub prng: &'static str,
     pub n_runs_per_scenario: usize,
+    /// Target settlement-rate CI half-width when runs were chosen adaptively
+    /// (`n_runs_per_scenario` is then the per-scenario cap)
+    pub adaptive_ci_half_width: Option<f64>,
+    /// `--quick` run: few runs, single-phase scenarios cut short
+    pub quick: bool,
+    /// Route scoring every scenario ran under (`TrustWeighted` or the core's
+    /// `CapacityOnly`)
+    pub route_scoring_mode: String,
     pub summary: Summary,
     pub whitepaper_validation: WhitepaperValidation,
+    /// Paired runs of one scenario and seed under toggled settings, as
+    /// registered in `comparisons::comparisons`
+    pub comparisons: Vec<Comparison>,
+    /// Never-routing probe packets checked against closed-form demurrage, per tier
+    pub analytic_cross_check: Vec<AnalyticCheck>,
+    /// Scale scenarios with node running costs (storage, bandwidth, energy)
+    pub resource_economics: Vec<ResourceEconomicsRun>,
     pub scenarios: Vec<MonteCarloReport>,
+    /// Paired normal vs drought scenarios, aggregated over the same seeds
+    pub incentive_scenarios: Vec<IncentiveReport>,
 }
 
 #[derive(Debug, Serialize)]
@@ -148,3 +313,69 @@ pub struct Summary {
     pub failed: usize,
     pub pass_rate: f64,
 }
+
+// ─── Markdown Rendering ─────────────────────────────────────────────────────
+
+fn pass_fail(ok: bool) -> &'static str {
+    if ok { "✅" } else { "❌" }
+}
+
+impl BenchReport {
+    /// Compact GitHub-flavored markdown summary for PR comments and appendices.
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::new();
+        let runs = match self.adaptive_ci_half_width {
+            Some(hw) => format!("adaptive runs to settlement CI ±{}, cap {}", hw, self.n_runs_per_scenario),
+            None => format!("{} runs/scenario", self.n_runs_per_scenario),
+        };
+        md.push_str(&format!(
+            "### Arena Bench — {}/{} scenarios passed ({}, {} route scoring)\n\n",
+            self.summary.passed, self.summary.total, runs, self.route_scoring_mode
+        ));
+        md.push_str("| Scenario | Pass % | Settlement % (95% CI) | Conservation (norm) | Peg % | |\n");
+        md.push_str("|---|---:|---:|---:|---:|:-:|\n");
+        for r in &self.scenarios {
+            md.push_str(&format!(
+                "| {} | {:.1} | {:.1} [{:.1}, {:.1}] | {:.2e} | {:.1} | {} |\n",
+                r.label,
+                r.pass_rate * 100.0,
+                r.settlement_rate.mean,
+                r.settlement_rate.ci_lower,
+                r.settlement_rate.ci_upper,
+                r.normalized_conservation_error.mean,
+                r.peg_elasticity_pct.mean,
+                pass_fail(r.pass_rate >= 0.933),
+            ));
+        }
+        for r in &self.incentive_scenarios {
+            md.push_str(&format!(
+                "| {} | {:.1} | profit ratio {:.2}x [{:.2}, {:.2}] | - | - | {} |\n",
+                r.label,
+                r.pass_rate * 100.0,
+                r.profit_ratio.mean,
+                r.profit_ratio.ci_lower,
+                r.profit_ratio.ci_upper,
+                pass_fail(r.passes),
+            ));
+        }
+
+        let wp = &self.whitepaper_validation;
+        md.push_str(&format!(
+            "\n**Whitepaper:** bank run {} · peg ≥95% {} · incentive {} · demurrage {} · route healing {} · max norm conservation {:.2e}\n",
+            pass_fail(wp.bank_run_no_fail),
+            pass_fail(wp.peg_elasticity_95pct),
+            pass_fail(wp.incentive_ratio_500pct),
+            pass_fail(wp.demurrage_decay_to_zero),
+            pass_fail(wp.route_healing_zero_loss),
+            wp.max_normalized_conservation,
+        ));
+        let analytic_passed = self.analytic_cross_check.iter().filter(|c| c.pass).count();
+        md.push_str(&format!(
+            "\n**Analytic demurrage:** {}/{} never-routing probes match the closed form {}\n",
+            analytic_passed,
+            self.analytic_cross_check.len(),
+            pass_fail(analytic_passed == self.analytic_cross_check.len()),
+        ));
+        md
+    }
+}
@@ -1,10 +1,18 @@
 // Scenario Definitions — all 34 original + 3 whitepaper-exact additions
 // Zero engine changes: all scenario logic is in curve functions and setup/event closures
 
-use arena_engine::ArenaSimulation;
+use arena_engine::backlog::BacklogSpec;
+use arena_engine::inventory::{self, ExpectedDemand, InventoryPolicy};
+use arena_engine::latency::TierLatencyStats;
+use arena_engine::{ArenaSimulation, MarketTier, NodeRole};
+
+use crate::series::{SeriesAssertion, SeriesMetric};
 
 // ─── Scenario Configuration ─────────────────────────────────────────────────
 
+pub type SetupFn = Box<dyn Fn(&mut ArenaSimulation) + Send + Sync>;
+pub type MidEventFn = Box<dyn Fn(&mut ArenaSimulation, u64) + Send + Sync>;
+
 pub struct Scenario {
     pub name: &'static str,
     pub label: &'static str,
@@ -19,9 +27,43 @@ pub struct Scenario {
     pub panic_curve: Option<fn(u64) -> f64>,
     pub criteria: PassCriteria,
     /// Pre-run setup (e.g., set_node_crypto for liquidity control)
-    pub setup: Option<Box<dyn Fn(&mut ArenaSimulation) + Send + Sync>>,
+    pub setup: Option<SetupFn>,
     /// Mid-simulation events (e.g., kill_node at specific tick)
-    pub mid_event: Option<Box<dyn Fn(&mut ArenaSimulation, u64) + Send + Sync>>,
+    pub mid_event: Option<MidEventFn>,
+    /// Phases run back to back on one continuous economy, as (scenario name,
+    /// ticks). Each phase brings its own curves, setup and events; the chain's
+    /// own nodes and criteria apply to the whole run. Empty for a single phase.
+    pub chain: &'static [(&'static str, u64)],
+    /// Suite membership for `--include-tags` / `--exclude-tags`
+    pub tags: &'static [Tag],
+}
+
+/// Scenario tags for composing a suite. Every scenario is either `Fast`
+/// (well under a second per run) or `Slow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tag {
+    Fast,
+    Slow,
+    Whitepaper,
+    Scale,
+}
+
+impl Tag {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "fast" => Some(Self::Fast),
+            "slow" => Some(Self::Slow),
+            "whitepaper" => Some(Self::Whitepaper),
+            "scale" => Some(Self::Scale),
+            _ => None,
+        }
+    }
+}
+
+impl Scenario {
+    pub fn has_any_tag(&self, tags: &[Tag]) -> bool {
+        self.tags.iter().any(|t| tags.contains(t))
+    }
 }
 
 pub struct PassCriteria {
@@ -33,6 +75,22 @@ pub struct PassCriteria {
     pub require_audit_trail: bool,
     pub require_zero_stuck: bool,
     pub max_held_at_end: Option<u32>,
+    /// Bound on the peak per-epoch share of settled value through the largest egress
+    pub max_egress_top1_share: Option<f64>,
+    /// Bound on the peak per-epoch share through the three largest egresses
+    pub max_egress_top3_share: Option<f64>,
+    /// Minimum share of settlements whose whole route was high-trust (rebated)
+    pub min_trusted_settlement_share: Option<f64>,
+    /// Bound on the run's mean liquidity stress index
+    pub max_mean_stress_index: Option<f64>,
+    /// Bounds on packets orbiting at the end for want of a liquid egress, and
+    /// for topology reasons (hop limit, disabled destination)
+    pub max_liquidity_orbits_at_end: Option<u32>,
+    pub max_topology_orbits_at_end: Option<u32>,
+    /// Claims about the run's recovery dynamics, checked tick by tick
+    pub series_assertions: &'static [SeriesAssertion],
+    /// Service levels on per-tier settlement latency, checked at the end
+    pub latency_slas: &'static [LatencySla],
 }
 
 impl Default for PassCriteria {
@@ -46,10 +104,140 @@ impl Default for PassCriteria {
             require_audit_trail: false,
             require_zero_stuck: false,
             max_held_at_end: None,
+            max_egress_top1_share: None,
+            max_egress_top3_share: None,
+            min_trusted_settlement_share: None,
+            max_mean_stress_index: None,
+            max_liquidity_orbits_at_end: None,
+            max_topology_orbits_at_end: None,
+            series_assertions: &[],
+            latency_slas: &[],
         }
     }
 }
 
+/// A user-experience guarantee: quantile `quantile` of `tier`'s
+/// spawn-to-settlement latency stays within `max_ticks`. Quantiles are upper
+/// bucket edges, so the check errs strict. A tier that settled nothing has
+/// no latency to judge and passes.
+#[derive(Debug, Clone, Copy)]
+pub struct LatencySla {
+    pub tier: MarketTier,
+    pub quantile: f64,
+    pub max_ticks: f64,
+}
+
+impl LatencySla {
+    /// Why the run misses this service level, if it does.
+    pub fn check(&self, latency: &[TierLatencyStats]) -> Option<String> {
+        let stats = &latency[self.tier as usize];
+        let ticks = stats.ticks.quantile(self.quantile);
+        (stats.settlements > 0 && ticks > self.max_ticks).then(|| {
+            format!("{:?} p{:.0} settlement {} ticks > {} ticks",
+                self.tier, self.quantile * 100.0, ticks, self.max_ticks)
+        })
+    }
+}
+
+// ─── Incentive Scenarios (paired normal vs drought runs) ────────────────────
+
+/// A paired scenario: the same seeded traffic run once on the default Egress
+/// inventory and once with it cut to `drought_liquidity`. Each seed yields
+/// one profit ratio, Egress return on inventory in the drought over the same
+/// in the normal run, and the criteria apply to its Monte Carlo distribution.
+pub struct IncentiveScenario {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub category: &'static str,
+    pub nodes: u32,
+    pub ticks: u64,
+    pub gold: f64,
+    pub demand: f64,
+    /// Share of the default Egress inventory left in the drought run
+    pub drought_liquidity: f64,
+    /// Panic level of the drought run (the normal run has none)
+    pub drought_panic: f64,
+    pub criteria: IncentiveCriteria,
+    pub tags: &'static [Tag],
+}
+
+impl IncentiveScenario {
+    pub fn has_any_tag(&self, tags: &[Tag]) -> bool {
+        self.tags.iter().any(|t| tags.contains(t))
+    }
+}
+
+pub struct IncentiveCriteria {
+    /// Profit ratio a seed must exceed to pass, and that the lower bound of
+    /// the ratio's 95% CI must clear for the claim to hold
+    pub min_profit_ratio: f64,
+}
+
+impl Default for IncentiveCriteria {
+    fn default() -> Self {
+        // Whitepaper Metric 3: Egress profits spike >500% in a drought
+        Self { min_profit_ratio: 5.0 }
+    }
+}
+
+pub fn incentive_scenarios() -> Vec<IncentiveScenario> {
+    vec![
+        IncentiveScenario { name: "WP_INCENTIVE_RATIO", label: "WP: Incentive Profit Ratio (paired)",
+            category: "whitepaper",
+            gold: 163.0, demand: 0.8, nodes: 100, ticks: 2000,
+            drought_liquidity: 0.1, drought_panic: 0.7,
+            criteria: IncentiveCriteria::default(),
+            tags: &[Tag::Slow, Tag::Whitepaper] },
+    ]
+}
+
+/// Share of the constitutional caps a regulated pilot Egress may charge.
+const PILOT_CAP_FRACTION: f64 = 0.5;
+
+/// Dust-flood packets sprayed per tick, and their value (the bench's L0 floor).
+const DUST_FLOOD_PER_TICK: u32 = 2000;
+const DUST_FLOOD_VALUE: f64 = 0.5;
+
+/// Backlog the congested-start run opens with: mostly retail packets up to
+/// 60 ticks old, a fifth of them already on a link.
+const CONGESTED_START_BACKLOG: BacklogSpec = BacklogSpec {
+    packets: 3000,
+    tier_mix: [0.7, 0.25, 0.05, 0.0],
+    min_age_ticks: 0,
+    max_age_ticks: 60,
+    in_transit_share: 0.2,
+};
+
+/// Retail service level: 95% of L0 payments settle within 40 ticks. Calm
+/// 24-node markets run at ~16, so the bound flags a regression without
+/// tripping on seed noise.
+const RETAIL_SLAS: &[LatencySla] = &[
+    LatencySla { tier: MarketTier::L0, quantile: 0.95, max_ticks: 40.0 },
+];
+
+/// Fiduciary service levels: the retail L0 bound, plus 95% of L1 within 50
+/// ticks (fiduciary runs measure ~12).
+const FIDUCIARY_SLAS: &[LatencySla] = &[
+    LatencySla { tier: MarketTier::L0, quantile: 0.95, max_ticks: 40.0 },
+    LatencySla { tier: MarketTier::L1, quantile: 0.95, max_ticks: 50.0 },
+];
+
+/// Gold period (ticks) of the long-horizon run's gentle ±2% swing.
+const LONG_HORIZON_GOLD_PERIOD: f64 = 5000.0;
+/// Ticks between the long-horizon run's Egress restocks. Settlements drain
+/// Egress crypto for good, so without operators rebalancing the economy
+/// would stall long before the horizon and every metric flatline.
+const LONG_HORIZON_RESTOCK_TICKS: u64 = 500;
+/// The long-horizon run's governor state and rolling windows, checked in
+/// 100K-tick stretches against the first, i.e. against a 100K-tick run on
+/// the same seed.
+const LONG_HORIZON_DRIFT: &[SeriesAssertion] = &[
+    SeriesAssertion::WithinFirstWindow { metric: SeriesMetric::IntegralError, window: 100_000, tolerance: 0.25 },
+    SeriesAssertion::WithinFirstWindow { metric: SeriesMetric::LiquidityLambda, window: 100_000, tolerance: 0.25 },
+    SeriesAssertion::WithinFirstWindow { metric: SeriesMetric::Volatility, window: 100_000, tolerance: 0.25 },
+    SeriesAssertion::WithinFirstWindow { metric: SeriesMetric::OrganicRatio, window: 100_000, tolerance: 0.25 },
+];
+
 // ─── Curve Functions ────────────────────────────────────────────────────────
 
 fn black_swan_gold(tick: u64) -> f64 {
@@ -67,6 +255,10 @@ fn governor_stress_demand(tick: u64) -> f64 {
     0.5 + (tick as f64 / 15.0).sin() * 0.4
 }
 
+fn long_horizon_gold(tick: u64) -> f64 {
+    2600.0 * (1.0 + 0.02 * (tick as f64 / LONG_HORIZON_GOLD_PERIOD * std::f64::consts::TAU).sin())
+}
+
 fn bull_2025_gold(tick: u64) -> f64 {
     let t = tick as f64;
     let progress = t / 600.0;
@@ -121,6 +313,24 @@ fn peg_elasticity_gold(tick: u64) -> f64 {
     163.0 + 81.5 * (t / 100.0).sin() * (1.0 + 0.3 * (t / 300.0).sin())
 }
 
+/// Oracle outage gold: a 20% slide over ticks 300-400, while the feed is frozen.
+fn oracle_outage_gold(tick: u64) -> f64 {
+    let t = tick as f64;
+    if tick < 300 { 163.0 }
+    else if tick < 400 { 163.0 - (t - 300.0) * 0.326 }
+    else { 130.4 }
+}
+
+/// Bound on packets left orbiting for topology reasons at the end of the
+/// partition run. Islanded L0-L2 packets time out within ~1000 ticks of the
+/// heal; islanded L3 packets that ran out of hops wait out dissolution past
+/// the end of the run (seeds end with ~45 held, nearly all L3).
+const PARTITION_MAX_TOPOLOGY_ORBITS: u32 = 100;
+
+/// Oracle outage window: the price feed freezes at tick 300 for 200 ticks.
+const ORACLE_OUTAGE_START: u64 = 300;
+const ORACLE_OUTAGE_TICKS: u32 = 200;
+
 // ─── Whitepaper-Exact Curve Functions ───────────────────────────────────────
 
 /// Bank Run gold: σ=2.0 (100% swing amplitude over 20-tick period)
@@ -150,188 +360,253 @@ pub fn scenarios() -> Vec<Scenario> {
         Scenario { name: "NORMAL_MARKET", label: "Normal Market", category: "market",
             gold: 2600.0, demand: 0.3, panic: 0.0, nodes: 24, ticks: 600,
             gold_curve: None, demand_curve: None, panic_curve: None,
-            criteria: PassCriteria { min_settlement_rate: Some(50.0), ..Default::default() },
-            setup: None, mid_event: None },
+            criteria: PassCriteria {
+                min_settlement_rate: Some(50.0),
+                max_mean_stress_index: Some(0.4),
+                latency_slas: RETAIL_SLAS,
+                ..Default::default()
+            },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "BULL_RUN", label: "Bull Run", category: "market",
             gold: 3200.0, demand: 0.8, panic: 0.05, nodes: 24, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { min_settlement_rate: Some(15.0), ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "BEAR_MARKET", label: "Bear Market", category: "market",
             gold: 1800.0, demand: 0.1, panic: 0.4, nodes: 24, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria::default(),
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "BLACK_SWAN", label: "Black Swan", category: "market",
             gold: 2600.0, demand: 0.9, panic: 0.95, nodes: 24, ticks: 300,
             gold_curve: Some(black_swan_gold), demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 2.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "STAGFLATION", label: "Stagflation", category: "market",
             gold: 2600.0, demand: 0.05, panic: 0.3, nodes: 24, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria::default(),
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
 
         // ─── Stress Tests (8) ───────────────────────────────────────────
         Scenario { name: "SCALE_100", label: "Scale 100", category: "stress",
             gold: 2600.0, demand: 0.3, panic: 0.0, nodes: 100, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 5.0, min_settlement_rate: Some(30.0), ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast, Tag::Scale] },
         Scenario { name: "SCALE_250", label: "Scale 250", category: "stress",
             gold: 2600.0, demand: 0.3, panic: 0.0, nodes: 250, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 10.0, min_settlement_rate: Some(20.0), ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast, Tag::Scale] },
         Scenario { name: "SCALE_500", label: "Scale 500", category: "stress",
             gold: 2600.0, demand: 0.5, panic: 0.0, nodes: 500, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 20.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast, Tag::Scale] },
         Scenario { name: "TIER_ISOLATION", label: "Tier Isolation", category: "stress",
             gold: 2600.0, demand: 0.5, panic: 0.0, nodes: 24, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria::default(),
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "FEE_CAP_STRESS", label: "Fee Cap Stress", category: "stress",
             gold: 2600.0, demand: 0.95, panic: 0.8, nodes: 24, ticks: 300,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 2.0, max_fee_cap_breaches: Some(0), ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
+        // Jurisdiction pilot: every other Egress is regulated at half the
+        // constitutional caps, and breaches count against its own caps
+        Scenario { name: "PILOT_FEE_CAPS", label: "Pilot Fee Caps (regulated Egress)", category: "stress",
+            gold: 2600.0, demand: 0.95, panic: 0.8, nodes: 24, ticks: 300,
+            gold_curve: None, demand_curve: None, panic_curve: None,
+            criteria: PassCriteria { max_conservation_error: 2.0, max_fee_cap_breaches: Some(0), ..Default::default() },
+            setup: Some(Box::new(|sim: &mut ArenaSimulation| {
+                let regulated: Vec<u32> = sim
+                    .get_nodes_filtered_core(Some(NodeRole::Egress), None, 0, usize::MAX)
+                    .iter()
+                    .step_by(2)
+                    .map(|n| n.id)
+                    .collect();
+                for id in regulated {
+                    for tier in [MarketTier::L0, MarketTier::L1, MarketTier::L2, MarketTier::L3] {
+                        sim.set_node_fee_cap(id, tier as u32, tier.fee_cap() * PILOT_CAP_FRACTION);
+                    }
+                }
+            })),
+            mid_event: None, chain: &[], tags: &[Tag::Fast] },
+        // Recovery from a standing backlog rather than an empty network;
+        // seeds settle ~79%, the backlog's aged L0 timing out first
+        Scenario { name: "CONGESTED_START", label: "Congested Start (pre-loaded backlog)", category: "stress",
+            gold: 2600.0, demand: 0.5, panic: 0.0, nodes: 24, ticks: 400,
+            gold_curve: None, demand_curve: None, panic_curve: None,
+            criteria: PassCriteria { max_conservation_error: 2.0, min_settlement_rate: Some(70.0), ..Default::default() },
+            setup: Some(Box::new(|sim: &mut ArenaSimulation| {
+                sim.seed_backlog_core(&CONGESTED_START_BACKLOG);
+            })),
+            mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "GOVERNOR_STRESS", label: "Governor Stress", category: "stress",
             gold: 2600.0, demand: 0.5, panic: 0.0, nodes: 24, ticks: 200,
             gold_curve: Some(governor_stress_gold), demand_curve: Some(governor_stress_demand), panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 2.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "DISSOLUTION_TEST", label: "Dissolution", category: "stress",
             gold: 2600.0, demand: 0.3, panic: 0.0, nodes: 24, ticks: 8000,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria::default(),
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow] },
         Scenario { name: "AML_DETECTION", label: "AML Detection", category: "stress",
             gold: 2600.0, demand: 0.9, panic: 0.0, nodes: 24, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria::default(),
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
 
         // ─── Fiduciary Tests (3) ────────────────────────────────────────
         Scenario { name: "SETTLEMENT_FINALITY", label: "Settlement Finality", category: "fiduciary",
             gold: 2600.0, demand: 0.5, panic: 0.0, nodes: 24, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
-            criteria: PassCriteria { max_conservation_error: 0.01, require_settlement_finality: true, ..Default::default() },
-            setup: None, mid_event: None },
+            criteria: PassCriteria { max_conservation_error: 0.01, require_settlement_finality: true, latency_slas: FIDUCIARY_SLAS, ..Default::default() },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "COST_CERTAINTY", label: "Cost Certainty", category: "fiduciary",
             gold: 2600.0, demand: 0.5, panic: 0.2, nodes: 24, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
-            criteria: PassCriteria { max_conservation_error: 0.1, require_cost_certainty: true, ..Default::default() },
-            setup: None, mid_event: None },
+            criteria: PassCriteria { max_conservation_error: 0.1, require_cost_certainty: true, latency_slas: FIDUCIARY_SLAS, ..Default::default() },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "AUDIT_TRAIL", label: "Audit Trail", category: "fiduciary",
             gold: 2600.0, demand: 0.3, panic: 0.0, nodes: 24, ticks: 200,
             gold_curve: None, demand_curve: None, panic_curve: None,
-            criteria: PassCriteria { max_conservation_error: 0.1, require_audit_trail: true, ..Default::default() },
-            setup: None, mid_event: None },
+            criteria: PassCriteria { max_conservation_error: 0.1, require_audit_trail: true, latency_slas: FIDUCIARY_SLAS, ..Default::default() },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
 
         // ─── Real-World 2025-2026 (per-gram, 4 scenarios) ──────────────
         Scenario { name: "RW_BASELINE_2026", label: "RW: Feb 2026 Baseline", category: "real-world",
             gold: 163.0, demand: 0.4, panic: 0.05, nodes: 24, ticks: 600,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { min_settlement_rate: Some(40.0), ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "RW_BULL_2025", label: "RW: 2025 Bull Run", category: "real-world",
             gold: 83.5, demand: 0.3, panic: 0.0, nodes: 24, ticks: 600,
             gold_curve: Some(bull_2025_gold), demand_curve: Some(bull_2025_demand), panic_curve: None,
             criteria: PassCriteria { min_settlement_rate: Some(30.0), ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "RW_FLASH_CRASH_OCT25", label: "RW: Oct25 Flash Crash", category: "real-world",
             gold: 141.0, demand: 0.5, panic: 0.0, nodes: 24, ticks: 300,
             gold_curve: Some(flash_crash_oct25_gold), demand_curve: Some(flash_crash_oct25_demand),
             panic_curve: Some(flash_crash_oct25_panic),
             criteria: PassCriteria { max_conservation_error: 2.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
         Scenario { name: "RW_FED_CORRECTION_26", label: "RW: 2026 Fed Correction", category: "real-world",
             gold: 177.0, demand: 0.6, panic: 0.1, nodes: 24, ticks: 400,
             gold_curve: Some(fed_correction_26_gold), demand_curve: Some(fed_correction_26_demand), panic_curve: None,
             criteria: PassCriteria { ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Fast] },
 
         // ─── Whitepaper Invariant Tests (4 original) ────────────────────
         Scenario { name: "WP_NO_FAIL_BANK_RUN", label: "WP: Bank Run No-Fail", category: "whitepaper",
             gold: 163.0, demand: 0.95, panic: 0.9, nodes: 100, ticks: 2000,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 10.0, max_held_at_end: Some(10000), ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Whitepaper] },
         Scenario { name: "WP_PEG_ELASTICITY", label: "WP: Peg Elasticity", category: "whitepaper",
             gold: 163.0, demand: 0.5, panic: 0.0, nodes: 100, ticks: 2000,
             gold_curve: Some(peg_elasticity_gold), demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 10.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Whitepaper] },
         Scenario { name: "WP_INCENTIVE_DROUGHT", label: "WP: Incentive Drought", category: "whitepaper",
             gold: 163.0, demand: 0.8, panic: 0.7, nodes: 100, ticks: 2000,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 20.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Whitepaper] },
         Scenario { name: "WP_DEMURRAGE_LOOP", label: "WP: Demurrage Loop Decay", category: "whitepaper",
             gold: 163.0, demand: 0.3, panic: 0.0, nodes: 24, ticks: 8000,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_held_at_end: Some(2000), ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Whitepaper] },
 
         // ─── Scale Validation (4) ───────────────────────────────────────
         Scenario { name: "SCALE_100_V2", label: "Scale: 100 Nodes", category: "scale",
             gold: 163.0, demand: 0.5, panic: 0.0, nodes: 100, ticks: 2000,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 5.0, min_settlement_rate: Some(40.0), ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Scale] },
         Scenario { name: "SCALE_1K", label: "Scale: 1K Nodes", category: "scale",
             gold: 163.0, demand: 0.5, panic: 0.0, nodes: 1000, ticks: 2000,
             gold_curve: None, demand_curve: None, panic_curve: None,
-            criteria: PassCriteria { max_conservation_error: 50.0, min_settlement_rate: Some(30.0), ..Default::default() },
-            setup: None, mid_event: None },
+            criteria: PassCriteria {
+                max_conservation_error: 50.0,
+                min_settlement_rate: Some(30.0),
+                max_egress_top1_share: Some(0.10),
+                max_egress_top3_share: Some(0.25),
+                ..Default::default()
+            },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Scale] },
         Scenario { name: "SCALE_5K", label: "Scale: 5K Nodes", category: "scale",
             gold: 163.0, demand: 0.4, panic: 0.0, nodes: 5000, ticks: 1000,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 200.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Scale] },
         Scenario { name: "SCALE_10K", label: "Scale: 10K Nodes", category: "scale",
             gold: 163.0, demand: 0.3, panic: 0.0, nodes: 10000, ticks: 500,
             gold_curve: None, demand_curve: None, panic_curve: None,
-            criteria: PassCriteria { max_conservation_error: 500.0, ..Default::default() },
-            setup: None, mid_event: None },
+            criteria: PassCriteria {
+                max_conservation_error: 500.0,
+                max_egress_top3_share: Some(0.10),
+                ..Default::default()
+            },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Scale] },
 
         // ─── Real-World at Scale (2) ────────────────────────────────────
         Scenario { name: "RW_1K_BULL_2025", label: "RW: 1K Bull Run 2025", category: "real-world",
             gold: 83.5, demand: 0.3, panic: 0.0, nodes: 1000, ticks: 2000,
             gold_curve: Some(bull_2025_gold), demand_curve: Some(bull_2025_demand), panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 50.0, min_settlement_rate: Some(30.0), ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Scale] },
         Scenario { name: "RW_1K_SOVEREIGN", label: "RW: 1K Sovereign Crisis", category: "real-world",
             gold: 177.0, demand: 0.9, panic: 0.8, nodes: 1000, ticks: 2000,
             gold_curve: Some(black_swan_gold), demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 200.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Scale] },
 
         // ─── Stress Envelope (4) ────────────────────────────────────────
         Scenario { name: "STRESS_20K", label: "Stress: 20K Nodes", category: "stress-envelope",
             gold: 163.0, demand: 0.5, panic: 0.0, nodes: 20000, ticks: 500,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 1000.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Scale] },
         Scenario { name: "STRESS_50K_TICKS", label: "Stress: 1K x 50K Ticks", category: "stress-envelope",
             gold: 163.0, demand: 0.5, panic: 0.0, nodes: 1000, ticks: 50000,
             gold_curve: Some(governor_stress_gold), demand_curve: Some(governor_stress_demand), panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 500.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Scale] },
         Scenario { name: "STRESS_FULL_PANIC", label: "Stress: 5K Full Panic", category: "stress-envelope",
             gold: 163.0, demand: 0.95, panic: 0.95, nodes: 5000, ticks: 1000,
             gold_curve: Some(black_swan_gold), demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 1000.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Scale] },
         Scenario { name: "STRESS_100K", label: "Stress: 100K Nodes", category: "stress-envelope",
             gold: 163.0, demand: 0.3, panic: 0.0, nodes: 100000, ticks: 100,
             gold_curve: None, demand_curve: None, panic_curve: None,
             criteria: PassCriteria { max_conservation_error: 10000.0, ..Default::default() },
-            setup: None, mid_event: None },
+            setup: None, mid_event: None, chain: &[], tags: &[Tag::Slow, Tag::Scale] },
+
+        // ─── Protocol Lifetime (1) ──────────────────────────────────────
+        // ~70s a run. The integral retraces the same 0..32 swing every gold
+        // period; lambda is heavy-tailed (capacity over a near-empty network)
+        Scenario { name: "LONG_HORIZON_DRIFT", label: "Long Horizon: 1M Ticks", category: "long-horizon",
+            gold: 2600.0, demand: 0.15, panic: 0.0, nodes: 24, ticks: 1_000_000,
+            gold_curve: Some(long_horizon_gold), demand_curve: None, panic_curve: None,
+            criteria: PassCriteria { max_conservation_error: 5.0, series_assertions: LONG_HORIZON_DRIFT, ..Default::default() },
+            setup: None,
+            // Egress operators restock to their starting reserve
+            mid_event: Some(Box::new(|sim: &mut ArenaSimulation, tick: u64| {
+                if tick.is_multiple_of(LONG_HORIZON_RESTOCK_TICKS) {
+                    let (_, reserve) = inventory::initial_inventory(InventoryPolicy::NetworkScaled,
+                        NodeRole::Egress, 24, 6, ExpectedDemand::auto_traffic(0.2));
+                    for i in (0..24u32).filter(|i| i % 4 == 1) {
+                        sim.set_node_crypto(i, reserve);
+                    }
+                }
+            })),
+            chain: &[], tags: &[Tag::Slow] },
     ];
 
     // ─── NEW: Whitepaper-Exact Scenarios (Gap #6, #7, demurrage) ────────
@@ -360,6 +635,8 @@ pub fn scenarios() -> Vec<Scenario> {
             }
         })),
         mid_event: None,
+        chain: &[],
+        tags: &[Tag::Slow, Tag::Whitepaper],
     });
 
     // Gap #7: Route Healing at Scale
@@ -371,6 +648,9 @@ pub fn scenarios() -> Vec<Scenario> {
         gold_curve: None, demand_curve: None, panic_curve: None,
         criteria: PassCriteria {
             max_conservation_error: 10.0,
+            // Healed routes leave orbits to liquidity, not topology
+            // (seeds end with 50-180 topology orbits out of ~1200)
+            max_topology_orbits_at_end: Some(500),
             ..Default::default()
         },
         setup: None,
@@ -382,6 +662,8 @@ pub fn scenarios() -> Vec<Scenario> {
                 sim.kill_node(6);
             }
         })),
+        chain: &[],
+        tags: &[Tag::Slow, Tag::Whitepaper],
     });
 
     // Demurrage Decay exact validation
@@ -393,10 +675,190 @@ pub fn scenarios() -> Vec<Scenario> {
         gold_curve: None, demand_curve: None, panic_curve: None,
         criteria: PassCriteria {
             max_held_at_end: Some(500),
+            // Low demand settles the fee at its floor early, and demurrage
+            // keeps the orbit bounded (seeds plateau near 200 held)
+            series_assertions: &[
+                SeriesAssertion::NonIncreasingAfter { metric: SeriesMetric::FeeRate, from_tick: 1000 },
+                SeriesAssertion::AtMostAfter { metric: SeriesMetric::HeldCount, from_tick: 2000, max: 400.0 },
+            ],
+            ..Default::default()
+        },
+        setup: None, mid_event: None, chain: &[],
+        tags: &[Tag::Slow, Tag::Whitepaper],
+    });
+
+    // Trust rebates: half the Egress fleet flaps (down 50 of every 200 ticks),
+    // so routes through it lose trust and their rebate
+    all.push(Scenario {
+        name: "TRUST_REBATE_FLAPPING",
+        label: "Trust Rebates (flapping Egress)",
+        category: "stress",
+        gold: 163.0, demand: 0.5, panic: 0.0, nodes: 100, ticks: 1000,
+        gold_curve: None, demand_curve: None, panic_curve: None,
+        criteria: PassCriteria {
+            max_conservation_error: 10.0,
+            min_trusted_settlement_share: Some(0.45),
+            ..Default::default()
+        },
+        setup: None,
+        mid_event: Some(Box::new(|sim: &mut ArenaSimulation, tick: u64| {
+            let flapping = (0..100u32).filter(|i| i % 8 == 1);
+            match tick % 200 {
+                100 => flapping.for_each(|i| sim.kill_node(i)),
+                150 => flapping.for_each(|i| sim.revive_node(i, NodeRole::Egress as u32)),
+                _ => {}
+            }
+        })),
+        chain: &[],
+        tags: &[Tag::Fast],
+    });
+
+    // Partition: the non-Egress nodes among the first 30 are islanded from
+    // the rest of the network for 400 ticks, so their traffic has nowhere to
+    // settle. After the heal, what they stranded must route on, time out or
+    // dissolve rather than sit Held.
+    all.push(Scenario {
+        name: "PARTITION_HEALING",
+        label: "Partition & Heal (islanded @ t=300-700)",
+        category: "stress",
+        gold: 163.0, demand: 0.5, panic: 0.0, nodes: 100, ticks: 2500,
+        gold_curve: None, demand_curve: None, panic_curve: None,
+        criteria: PassCriteria {
+            max_conservation_error: 10.0,
+            // Healed runs settle ~84%; a network left split settles ~73%
+            min_settlement_rate: Some(80.0),
+            max_topology_orbits_at_end: Some(PARTITION_MAX_TOPOLOGY_ORBITS),
+            ..Default::default()
+        },
+        setup: None,
+        mid_event: Some(Box::new(|sim: &mut ArenaSimulation, tick: u64| {
+            match tick {
+                300 => {
+                    let island: Vec<u32> = sim
+                        .get_nodes_filtered_core(None, None, 0, usize::MAX)
+                        .iter()
+                        .filter(|n| n.id < 30 && n.role != NodeRole::Egress)
+                        .map(|n| n.id)
+                        .collect();
+                    let mainland: Vec<u32> = (0..100).filter(|id| !island.contains(id)).collect();
+                    sim.partition_network_core(&island, &mainland);
+                }
+                700 => {
+                    sim.heal_partition_core();
+                }
+                _ => {}
+            }
+        })),
+        chain: &[],
+        tags: &[Tag::Slow],
+    });
+
+    // Dust flood: one origin sprays minimum-value L0 packets for 150 ticks,
+    // eating the NGauge verification capacity honest hops need
+    all.push(Scenario {
+        name: "DUST_FLOOD",
+        label: "Dust Flood (single-origin L0 spray)",
+        category: "stress",
+        gold: 163.0, demand: 0.5, panic: 0.0, nodes: 100, ticks: 500,
+        gold_curve: None, demand_curve: None, panic_curve: None,
+        criteria: PassCriteria {
+            max_conservation_error: 10.0,
+            ..Default::default()
+        },
+        setup: None,
+        mid_event: Some(Box::new(|sim: &mut ArenaSimulation, tick: u64| {
+            if (100..250).contains(&tick) {
+                for _ in 0..DUST_FLOOD_PER_TICK {
+                    sim.spawn_packet(0, DUST_FLOOD_VALUE);
+                }
+            }
+        })),
+        chain: &[],
+        tags: &[Tag::Slow],
+    });
+
+    // Oracle outage: the gold feed freezes for 200 ticks while gold slides
+    // 20%. The governor holds its parameters on the stale price, then has to
+    // act on the whole move at once when the feed returns.
+    all.push(Scenario {
+        name: "ORACLE_OUTAGE",
+        label: "Oracle Outage (feed frozen 200 ticks)",
+        category: "stress",
+        gold: 163.0, demand: 0.5, panic: 0.0, nodes: 100, ticks: 1000,
+        gold_curve: Some(oracle_outage_gold), demand_curve: None, panic_curve: None,
+        criteria: PassCriteria {
+            max_conservation_error: 10.0,
+            // Seeds settle ~94% through the outage and catch-up
+            min_settlement_rate: Some(85.0),
+            ..Default::default()
+        },
+        setup: None,
+        mid_event: Some(Box::new(|sim: &mut ArenaSimulation, tick: u64| {
+            if tick == ORACLE_OUTAGE_START {
+                sim.apply_oracle_outage(ORACLE_OUTAGE_TICKS);
+            }
+        })),
+        chain: &[],
+        tags: &[Tag::Fast],
+    });
+
+    // Chained: baseline market, the Oct-25 flash crash, then recovery, run as
+    // one continuous economy so the crash inherits the baseline's float and
+    // the recovery inherits the crash's backlog. No replenishment policy is
+    // set, so a default fleet runs dry after ~600 baseline ticks and
+    // the chain starts with 10x the usual Egress liquidity to last all phases.
+    all.push(Scenario {
+        name: "CHAIN_CRASH_RECOVERY",
+        label: "Chain: Baseline → Flash Crash → Recovery",
+        category: "chain",
+        gold: 163.0, demand: 0.4, panic: 0.05, nodes: 24, ticks: 1500,
+        gold_curve: None, demand_curve: None, panic_curve: None,
+        criteria: PassCriteria {
+            max_conservation_error: 5.0,
+            min_settlement_rate: Some(70.0),
+            // The crash hits at run tick 650 (tick 50 of the crash phase)
+            series_assertions: &[SeriesAssertion::RecoversWithin {
+                metric: SeriesMetric::FeeRate, shock_tick: 650, within: 200, tolerance: 0.05,
+            }],
             ..Default::default()
         },
-        setup: None, mid_event: None,
+        setup: Some(Box::new(|sim: &mut ArenaSimulation| {
+            let egress: Vec<(u32, f64)> = sim
+                .get_nodes_filtered_core(Some(NodeRole::Egress), None, 0, usize::MAX)
+                .iter()
+                .map(|n| (n.id, n.inventory_crypto))
+                .collect();
+            for (id, crypto) in egress {
+                sim.set_node_crypto(id, crypto * 10.0);
+            }
+        })),
+        mid_event: None,
+        chain: &[
+            ("RW_BASELINE_2026", 600),
+            ("RW_FLASH_CRASH_OCT25", 300),
+            ("RW_BASELINE_2026", 600),
+        ],
+        tags: &[Tag::Fast],
     });
 
     all
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_scenario_is_fast_or_slow() {
+        for s in scenarios() {
+            assert!(s.has_any_tag(&[Tag::Fast]) != s.has_any_tag(&[Tag::Slow]),
+                "{} must be tagged exactly one of fast / slow", s.name);
+        }
+        for s in incentive_scenarios() {
+            assert!(s.has_any_tag(&[Tag::Fast]) != s.has_any_tag(&[Tag::Slow]),
+                "{} must be tagged exactly one of fast / slow", s.name);
+        }
+        assert_eq!(Tag::from_arg("whitepaper"), Some(Tag::Whitepaper));
+        assert_eq!(Tag::from_arg("medium"), None);
+    }
+}
@@ -0,0 +1,198 @@
+// Time-Series Assertions — pass criteria on the shape of a run, not just its end
+// Ticks are run ticks, counted from 0 across every phase of a chained scenario
+
+use arena_engine::{ArenaSimulation, WorldState};
+
+/// Per-tick quantity a series assertion is made about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeriesMetric {
+    FeeRate,
+    HeldCount,
+    /// Smoothed liquidity coefficient (the lambda EMA)
+    LiquidityLambda,
+    /// Gold volatility over the rolling price window
+    Volatility,
+    /// Organic ratio over the rolling NGauge window
+    OrganicRatio,
+    /// The core PID's accumulated peg error
+    IntegralError,
+}
+
+impl SeriesMetric {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FeeRate => "fee rate",
+            Self::HeldCount => "held count",
+            Self::LiquidityLambda => "liquidity lambda",
+            Self::Volatility => "volatility",
+            Self::OrganicRatio => "organic ratio",
+            Self::IntegralError => "integral error",
+        }
+    }
+
+    pub fn sample(&self, sim: &ArenaSimulation, state: &WorldState) -> f64 {
+        match self {
+            Self::FeeRate => state.current_fee_rate,
+            Self::HeldCount => state.held_count as f64,
+            Self::LiquidityLambda => state.liquidity_lambda,
+            Self::Volatility => state.volatility,
+            Self::OrganicRatio => state.organic_ratio,
+            Self::IntegralError => sim.get_governor_state_core().integral_error,
+        }
+    }
+}
+
+/// A claim about how a metric evolves over the run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SeriesAssertion {
+    /// Comes back within `tolerance` (relative) of its value on the tick
+    /// before `shock_tick`, no later than `within` ticks after the shock.
+    RecoversWithin { metric: SeriesMetric, shock_tick: u64, within: u64, tolerance: f64 },
+    /// Never rises from `from_tick` to the end of the run.
+    NonIncreasingAfter { metric: SeriesMetric, from_tick: u64 },
+    /// Stays at or below `max` from `from_tick` to the end of the run.
+    AtMostAfter { metric: SeriesMetric, from_tick: u64, max: f64 },
+    /// Every `window`-tick stretch stays inside the range of the first,
+    /// which is what a `window`-tick run on the same seed sees, widened by
+    /// `tolerance` of that range's largest magnitude. Ranges leave out the
+    /// top and bottom `TRIM_QUANTILE` of each stretch. A metric drifting or
+    /// winding up over a long horizon leaves the range.
+    WithinFirstWindow { metric: SeriesMetric, window: u64, tolerance: f64 },
+}
+
+impl SeriesAssertion {
+    pub fn metric(&self) -> SeriesMetric {
+        match *self {
+            Self::RecoversWithin { metric, .. }
+            | Self::NonIncreasingAfter { metric, .. }
+            | Self::AtMostAfter { metric, .. }
+            | Self::WithinFirstWindow { metric, .. } => metric,
+        }
+    }
+
+    /// Check the assertion against its metric's per-tick series. On failure,
+    /// returns a description naming the offending tick.
+    pub fn check(&self, series: &[f64]) -> Result<(), String> {
+        match *self {
+            Self::RecoversWithin { metric, shock_tick, within, tolerance } => {
+                let Some(&baseline) = shock_tick.checked_sub(1)
+                    .and_then(|t| series.get(t as usize)) else {
+                    return Err(format!("{}: no baseline before tick {}", metric.name(), shock_tick));
+                };
+                let deadline = (shock_tick + within) as usize;
+                let band = tolerance * baseline.abs();
+                let recovered = series.iter()
+                    .enumerate()
+                    .skip(shock_tick as usize)
+                    .take_while(|&(t, _)| t <= deadline)
+                    .any(|(_, &v)| (v - baseline).abs() <= band);
+                if recovered {
+                    Ok(())
+                } else {
+                    Err(format!("{}: not within {:.0}% of {:.6} by tick {}",
+                        metric.name(), tolerance * 100.0, baseline, deadline))
+                }
+            }
+            Self::NonIncreasingAfter { metric, from_tick } => {
+                let start = from_tick as usize;
+                match series.windows(2).enumerate().skip(start).find(|(_, w)| w[1] > w[0]) {
+                    Some((t, w)) => Err(format!("{}: rose {:.6} -> {:.6} at tick {}",
+                        metric.name(), w[0], w[1], t + 1)),
+                    None => Ok(()),
+                }
+            }
+            Self::AtMostAfter { metric, from_tick, max } => {
+                match series.iter().enumerate().skip(from_tick as usize).find(|&(_, &v)| v > max) {
+                    Some((t, v)) => Err(format!("{}: {:.6} above {:.6} at tick {}",
+                        metric.name(), v, max, t)),
+                    None => Ok(()),
+                }
+            }
+            Self::WithinFirstWindow { metric, window, tolerance } => {
+                let mut windows = series.chunks(window.max(1) as usize).map(trimmed_range);
+                let Some((first_lo, first_hi)) = windows.next() else {
+                    return Ok(());
+                };
+                let slack = tolerance * first_lo.abs().max(first_hi.abs());
+                let (lo, hi) = (first_lo - slack, first_hi + slack);
+                match windows.enumerate().find(|(_, (w_lo, w_hi))| *w_lo < lo || *w_hi > hi) {
+                    Some((i, (w_lo, w_hi))) => Err(format!(
+                        "{}: ticks {}..{} span {:.6}..{:.6}, outside {:.6}..{:.6} of the first {}",
+                        metric.name(), (i as u64 + 1) * window, (i as u64 + 2) * window, w_lo, w_hi, lo, hi, window)),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Share of samples trimmed from each end of a window's range, so single
+/// spikes don't stand for the window.
+const TRIM_QUANTILE: f64 = 0.01;
+
+/// (low, high) of `window` between its `TRIM_QUANTILE` quantiles.
+fn trimmed_range(window: &[f64]) -> (f64, f64) {
+    let mut sorted = window.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let trim = (sorted.len() as f64 * TRIM_QUANTILE) as usize;
+    (sorted[trim], sorted[sorted.len() - 1 - trim])
+}
+
+// ─── Series Recorder ────────────────────────────────────────────────────────
+
+/// Records the per-tick series each of a scenario's assertions needs.
+pub struct SeriesRecorder {
+    assertions: &'static [SeriesAssertion],
+    series: Vec<Vec<f64>>,
+}
+
+impl SeriesRecorder {
+    pub fn new(assertions: &'static [SeriesAssertion]) -> Self {
+        Self { assertions, series: vec![Vec::new(); assertions.len()] }
+    }
+
+    pub fn record(&mut self, sim: &ArenaSimulation, state: &WorldState) {
+        for (assertion, series) in self.assertions.iter().zip(&mut self.series) {
+            series.push(assertion.metric().sample(sim, state));
+        }
+    }
+
+    /// Descriptions of every failed assertion (empty when all hold).
+    pub fn failures(&self) -> Vec<String> {
+        self.assertions.iter()
+            .zip(&self.series)
+            .filter_map(|(assertion, series)| assertion.check(series).err())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_series_assertions() {
+        let fee = SeriesMetric::FeeRate;
+        let shock = [1.0, 1.0, 4.0, 3.0, 1.04, 1.0];
+        let recovers = |within| SeriesAssertion::RecoversWithin {
+            metric: fee, shock_tick: 2, within, tolerance: 0.05,
+        };
+        assert!(recovers(2).check(&shock).is_ok(), "1.04 at tick 4 is within 5%");
+        assert!(recovers(1).check(&shock).is_err());
+
+        let draining = [5.0, 7.0, 6.0, 6.0, 2.0];
+        let non_increasing = |from_tick| SeriesAssertion::NonIncreasingAfter { metric: fee, from_tick };
+        assert!(non_increasing(1).check(&draining).is_ok());
+        assert_eq!(non_increasing(0).check(&draining),
+            Err("fee rate: rose 5.000000 -> 7.000000 at tick 1".to_string()));
+
+        let capped = SeriesAssertion::AtMostAfter { metric: fee, from_tick: 2, max: 6.0 };
+        assert!(capped.check(&draining).is_ok());
+
+        let within = |tolerance| SeriesAssertion::WithinFirstWindow { metric: fee, window: 2, tolerance };
+        let drifting = [1.0, 2.0, 1.5, 2.1, 1.2, 2.4];
+        assert!(within(0.2).check(&drifting).is_ok(), "2.4 is within 20% of 2.0 above the first window");
+        assert_eq!(within(0.1).check(&drifting),
+            Err("fee rate: ticks 4..6 span 1.200000..2.400000, outside 0.800000..2.200000 of the first 2".to_string()));
+    }
+}
@@ -2,6 +2,9 @@
 // Outputs one JSON line per tick for independent analysis
 
 use serde::Serialize;
+use arena_engine::cohort::CohortStats;
+use arena_engine::control_loop::GovernorIo;
+use arena_engine::hop_trace::NodeHopStats;
 use arena_engine::WorldState;
 use std::io::Write;
 
@@ -16,11 +19,15 @@ pub struct TickSnapshot {
     pub total_input: f64,
     pub total_output: f64,
     pub active_value: f64,
+    pub in_transit_float: f64,
     pub current_fee_rate: f64,
     pub tier_fee_rates: [f64; 4],
     pub settlement_count: u32,
     pub held_count: u32,
     pub orbit_count: u32,
+    pub orbit_no_liquid_egress: u32,
+    pub orbit_hop_limit: u32,
+    pub orbit_disabled_destination: u32,
     pub egress_profit_cumulative: f64,
     pub transit_profit_cumulative: f64,
     pub demurrage_burned_cumulative: f64,
@@ -29,6 +36,46 @@ pub struct TickSnapshot {
     pub surge_multiplier: f64,
     pub volatility: f64,
     pub dissolved_count: u32,
+    pub network_velocity: f64,
+    pub realized_cost: f64,
+    pub demand_retention: f64,
+    /// Cumulative outcomes of every spawn cohort, on cohort boundary ticks
+    /// only: each cohort's rows across boundaries are its vintage curve
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cohorts: Vec<CohortRow>,
+    /// Cumulative per-node hop dwell and hop fees, on the same ticks
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hop_stats: Vec<NodeHopStats>,
+    /// Metrics the governor saw and the parameters it produced, on ticks
+    /// it decided, with `--governor-io`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub governor: Option<GovernorIo>,
+}
+
+/// One spawn cohort's cumulative outcomes as of a snapshot tick.
+#[derive(Debug, Serialize)]
+pub struct CohortRow {
+    pub cohort: u32,
+    pub start_tick: u64,
+    pub spawned: u32,
+    pub settled: u32,
+    pub reverted: u32,
+    pub dissolved: u32,
+    pub outstanding: u32,
+}
+
+impl CohortRow {
+    pub fn from_stats(stats: &CohortStats) -> Self {
+        Self {
+            cohort: stats.cohort,
+            start_tick: stats.start_tick,
+            spawned: stats.spawned,
+            settled: stats.settled(),
+            reverted: stats.reverted(),
+            dissolved: stats.dissolved(),
+            outstanding: stats.outstanding(),
+        }
+    }
 }
 
 impl TickSnapshot {
@@ -50,11 +97,15 @@ impl TickSnapshot {
             total_input: state.total_input,
             total_output: state.total_output,
             active_value: state.active_value,
+            in_transit_float: state.in_transit_float,
             current_fee_rate: state.current_fee_rate,
             tier_fee_rates: state.tier_fee_rates,
             settlement_count: state.settlement_count,
             held_count: state.held_count,
             orbit_count: state.orbit_count,
+            orbit_no_liquid_egress: state.orbit_no_liquid_egress,
+            orbit_hop_limit: state.orbit_hop_limit,
+            orbit_disabled_destination: state.orbit_disabled_destination,
             egress_profit_cumulative: state.total_rewards_egress,
             transit_profit_cumulative: state.total_rewards_transit,
             demurrage_burned_cumulative: state.total_demurrage_burned,
@@ -63,6 +114,12 @@ impl TickSnapshot {
             surge_multiplier: state.surge_multiplier,
             volatility: state.volatility,
             dissolved_count: state.dissolved_count,
+            network_velocity: state.network_velocity,
+            realized_cost: state.realized_cost,
+            demand_retention: state.demand_retention,
+            cohorts: Vec::new(),
+            hop_stats: Vec::new(),
+            governor: None,
         }
     }
 }
@@ -81,21 +138,44 @@ impl TimeSeriesRecorder {
         self.snapshots.push(TickSnapshot::from_state(state));
     }
 
+    /// Attach the cohort table to the latest snapshot.
+    pub fn record_cohorts(&mut self, cohorts: &[CohortStats]) {
+        if let Some(snapshot) = self.snapshots.last_mut() {
+            snapshot.cohorts = cohorts.iter().map(CohortRow::from_stats).collect();
+        }
+    }
+
+    /// Attach the per-node hop attribution to the latest snapshot.
+    pub fn record_hop_stats(&mut self, hop_stats: Vec<NodeHopStats>) {
+        if let Some(snapshot) = self.snapshots.last_mut() {
+            snapshot.hop_stats = hop_stats;
+        }
+    }
+
+    /// Attach a governor decision's inputs and outputs to the latest snapshot.
+    pub fn record_governor_io(&mut self, io: &GovernorIo) {
+        if let Some(snapshot) = self.snapshots.last_mut() {
+            snapshot.governor = Some(io.clone());
+        }
+    }
+
+    /// Render all snapshots as JSONL (one line per tick)
+    pub fn to_jsonl(&self) -> serde_json::Result<String> {
+        let mut out = String::new();
+        for snapshot in &self.snapshots {
+            out.push_str(&serde_json::to_string(snapshot)?);
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
     /// Write all snapshots to a JSONL file
     pub fn write_jsonl(&self, path: &std::path::Path) -> std::io::Result<()> {
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
         let mut file = std::fs::File::create(path)?;
-        for snapshot in &self.snapshots {
-            let line = serde_json::to_string(snapshot)
-                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
-            writeln!(file, "{}", line)?;
-        }
-        Ok(())
-    }
-
-    pub fn len(&self) -> usize {
-        self.snapshots.len()
+        let jsonl = self.to_jsonl().map_err(std::io::Error::other)?;
+        file.write_all(jsonl.as_bytes())
     }
 }
@@ -20,7 +20,7 @@ pub struct TrafficGenerator {
     pub ingress_nodes: Vec<u32>,
     pub spawn_count: u32,
     pub tier_counts: [u32; 4],
-    current_fee_rate: f64,
+    demand_retention: f64,
 }
 
 impl TrafficGenerator {
@@ -30,35 +30,30 @@ impl TrafficGenerator {
             ingress_nodes,
             spawn_count: 0,
             tier_counts: [0; 4],
-            current_fee_rate: 0.0,
+            demand_retention: 1.0,
         }
     }
 
-    /// Update current fee rate for demand destruction logic
-    pub fn set_fee_rate(&mut self, rate: f64) {
-        self.current_fee_rate = rate;
+    /// Share of offered demand still spawned, from the engine's elasticity
+    /// curve at the recent realized all-in cost
+    pub fn set_demand_retention(&mut self, retention: f64) {
+        self.demand_retention = retention.clamp(0.0, 1.0);
     }
 
     /// Generate Poisson-distributed traffic for one tick.
     /// Returns Vec of (node_id, amount) to spawn.
-    /// `lambda` is the expected number of packets per tick.
+    /// `lambda` is the expected number of packets per tick offered, before
+    /// demand destruction.
     pub fn generate_tick(&mut self, lambda: f64) -> Vec<(u32, f64)> {
         if self.ingress_nodes.is_empty() || lambda <= 0.0 {
             return Vec::new();
         }
 
-        let n_packets = poisson_sample(&mut self.rng, lambda);
+        // E4: Demand destruction — thin the arrivals along the elasticity curve
+        let n_packets = poisson_sample(&mut self.rng, lambda * self.demand_retention);
         let mut spawns = Vec::with_capacity(n_packets as usize);
 
         for _ in 0..n_packets {
-            // E4: Demand destruction — cancel if fee > 10%
-            if self.current_fee_rate > 0.10 {
-                let cancel_prob = ((self.current_fee_rate - 0.10) * 5.0).min(1.0);
-                if self.rng.gen::<f64>() < cancel_prob {
-                    continue;
-                }
-            }
-
             // Select ingress node uniformly
             let node_idx = self.rng.gen_range(0..self.ingress_nodes.len());
             let node_id = self.ingress_nodes[node_idx];
@@ -83,6 +78,19 @@ impl TrafficGenerator {
     pub fn compute_lambda(demand: f64, nodes: u32) -> f64 {
         demand * 5.0 * (nodes as f64 / 24.0).sqrt()
     }
+
+    /// Expected packet value before demand destruction: each tier's share
+    /// times the midpoint of its value range.
+    pub fn mean_packet_value() -> f64 {
+        let mut previous = 0.0;
+        TIER_CDF.iter().zip(TIER_VALUE_RANGES.iter())
+            .map(|(&cdf, &(lo, hi))| {
+                let share = cdf - previous;
+                previous = cdf;
+                share * (lo + hi) / 2.0
+            })
+            .sum()
+    }
 }
 
 /// Poisson sampling via Knuth algorithm.
@@ -136,6 +144,12 @@ mod tests {
         assert!((mean - lambda).abs() < 0.5, "Poisson mean {} far from λ={}", mean, lambda);
     }
 
+    #[test]
+    fn test_mean_packet_value_weights_tier_midpoints() {
+        let expected = 0.60 * 5.25 + 0.25 * 505.0 + 0.12 * 50_500.0 + 0.03 * 300_000.0;
+        assert!((TrafficGenerator::mean_packet_value() - expected).abs() < 1e-6);
+    }
+
     #[test]
     fn test_tier_distribution() {
         let mut rng = ChaCha8Rng::seed_from_u64(42);
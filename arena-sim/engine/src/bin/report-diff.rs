@@ -0,0 +1,167 @@
+// Bench Report Diff — per-scenario metric deltas between two bench JSON reports
+// For release notes and PR review
+//
+// Usage:
+//   cargo run --release --bin report-diff -- old.json new.json
+//
+// Significance markers compare Monte Carlo means with a two-sample z-test on
+// the per-scenario standard errors: `*` p<0.05, `**` p<0.01. Exits non-zero
+// if any scenario that passed in the old report fails in the new one.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Suite gate: a scenario passes when at least 93.3% of its runs pass.
+const PASS_THRESHOLD: f64 = 0.933;
+
+// ─── Report Schema (subset of bench output) ─────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct Report {
+    timestamp: String,
+    n_runs_per_scenario: usize,
+    scenarios: Vec<ScenarioReport>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ScenarioReport {
+    scenario_name: String,
+    pass_rate: f64,
+    settlement_rate: Stats,
+    normalized_conservation_error: Stats,
+    peg_elasticity_pct: Stats,
+    held_count: Stats,
+    packets_per_tick: Stats,
+    throughput_per_sec: Stats,
+}
+
+#[derive(Debug, Deserialize)]
+struct Stats {
+    mean: f64,
+    std_dev: f64,
+    n: usize,
+}
+
+impl Stats {
+    fn std_err(&self) -> f64 {
+        if self.n == 0 { 0.0 } else { self.std_dev / (self.n as f64).sqrt() }
+    }
+}
+
+// ─── Comparison ─────────────────────────────────────────────────────────────
+
+fn significance(old: &Stats, new: &Stats) -> &'static str {
+    let se = (old.std_err().powi(2) + new.std_err().powi(2)).sqrt();
+    let delta = (new.mean - old.mean).abs();
+    if se == 0.0 {
+        return ""; // single run or zero variance: no basis for a test
+    }
+    let z = delta / se;
+    if z > 2.576 {
+        "**"
+    } else if z > 1.96 {
+        "*"
+    } else {
+        ""
+    }
+}
+
+fn fmt_delta(old: &Stats, new: &Stats) -> String {
+    let delta = new.mean - old.mean;
+    if delta.abs() >= 1e-3 || delta == 0.0 {
+        format!("{:+.2}{}", delta, significance(old, new))
+    } else {
+        format!("{:+.1e}{}", delta, significance(old, new))
+    }
+}
+
+fn load(path: &str) -> Report {
+    let json = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Failed to read {}: {}", path, e);
+        std::process::exit(2);
+    });
+    serde_json::from_str(&json).unwrap_or_else(|e| {
+        eprintln!("Failed to parse {}: {}", path, e);
+        std::process::exit(2);
+    })
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() != 2 {
+        eprintln!("Usage: report-diff <old.json> <new.json>");
+        std::process::exit(2);
+    }
+    let old = load(&args[0]);
+    let new = load(&args[1]);
+
+    let old_by_name: BTreeMap<&str, &ScenarioReport> = old.scenarios.iter()
+        .map(|s| (s.scenario_name.as_str(), s))
+        .collect();
+
+    println!("\n  Bench Report Diff");
+    println!("  old: {} (runs/scenario: {})", old.timestamp, old.n_runs_per_scenario);
+    println!("  new: {} (runs/scenario: {})\n", new.timestamp, new.n_runs_per_scenario);
+    println!("  {:<28} {:>11} {:>11} {:>11} {:>11} {:>11} {:>11}",
+        "Scenario", "ΔPass%", "ΔSettle%", "ΔConserv", "ΔPeg%", "ΔHeld", "ΔPkt/tick");
+    println!("  {}", "-".repeat(100));
+
+    let mut newly_failing = Vec::new();
+    let mut newly_passing = Vec::new();
+    let mut added = Vec::new();
+
+    for n in &new.scenarios {
+        let Some(o) = old_by_name.get(n.scenario_name.as_str()) else {
+            added.push(n.scenario_name.as_str());
+            continue;
+        };
+
+        let was_pass = o.pass_rate >= PASS_THRESHOLD;
+        let is_pass = n.pass_rate >= PASS_THRESHOLD;
+        match (was_pass, is_pass) {
+            (true, false) => newly_failing.push(n.scenario_name.as_str()),
+            (false, true) => newly_passing.push(n.scenario_name.as_str()),
+            _ => {}
+        }
+
+        println!("  {:<28} {:>+11.1} {:>11} {:>11} {:>11} {:>11} {:>11}",
+            n.scenario_name,
+            (n.pass_rate - o.pass_rate) * 100.0,
+            fmt_delta(&o.settlement_rate, &n.settlement_rate),
+            fmt_delta(&o.normalized_conservation_error, &n.normalized_conservation_error),
+            fmt_delta(&o.peg_elasticity_pct, &n.peg_elasticity_pct),
+            fmt_delta(&o.held_count, &n.held_count),
+            fmt_delta(&o.packets_per_tick, &n.packets_per_tick),
+        );
+        let speedup = if o.throughput_per_sec.mean > 0.0 {
+            n.throughput_per_sec.mean / o.throughput_per_sec.mean
+        } else { 0.0 };
+        if !(0.8..=1.25).contains(&speedup) && speedup > 0.0 {
+            println!("  {:<28} throughput {:.2}x", "", speedup);
+        }
+    }
+
+    let new_names: std::collections::BTreeSet<&str> = new.scenarios.iter()
+        .map(|s| s.scenario_name.as_str())
+        .collect();
+    let removed: Vec<&str> = old_by_name.keys()
+        .filter(|name| !new_names.contains(*name))
+        .copied()
+        .collect();
+
+    println!("  {}", "-".repeat(100));
+    println!("  Significance: * p<0.05, ** p<0.01 (two-sample z-test on MC means)\n");
+    println!("  Newly failing: {}", if newly_failing.is_empty() { "none".to_string() } else { newly_failing.join(", ") });
+    println!("  Newly passing: {}", if newly_passing.is_empty() { "none".to_string() } else { newly_passing.join(", ") });
+    if !added.is_empty() {
+        println!("  Added:         {}", added.join(", "));
+    }
+    if !removed.is_empty() {
+        println!("  Removed:       {}", removed.join(", "));
+    }
+    println!();
+
+    if !newly_failing.is_empty() {
+        std::process::exit(1);
+    }
+}
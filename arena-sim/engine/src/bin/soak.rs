@@ -0,0 +1,211 @@
+// Long-Run Soak — certifies the engine for always-on server-mode deployments
+// Runs a mid-size network for tens of millions of ticks under shifting demand
+// and periodic gold shocks, sampling process RSS and the engine's container
+// sizes, and fails if any of them keeps growing.
+//
+// Usage:
+//   cargo run --release --bin soak                                  # 20M ticks, 120 nodes
+//   cargo run --release --bin soak -- --ticks 2000000 --sample-every 20000
+//   cargo run --release --bin soak -- --nodes 240 --seed 7
+//
+// Growth check: samples from the first WARMUP_FRACTION of the run are
+// discarded; the rest split into an early and a late half. A series fails
+// when its late peak exceeds its early peak by more than GROWTH_TOLERANCE
+// plus the series' absolute slack. Exits non-zero on any failure.
+
+use arena_engine::receipts::MAX_REVERT_RECEIPTS;
+use arena_engine::simulation::MAX_QUADRANT_TRANSITIONS;
+use arena_engine::{ArenaSimulation, NodeRole};
+use std::time::Instant;
+
+const DEFAULT_TICKS: u64 = 20_000_000;
+const DEFAULT_NODES: u32 = 120;
+const DEFAULT_SAMPLE_EVERY: u64 = 100_000;
+/// Ticks between workload changes (demand level, gold shocks).
+const SEGMENT_TICKS: u64 = 500;
+/// Demand swings around this level over DEMAND_PERIOD_TICKS.
+const DEMAND_MEAN: f64 = 0.35;
+const DEMAND_SWING: f64 = 0.25;
+const DEMAND_PERIOD_TICKS: f64 = 50_000.0;
+/// A ±SHOCK_PCT gold shock every SHOCK_EVERY_TICKS, cycling step/spike/ramp.
+const SHOCK_EVERY_TICKS: u64 = 5_000;
+const SHOCK_PCT: f64 = 15.0;
+const SHOCK_DURATION_TICKS: u32 = 400;
+const WARMUP_FRACTION: f64 = 0.1;
+const GROWTH_TOLERANCE: f64 = 0.25;
+/// Absolute slack on RSS, for allocator noise.
+const RSS_SLACK_BYTES: f64 = 8.0 * 1024.0 * 1024.0;
+const PAGE_SIZE_BYTES: u64 = 4096;
+
+// ─── CLI Parsing ────────────────────────────────────────────────────────────
+
+struct CliArgs {
+    ticks: u64,
+    nodes: u32,
+    seed: u32,
+    sample_every: u64,
+}
+
+fn parse_args() -> CliArgs {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut cli = CliArgs {
+        ticks: DEFAULT_TICKS,
+        nodes: DEFAULT_NODES,
+        seed: 0,
+        sample_every: DEFAULT_SAMPLE_EVERY,
+    };
+
+    let mut i = 0;
+    while i < args.len() {
+        let value = args.get(i + 1);
+        match args[i].as_str() {
+            "--ticks" => cli.ticks = value.and_then(|v| v.parse().ok()).unwrap_or(cli.ticks),
+            "--nodes" => cli.nodes = value.and_then(|v| v.parse().ok()).unwrap_or(cli.nodes),
+            "--seed" => cli.seed = value.and_then(|v| v.parse().ok()).unwrap_or(cli.seed),
+            "--sample-every" => {
+                cli.sample_every = value.and_then(|v| v.parse().ok()).unwrap_or(cli.sample_every);
+            }
+            arg => {
+                eprintln!("Unknown argument: {}", arg);
+                i += 1;
+                continue;
+            }
+        }
+        i += 2;
+    }
+    // Samples land on segment boundaries
+    cli.sample_every = cli.sample_every.max(SEGMENT_TICKS) / SEGMENT_TICKS * SEGMENT_TICKS;
+    cli
+}
+
+// ─── Sampling ───────────────────────────────────────────────────────────────
+
+/// Resident set size from /proc/self/statm; None off Linux.
+fn rss_bytes() -> Option<u64> {
+    let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+    let resident: u64 = statm.split_whitespace().nth(1)?.parse().ok()?;
+    Some(resident * PAGE_SIZE_BYTES)
+}
+
+/// A sampled quantity and the absolute growth it may show on top of
+/// GROWTH_TOLERANCE.
+struct Series {
+    name: &'static str,
+    slack: f64,
+    samples: Vec<f64>,
+}
+
+impl Series {
+    fn new(name: &'static str, slack: f64) -> Self {
+        Self { name, slack, samples: Vec::new() }
+    }
+
+    /// (early peak, late peak, bounded) after discarding the warm-up.
+    fn check(&self) -> (f64, f64, bool) {
+        let warmup = ((self.samples.len() as f64 * WARMUP_FRACTION).ceil() as usize).min(self.samples.len());
+        let steady = &self.samples[warmup..];
+        let (early, late) = steady.split_at(steady.len() / 2);
+        let peak = |s: &[f64]| s.iter().copied().fold(0.0_f64, f64::max);
+        let (early, late) = (peak(early), peak(late));
+        (early, late, late <= early * (1.0 + GROWTH_TOLERANCE) + self.slack)
+    }
+}
+
+fn sample(sim: &ArenaSimulation, series: &mut [Series]) {
+    let footprint = sim.get_memory_footprint_core();
+    let values = [
+        rss_bytes().map_or(0.0, |b| b as f64),
+        footprint.estimated_bytes as f64,
+        footprint.packets as f64,
+        footprint.route_entries as f64,
+        footprint.fee_schedule_entries as f64,
+        footprint.quadrant_transitions as f64,
+        footprint.revert_receipts as f64,
+        sim.get_cohorts_core().len() as f64,
+    ];
+    for (s, v) in series.iter_mut().zip(values) {
+        s.samples.push(v);
+    }
+}
+
+// ─── Main ───────────────────────────────────────────────────────────────────
+
+fn main() {
+    let cli = parse_args();
+    eprintln!("\n  Soak: {} ticks on {} nodes (seed {}), sampling every {} ticks",
+        cli.ticks, cli.nodes, cli.seed, cli.sample_every);
+
+    let mut sim = ArenaSimulation::new(cli.nodes);
+    sim.set_traffic_seed(cli.seed);
+    // Egress operators rebalance to their starting reserves every segment,
+    // so the economy stays live instead of draining to a standstill
+    let reserves: Vec<(u32, f64)> = sim
+        .get_nodes_filtered_core(Some(NodeRole::Egress), None, 0, usize::MAX)
+        .iter()
+        .map(|n| (n.id, n.inventory_crypto))
+        .collect();
+    let mut series = [
+        Series::new("rss_bytes", RSS_SLACK_BYTES),
+        Series::new("estimated_bytes", 64.0 * 1024.0),
+        Series::new("packets", 64.0),
+        Series::new("route_entries", 1024.0),
+        Series::new("fee_schedule_entries", 1024.0),
+        // Capped logs may fill toward their cap during the run
+        Series::new("quadrant_transitions", MAX_QUADRANT_TRANSITIONS as f64),
+        Series::new("revert_receipts", MAX_REVERT_RECEIPTS as f64),
+        Series::new("cohorts", 16.0),
+    ];
+
+    let start = Instant::now();
+    let mut max_conservation_error = 0.0_f64;
+    let mut elapsed_ticks = 0;
+    let mut shocks: u32 = 0;
+    while elapsed_ticks < cli.ticks {
+        let phase = elapsed_ticks as f64 / DEMAND_PERIOD_TICKS * std::f64::consts::TAU;
+        sim.set_demand_factor(DEMAND_MEAN + DEMAND_SWING * phase.sin());
+        if elapsed_ticks % SHOCK_EVERY_TICKS == 0 && elapsed_ticks > 0 {
+            let sign = if shocks.is_multiple_of(2) { -1.0 } else { 1.0 };
+            sim.apply_price_shock(sign * SHOCK_PCT, SHOCK_DURATION_TICKS, shocks % 3);
+            shocks += 1;
+        }
+        for &(id, crypto) in &reserves {
+            sim.set_node_crypto(id, crypto);
+        }
+
+        let segment = SEGMENT_TICKS.min(cli.ticks - elapsed_ticks);
+        let batch = sim.run_batch_core(segment as u32);
+        max_conservation_error = max_conservation_error.max(batch.max_conservation_error);
+        elapsed_ticks += segment;
+
+        if elapsed_ticks % cli.sample_every == 0 || elapsed_ticks == cli.ticks {
+            sample(&sim, &mut series);
+            let rate = elapsed_ticks as f64 / start.elapsed().as_secs_f64().max(1e-9);
+            eprintln!("  tick {:>10}  rss {:>8.1} MiB  packets {:>6}  {:.0} ticks/s",
+                elapsed_ticks, series[0].samples.last().unwrap_or(&0.0) / (1024.0 * 1024.0),
+                series[2].samples.last().unwrap_or(&0.0), rate);
+        }
+    }
+
+    let stats = sim.get_stats_core();
+    println!("\n  Soak Report ({} ticks, {:.1}s)", cli.ticks, start.elapsed().as_secs_f64());
+    println!("    settled {}, reverted {}, {} gold shocks, max conservation error {:.3e}",
+        stats.settlement_count, stats.revert_count, shocks, max_conservation_error);
+    println!("  {:<22} {:>16} {:>16}  Bounded", "Series", "Early peak", "Late peak");
+    let mut unbounded = 0;
+    for s in &series {
+        if s.samples.len() < 4 {
+            println!("  {:<22} {:>16} {:>16}  too few samples", s.name, "-", "-");
+            continue;
+        }
+        let (early, late, bounded) = s.check();
+        println!("  {:<22} {:>16.0} {:>16.0}  {}", s.name, early, late, if bounded { "PASS" } else { "FAIL" });
+        if !bounded {
+            unbounded += 1;
+        }
+    }
+    if unbounded > 0 {
+        eprintln!("\n  Soak FAILED: {} series kept growing\n", unbounded);
+        std::process::exit(1);
+    }
+    println!("\n  Soak passed: every sampled series stayed bounded\n");
+}
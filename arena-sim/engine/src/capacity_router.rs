@@ -0,0 +1,148 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Core Capacity Router
+//
+// The protocol's router (`core_routing::PacketRouter`) scores next hops on
+// observable capacity alone: bandwidth, free buffer, latency and load. This
+// puts it in the tick loop in place of the Arena's own scoring. Each tick
+// every node's `CapacityMetrics` is snapshotted through the adapter, and the
+// router picks among a packet's candidate neighbors from that snapshot, so
+// routing regimes can be compared under identical traffic.
+
+use crate::adapter;
+use crate::core_routing::{CapacityMetrics, PacketRouter};
+use crate::routing::{self, RouteScoring, RoutingMode};
+use crate::types::{NodeRole, SimNode, SimPacket};
+
+/// Which router picks next hops.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoutingRegime {
+    /// `routing::find_next_hop` in the configured `RoutingMode` and
+    /// `RouteScoring`
+    #[default]
+    Legacy = 0,
+    /// `PacketRouter` over every live neighbor, on capacity alone
+    CapacityBased = 1,
+    /// `PacketRouter` over the live neighbors one hop closer to an Egress
+    /// (`distance_to_egress`), falling back to `Legacy` where none is
+    Hybrid = 2,
+}
+
+impl RoutingRegime {
+    /// Map a numeric discriminant (as sent from JS) back to a regime.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Legacy),
+            1 => Some(Self::CapacityBased),
+            2 => Some(Self::Hybrid),
+            _ => None,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CapacityRouter
+// ---------------------------------------------------------------------------
+
+/// Core's router and the capacity snapshot it scores, for the
+/// `CapacityBased` and `Hybrid` regimes. In both, a packet heads for a
+/// liquid Egress it may settle at: with none it orbits as under the legacy
+/// router, and a neighbor that is one is taken outright.
+#[derive(Debug, Clone)]
+pub struct CapacityRouter {
+    regime: RoutingRegime,
+    router: PacketRouter,
+    /// Capacity metrics by node id, as of the start of this tick's node cycle
+    snapshot: Vec<CapacityMetrics>,
+}
+
+impl CapacityRouter {
+    /// A router for `regime`; None for `Legacy`, which needs none.
+    pub fn new(regime: RoutingRegime) -> Option<Self> {
+        (regime != RoutingRegime::Legacy).then(|| Self {
+            regime,
+            router: PacketRouter::default(),
+            snapshot: Vec::new(),
+        })
+    }
+
+    pub fn regime(&self) -> RoutingRegime {
+        self.regime
+    }
+
+    /// Once per tick, before the node cycle: snapshot every node's capacity.
+    pub fn begin_tick(&mut self, nodes: &[SimNode]) {
+        self.snapshot = nodes.iter().map(adapter::capacity_metrics).collect();
+    }
+
+    /// Next hop for `packet` at `node_id`; `mode` and `scoring` drive the
+    /// legacy fallback.
+    pub fn next_hop(
+        &self,
+        nodes: &[SimNode],
+        node_id: u32,
+        packet: &SimPacket,
+        mode: RoutingMode,
+        scoring: RouteScoring,
+    ) -> Option<u32> {
+        let current = &nodes[node_id as usize];
+        routing::target_egress(nodes, current, packet)?;
+        let live: Vec<u32> = current.neighbors.iter()
+            .copied()
+            .filter(|&n| nodes[n as usize].role != NodeRole::Disabled)
+            .collect();
+        if let Some(&egress) = live.iter()
+            .find(|&&n| routing::is_liquid_egress(&nodes[n as usize]) && routing::may_settle_at(packet, &nodes[n as usize]))
+        {
+            return Some(egress);
+        }
+
+        let candidates: Vec<u32> = match self.regime {
+            RoutingRegime::Hybrid => live.into_iter()
+                .filter(|&n| nodes[n as usize].distance_to_egress < current.distance_to_egress)
+                .collect(),
+            _ => live,
+        };
+        if candidates.is_empty() && self.regime == RoutingRegime::Hybrid {
+            return routing::find_next_hop(nodes, node_id, packet, mode, scoring);
+        }
+        adapter::route_via_core(&self.router, &self.snapshot, &candidates, &packet.tier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArenaSimulation;
+
+    #[test]
+    fn test_core_router_picks_by_capacity_among_regime_candidates() {
+        let mut sim = ArenaSimulation::new(24);
+        sim.spawn_packet(0, 100.0);
+        let p = sim.node_buffers[&0].last().unwrap().clone();
+        // Drain node 0's adjacent Egress so no neighbor is taken outright
+        sim.nodes[1].inventory_crypto = 0.0;
+        let live: Vec<u32> = sim.nodes[0].neighbors.clone();
+        let (mode, scoring) = (RoutingMode::Euclidean, RouteScoring::TrustWeighted);
+
+        let mut capacity = CapacityRouter::new(RoutingRegime::CapacityBased).unwrap();
+        assert!(CapacityRouter::new(RoutingRegime::Legacy).is_none());
+        // The widest neighbor wins on bandwidth
+        let widest = *live.iter()
+            .max_by(|&&a, &&b| sim.nodes[a as usize].bandwidth.total_cmp(&sim.nodes[b as usize].bandwidth))
+            .unwrap();
+        sim.nodes[widest as usize].bandwidth += 500.0;
+        capacity.begin_tick(&sim.nodes);
+        assert_eq!(capacity.next_hop(&sim.nodes, 0, &p, mode, scoring), Some(widest));
+
+        // Hybrid only considers neighbors closer to an Egress
+        let mut hybrid = CapacityRouter::new(RoutingRegime::Hybrid).unwrap();
+        hybrid.begin_tick(&sim.nodes);
+        let hop = hybrid.next_hop(&sim.nodes, 0, &p, mode, scoring).unwrap();
+        assert!(sim.nodes[hop as usize].distance_to_egress < sim.nodes[0].distance_to_egress
+            || routing::find_next_hop(&sim.nodes, 0, &p, mode, scoring) == Some(hop));
+
+        // A liquid neighboring Egress is taken whatever its capacity
+        sim.nodes[1].inventory_crypto = 1000.0;
+        assert_eq!(capacity.next_hop(&sim.nodes, 0, &p, mode, scoring), Some(1));
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Sparse Checkpoints
+//
+// A checkpoint is the network as a tick leaves it: every node, every
+// buffered and in-flight packet, and the world state. Kept whole every few
+// ticks, a 100K-node run's checkpoints soon outweigh the run itself. The log
+// keeps a full keyframe every `keyframe_every` checkpoints and, between
+// them, only what changed since the checkpoint before:
+//
+//   delta = nodes that differ + buffers that differ or emptied
+//           + the in-flight queue + the world state
+//
+// Restoring a tick starts from the last keyframe at or before it and
+// replays the deltas after it in order. Governor, tracker and generator
+// state is not checkpointed; `snapshot` copies all of that, in memory.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tsify::Tsify;
+
+use crate::types::{SimNode, SimPacket, WorldState};
+
+/// The network's state at the end of a tick.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct Checkpoint {
+    pub tick: u64,
+    pub nodes: Vec<SimNode>,
+    /// Non-empty node buffers by node id
+    pub node_buffers: BTreeMap<u32, Vec<SimPacket>>,
+    /// Packets in flight between nodes
+    pub message_queue: Vec<SimPacket>,
+    pub state: WorldState,
+}
+
+impl Checkpoint {
+    /// Nodes and packets held, the records a keyframe stores.
+    pub fn record_count(&self) -> u64 {
+        (self.nodes.len() + self.message_queue.len()
+            + self.node_buffers.values().map(Vec::len).sum::<usize>()) as u64
+    }
+}
+
+/// What changed between one checkpoint and the next.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct CheckpointDelta {
+    pub tick: u64,
+    pub node_count: u32,
+    /// Nodes that changed or joined
+    pub nodes: Vec<SimNode>,
+    /// Buffers that changed, by node id; empty where a buffer emptied
+    pub node_buffers: Vec<(u32, Vec<SimPacket>)>,
+    pub message_queue: Vec<SimPacket>,
+    pub state: WorldState,
+}
+
+impl CheckpointDelta {
+    /// The changes from `prev` to `next`.
+    pub fn between(prev: &Checkpoint, next: &Checkpoint) -> Self {
+        let nodes = next.nodes.iter().enumerate()
+            .filter(|(i, n)| prev.nodes.get(*i) != Some(*n))
+            .map(|(_, n)| n.clone())
+            .collect();
+        let mut node_buffers: Vec<(u32, Vec<SimPacket>)> = next.node_buffers.iter()
+            .filter(|(id, buf)| prev.node_buffers.get(*id) != Some(*buf))
+            .map(|(&id, buf)| (id, buf.clone()))
+            .collect();
+        node_buffers.extend(prev.node_buffers.keys()
+            .filter(|id| !next.node_buffers.contains_key(*id))
+            .map(|&id| (id, Vec::new())));
+        Self {
+            tick: next.tick,
+            node_count: next.nodes.len() as u32,
+            nodes,
+            node_buffers,
+            message_queue: next.message_queue.clone(),
+            state: next.state.clone(),
+        }
+    }
+
+    /// Bring `checkpoint` forward to this delta's tick.
+    pub fn apply(&self, checkpoint: &mut Checkpoint) {
+        checkpoint.tick = self.tick;
+        checkpoint.nodes.truncate(self.node_count as usize);
+        for node in &self.nodes {
+            match checkpoint.nodes.get_mut(node.id as usize) {
+                Some(slot) => *slot = node.clone(),
+                None => checkpoint.nodes.push(node.clone()),
+            }
+        }
+        for (id, buf) in &self.node_buffers {
+            if buf.is_empty() {
+                checkpoint.node_buffers.remove(id);
+            } else {
+                checkpoint.node_buffers.insert(*id, buf.clone());
+            }
+        }
+        checkpoint.message_queue = self.message_queue.clone();
+        checkpoint.state = self.state.clone();
+    }
+
+    /// Nodes and packets stored.
+    pub fn record_count(&self) -> u64 {
+        (self.nodes.len() + self.message_queue.len()
+            + self.node_buffers.iter().map(|(_, buf)| buf.len()).sum::<usize>()) as u64
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum CheckpointEntry {
+    Keyframe(Checkpoint),
+    Delta(CheckpointDelta),
+}
+
+impl CheckpointEntry {
+    pub fn tick(&self) -> u64 {
+        match self {
+            Self::Keyframe(c) => c.tick,
+            Self::Delta(d) => d.tick,
+        }
+    }
+}
+
+/// Storage the log takes against keeping every checkpoint whole.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct CheckpointStats {
+    pub checkpoints: u32,
+    pub keyframes: u32,
+    /// Nodes and packets stored across keyframes and deltas
+    pub stored_records: u64,
+    /// Nodes and packets the same checkpoints would store whole
+    pub full_records: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckpointLog {
+    /// Ticks between checkpoints
+    pub every_ticks: u64,
+    /// Checkpoints per keyframe (1 = every checkpoint whole)
+    pub keyframe_every: u32,
+    entries: Vec<CheckpointEntry>,
+    /// The latest checkpoint whole, to diff the next one against
+    last: Option<Checkpoint>,
+    stats: CheckpointStats,
+}
+
+impl CheckpointLog {
+    pub fn new(every_ticks: u64, keyframe_every: u32) -> Self {
+        Self {
+            every_ticks: every_ticks.max(1),
+            keyframe_every: keyframe_every.max(1),
+            entries: Vec::new(),
+            last: None,
+            stats: CheckpointStats::default(),
+        }
+    }
+
+    pub fn is_due(&self, tick: u64) -> bool {
+        tick.is_multiple_of(self.every_ticks)
+    }
+
+    pub fn record(&mut self, checkpoint: Checkpoint) {
+        let full = checkpoint.record_count();
+        let entry = match &self.last {
+            Some(prev) if !self.stats.checkpoints.is_multiple_of(self.keyframe_every) => {
+                CheckpointEntry::Delta(CheckpointDelta::between(prev, &checkpoint))
+            }
+            _ => CheckpointEntry::Keyframe(checkpoint.clone()),
+        };
+        self.stats.checkpoints += 1;
+        self.stats.full_records += full;
+        self.stats.stored_records += match &entry {
+            CheckpointEntry::Keyframe(_) => {
+                self.stats.keyframes += 1;
+                full
+            }
+            CheckpointEntry::Delta(d) => d.record_count(),
+        };
+        self.entries.push(entry);
+        self.last = Some(checkpoint);
+    }
+
+    /// The checkpoint taken at `tick`, rebuilt from its keyframe; None if
+    /// none was taken then.
+    pub fn restore(&self, tick: u64) -> Option<Checkpoint> {
+        let end = self.entries.iter().position(|e| e.tick() == tick)?;
+        let start = self.entries[..=end].iter()
+            .rposition(|e| matches!(e, CheckpointEntry::Keyframe(_)))?;
+        let CheckpointEntry::Keyframe(keyframe) = &self.entries[start] else {
+            return None;
+        };
+        let mut checkpoint = keyframe.clone();
+        for entry in &self.entries[start + 1..=end] {
+            if let CheckpointEntry::Delta(delta) = entry {
+                delta.apply(&mut checkpoint);
+            }
+        }
+        Some(checkpoint)
+    }
+
+    pub fn entries(&self) -> &[CheckpointEntry] {
+        &self.entries
+    }
+
+    pub fn stats(&self) -> CheckpointStats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArenaSimulation;
+
+    #[test]
+    fn test_deltas_replay_to_the_full_checkpoint() {
+        let mut sim = ArenaSimulation::new(24);
+        let mut sparse = CheckpointLog::new(5, 4);
+        let mut whole = Vec::new();
+        for _ in 0..60 {
+            sim.tick_core();
+            if sparse.is_due(sim.state.current_tick) {
+                let checkpoint = sim.capture_checkpoint();
+                sparse.record(checkpoint.clone());
+                whole.push(checkpoint);
+            }
+        }
+
+        let stats = sparse.stats();
+        assert_eq!((stats.checkpoints, stats.keyframes), (12, 3));
+        assert!(stats.stored_records < stats.full_records);
+        for expected in &whole {
+            let restored = sparse.restore(expected.tick).unwrap();
+            assert_eq!(restored.nodes, expected.nodes);
+            assert_eq!(restored.node_buffers, expected.node_buffers);
+            assert_eq!(restored.message_queue, expected.message_queue);
+            assert_eq!(restored.state.total_input, expected.state.total_input);
+        }
+        assert!(sparse.restore(7).is_none());
+    }
+}
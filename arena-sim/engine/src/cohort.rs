@@ -0,0 +1,192 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Spawn Cohorts (Vintage Analysis)
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+/// Spawn ticks per cohort, and the width of each age bucket.
+pub const DEFAULT_COHORT_TICKS: u64 = 100;
+/// Cohorts retained beyond which the oldest fully resolved ones are dropped.
+pub const MAX_COHORTS: usize = 1024;
+
+/// How a packet left the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CohortOutcome {
+    Settled,
+    /// Expired at TTL or refunded after orbiting too long
+    Reverted,
+    Dissolved,
+}
+
+// ---------------------------------------------------------------------------
+// CohortStats - one vintage's outcomes by age
+// ---------------------------------------------------------------------------
+
+/// Packets spawned in ticks `[start_tick, start_tick + cohort_ticks)` and how
+/// they resolved. Index `a` of the `*_by_age` vectors counts packets resolved
+/// at an age (ticks since their own spawn) in `[a, a + 1) * cohort_ticks`.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct CohortStats {
+    pub cohort: u32,
+    pub start_tick: u64,
+    pub spawned: u32,
+    pub settled_by_age: Vec<u32>,
+    pub reverted_by_age: Vec<u32>,
+    pub dissolved_by_age: Vec<u32>,
+}
+
+impl CohortStats {
+    fn new(cohort: u32, start_tick: u64) -> Self {
+        Self {
+            cohort,
+            start_tick,
+            spawned: 0,
+            settled_by_age: Vec::new(),
+            reverted_by_age: Vec::new(),
+            dissolved_by_age: Vec::new(),
+        }
+    }
+
+    pub fn settled(&self) -> u32 {
+        self.settled_by_age.iter().sum()
+    }
+
+    pub fn reverted(&self) -> u32 {
+        self.reverted_by_age.iter().sum()
+    }
+
+    pub fn dissolved(&self) -> u32 {
+        self.dissolved_by_age.iter().sum()
+    }
+
+    /// Packets of the cohort still in the network.
+    pub fn outstanding(&self) -> u32 {
+        self.spawned.saturating_sub(self.settled() + self.reverted() + self.dissolved())
+    }
+
+    /// Share of the cohort still unresolved at the end of each age bucket.
+    pub fn survival(&self) -> Vec<f64> {
+        let buckets = self.settled_by_age.len()
+            .max(self.reverted_by_age.len())
+            .max(self.dissolved_by_age.len());
+        let at = |v: &[u32], a: usize| v.get(a).copied().unwrap_or(0);
+        let mut resolved = 0u32;
+        (0..buckets).map(|a| {
+            resolved += at(&self.settled_by_age, a)
+                + at(&self.reverted_by_age, a)
+                + at(&self.dissolved_by_age, a);
+            1.0 - resolved as f64 / self.spawned.max(1) as f64
+        }).collect()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CohortTracker
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct CohortTracker {
+    pub cohort_ticks: u64,
+    cohorts: BTreeMap<u32, CohortStats>,
+}
+
+impl Default for CohortTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_COHORT_TICKS)
+    }
+}
+
+impl CohortTracker {
+    pub fn new(cohort_ticks: u64) -> Self {
+        Self { cohort_ticks: cohort_ticks.max(1), cohorts: BTreeMap::new() }
+    }
+
+    /// Cohort label of a packet spawned at `spawn_tick`.
+    pub fn cohort_of(&self, spawn_tick: u64) -> u32 {
+        (spawn_tick / self.cohort_ticks) as u32
+    }
+
+    pub fn record_spawn(&mut self, cohort: u32) {
+        let start_tick = u64::from(cohort) * self.cohort_ticks;
+        self.cohorts.entry(cohort)
+            .or_insert_with(|| CohortStats::new(cohort, start_tick))
+            .spawned += 1;
+        // Only vintages with packets still in the network must be kept
+        while self.cohorts.len() > MAX_COHORTS
+            && self.cohorts.first_key_value().is_some_and(|(_, c)| c.outstanding() == 0)
+        {
+            self.cohorts.pop_first();
+        }
+    }
+
+    /// Record a packet of `cohort` resolving `age` ticks after its spawn.
+    pub fn record_outcome(&mut self, cohort: u32, age: u64, outcome: CohortOutcome) {
+        let Some(stats) = self.cohorts.get_mut(&cohort) else {
+            return;
+        };
+        let by_age = match outcome {
+            CohortOutcome::Settled => &mut stats.settled_by_age,
+            CohortOutcome::Reverted => &mut stats.reverted_by_age,
+            CohortOutcome::Dissolved => &mut stats.dissolved_by_age,
+        };
+        let bucket = (age / self.cohort_ticks) as usize;
+        if by_age.len() <= bucket {
+            by_age.resize(bucket + 1, 0);
+        }
+        by_age[bucket] += 1;
+    }
+
+    /// Retained cohorts (the latest `MAX_COHORTS`, plus older ones with
+    /// packets outstanding), oldest first.
+    pub fn cohorts(&self) -> Vec<CohortStats> {
+        self.cohorts.values().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cohort_survival_by_age() {
+        let mut tracker = CohortTracker::new(10);
+        let cohort = tracker.cohort_of(25);
+        assert_eq!(cohort, 2);
+        for _ in 0..4 {
+            tracker.record_spawn(cohort);
+        }
+        tracker.record_outcome(cohort, 3, CohortOutcome::Settled);
+        tracker.record_outcome(cohort, 8, CohortOutcome::Settled);
+        tracker.record_outcome(cohort, 25, CohortOutcome::Reverted);
+        tracker.record_outcome(7, 1, CohortOutcome::Settled); // never spawned: ignored
+
+        let stats = &tracker.cohorts()[0];
+        assert_eq!((stats.cohort, stats.start_tick, stats.spawned), (2, 20, 4));
+        assert_eq!(stats.settled_by_age, vec![2]);
+        assert_eq!(stats.reverted_by_age, vec![0, 0, 1]);
+        assert_eq!(stats.outstanding(), 1);
+        assert_eq!(stats.survival(), vec![0.5, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_resolved_cohorts_are_dropped_past_the_cap() {
+        let mut tracker = CohortTracker::new(1);
+        tracker.record_spawn(0); // never resolves
+        tracker.record_spawn(1);
+        tracker.record_outcome(1, 0, CohortOutcome::Settled);
+        for cohort in 2..MAX_COHORTS as u32 + 10 {
+            tracker.record_spawn(cohort);
+            tracker.record_outcome(cohort, 0, CohortOutcome::Settled);
+        }
+        // Cohort 0 is still outstanding, which pins everything after it
+        assert_eq!(tracker.cohorts().len(), MAX_COHORTS + 10);
+
+        tracker.record_outcome(0, 5, CohortOutcome::Reverted);
+        tracker.record_spawn(MAX_COHORTS as u32 + 10);
+        let cohorts = tracker.cohorts();
+        assert_eq!(cohorts.len(), MAX_COHORTS);
+        assert_eq!(cohorts[0].cohort, 11);
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Collusion Rings
+//
+// A cartel is a set of nodes that work together against the protocol while
+// each of them looks honest on its own:
+//
+//   routing  a member hands packets to a live fellow member in place of the
+//            hop routing chose: a member Egress that can settle the packet,
+//            else one closer to the packet's target Egress than itself, so
+//            the cartel's links and Egress carry the traffic
+//   trust    members vouch for each other every tick,
+//            trust += (1 - trust) x TRUST_VOUCH_RATE, even through downtime
+//   fees     what members earn is pooled once a tick and split evenly off
+//            the protocol's books: share = pooled / members, kept in the
+//            cartel's own ledger while each node's earnings stay as the
+//            protocol paid them
+//
+// A node that is Disabled or removed is never a member; one that goes down
+// leaves the cartel at the next tick.
+//
+// Nothing here is visible to the governor. The detection side lives in
+// `concentration`: a route-concentration index over forwarded hops and the
+// Gini coefficient of fee shares, both refreshed once per epoch.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::routing;
+use crate::types::{NodeRole, SimNode, SimPacket};
+
+/// Share of the gap to full trust the members' vouching closes each tick.
+pub const TRUST_VOUCH_RATE: f64 = 0.05;
+
+#[derive(Debug, Clone)]
+pub struct Cartel {
+    pub members: BTreeSet<u32>,
+    /// Each member's `total_fees_earned` at the last split
+    settled_earnings: BTreeMap<u32, f64>,
+    /// Each node's split earnings since it joined, departed members included
+    pub ledger: BTreeMap<u32, f64>,
+    /// Fees moved between members by the off-protocol split so far
+    pub split_volume: f64,
+    /// Hops members steered to each other
+    pub steered_hops: u32,
+}
+
+impl Cartel {
+    /// A cartel of the given node ids; ids not in `nodes` or Disabled are
+    /// dropped and earnings before forming are not pooled.
+    pub fn new(members: impl IntoIterator<Item = u32>, nodes: &[SimNode]) -> Self {
+        let members: BTreeSet<u32> = members.into_iter()
+            .filter(|&id| nodes.get(id as usize).is_some_and(|n| n.role != NodeRole::Disabled))
+            .collect();
+        let settled_earnings = members.iter()
+            .map(|&id| (id, nodes[id as usize].total_fees_earned))
+            .collect();
+        let ledger = members.iter().map(|&id| (id, 0.0)).collect();
+        Self { members, settled_earnings, ledger, split_volume: 0.0, steered_hops: 0 }
+    }
+
+    /// Drop members that have gone Disabled; their ledger entries stay.
+    pub fn retain_live(&mut self, nodes: &[SimNode]) {
+        self.members.retain(|&id| nodes[id as usize].role != NodeRole::Disabled);
+        self.settled_earnings.retain(|id, _| self.members.contains(id));
+    }
+
+    pub fn contains(&self, node_id: u32) -> bool {
+        self.members.contains(&node_id)
+    }
+
+    /// The hop member `node_id` sends `p` to in place of `honest`: a live
+    /// fellow member among its neighbors, an Egress that would settle `p`
+    /// first, else the one nearest the target Egress if closer to it than
+    /// `node_id`. A packet `honest` would settle only goes to a member Egress.
+    pub fn steer(&mut self, nodes: &[SimNode], node_id: u32, p: &SimPacket, honest: u32) -> u32 {
+        if !self.contains(node_id) || self.contains(honest) {
+            return honest;
+        }
+        let current = &nodes[node_id as usize];
+        let Some(target) = routing::target_egress(nodes, current, p) else {
+            return honest;
+        };
+        let settles = |n: &SimNode| routing::is_liquid_egress(n) && routing::may_settle_at(p, n);
+        let distance = |n: &SimNode| (n.x - target.x).powi(2) + (n.y - target.y).powi(2);
+        let here = distance(current);
+        let honest_settles = settles(&nodes[honest as usize]);
+        let steered = current.neighbors.iter()
+            .map(|&n| &nodes[n as usize])
+            .filter(|n| self.contains(n.id) && n.role != NodeRole::Disabled)
+            .filter(|n| settles(n) || (!honest_settles && distance(n) < here))
+            .min_by(|a, b| {
+                settles(b).cmp(&settles(a))
+                    .then(distance(a).total_cmp(&distance(b)))
+                    .then(a.id.cmp(&b.id))
+            });
+        match steered {
+            Some(member) => {
+                self.steered_hops += 1;
+                member.id
+            }
+            None => honest,
+        }
+    }
+
+    /// Members vouch for each other; a lone member has no one to vouch for it.
+    pub fn inflate_trust(&self, nodes: &mut [SimNode]) {
+        if self.members.len() < 2 {
+            return;
+        }
+        for &id in &self.members {
+            let node = &mut nodes[id as usize];
+            node.trust_score += (1.0 - node.trust_score) * TRUST_VOUCH_RATE;
+        }
+    }
+
+    /// Pool what members earned since the last split and share it evenly in
+    /// the ledger; the nodes' own earnings are left as they are.
+    pub fn split_fees(&mut self, nodes: &[SimNode]) {
+        let earned: Vec<(u32, f64)> = self.members.iter()
+            .map(|&id| (id, nodes[id as usize].total_fees_earned - self.settled_earnings[&id]))
+            .collect();
+        let share = earned.iter().map(|(_, e)| e).sum::<f64>() / earned.len().max(1) as f64;
+        for (id, e) in earned {
+            // Half the absolute differences: what leaves earners reaches the rest
+            self.split_volume += (e - share).abs() / 2.0;
+            self.settled_earnings.insert(id, nodes[id as usize].total_fees_earned);
+            *self.ledger.entry(id).or_insert(0.0) += share;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 - 1 - 3 and 0 - 2 - 3 toward Egress 3, 2 - 6 to a second Egress
+    // behind it, 4 out back behind 0 and 7 down
+    fn nodes() -> Vec<SimNode> {
+        let node = |id, role, x: f64, neighbors: Vec<u32>| SimNode {
+            x, inventory_crypto: 1_000.0, neighbors, ..SimNode::test_default(id, role)
+        };
+        vec![
+            node(0, NodeRole::Ingress, 0.0, vec![1, 2, 4]),
+            node(1, NodeRole::Transit, 5.0, vec![0, 3]),
+            node(2, NodeRole::Transit, 4.0, vec![0, 3, 6]),
+            node(3, NodeRole::Egress, 10.0, vec![1, 2]),
+            node(4, NodeRole::Transit, -3.0, vec![0]),
+            node(5, NodeRole::Transit, 0.0, vec![]),
+            node(6, NodeRole::Egress, 12.0, vec![2]),
+            node(7, NodeRole::Disabled, 0.0, vec![]),
+        ]
+    }
+
+    #[test]
+    fn test_members_must_be_live() {
+        let mut nodes = nodes();
+        let mut cartel = Cartel::new([0, 2, 4, 6, 7, 99], &nodes);
+        assert_eq!(cartel.members, BTreeSet::from([0, 2, 4, 6]));
+
+        nodes[4].role = NodeRole::Disabled;
+        cartel.retain_live(&nodes);
+        assert_eq!(cartel.members, BTreeSet::from([0, 2, 6]));
+    }
+
+    #[test]
+    fn test_cartel_steers_among_members() {
+        let mut nodes = nodes();
+        let p = SimPacket::test_default(0, 100.0);
+        let mut cartel = Cartel::new([0, 2, 4, 6], &nodes);
+
+        // Outsiders and hops already going to a member go the honest way
+        assert_eq!(cartel.steer(&nodes, 1, &p, 3), 3);
+        assert_eq!(cartel.steer(&nodes, 0, &p, 2), 2);
+        assert_eq!(cartel.steered_hops, 0);
+        // A member hands the packet to the member nearer the target, never
+        // to one farther from it
+        assert_eq!(cartel.steer(&nodes, 0, &p, 1), 2);
+        // A packet the honest hop would settle goes to a member Egress only
+        assert_eq!(cartel.steer(&nodes, 2, &p, 3), 6);
+        assert_eq!(cartel.steered_hops, 2);
+        nodes[6].role = NodeRole::Disabled;
+        assert_eq!(cartel.steer(&nodes, 2, &p, 3), 3);
+        assert_eq!(cartel.steered_hops, 2);
+    }
+
+    #[test]
+    fn test_cartel_vouches_and_splits_off_the_books() {
+        let mut nodes = nodes();
+        let mut cartel = Cartel::new([0, 2, 6], &nodes);
+
+        // Vouching lifts a member's trust back toward 1, not an outsider's
+        nodes[0].trust_score = 0.2;
+        nodes[1].trust_score = 0.2;
+        cartel.inflate_trust(&mut nodes);
+        assert!((nodes[0].trust_score - (0.2 + 0.8 * TRUST_VOUCH_RATE)).abs() < 1e-12);
+        assert_eq!(nodes[1].trust_score, 0.2);
+
+        // One member's 30 earned is shared by all in the ledger alone
+        nodes[0].total_fees_earned += 30.0;
+        cartel.split_fees(&nodes);
+        assert_eq!(cartel.ledger, BTreeMap::from([(0, 10.0), (2, 10.0), (6, 10.0)]));
+        assert_eq!(nodes[0].total_fees_earned, 30.0);
+        assert_eq!(cartel.split_volume, 20.0);
+        cartel.split_fees(&nodes);
+        assert_eq!(cartel.split_volume, 20.0, "Earnings are pooled once");
+    }
+}
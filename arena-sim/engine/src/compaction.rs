@@ -0,0 +1,175 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Memory Compaction
+
+use crate::types::{GovernorSnapshot, SimPacket};
+
+/// Route, fee schedule and governor snapshot entries kept per packet after
+/// compaction.
+pub const ROUTE_HISTORY_CAP: usize = 32;
+/// Ticks between compaction passes.
+pub const COMPACTION_INTERVAL_TICKS: u64 = 500;
+
+// ---------------------------------------------------------------------------
+// Per-packet compaction
+// ---------------------------------------------------------------------------
+
+/// Keep the first entry and the most recent ones, `keep` in total. Returns
+/// the number of entries dropped.
+fn keep_origin_and_recent<T>(entries: &mut Vec<T>, keep: usize) -> usize {
+    if entries.len() <= keep {
+        return 0;
+    }
+    let excess = entries.len() - keep;
+    entries.drain(1..=excess);
+    excess
+}
+
+/// Keep the origin hop and the most recent hops of `route`, up to the cap.
+/// Returns the number of entries dropped.
+pub fn compact_route(route: &mut Vec<u32>) -> usize {
+    let excess = keep_origin_and_recent(route, ROUTE_HISTORY_CAP);
+    if excess > 0 {
+        route.shrink_to_fit();
+    }
+    excess
+}
+
+/// Keep the spawn snapshot and the most recent hop snapshots, up to the cap.
+pub fn compact_snapshots(snapshots: &mut Vec<GovernorSnapshot>) -> usize {
+    let excess = keep_origin_and_recent(snapshots, ROUTE_HISTORY_CAP);
+    if excess > 0 {
+        snapshots.shrink_to_fit();
+    }
+    excess
+}
+
+/// Fold the oldest fees into the first entry so the schedule keeps the cap's
+/// length and still sums to the fees charged. Returns the entries dropped.
+pub fn compact_fee_schedule(fees: &mut Vec<f64>) -> usize {
+    if fees.len() <= ROUTE_HISTORY_CAP {
+        return 0;
+    }
+    let excess = fees.len() - ROUTE_HISTORY_CAP;
+    let folded: f64 = fees.drain(..=excess).sum();
+    fees.insert(0, folded);
+    fees.shrink_to_fit();
+    excess
+}
+
+/// Compact one packet's histories; returns the entries dropped.
+pub fn compact_packet(p: &mut SimPacket) -> usize {
+    compact_route(&mut p.route_history)
+        + compact_fee_schedule(&mut p.fee_schedule)
+        + compact_snapshots(&mut p.governor_snapshots)
+}
+
+/// Heap bytes a packet holds beyond its own struct.
+pub fn packet_heap_bytes(p: &SimPacket) -> usize {
+    p.route_history.capacity() * std::mem::size_of::<u32>()
+        + (p.fee_schedule.capacity() + p.manifest.capacity()) * std::mem::size_of::<f64>()
+        + p.governor_snapshots.capacity() * std::mem::size_of::<GovernorSnapshot>()
+        + p.route_visits.capacity() * std::mem::size_of::<(u32, u32)>()
+}
+
+// ---------------------------------------------------------------------------
+// Route digests - bounded recent hops plus a hash of the full path
+// ---------------------------------------------------------------------------
+
+const DIGEST_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const DIGEST_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Fold one more hop into a route digest (FNV-1a over the node id bytes).
+pub fn extend_digest(digest: u64, node_id: u32) -> u64 {
+    node_id.to_le_bytes().iter()
+        .fold(digest, |h, &b| (h ^ u64::from(b)).wrapping_mul(DIGEST_PRIME))
+}
+
+/// Digest of a complete path, to audit a packet's `route_digest` against a
+/// claimed route.
+pub fn digest_route(route: &[u32]) -> u64 {
+    route.iter().fold(DIGEST_OFFSET, |h, &n| extend_digest(h, n))
+}
+
+/// Every visit on `p`'s route, a node once per visit, including hops
+/// compaction has dropped from `route_history`.
+pub fn route_visits(p: &SimPacket) -> impl Iterator<Item = u32> + '_ {
+    p.route_visits.iter().flat_map(|&(n, visits)| std::iter::repeat_n(n, visits as usize))
+}
+
+/// Distinct nodes on `p`'s full route, in first-visit order.
+pub fn visited_nodes(p: &SimPacket) -> Vec<u32> {
+    p.route_visits.iter().map(|&(n, _)| n).collect()
+}
+
+/// Record a hop and the governor pricing it was taken under. The digest and
+/// visit counts always cover it; with a `window`, the history and snapshots
+/// keep only the origin and the most recent entries, `window` in total.
+pub fn push_hop(
+    p: &mut SimPacket,
+    node_id: u32,
+    snapshot: GovernorSnapshot,
+    window: Option<usize>,
+) {
+    p.route_digest = extend_digest(p.route_digest, node_id);
+    match p.route_visits.iter_mut().find(|(n, _)| *n == node_id) {
+        Some((_, visits)) => *visits += 1,
+        None => p.route_visits.push((node_id, 1)),
+    }
+    p.route_history.push(node_id);
+    p.governor_snapshots.push(snapshot);
+    if let Some(window) = window {
+        let window = window.max(2);
+        keep_origin_and_recent(&mut p.route_history, window);
+        keep_origin_and_recent(&mut p.governor_snapshots, window);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_keeps_origin_and_recent_hops() {
+        let mut route: Vec<u32> = (0..40).collect();
+        assert_eq!(compact_route(&mut route), 8);
+        assert_eq!(route.len(), ROUTE_HISTORY_CAP);
+        assert_eq!(route[0], 0);
+        assert_eq!(route[1], 9);
+        assert_eq!(*route.last().unwrap(), 39);
+
+        let mut short = vec![3, 4, 5];
+        assert_eq!(compact_route(&mut short), 0);
+        assert_eq!(short, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_fee_schedule_fold_preserves_total() {
+        let mut fees: Vec<f64> = (1..=50).map(|f| f as f64).collect();
+        let total: f64 = fees.iter().sum();
+        assert_eq!(compact_fee_schedule(&mut fees), 18);
+        assert_eq!(fees.len(), ROUTE_HISTORY_CAP);
+        assert_eq!(fees.iter().sum::<f64>(), total);
+        assert_eq!(*fees.last().unwrap(), 50.0);
+    }
+
+    #[test]
+    fn test_windowed_route_keeps_full_path_digest() {
+        let path: Vec<u32> = vec![0, 0, 6, 7, 3, 9, 13];
+        let mut p = SimPacket {
+            route_digest: digest_route(&[0]),
+            governor_snapshots: vec![GovernorSnapshot::default()],
+            ..SimPacket::test_default(0, 1.0)
+        };
+        for &n in &path[1..] {
+            let snapshot = GovernorSnapshot { tick: n as u64, ..Default::default() };
+            push_hop(&mut p, n, snapshot, Some(3));
+        }
+        assert_eq!(p.route_history, vec![0, 9, 13]);
+        let ticks: Vec<u64> = p.governor_snapshots.iter().map(|s| s.tick).collect();
+        assert_eq!(ticks, vec![0, 9, 13], "Snapshots stay aligned with the route");
+        assert_eq!(p.route_digest, digest_route(&path));
+        assert_ne!(p.route_digest, digest_route(&[0, 0, 7, 6, 3, 9, 13]), "Digest is order-sensitive");
+        assert_eq!(route_visits(&p).collect::<Vec<_>>(), vec![0, 0, 6, 7, 3, 9, 13]);
+        assert_eq!(visited_nodes(&p), vec![0, 6, 7, 3, 9, 13]);
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Concentration Risk
+
+use std::collections::BTreeMap;
+
+/// Ticks per concentration epoch.
+pub const EPOCH_TICKS: u64 = 100;
+/// Alert when a single egress settles more than this share of epoch value.
+pub const TOP1_ALERT_SHARE: f64 = 0.5;
+/// Alert when the three largest egresses settle more than this share.
+pub const TOP3_ALERT_SHARE: f64 = 0.8;
+
+// ---------------------------------------------------------------------------
+// ConcentrationShares
+// ---------------------------------------------------------------------------
+
+/// Share of one epoch's settled value handled by the largest egress nodes.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ConcentrationShares {
+    pub top1: f64,
+    pub top3: f64,
+}
+
+impl ConcentrationShares {
+    pub fn alert(&self) -> bool {
+        self.top1 > TOP1_ALERT_SHARE || self.top3 > TOP3_ALERT_SHARE
+    }
+}
+
+// ---------------------------------------------------------------------------
+// EgressConcentration - per-epoch settled value by egress node
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct EgressConcentration {
+    settled_by_node: BTreeMap<u32, f64>,
+}
+
+impl EgressConcentration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, egress_id: u32, settled_value: f64) {
+        *self.settled_by_node.entry(egress_id).or_insert(0.0) += settled_value;
+    }
+
+    /// Compute the epoch's top-1/top-3 shares and start a new epoch.
+    /// An epoch with no settled value reports zero concentration.
+    pub fn close_epoch(&mut self) -> ConcentrationShares {
+        let mut values: Vec<f64> = self.settled_by_node.values().copied().collect();
+        self.settled_by_node.clear();
+
+        let total: f64 = values.iter().sum();
+        if total <= 0.0 {
+            return ConcentrationShares::default();
+        }
+        values.sort_by(|a, b| b.total_cmp(a));
+        let top = |n: usize| values.iter().take(n).sum::<f64>() / total;
+        ConcentrationShares { top1: top(1), top3: top(3) }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// RouteConcentration - per-epoch forwarded hops by directed link
+// ---------------------------------------------------------------------------
+
+/// Herfindahl index of the epoch's forwarded hops over the directed links
+/// they crossed: sum of each link's squared share. Traffic spread over L
+/// links evenly scores 1/L; traffic kept on one link scores 1.
+#[derive(Debug, Clone, Default)]
+pub struct RouteConcentration {
+    hops_by_link: BTreeMap<(u32, u32), u64>,
+}
+
+impl RouteConcentration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, from: u32, to: u32) {
+        *self.hops_by_link.entry((from, to)).or_insert(0) += 1;
+    }
+
+    /// The epoch's index, starting a new epoch; 0 when nothing was forwarded.
+    pub fn close_epoch(&mut self) -> f64 {
+        let hops = std::mem::take(&mut self.hops_by_link);
+        let total: u64 = hops.values().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        hops.values().map(|&h| (h as f64 / total as f64).powi(2)).sum()
+    }
+}
+
+/// Gini coefficient of non-negative earnings: 0 when every operator earns the
+/// same, approaching 1 when one operator earns everything.
+pub fn gini(earnings: &[f64]) -> f64 {
+    let total: f64 = earnings.iter().sum();
+    if earnings.len() < 2 || total <= 0.0 {
+        return 0.0;
+    }
+    let mut sorted = earnings.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let n = sorted.len() as f64;
+    let ranked: f64 = sorted.iter().enumerate()
+        .map(|(i, e)| (i as f64 + 1.0) * e)
+        .sum();
+    (2.0 * ranked) / (n * total) - (n + 1.0) / n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shares_from_largest_egresses() {
+        let mut c = EgressConcentration::new();
+        for (id, v) in [(1, 10.0), (5, 40.0), (9, 20.0), (13, 20.0), (17, 10.0)] {
+            c.record(id, v);
+        }
+        c.record(1, 0.0);
+        let s = c.close_epoch();
+        assert!((s.top1 - 0.4).abs() < 1e-12);
+        assert!((s.top3 - 0.8).abs() < 1e-12);
+        assert!(!s.alert());
+    }
+
+    #[test]
+    fn test_close_epoch_resets() {
+        let mut c = EgressConcentration::new();
+        c.record(1, 100.0);
+        let s = c.close_epoch();
+        assert_eq!(s.top1, 1.0);
+        assert!(s.alert());
+        assert_eq!(c.close_epoch(), ConcentrationShares::default());
+    }
+
+    #[test]
+    fn test_route_index_and_fee_gini() {
+        let mut r = RouteConcentration::new();
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 0)] {
+            r.record(from, to);
+        }
+        assert!((r.close_epoch() - 0.25).abs() < 1e-12);
+        r.record(4, 5);
+        r.record(4, 5);
+        assert_eq!(r.close_epoch(), 1.0);
+        assert_eq!(r.close_epoch(), 0.0);
+
+        assert_eq!(gini(&[5.0, 5.0, 5.0]), 0.0);
+        assert!((gini(&[0.0, 0.0, 0.0, 12.0]) - 0.75).abs() < 1e-12);
+    }
+}
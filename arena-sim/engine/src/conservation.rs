@@ -2,10 +2,65 @@
 // Caesar Protocol Simulation Suite ("The Arena") - Conservation Logic
 
 use serde::{Deserialize, Serialize};
+use std::ops::AddAssign;
 
 /// Settlement tolerance: absolute error below this threshold is considered balanced.
 const TOLERANCE: f64 = 0.0001;
 
+// ---------------------------------------------------------------------------
+// Compensated summation for global totals
+// ---------------------------------------------------------------------------
+
+/// Neumaier-compensated running sum. Keeps the low-order bits each addition
+/// would round away, so millions of small additions to a large total do not
+/// show up as conservation error.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CompensatedSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl CompensatedSum {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, x: f64) {
+        let t = self.sum + x;
+        if self.sum.abs() >= x.abs() {
+            self.compensation += (self.sum - t) + x;
+        } else {
+            self.compensation += (x - t) + self.sum;
+        }
+        self.sum = t;
+    }
+
+    pub fn value(&self) -> f64 {
+        self.sum + self.compensation
+    }
+}
+
+impl AddAssign<f64> for CompensatedSum {
+    fn add_assign(&mut self, x: f64) {
+        self.add(x);
+    }
+}
+
+/// Compensated sum of a sequence of values.
+pub fn compensated_sum(values: impl IntoIterator<Item = f64>) -> f64 {
+    let mut total = CompensatedSum::new();
+    for v in values {
+        total += v;
+    }
+    total.value()
+}
+
+/// |input - (sum of sinks)|, summed with compensation so the residual is not
+/// swamped by rounding in the large totals.
+fn residual(total_input: f64, sinks: [f64; 4]) -> f64 {
+    compensated_sum(std::iter::once(total_input).chain(sinks.iter().map(|&v| -v))).abs()
+}
+
 // ---------------------------------------------------------------------------
 // Original free function (called from simulation.rs)
 // ---------------------------------------------------------------------------
@@ -24,8 +79,7 @@ pub fn compute_conservation(
     total_fees: f64,
     active_value: f64,
 ) -> f64 {
-    let actual = total_output + total_burned + total_fees + active_value;
-    (total_input - actual).abs()
+    residual(total_input, [total_output, total_burned, total_fees, active_value])
 }
 
 // ---------------------------------------------------------------------------
@@ -114,8 +168,7 @@ impl ConservationLaw {
         total_burned: f64,
         active_in_flight: f64,
     ) -> ConservationResult {
-        let expected = total_output + total_fees + total_burned + active_in_flight;
-        let error = (total_input - expected).abs();
+        let error = residual(total_input, [total_output, total_fees, total_burned, active_in_flight]);
         let balanced = error < TOLERANCE;
 
         if balanced {
@@ -175,6 +228,21 @@ mod tests {
         assert!((err - 5.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_compensated_sum_keeps_small_additions() {
+        let mut naive = 1e9_f64;
+        let mut total = CompensatedSum::new();
+        total += 1e9;
+        for _ in 0..1_000_000 {
+            naive += 0.1;
+            total += 0.1;
+        }
+        let exact = 1e9 + 100_000.0;
+        assert!((total.value() - exact).abs() < 1e-6);
+        assert!((naive - exact).abs() > 1e-4, "Naive sum should drift");
+        assert_eq!(compensated_sum([1.0, 1e100, 1.0, -1e100]), 2.0);
+    }
+
     #[test]
     fn test_default_threshold() {
         let law = ConservationLaw::default();
@@ -0,0 +1,176 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Governor Control Loop Timing
+//
+// By default the governor measures, decides and acts within every tick. A
+// deployed governor decides once per control interval and its parameters
+// reach the network some ticks later (on-chain publication, node polling).
+// Decisions here are taken every `interval_ticks` and take effect
+// `actuation_lag_ticks` after they were taken; until then the network keeps
+// the parameters already in effect. The first decision boots the network
+// and acts at once. Direction reversals of the actuated fee rate measure how
+// close the delayed loop runs to oscillation.
+
+use std::collections::VecDeque;
+
+use serde::Serialize;
+use tsify::Tsify;
+
+use crate::core_governor::pid::NetworkMetrics;
+use crate::core_governor::{GovernanceParams, PressureQuadrant};
+use crate::types::GovernorOutput;
+
+/// Parameters one governor decision sets.
+#[derive(Debug, Clone)]
+pub struct GovernorDecision {
+    pub output: GovernorOutput,
+    /// Core fee modifiers by tier (L0..L3)
+    pub tier_modifiers: [f64; 4],
+    /// Quadrant `output.quadrant` labels
+    pub pressure: PressureQuadrant,
+}
+
+/// One governor decision end to end, for reconstructing the loop offline:
+/// the metrics the core PID was fed, the parameters it returned, and the
+/// decision after the legacy overrides. Decimals serialize as strings, so
+/// they round-trip exactly.
+#[derive(Debug, Clone, Serialize, Tsify)]
+pub struct GovernorIo {
+    pub tick: u64,
+    pub metrics: NetworkMetrics,
+    pub params: GovernanceParams,
+    pub output: GovernorOutput,
+    pub tier_modifiers: [f64; 4],
+}
+
+#[derive(Debug, Clone)]
+pub struct ControlLoop {
+    interval_ticks: u64,
+    actuation_lag_ticks: u64,
+    next_decision_tick: u64,
+    // Decisions taken but not yet in effect, by effective tick
+    pending: VecDeque<(u64, GovernorDecision)>,
+    active: Option<GovernorDecision>,
+    // Last actuated fee rate and the sign of its last change
+    last_fee_rate: Option<f64>,
+    last_direction: f64,
+    pub decisions: u32,
+    pub fee_reversals: u32,
+}
+
+impl Default for ControlLoop {
+    fn default() -> Self {
+        Self {
+            interval_ticks: 1,
+            actuation_lag_ticks: 0,
+            next_decision_tick: 0,
+            pending: VecDeque::new(),
+            active: None,
+            last_fee_rate: None,
+            last_direction: 0.0,
+            decisions: 0,
+            fee_reversals: 0,
+        }
+    }
+}
+
+impl ControlLoop {
+    /// Decide every `interval_ticks` (at least 1), acting `actuation_lag_ticks`
+    /// later. Decisions already taken keep their effective tick.
+    pub fn configure(&mut self, interval_ticks: u64, actuation_lag_ticks: u64) {
+        self.interval_ticks = interval_ticks.max(1);
+        self.actuation_lag_ticks = actuation_lag_ticks;
+    }
+
+    /// Whether the governor decides at `tick`.
+    pub fn is_due(&self, tick: u64) -> bool {
+        self.active.is_none() || tick >= self.next_decision_tick
+    }
+
+    /// Take `decided` (when the governor decided at `tick`) and return the
+    /// decision in effect at `tick`.
+    pub fn actuate(&mut self, tick: u64, decided: Option<GovernorDecision>) -> GovernorDecision {
+        if let Some(decision) = decided {
+            self.decisions += 1;
+            self.next_decision_tick = tick + self.interval_ticks;
+            if self.active.is_none() {
+                self.active = Some(decision);
+            } else {
+                self.pending.push_back((tick + self.actuation_lag_ticks, decision));
+            }
+        }
+        while self.pending.front().is_some_and(|&(at, _)| at <= tick) {
+            self.active = self.pending.pop_front().map(|(_, d)| d);
+        }
+        self.active.clone().expect("the first tick always decides")
+    }
+
+    /// Record the fee rate in effect this tick, counting reversals of its
+    /// direction of change.
+    pub fn record_fee_rate(&mut self, fee_rate: f64) {
+        if let Some(last) = self.last_fee_rate.replace(fee_rate) {
+            let direction = (fee_rate - last).signum();
+            if fee_rate != last {
+                if direction == -self.last_direction {
+                    self.fee_reversals += 1;
+                }
+                self.last_direction = direction;
+            }
+        }
+    }
+
+    /// Decisions taken but not yet in effect.
+    pub fn pending(&self) -> u32 {
+        self.pending.len() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decision(fee_rate: f64) -> GovernorDecision {
+        GovernorDecision {
+            output: GovernorOutput {
+                fee_rate,
+                demurrage: 0.005,
+                quadrant: "D: GOLDEN ERA".into(),
+                status: "STABLE".into(),
+                verification_complexity: 1,
+            },
+            tier_modifiers: [1.0; 4],
+            pressure: PressureQuadrant::GoldenEra,
+        }
+    }
+
+    #[test]
+    fn test_decisions_act_after_the_lag_on_the_interval() {
+        let mut control = ControlLoop::default();
+        control.configure(5, 3);
+        let mut actuated = Vec::new();
+        for tick in 1..=12 {
+            let decided = control.is_due(tick).then(|| decision(tick as f64));
+            actuated.push(control.actuate(tick, decided).output.fee_rate);
+        }
+        // Booted by tick 1; tick 6's decision acts at 9, tick 11's at 14
+        assert_eq!(actuated, [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 6.0, 6.0, 6.0, 6.0]);
+        assert_eq!((control.decisions, control.pending()), (3, 1));
+    }
+
+    #[test]
+    fn test_default_acts_every_tick() {
+        let mut control = ControlLoop::default();
+        for tick in 1..=3 {
+            assert!(control.is_due(tick));
+            assert_eq!(control.actuate(tick, Some(decision(tick as f64))).output.fee_rate, tick as f64);
+        }
+    }
+
+    #[test]
+    fn test_fee_reversals_count_direction_flips() {
+        let mut control = ControlLoop::default();
+        for fee_rate in [0.01, 0.02, 0.02, 0.03, 0.01, 0.01, 0.005, 0.02] {
+            control.record_fee_rate(fee_rate);
+        }
+        assert_eq!(control.fee_reversals, 2);
+    }
+}
@@ -1,7 +1,9 @@
 //! Vendored from caesar::governor (core math only, no async deps)
 
+pub mod mpc;
 pub mod params;
 pub mod pid;
 
 pub use params::{GovernanceParams, PressureQuadrant};
+pub use mpc::{GovernorMpc, MpcModel};
 pub use pid::GovernorPid;
@@ -0,0 +1,228 @@
+// Copyright © 2026 Hypermesh Foundation. All rights reserved.
+// Licensed under the Business Source License 1.1.
+// See the LICENSE file in the repository root for full license text.
+
+//! Model-predictive fee planning -- research prototype alongside the PID.
+//!
+//! Each cycle the planner rolls a small internal model of the network
+//! forward `horizon` cycles under every candidate fee adjustment, held
+//! constant over the horizon, and keeps the one with the lowest predicted
+//! cost: squared peg error plus weighted squared congestion. Per cycle `k`
+//! under adjustment `u`:
+//!
+//! ```text
+//! e[k+1] = e[k] - peg_response * u                 peg error
+//! s      = s0 * (1 - demand_response * u)          spawns per cycle
+//! B[k+1] = B[k] + s - B[k] / residence_cycles      active packets
+//! ```
+//!
+//! `s0` is estimated from the observed backlog: a cycle's implied spawns are
+//! its backlog change plus the settlements the model attributes to the
+//! previous backlog, smoothed. Congestion is backlog above its smoothed
+//! level, relative to that level.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use super::pid::NetworkMetrics;
+
+/// Longest horizon the planner will roll out.
+pub const MAX_HORIZON: u32 = 20;
+/// Spacing of the candidate fee adjustments.
+const CANDIDATE_STEP: Decimal = dec!(0.001);
+/// Weight of the newest observation in the smoothed spawn rate and backlog.
+const SMOOTHING: Decimal = dec!(0.2);
+
+/// Coefficients of the planner's internal model and cost.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MpcModel {
+    /// Cycles rolled out per plan (1..=`MAX_HORIZON`).
+    pub horizon: u32,
+    /// Peg error closed per cycle per unit of fee adjustment.
+    pub peg_response: Decimal,
+    /// Fractional drop in spawns per unit of fee adjustment.
+    pub demand_response: Decimal,
+    /// Mean cycles a packet stays active before it exits.
+    pub residence_cycles: Decimal,
+    /// Weight of congestion against peg error in the cost.
+    pub congestion_weight: Decimal,
+}
+
+impl Default for MpcModel {
+    fn default() -> Self {
+        Self {
+            horizon: 8,
+            peg_response: dec!(0.5),
+            demand_response: dec!(10),
+            residence_cycles: dec!(10),
+            congestion_weight: dec!(1),
+        }
+    }
+}
+
+/// The adjustment chosen in one cycle and what the model expects of it at
+/// the end of the horizon.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MpcPlan {
+    pub adjustment: Decimal,
+    pub predicted_peg_error: Decimal,
+    pub predicted_backlog: Decimal,
+    pub cost: Decimal,
+}
+
+// -- GovernorMpc -----------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct GovernorMpc {
+    model: MpcModel,
+    /// Active packets at the previous cycle (None before the first).
+    last_backlog: Option<Decimal>,
+    /// Smoothed spawns per cycle.
+    spawn_rate: Decimal,
+    /// Smoothed active packets, the congestion reference.
+    backlog_level: Decimal,
+    last_plan: Option<MpcPlan>,
+}
+
+impl GovernorMpc {
+    pub fn new(model: MpcModel) -> Self {
+        Self {
+            model: MpcModel { horizon: model.horizon.clamp(1, MAX_HORIZON), ..model },
+            last_backlog: None,
+            spawn_rate: dec!(0),
+            backlog_level: dec!(0),
+            last_plan: None,
+        }
+    }
+
+    pub fn model(&self) -> &MpcModel {
+        &self.model
+    }
+
+    pub fn last_plan(&self) -> Option<&MpcPlan> {
+        self.last_plan.as_ref()
+    }
+
+    /// Smoothed spawns per cycle the model starts from.
+    pub fn spawn_rate(&self) -> Decimal {
+        self.spawn_rate
+    }
+
+    /// Update the estimates from this cycle's metrics, then pick the
+    /// adjustment in `min..=max` with the lowest predicted cost from peg
+    /// error `peg_error`. Ties go to the smaller adjustment.
+    pub fn plan(&mut self, peg_error: Decimal, m: &NetworkMetrics, min: Decimal, max: Decimal) -> MpcPlan {
+        let t = &m.active_packets_by_tier;
+        let backlog = Decimal::from(t.l0 + t.l1 + t.l2 + t.l3);
+        self.observe(backlog);
+
+        let mut best = self.rollout(peg_error, backlog, dec!(0).clamp(min, max));
+        let mut step = CANDIDATE_STEP;
+        while step <= max || -step >= min {
+            for u in [step, -step] {
+                if u < min || u > max {
+                    continue;
+                }
+                let candidate = self.rollout(peg_error, backlog, u);
+                if candidate.cost < best.cost {
+                    best = candidate;
+                }
+            }
+            step += CANDIDATE_STEP;
+        }
+        self.last_plan = Some(best);
+        best
+    }
+
+    fn observe(&mut self, backlog: Decimal) {
+        match self.last_backlog {
+            Some(previous) => {
+                let settled = previous / self.model.residence_cycles.max(dec!(1));
+                let spawned = (backlog - previous + settled).max(dec!(0));
+                self.spawn_rate += SMOOTHING * (spawned - self.spawn_rate);
+                self.backlog_level += SMOOTHING * (backlog - self.backlog_level);
+            }
+            None => {
+                self.spawn_rate = backlog / self.model.residence_cycles.max(dec!(1));
+                self.backlog_level = backlog;
+            }
+        }
+        self.last_backlog = Some(backlog);
+    }
+
+    /// Roll the model `horizon` cycles forward under adjustment `u`.
+    fn rollout(&self, peg_error: Decimal, backlog: Decimal, u: Decimal) -> MpcPlan {
+        let MpcModel { horizon, peg_response, demand_response, residence_cycles, congestion_weight } = self.model;
+        let spawns = (self.spawn_rate * (dec!(1) - demand_response * u)).max(dec!(0));
+        let reference = self.backlog_level.max(dec!(1));
+        let (mut e, mut b, mut cost) = (peg_error, backlog, dec!(0));
+        for _ in 0..horizon {
+            e -= peg_response * u;
+            b = (b + spawns - b / residence_cycles.max(dec!(1))).max(dec!(0));
+            let congestion = ((b - reference) / reference).max(dec!(0));
+            cost += e * e + congestion_weight * congestion * congestion;
+        }
+        MpcPlan { adjustment: u, predicted_peg_error: e, predicted_backlog: b, cost }
+    }
+}
+
+impl Default for GovernorMpc {
+    fn default() -> Self {
+        Self::new(MpcModel::default())
+    }
+}
+
+// ===========================================================================
+// Tests
+// ===========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_governor::pid::TierCounts;
+
+    fn metrics(backlog: u64) -> NetworkMetrics {
+        NetworkMetrics {
+            current_gold_price_usd: dec!(84),
+            target_gold_price_usd: dec!(84),
+            market_volatility: dec!(0.1),
+            transaction_volume: dec!(500000),
+            liquidity_depth: dec!(1000000),
+            network_velocity: dec!(1),
+            active_packets_by_tier: TierCounts { l0: backlog, ..Default::default() },
+            in_transit_float: dec!(0),
+            oracle_staleness_ticks: 0,
+        }
+    }
+
+    #[test]
+    fn plan_trades_peg_error_against_congestion() {
+        let (min, max) = (dec!(-0.02), dec!(0.02));
+        // On peg with a steady backlog there is nothing to correct
+        let mut mpc = GovernorMpc::default();
+        for _ in 0..5 {
+            mpc.plan(dec!(0), &metrics(100), min, max);
+        }
+        assert_eq!(mpc.plan(dec!(0), &metrics(100), min, max).adjustment, dec!(0));
+
+        // Over peg: raise fees; under peg: cut them, but less once the cut
+        // would swell a backlog
+        let over = mpc.clone().plan(dec!(0.05), &metrics(100), min, max);
+        assert!(over.adjustment > dec!(0));
+        assert!(over.predicted_peg_error < dec!(0.05));
+        let under = mpc.clone().plan(dec!(-0.05), &metrics(100), min, max).adjustment;
+        assert!(under < dec!(0));
+        let mut congested = GovernorMpc::new(MpcModel { congestion_weight: dec!(50), ..MpcModel::default() });
+        for _ in 0..6 {
+            congested.plan(dec!(0), &metrics(100), min, max);
+        }
+        let braked = congested.plan(dec!(-0.05), &metrics(100), min, max).adjustment;
+        assert!(under < braked && braked <= dec!(0), "{} vs {}", under, braked);
+
+        // Candidates stay inside the bounds
+        let bounded = mpc.plan(dec!(0.5), &metrics(100), dec!(-0.01), dec!(0.004));
+        assert_eq!(bounded.adjustment, dec!(0.004));
+        assert_eq!(GovernorMpc::new(MpcModel { horizon: 99, ..MpcModel::default() }).model().horizon, MAX_HORIZON);
+    }
+}
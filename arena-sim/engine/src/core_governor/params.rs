@@ -11,13 +11,14 @@ use crate::core_types::{DemurrageRate, GoldGrams, MarketTier};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use tsify::Tsify;
 
 // ---------------------------------------------------------------------------
 // GovernanceParams
 // ---------------------------------------------------------------------------
 
 /// Output of the Governor PID controller -- modulations applied to the network.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
 pub struct GovernanceParams {
     /// Current fee modifier per tier (multiplier, 1.0 = no change).
     pub fee_modifiers: TierModifiers,
@@ -26,8 +27,10 @@ pub struct GovernanceParams {
     /// Current network pressure classification.
     pub pressure: PressureQuadrant,
     /// Overall health score (0-100).
+    #[tsify(type = "string")]
     pub health_score: Decimal,
     /// Recommended fee adjustment as fraction (e.g., -0.008 = -0.8%).
+    #[tsify(type = "string")]
     pub recommended_fee_adjustment: Decimal,
     /// Constitutional fee caps per tier.
     pub fee_caps: FeeCaps,
@@ -52,15 +55,19 @@ impl Default for GovernanceParams {
 
 /// Constitutional fee caps per tier -- the Governor CANNOT exceed these under any conditions.
 /// Whitepaper section 6.5: These are baked into the protocol specification.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
 pub struct FeeCaps {
     /// L0 (retail) cap: 5%
+    #[tsify(type = "string")]
     pub l0: Decimal,
     /// L1 (professional) cap: 2%
+    #[tsify(type = "string")]
     pub l1: Decimal,
     /// L2 (institutional) cap: 0.5%
+    #[tsify(type = "string")]
     pub l2: Decimal,
     /// L3 (sovereign) cap: 0.1%
+    #[tsify(type = "string")]
     pub l3: Decimal,
 }
 
@@ -108,11 +115,15 @@ impl FeeCaps {
 // ---------------------------------------------------------------------------
 
 /// Per-tier fee modifiers (multipliers applied to the base fee).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
 pub struct TierModifiers {
+    #[tsify(type = "string")]
     pub l0: Decimal,
+    #[tsify(type = "string")]
     pub l1: Decimal,
+    #[tsify(type = "string")]
     pub l2: Decimal,
+    #[tsify(type = "string")]
     pub l3: Decimal,
 }
 
@@ -144,7 +155,7 @@ impl Default for TierModifiers {
 // ---------------------------------------------------------------------------
 
 /// Per-tier demurrage overrides. `None` means "use tier default".
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Tsify)]
 pub struct TierDemurrageOverrides {
     pub l0: Option<DemurrageRate>,
     pub l1: Option<DemurrageRate>,
@@ -164,23 +175,12 @@ impl TierDemurrageOverrides {
     }
 }
 
-impl Default for TierDemurrageOverrides {
-    fn default() -> Self {
-        Self {
-            l0: None,
-            l1: None,
-            l2: None,
-            l3: None,
-        }
-    }
-}
-
 // ---------------------------------------------------------------------------
 // PressureQuadrant
 // ---------------------------------------------------------------------------
 
 /// Network pressure classification -- six quadrants describing macro conditions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Tsify)]
 pub enum PressureQuadrant {
     /// High velocity + high gold deviation upward: speculative bubble.
     Bubble,
@@ -197,6 +197,32 @@ pub enum PressureQuadrant {
 }
 
 impl PressureQuadrant {
+    /// Quadrant by label letter A..F (0 = Bubble .. 5 = Vacuum), the order
+    /// of `WorldState::quadrant_ticks`.
+    pub fn from_u32(v: u32) -> Option<Self> {
+        match v {
+            0 => Some(Self::Bubble),
+            1 => Some(Self::Crash),
+            2 => Some(Self::Stagnation),
+            3 => Some(Self::GoldenEra),
+            4 => Some(Self::Bottleneck),
+            5 => Some(Self::Vacuum),
+            _ => None,
+        }
+    }
+
+    /// Position in label-letter order, the inverse of `from_u32`.
+    pub fn index(self) -> usize {
+        match self {
+            Self::Bubble => 0,
+            Self::Crash => 1,
+            Self::Stagnation => 2,
+            Self::GoldenEra => 3,
+            Self::Bottleneck => 4,
+            Self::Vacuum => 5,
+        }
+    }
+
     /// Human-readable description of this quadrant.
     pub fn description(&self) -> &'static str {
         match self {
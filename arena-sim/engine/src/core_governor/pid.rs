@@ -11,32 +11,44 @@ use crate::core_types::{GoldGrams, MarketTier};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use tsify::Tsify;
 
+use super::mpc::{GovernorMpc, MpcModel};
 use super::params::*;
 
 /// Network metrics fed into the Governor each control cycle.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
 pub struct NetworkMetrics {
     /// Current gold price in USD per gram (from oracle).
+    #[tsify(type = "string")]
     pub current_gold_price_usd: Decimal,
     /// Target gold price (reference spot price from trusted oracle).
+    #[tsify(type = "string")]
     pub target_gold_price_usd: Decimal,
     /// Market volatility on a 0.0-1.0 scale.
+    #[tsify(type = "string")]
     pub market_volatility: Decimal,
     /// Transaction volume in gold grams per period.
+    #[tsify(type = "string")]
     pub transaction_volume: Decimal,
     /// Network liquidity depth in gold grams available.
+    #[tsify(type = "string")]
     pub liquidity_depth: Decimal,
     /// Network velocity (transactions per second).
+    #[tsify(type = "string")]
     pub network_velocity: Decimal,
     /// Per-tier active packet counts.
     pub active_packets_by_tier: TierCounts,
     /// In-transit float: total value currently in-flight (liquidity shadow).
+    #[tsify(type = "string")]
     pub in_transit_float: Decimal,
+    /// Periods since the oracle last published a price (0 = fresh).
+    #[serde(default)]
+    pub oracle_staleness_ticks: u64,
 }
 
 /// Per-tier active packet counts.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Tsify)]
 pub struct TierCounts {
     pub l0: u64,
     pub l1: u64,
@@ -64,18 +76,139 @@ const LOW_VOLUME: Decimal = dec!(100000);
 const HIGH_VOLUME: Decimal = dec!(1000000);
 const LOW_LIQUIDITY: Decimal = dec!(100000);
 const HIGH_LIQUIDITY: Decimal = dec!(1000000);
+/// Weighted float pressure at or above which the network is a Bottleneck.
+const FLOAT_BOTTLENECK_PRESSURE: Decimal = dec!(1);
+/// Periods without a fresh oracle price after which the Governor stops
+/// steering on the price it last saw.
+pub const ORACLE_STALE_PERIODS: u64 = 10;
+/// Fee adjustment added per stale period, pricing in the risk that the held
+/// price is wrong.
+const STALE_FEE_PREMIUM: Decimal = dec!(0.0005);
+/// Fee adjustment ceiling while the oracle is stale, widened from
+/// `MAX_FEE_ADJ`. Constitutional fee caps still bound every fee.
+const STALE_MAX_FEE_ADJ: Decimal = dec!(0.04);
 const EGRESS_SHARE: Decimal = dec!(0.8);
 const TRANSIT_SHARE: Decimal = dec!(0.2);
 
+// -- Gain scheduling -------------------------------------------------------
+
+/// One set of PID gains.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PidGains {
+    pub kp: Decimal,
+    pub ki: Decimal,
+    pub kd: Decimal,
+}
+
+impl PidGains {
+    pub fn new(kp: Decimal, ki: Decimal, kd: Decimal) -> Self {
+        Self { kp, ki, kd }
+    }
+
+    /// Every gain multiplied by `factor`.
+    pub fn scaled(self, factor: Decimal) -> Self {
+        Self { kp: self.kp * factor, ki: self.ki * factor, kd: self.kd * factor }
+    }
+}
+
+/// Gains per pressure quadrant, so the controller can push hard in a Crash
+/// or Bubble and ease off in a Golden Era. A quadrant without an entry runs
+/// on the controller's base gains; the default schedule is empty (fixed
+/// gains).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GainSchedule {
+    pub bubble: Option<PidGains>,
+    pub crash: Option<PidGains>,
+    pub stagnation: Option<PidGains>,
+    pub golden_era: Option<PidGains>,
+    pub bottleneck: Option<PidGains>,
+    pub vacuum: Option<PidGains>,
+}
+
+impl GainSchedule {
+    /// Gains scheduled for `quadrant`, if any.
+    pub fn for_quadrant(&self, quadrant: PressureQuadrant) -> Option<PidGains> {
+        match quadrant {
+            PressureQuadrant::Bubble => self.bubble,
+            PressureQuadrant::Crash => self.crash,
+            PressureQuadrant::Stagnation => self.stagnation,
+            PressureQuadrant::GoldenEra => self.golden_era,
+            PressureQuadrant::Bottleneck => self.bottleneck,
+            PressureQuadrant::Vacuum => self.vacuum,
+        }
+    }
+
+    /// Schedule `gains` for `quadrant` (None returns it to the base gains).
+    pub fn set(&mut self, quadrant: PressureQuadrant, gains: Option<PidGains>) {
+        let slot = match quadrant {
+            PressureQuadrant::Bubble => &mut self.bubble,
+            PressureQuadrant::Crash => &mut self.crash,
+            PressureQuadrant::Stagnation => &mut self.stagnation,
+            PressureQuadrant::GoldenEra => &mut self.golden_era,
+            PressureQuadrant::Bottleneck => &mut self.bottleneck,
+            PressureQuadrant::Vacuum => &mut self.vacuum,
+        };
+        *slot = gains;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 // -- GovernorPid -----------------------------------------------------------
 
 /// PID controller producing [`GovernanceParams`] from [`NetworkMetrics`].
+///
+/// ```
+/// use arena_engine::core_governor::pid::{GovernorPid, NetworkMetrics, TierCounts};
+/// use arena_engine::core_governor::PressureQuadrant;
+/// use rust_decimal_macros::dec;
+///
+/// let on_peg = NetworkMetrics {
+///     current_gold_price_usd: dec!(100),
+///     target_gold_price_usd: dec!(100),
+///     market_volatility: dec!(0.1),
+///     transaction_volume: dec!(500000),
+///     liquidity_depth: dec!(500000),
+///     network_velocity: dec!(1),
+///     active_packets_by_tier: TierCounts::default(),
+///     in_transit_float: dec!(0),
+///     oracle_staleness_ticks: 0,
+/// };
+/// let mut pid = GovernorPid::new();
+/// let steady = pid.recalculate(&on_peg);
+/// assert_eq!(steady.pressure, PressureQuadrant::GoldenEra);
+///
+/// // Gold 30% under target: a crash, and fees come down
+/// let crash = pid.recalculate(&NetworkMetrics { current_gold_price_usd: dec!(70), ..on_peg });
+/// assert_eq!(crash.pressure, PressureQuadrant::Crash);
+/// assert!(crash.recommended_fee_adjustment < steady.recommended_fee_adjustment);
+/// ```
+#[derive(Debug, Clone)]
 pub struct GovernorPid {
     last_params: GovernanceParams,
     integral_error: Decimal,
+    /// Gold deviation at the last fresh-oracle cycle.
+    last_error: Decimal,
     kp: Decimal,
     ki: Decimal,
     kd: Decimal,
+    /// Weight of in-transit float build-up in Bottleneck classification
+    /// (0 = float is ignored).
+    float_weight: Decimal,
+    /// Fee adjustment held since the oracle went stale (None while fresh).
+    stale_hold: Option<Decimal>,
+    /// Per-quadrant gains overriding Kp/Ki/Kd (empty = fixed gains).
+    schedule: GainSchedule,
+    /// Consecutive cycles a new classification must hold before the
+    /// pressure quadrant switches (0 and 1 switch at once).
+    quadrant_debounce: u32,
+    /// Classification waiting to take over, and the cycles it has held.
+    pending_pressure: Option<(PressureQuadrant, u32)>,
+    /// Model-predictive planner choosing the fee adjustment in place of the
+    /// PID term (None = PID).
+    mpc: Option<GovernorMpc>,
 }
 
 impl GovernorPid {
@@ -84,9 +217,16 @@ impl GovernorPid {
         Self {
             last_params: GovernanceParams::default(),
             integral_error: dec!(0),
+            last_error: dec!(0),
             kp: dec!(0.5),
             ki: dec!(0.1),
             kd: dec!(0.05),
+            float_weight: dec!(0),
+            stale_hold: None,
+            schedule: GainSchedule::default(),
+            quadrant_debounce: 0,
+            pending_pressure: None,
+            mpc: None,
         }
     }
 
@@ -95,6 +235,76 @@ impl GovernorPid {
         Self { kp, ki, kd, ..Self::new() }
     }
 
+    /// Retune the gains in place; the integral and last parameters carry over.
+    pub fn set_gains(&mut self, kp: Decimal, ki: Decimal, kd: Decimal) {
+        self.kp = kp;
+        self.ki = ki;
+        self.kd = kd;
+    }
+
+    /// Current base (Kp, Ki, Kd), used in quadrants the schedule leaves out.
+    pub fn gains(&self) -> (Decimal, Decimal, Decimal) {
+        (self.kp, self.ki, self.kd)
+    }
+
+    /// Schedule gains by pressure quadrant. Each cycle runs on the gains of
+    /// the quadrant its metrics classify as; the integral carries across
+    /// switches.
+    pub fn set_gain_schedule(&mut self, schedule: GainSchedule) {
+        self.schedule = schedule;
+    }
+
+    pub fn gain_schedule(&self) -> &GainSchedule {
+        &self.schedule
+    }
+
+    /// Gains a cycle in `quadrant` runs on.
+    pub fn gains_for(&self, quadrant: PressureQuadrant) -> PidGains {
+        self.schedule.for_quadrant(quadrant).unwrap_or(PidGains::new(self.kp, self.ki, self.kd))
+    }
+
+    /// Gold deviation accumulated over every fresh-oracle cycle.
+    pub fn integral_error(&self) -> Decimal {
+        self.integral_error
+    }
+
+    /// Gold deviation at the last fresh-oracle cycle.
+    pub fn last_error(&self) -> Decimal {
+        self.last_error
+    }
+
+    /// Weigh in-transit float build-up when classifying pressure: the
+    /// network is a Bottleneck once `weight * float / volume` reaches 1, so
+    /// a weight of 0.1 trips on a float of ten periods' volume.
+    pub fn set_float_weight(&mut self, weight: Decimal) {
+        self.float_weight = weight.max(dec!(0));
+    }
+
+    /// Hold the pressure quadrant until a different classification has
+    /// held for `cycles` consecutive cycles, so a network hovering on a
+    /// boundary does not whipsaw fees and demurrage. 0 or 1 switches on
+    /// the first differing cycle.
+    pub fn set_quadrant_debounce(&mut self, cycles: u32) {
+        self.quadrant_debounce = cycles;
+        self.pending_pressure = None;
+    }
+
+    pub fn quadrant_debounce(&self) -> u32 {
+        self.quadrant_debounce
+    }
+
+    /// Hand the fee adjustment to a model-predictive planner (None returns
+    /// it to the PID). Classification, hysteresis, the stale-oracle hold and
+    /// tier modifiers are shared; the integral keeps accumulating so the PID
+    /// can take back over without a jump in its state.
+    pub fn set_mpc(&mut self, model: Option<MpcModel>) {
+        self.mpc = model.map(GovernorMpc::new);
+    }
+
+    pub fn mpc(&self) -> Option<&GovernorMpc> {
+        self.mpc.as_ref()
+    }
+
     /// Return the last computed governance parameters.
     ///
     /// Returns the default if `recalculate` has not been called yet.
@@ -103,20 +313,34 @@ impl GovernorPid {
     }
 
     /// Run one PID control cycle, producing updated [`GovernanceParams`].
+    ///
+    /// While the oracle is stale the last parameters are held instead (see
+    /// [`Self::hold_on_stale_oracle`]).
     pub fn recalculate(&mut self, metrics: &NetworkMetrics) -> GovernanceParams {
+        if self.is_oracle_stale(metrics) {
+            return self.hold_on_stale_oracle(metrics);
+        }
+        self.stale_hold = None;
+
         let error = self.gold_deviation(metrics);
         let health = self.calculate_economic_health_score(metrics);
         let base_adj = self.score_to_fee_adjustment(health);
+        let pressure = self.debounce_pressure(self.classify_pressure(metrics));
+        let PidGains { kp, ki, kd } = self.gains_for(pressure);
 
         self.integral_error += error;
+        self.last_error = error;
         let derivative = error - self.last_params.recommended_fee_adjustment;
-        let pid = self.kp * error + self.ki * self.integral_error + self.kd * derivative;
-        let clamped = (base_adj + pid).clamp(MIN_FEE_ADJ, MAX_FEE_ADJ);
+        let pid = kp * error + ki * self.integral_error + kd * derivative;
+        let clamped = match self.mpc.as_mut() {
+            Some(mpc) => mpc.plan(error, metrics, MIN_FEE_ADJ, MAX_FEE_ADJ).adjustment,
+            None => (base_adj + pid).clamp(MIN_FEE_ADJ, MAX_FEE_ADJ),
+        };
 
         let params = GovernanceParams {
             fee_modifiers: self.compute_tier_modifiers(clamped),
             demurrage_overrides: TierDemurrageOverrides::default(),
-            pressure: self.classify_pressure(metrics),
+            pressure,
             health_score: health,
             recommended_fee_adjustment: clamped,
             fee_caps: FeeCaps::default(),
@@ -125,6 +349,30 @@ impl GovernorPid {
         params
     }
 
+    /// Whether the oracle has gone `ORACLE_STALE_PERIODS` or more without
+    /// publishing a price.
+    pub fn is_oracle_stale(&self, m: &NetworkMetrics) -> bool {
+        m.oracle_staleness_ticks >= ORACLE_STALE_PERIODS
+    }
+
+    /// Hold the last parameters rather than steer on a price that may be
+    /// wrong: pressure, health and the integral stay frozen, while the fee
+    /// adjustment climbs by `STALE_FEE_PREMIUM` per stale period up to the
+    /// widened `STALE_MAX_FEE_ADJ`.
+    fn hold_on_stale_oracle(&mut self, m: &NetworkMetrics) -> GovernanceParams {
+        let held = *self.stale_hold.get_or_insert(self.last_params.recommended_fee_adjustment);
+        let stale_periods = m.oracle_staleness_ticks - ORACLE_STALE_PERIODS + 1;
+        let adj = (held + STALE_FEE_PREMIUM * Decimal::from(stale_periods))
+            .clamp(MIN_FEE_ADJ, STALE_MAX_FEE_ADJ);
+        let params = GovernanceParams {
+            fee_modifiers: self.compute_tier_modifiers(adj),
+            recommended_fee_adjustment: adj,
+            ..self.last_params.clone()
+        };
+        self.last_params = params.clone();
+        params
+    }
+
     /// Classify the current network pressure quadrant.
     pub fn classify_pressure(&self, m: &NetworkMetrics) -> PressureQuadrant {
         let dev = self.gold_deviation(m);
@@ -136,6 +384,9 @@ impl GovernorPid {
             };
         }
         if dev < -GOLD_DEV_EMERGENCY { return PressureQuadrant::Crash; }
+        if self.float_pressure(m) >= FLOAT_BOTTLENECK_PRESSURE {
+            return PressureQuadrant::Bottleneck;
+        }
         if m.network_velocity < LOW_VELOCITY && m.transaction_volume < LOW_VOLUME {
             return PressureQuadrant::Stagnation;
         }
@@ -145,6 +396,27 @@ impl GovernorPid {
         PressureQuadrant::GoldenEra
     }
 
+    /// The quadrant to govern in, given this cycle's classification: the
+    /// current one until `raw` has held for `quadrant_debounce` cycles.
+    fn debounce_pressure(&mut self, raw: PressureQuadrant) -> PressureQuadrant {
+        let current = self.last_params.pressure;
+        if raw == current {
+            self.pending_pressure = None;
+            return current;
+        }
+        let held = match self.pending_pressure {
+            Some((pending, cycles)) if pending == raw => cycles + 1,
+            _ => 1,
+        };
+        if held >= self.quadrant_debounce {
+            self.pending_pressure = None;
+            raw
+        } else {
+            self.pending_pressure = Some((raw, held));
+            current
+        }
+    }
+
     /// Economic health score (0-10). Weights: 40% gold, 30% vol, 20% txn, 10% liq.
     pub fn calculate_economic_health_score(&self, m: &NetworkMetrics) -> Decimal {
         let gold = (dec!(1) - self.gold_deviation(m).abs()).max(dec!(0)) * dec!(10);
@@ -189,6 +461,18 @@ impl GovernorPid {
         }
     }
 
+    /// Weighted in-transit float per period of transaction volume. A float
+    /// with no volume to clear it counts as saturated.
+    pub fn float_pressure(&self, m: &NetworkMetrics) -> Decimal {
+        if self.float_weight.is_zero() || m.in_transit_float <= dec!(0) {
+            return dec!(0);
+        }
+        if m.transaction_volume <= dec!(0) {
+            return FLOAT_BOTTLENECK_PRESSURE;
+        }
+        self.float_weight * m.in_transit_float / m.transaction_volume
+    }
+
     fn gold_deviation(&self, m: &NetworkMetrics) -> Decimal {
         if m.target_gold_price_usd.is_zero() { return dec!(0); }
         (m.current_gold_price_usd - m.target_gold_price_usd) / m.target_gold_price_usd
@@ -227,6 +511,7 @@ mod tests {
             network_velocity: velocity,
             active_packets_by_tier: TierCounts::default(),
             in_transit_float: dec!(0),
+            oracle_staleness_ticks: 0,
         }
     }
 
@@ -256,6 +541,75 @@ mod tests {
         assert_eq!(g.classify_pressure(&vacuum()), PressureQuadrant::Vacuum);
     }
 
+    #[test]
+    fn retuned_gains_keep_the_integral() {
+        let mut g = GovernorPid::new();
+        g.recalculate(&bubble());
+        let integral = g.integral_error();
+        assert_eq!(g.last_error(), (dec!(110) - dec!(84)) / dec!(84));
+        assert_eq!(integral, g.last_error());
+
+        g.set_gains(dec!(0), dec!(0), dec!(0));
+        assert_eq!(g.gains(), (dec!(0), dec!(0), dec!(0)));
+        assert_eq!(g.integral_error(), integral);
+        // With no PID term the adjustment is the health bracket alone
+        let params = g.recalculate(&bubble());
+        let health = g.calculate_economic_health_score(&bubble());
+        assert_eq!(params.recommended_fee_adjustment, g.score_to_fee_adjustment(health));
+    }
+
+    #[test]
+    fn gain_schedule_switches_by_quadrant() {
+        let mild = metrics(dec!(85), dec!(84), dec!(0.10), dec!(500000), dec!(1500000), dec!(1.0));
+        let base = PidGains::new(dec!(0.5), dec!(0.1), dec!(0.05));
+        let mut schedule = GainSchedule::default();
+        schedule.set(PressureQuadrant::GoldenEra, Some(base.scaled(dec!(1.5))));
+        schedule.set(PressureQuadrant::Crash, Some(PidGains::new(dec!(0), dec!(0), dec!(0))));
+        assert_eq!(schedule.for_quadrant(PressureQuadrant::Bubble), None);
+
+        let mut fixed = GovernorPid::new();
+        let mut scheduled = GovernorPid::new();
+        scheduled.set_gain_schedule(schedule.clone());
+        assert_eq!(scheduled.gains_for(PressureQuadrant::Bubble), base, "unscheduled quadrants run on base gains");
+
+        // Golden Era: the PID term scales with the scheduled gains
+        let bracket = fixed.score_to_fee_adjustment(fixed.calculate_economic_health_score(&mild));
+        let (f, s) = (fixed.recalculate(&mild), scheduled.recalculate(&mild));
+        assert_eq!(s.pressure, PressureQuadrant::GoldenEra);
+        let pid = |p: &GovernanceParams| p.recommended_fee_adjustment - bracket;
+        assert!((pid(&s) - pid(&f) * dec!(1.5)).abs() < dec!(1e-20), "{} vs {}", pid(&s), pid(&f));
+
+        // Crash: no PID term at all, where the fixed gains hit the clamp
+        let (f, s) = (fixed.recalculate(&crash()), scheduled.recalculate(&crash()));
+        assert_eq!(s.pressure, PressureQuadrant::Crash);
+        assert_eq!(f.recommended_fee_adjustment, MIN_FEE_ADJ);
+        assert_eq!(s.recommended_fee_adjustment,
+            scheduled.score_to_fee_adjustment(scheduled.calculate_economic_health_score(&crash())));
+        assert_eq!(scheduled.integral_error(), fixed.integral_error(), "the integral carries across switches");
+
+        let json = serde_json::to_string(&schedule).unwrap();
+        assert_eq!(serde_json::from_str::<GainSchedule>(&json).unwrap(), schedule);
+        assert!(GainSchedule::default().is_empty() && !schedule.is_empty());
+    }
+
+    #[test]
+    fn quadrant_debounce_needs_consecutive_cycles() {
+        let mut g = GovernorPid::new();
+        g.set_quadrant_debounce(3);
+        let pressures: Vec<PressureQuadrant> = [
+            crash(), crash(), golden_era(), crash(), crash(), crash(), bubble(), crash(),
+        ]
+        .iter()
+        .map(|m| g.recalculate(m).pressure)
+        .collect();
+        use PressureQuadrant::*;
+        // A break in the run restarts the count; one stray cycle changes nothing
+        assert_eq!(pressures, [GoldenEra, GoldenEra, GoldenEra, GoldenEra, GoldenEra, Crash, Crash, Crash]);
+
+        g.set_quadrant_debounce(0);
+        assert_eq!(g.recalculate(&bubble()).pressure, Bubble);
+    }
+
     #[test]
     fn health_scoring() {
         let g = GovernorPid::new();
@@ -452,7 +806,52 @@ mod tests {
             network_velocity: dec!(1.0),
             active_packets_by_tier: TierCounts::default(),
             in_transit_float: dec!(250000),
+            oracle_staleness_ticks: 0,
         };
         assert_eq!(m.in_transit_float, dec!(250000));
     }
+
+    #[test]
+    fn float_build_up_classifies_bottleneck() {
+        let mut g = GovernorPid::new();
+        let mut m = golden_era();
+        m.in_transit_float = dec!(5000000); // ten periods of volume
+        assert_eq!(g.classify_pressure(&m), PressureQuadrant::GoldenEra, "unweighted float");
+
+        g.set_float_weight(dec!(0.05));
+        assert_eq!(g.float_pressure(&m), dec!(0.5));
+        assert_eq!(g.classify_pressure(&m), PressureQuadrant::GoldenEra);
+        g.set_float_weight(dec!(0.1));
+        assert_eq!(g.classify_pressure(&m), PressureQuadrant::Bottleneck);
+
+        m.in_transit_float = dec!(0);
+        assert_eq!(g.classify_pressure(&m), PressureQuadrant::GoldenEra);
+        assert_eq!(g.classify_pressure(&crash()), PressureQuadrant::Crash, "gold shocks first");
+    }
+
+    #[test]
+    fn stale_oracle_holds_params_with_widening_premium() {
+        let mut g = GovernorPid::new();
+        let fresh = g.recalculate(&golden_era());
+
+        // A crash seen through a stale feed moves nothing but the premium
+        let mut stale = crash();
+        stale.oracle_staleness_ticks = ORACLE_STALE_PERIODS - 1;
+        assert!(!g.is_oracle_stale(&stale));
+        stale.oracle_staleness_ticks = ORACLE_STALE_PERIODS;
+        let held = g.recalculate(&stale);
+        assert_eq!(held.pressure, fresh.pressure);
+        assert_eq!(held.health_score, fresh.health_score);
+        assert_eq!(held.recommended_fee_adjustment,
+            fresh.recommended_fee_adjustment + STALE_FEE_PREMIUM);
+
+        stale.oracle_staleness_ticks = 200;
+        let widened = g.recalculate(&stale);
+        assert_eq!(widened.recommended_fee_adjustment, STALE_MAX_FEE_ADJ, "past MAX_FEE_ADJ");
+        assert_eq!(widened.fee_caps.l0, FeeCaps::default().l0, "constitutional caps untouched");
+
+        let recovered = g.recalculate(&crash());
+        assert_eq!(recovered.pressure, PressureQuadrant::Crash);
+        assert!(recovered.recommended_fee_adjustment <= MAX_FEE_ADJ);
+    }
 }
@@ -7,6 +7,7 @@
 use rust_decimal::Decimal;
 use rust_decimal::prelude::FromPrimitive;
 use serde::{Deserialize, Serialize};
+use tsify::Tsify;
 use std::fmt;
 use std::ops::{Add, Sub};
 
@@ -209,7 +210,7 @@ impl PacketState {
 /// Per-tier demurrage (decay) parameters
 ///
 /// Value decays exponentially: `V_t = V_0 * e^(-lambda * t)`
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Tsify)]
 pub struct DemurrageRate {
     /// Decay rate per second (lambda)
     pub lambda: f64,
@@ -0,0 +1,316 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Spawn-Side Corridors
+//
+// A corridor names a flow of payments from one region of sites to another,
+// with its own demand curve and tier mix. Once any corridor is defined, auto
+// traffic is generated per corridor instead of network-wide: each packet
+// enters at an Ingress in the origin region and may only settle at an Egress
+// in the destination region, so pricing and liquidity can be read per
+// corridor rather than only in the global aggregates.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::cohort::CohortOutcome;
+use crate::types::{NodeRole, SimNode, SimPacket};
+
+/// Spawn rate of a corridor with no demand curve set: the network-wide
+/// generator's rate at `demand_factor` 1.
+pub const DEFAULT_BASE_RATE: f64 = 5.0;
+
+// ---------------------------------------------------------------------------
+// Region, DemandCurve, Corridor
+// ---------------------------------------------------------------------------
+
+/// Sites `[first_site, end_site)` (see `SimNode::site`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Tsify)]
+pub struct Region {
+    pub first_site: u32,
+    pub end_site: u32,
+}
+
+impl Region {
+    pub fn new(first_site: u32, end_site: u32) -> Self {
+        Self { first_site, end_site }
+    }
+
+    pub fn contains(&self, site: u32) -> bool {
+        (self.first_site..self.end_site).contains(&site)
+    }
+}
+
+/// Packets per tick: `base_rate * (1 + amplitude * sin(2π tick / period_ticks))`,
+/// floored at zero. A `period_ticks` of 0 is a flat `base_rate`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct DemandCurve {
+    pub base_rate: f64,
+    pub amplitude: f64,
+    pub period_ticks: u64,
+}
+
+impl Default for DemandCurve {
+    fn default() -> Self {
+        Self { base_rate: DEFAULT_BASE_RATE, amplitude: 0.0, period_ticks: 0 }
+    }
+}
+
+impl DemandCurve {
+    pub fn rate(&self, tick: u64) -> f64 {
+        if self.period_ticks == 0 {
+            return self.base_rate.max(0.0);
+        }
+        let phase = (tick % self.period_ticks) as f64 / self.period_ticks as f64;
+        (self.base_rate * (1.0 + self.amplitude * (std::f64::consts::TAU * phase).sin())).max(0.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct Corridor {
+    pub name: String,
+    pub origin: Region,
+    pub destination: Region,
+    pub demand: DemandCurve,
+    /// Relative weight of each tier (L0..L3) among spawned packets
+    pub tier_mix: [f64; 4],
+}
+
+impl Corridor {
+    /// A corridor at the default demand with an even tier mix.
+    pub fn new(name: impl Into<String>, origin: Region, destination: Region) -> Self {
+        Self {
+            name: name.into(),
+            origin,
+            destination,
+            demand: DemandCurve::default(),
+            tier_mix: [1.0; 4],
+        }
+    }
+
+    /// Tier (0..4) that a uniform draw `u` in [0, 1) falls on under
+    /// `tier_mix`; L0 when every weight is zero.
+    pub fn pick_tier(&self, u: f64) -> u64 {
+        let weights = self.tier_mix.map(|w| w.max(0.0));
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return 0;
+        }
+        let mut cumulative = 0.0;
+        for (tier, w) in weights.iter().enumerate() {
+            cumulative += w / total;
+            if u < cumulative && *w > 0.0 {
+                return tier as u64;
+            }
+        }
+        weights.iter().rposition(|&w| w > 0.0).unwrap_or(0) as u64
+    }
+}
+
+/// Carried by corridor traffic: the corridor it belongs to and the region
+/// it has to settle in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+pub struct CorridorTag {
+    pub corridor: u32,
+    pub destination: Region,
+}
+
+// ---------------------------------------------------------------------------
+// CorridorStats
+// ---------------------------------------------------------------------------
+
+/// Outcomes, pricing and destination liquidity of one corridor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct CorridorStats {
+    pub name: String,
+    pub spawned: u32,
+    pub settled: u32,
+    pub reverted: u32,
+    pub dissolved: u32,
+    /// Packets still in the network, and how many of them are orbiting
+    pub in_flight: u32,
+    pub orbiting: u32,
+    pub value_spawned: f64,
+    /// Value delivered to beneficiaries at settlement
+    pub value_settled: f64,
+    /// Fees paid by settled packets, net of rebates
+    pub fees_paid: f64,
+    /// Mean share of original value lost between minting and delivery
+    pub mean_cost: f64,
+    pub avg_time_to_settle: f64,
+    /// Inventory held by the destination region's Egress nodes, and how
+    /// many of them are liquid enough to route toward
+    pub destination_inventory: f64,
+    pub liquid_egress: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+struct CorridorTotals {
+    spawned: u32,
+    settled: u32,
+    reverted: u32,
+    dissolved: u32,
+    value_spawned: f64,
+    original_settled: f64,
+    value_settled: f64,
+    fees_paid: f64,
+    settle_ticks: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Corridors - definitions and running totals
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct Corridors {
+    defs: Vec<Corridor>,
+    totals: Vec<CorridorTotals>,
+}
+
+impl Corridors {
+    /// Add a corridor; returns its index.
+    pub fn add(&mut self, corridor: Corridor) -> u32 {
+        self.defs.push(corridor);
+        self.totals.push(CorridorTotals::default());
+        (self.defs.len() - 1) as u32
+    }
+
+    pub fn clear(&mut self) {
+        self.defs.clear();
+        self.totals.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.defs.is_empty()
+    }
+
+    pub fn get(&self, index: u32) -> Option<&Corridor> {
+        self.defs.get(index as usize)
+    }
+
+    pub fn get_mut(&mut self, index: u32) -> Option<&mut Corridor> {
+        self.defs.get_mut(index as usize)
+    }
+
+    pub fn len(&self) -> usize {
+        self.defs.len()
+    }
+
+    pub fn tag(&self, index: u32) -> CorridorTag {
+        CorridorTag { corridor: index, destination: self.defs[index as usize].destination }
+    }
+
+    pub fn record_spawn(&mut self, index: u32, amount: f64) {
+        if let Some(t) = self.totals.get_mut(index as usize) {
+            t.spawned += 1;
+            t.value_spawned += amount;
+        }
+    }
+
+    /// Record `p` settling with `delivered` reaching the beneficiary.
+    pub fn record_settlement(&mut self, p: &SimPacket, delivered: f64, ticks: u64) {
+        if let Some(t) = self.totals_of(p) {
+            t.settled += 1;
+            t.original_settled += p.original_value;
+            t.value_settled += delivered;
+            t.fees_paid += p.fees_consumed;
+            t.settle_ticks += ticks;
+        }
+    }
+
+    /// Record `p` leaving the network without settling.
+    pub fn record_exit(&mut self, p: &SimPacket, outcome: CohortOutcome) {
+        if let Some(t) = self.totals_of(p) {
+            match outcome {
+                CohortOutcome::Reverted => t.reverted += 1,
+                CohortOutcome::Dissolved => t.dissolved += 1,
+                CohortOutcome::Settled => {}
+            }
+        }
+    }
+
+    fn totals_of(&mut self, p: &SimPacket) -> Option<&mut CorridorTotals> {
+        self.totals.get_mut(p.corridor?.corridor as usize)
+    }
+
+    /// Per-corridor stats, counting the `active` packets still in the network.
+    pub fn stats<'a>(
+        &self,
+        nodes: &[SimNode],
+        active: impl Iterator<Item = &'a SimPacket>,
+    ) -> Vec<CorridorStats> {
+        let mut in_flight = vec![(0u32, 0u32); self.defs.len()];
+        for p in active {
+            if let Some(slot) = p.corridor.and_then(|c| in_flight.get_mut(c.corridor as usize)) {
+                slot.0 += 1;
+                if p.status == crate::types::PacketStatus::Held {
+                    slot.1 += 1;
+                }
+            }
+        }
+        self.defs.iter().zip(&self.totals).zip(in_flight).map(|((c, t), (active, orbiting))| {
+            let destination_egress = nodes.iter()
+                .filter(|n| n.role == NodeRole::Egress && c.destination.contains(n.site));
+            let settled = t.settled.max(1) as f64;
+            CorridorStats {
+                name: c.name.clone(),
+                spawned: t.spawned,
+                settled: t.settled,
+                reverted: t.reverted,
+                dissolved: t.dissolved,
+                in_flight: active,
+                orbiting,
+                value_spawned: t.value_spawned,
+                value_settled: t.value_settled,
+                fees_paid: t.fees_paid,
+                mean_cost: if t.original_settled > 0.0 {
+                    1.0 - t.value_settled / t.original_settled
+                } else { 0.0 },
+                avg_time_to_settle: if t.settled > 0 { t.settle_ticks as f64 / settled } else { 0.0 },
+                destination_inventory: destination_egress.clone().map(|n| n.inventory_crypto).sum(),
+                liquid_egress: destination_egress
+                    .filter(|n| crate::routing::is_liquid_egress(n))
+                    .count() as u32,
+            }
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regions_cover_whole_sites() {
+        let r = Region::new(1, 3);
+        assert!(!r.contains(0));
+        assert!(r.contains(1) && r.contains(2));
+        assert!(!r.contains(3));
+    }
+
+    #[test]
+    fn test_demand_curve_oscillates_around_base() {
+        let flat = DemandCurve::default();
+        assert_eq!(flat.rate(0), DEFAULT_BASE_RATE);
+        assert_eq!(flat.rate(1_234), DEFAULT_BASE_RATE);
+
+        let wave = DemandCurve { base_rate: 4.0, amplitude: 0.5, period_ticks: 100 };
+        assert!((wave.rate(0) - 4.0).abs() < 1e-12);
+        assert!((wave.rate(25) - 6.0).abs() < 1e-12);
+        assert!((wave.rate(75) - 2.0).abs() < 1e-12);
+        assert!((wave.rate(125) - 6.0).abs() < 1e-12);
+
+        let deep = DemandCurve { base_rate: 4.0, amplitude: 2.0, period_ticks: 100 };
+        assert_eq!(deep.rate(75), 0.0, "Rates never go negative");
+    }
+
+    #[test]
+    fn test_tier_mix_partitions_the_unit_interval() {
+        let mut c = Corridor::new("a-b", Region::new(0, 1), Region::new(1, 2));
+        assert_eq!([0.1, 0.3, 0.6, 0.9].map(|u| c.pick_tier(u)), [0, 1, 2, 3]);
+
+        c.tier_mix = [0.0, 3.0, 0.0, 1.0];
+        assert_eq!([0.0, 0.74, 0.76, 0.999].map(|u| c.pick_tier(u)), [1, 1, 3, 3]);
+
+        c.tier_mix = [0.0; 4];
+        assert_eq!(c.pick_tier(0.5), 0);
+    }
+}
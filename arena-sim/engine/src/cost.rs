@@ -0,0 +1,151 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Effective Settlement Cost
+
+use crate::histogram::Histogram;
+use crate::types::MarketTier;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+/// All-in cost buckets in basis points of original value.
+const COST_BPS_EDGES: [f64; 13] = [
+    0.0, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1_000.0, 2_000.0, 5_000.0,
+];
+
+// ---------------------------------------------------------------------------
+// TierCostStats - what an end user actually paid, per tier
+// ---------------------------------------------------------------------------
+
+/// Effective all-in cost of settled packets in one tier. Cost is everything
+/// the sender lost between minting and settlement (fees + demurrage + surge
+/// burn) as a fraction of original value.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct TierCostStats {
+    pub tier: MarketTier,
+    pub settlements: u32,
+    pub mean_cost: f64,
+    pub min_cost: f64,
+    pub max_cost: f64,
+    pub p50_cost_bps: f64,
+    pub p95_cost_bps: f64,
+    /// Implied bid/ask-style spread around the gold peg, in basis points:
+    /// the executed rate sits `mean_cost` below the peg on the way out.
+    pub spread_bps: f64,
+    /// Peg-equivalent rate actually received: `gold_price * (1 - mean_cost)`
+    pub effective_rate: f64,
+    pub cost_bps: Histogram,
+}
+
+// ---------------------------------------------------------------------------
+// SettlementCostTracker - running per-tier accumulators
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+struct TierAccumulator {
+    settlements: u32,
+    cost_sum: f64,
+    min_cost: f64,
+    max_cost: f64,
+    cost_bps: Histogram,
+}
+
+impl TierAccumulator {
+    fn new() -> Self {
+        Self {
+            settlements: 0,
+            cost_sum: 0.0,
+            min_cost: f64::INFINITY,
+            max_cost: 0.0,
+            cost_bps: Histogram::new(&COST_BPS_EDGES),
+        }
+    }
+
+    fn mean_cost(&self) -> f64 {
+        if self.settlements == 0 { 0.0 } else { self.cost_sum / self.settlements as f64 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct SettlementCostTracker {
+    tiers: Vec<TierAccumulator>,
+}
+
+impl Default for SettlementCostTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SettlementCostTracker {
+    pub fn new() -> Self {
+        Self { tiers: (0..4).map(|_| TierAccumulator::new()).collect() }
+    }
+
+    /// Record a settlement: `original_value` minted, `settled_value` paid out.
+    pub fn record(&mut self, tier: MarketTier, original_value: f64, settled_value: f64) {
+        if original_value <= 0.0 {
+            return;
+        }
+        let cost = ((original_value - settled_value) / original_value).clamp(0.0, 1.0);
+        let acc = &mut self.tiers[tier as usize];
+        acc.settlements += 1;
+        acc.cost_sum += cost;
+        acc.min_cost = acc.min_cost.min(cost);
+        acc.max_cost = acc.max_cost.max(cost);
+        acc.cost_bps.record(cost * 10_000.0);
+    }
+
+    /// Mean all-in cost fraction per tier (0 where nothing settled).
+    pub fn mean_costs(&self) -> [f64; 4] {
+        std::array::from_fn(|t| self.tiers[t].mean_cost())
+    }
+
+    pub fn summary(&self, gold_price: f64) -> Vec<TierCostStats> {
+        let tiers = [MarketTier::L0, MarketTier::L1, MarketTier::L2, MarketTier::L3];
+        tiers.iter().zip(&self.tiers).map(|(&tier, acc)| {
+            let mean_cost = acc.mean_cost();
+            TierCostStats {
+                tier,
+                settlements: acc.settlements,
+                mean_cost,
+                min_cost: if acc.settlements > 0 { acc.min_cost } else { 0.0 },
+                max_cost: acc.max_cost,
+                p50_cost_bps: acc.cost_bps.quantile(0.5),
+                p95_cost_bps: acc.cost_bps.quantile(0.95),
+                spread_bps: mean_cost * 10_000.0,
+                effective_rate: gold_price * (1.0 - mean_cost),
+                cost_bps: acc.cost_bps.clone(),
+            }
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_cost_fraction() {
+        let mut t = SettlementCostTracker::new();
+        t.record(MarketTier::L1, 100.0, 99.0);
+        t.record(MarketTier::L1, 100.0, 97.0);
+        let costs = t.mean_costs();
+        assert!((costs[1] - 0.02).abs() < 1e-12);
+        assert_eq!(costs[0], 0.0);
+
+        let s = &t.summary(2600.0)[1];
+        assert_eq!(s.settlements, 2);
+        assert!((s.min_cost - 0.01).abs() < 1e-12);
+        assert!((s.max_cost - 0.03).abs() < 1e-12);
+        assert!((s.spread_bps - 200.0).abs() < 1e-9);
+        assert!((s.effective_rate - 2600.0 * 0.98).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_empty_tier_summary_is_zero() {
+        let t = SettlementCostTracker::new();
+        let s = &t.summary(2600.0)[3];
+        assert_eq!(s.settlements, 0);
+        assert_eq!(s.min_cost, 0.0);
+        assert_eq!(s.p95_cost_bps, 0.0);
+    }
+}
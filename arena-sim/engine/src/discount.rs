@@ -0,0 +1,104 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Per-Origin Volume Discounts
+
+use std::collections::BTreeMap;
+
+/// Effective window (ticks) of each origin's rolling settled volume.
+pub const VOLUME_WINDOW_TICKS: f64 = 100.0;
+
+// ---------------------------------------------------------------------------
+// VolumeDiscounts - rolling volume per origin and the discount it earns
+// ---------------------------------------------------------------------------
+
+/// Origins whose rolling settled volume is at or above `threshold` pay
+/// `rate` less base fee. Also tracks the revenue forgone and how much of the
+/// settled flow came from discounted origins.
+#[derive(Debug, Clone)]
+pub struct VolumeDiscounts {
+    threshold: f64,
+    rate: f64,
+    // Rolling settled value per origin node, ~VOLUME_WINDOW_TICKS wide
+    volume: BTreeMap<u32, f64>,
+    pub forgone: f64,
+    pub discounted_settlements: u32,
+    pub discounted_value: f64,
+    pub settled_value: f64,
+}
+
+impl VolumeDiscounts {
+    pub fn new(threshold: f64, rate: f64) -> Self {
+        Self {
+            threshold: threshold.max(0.0),
+            rate: rate.clamp(0.0, 1.0),
+            volume: BTreeMap::new(),
+            forgone: 0.0,
+            discounted_settlements: 0,
+            discounted_value: 0.0,
+            settled_value: 0.0,
+        }
+    }
+
+    /// Discount rate `origin` currently earns (0 below the threshold).
+    pub fn rate_for(&self, origin: u32) -> f64 {
+        match self.volume.get(&origin) {
+            Some(&v) if v >= self.threshold => self.rate,
+            _ => 0.0,
+        }
+    }
+
+    /// Record a settlement: `forgone` is the fee revenue the discount cost,
+    /// which is zero when the fee budget cap already bound.
+    pub fn record(&mut self, origin: u32, value: f64, forgone: f64) {
+        if self.rate_for(origin) > 0.0 {
+            self.discounted_settlements += 1;
+            self.discounted_value += value;
+        }
+        *self.volume.entry(origin).or_insert(0.0) += value;
+        self.settled_value += value;
+        self.forgone += forgone.max(0.0);
+    }
+
+    /// Age every origin's rolling volume by one tick.
+    pub fn end_tick(&mut self) {
+        let keep = 1.0 - 1.0 / VOLUME_WINDOW_TICKS;
+        for v in self.volume.values_mut() {
+            *v *= keep;
+        }
+    }
+
+    /// Share of settled value that came from discounted origins.
+    pub fn discounted_share(&self) -> f64 {
+        if self.settled_value > 0.0 { self.discounted_value / self.settled_value } else { 0.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discount_after_volume_threshold() {
+        let mut d = VolumeDiscounts::new(1_000.0, 0.2);
+        assert_eq!(d.rate_for(0), 0.0);
+        d.record(0, 600.0, 0.0);
+        d.record(0, 600.0, 0.0);
+        assert_eq!(d.rate_for(0), 0.2);
+        assert_eq!(d.rate_for(4), 0.0);
+
+        d.record(0, 100.0, 0.5);
+        d.record(4, 100.0, 0.0);
+        assert_eq!(d.discounted_settlements, 1);
+        assert_eq!(d.forgone, 0.5);
+        assert!((d.discounted_share() - 100.0 / 1_400.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_volume_decays_below_threshold() {
+        let mut d = VolumeDiscounts::new(1_000.0, 0.2);
+        d.record(0, 1_000.0, 0.0);
+        assert_eq!(d.rate_for(0), 0.2);
+        d.end_tick();
+        assert_eq!(d.rate_for(0), 0.0);
+        assert!(VolumeDiscounts::new(0.0, 5.0).rate_for(0) == 0.0);
+    }
+}
@@ -6,6 +6,10 @@
 // to qualified nodes, with shard holders receiving double weight.
 
 use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::resources::{self, ResourceModel};
+use crate::types::{NodeRole, SimNode};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -17,6 +21,34 @@ pub const DISSOLUTION_TIMEOUT_SECS: u64 = 7_776_000;
 /// Simulation-scale equivalent of the 90-day dissolution timeout.
 pub const DISSOLUTION_TIMEOUT_TICKS: u64 = 5000;
 
+// ---------------------------------------------------------------------------
+// Payout path
+// ---------------------------------------------------------------------------
+
+/// What a node receives for its share of a dissolution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+pub enum DissolutionPayout {
+    /// Credited straight to the node's fiat inventory, off the settlement rails
+    #[default]
+    FiatCredit = 0,
+    /// Credited to the node's crypto inventory, where an Egress can settle with it
+    CryptoInventory = 1,
+    /// Owed to the node by the treasury; no inventory moves
+    TreasuryClaim = 2,
+}
+
+impl DissolutionPayout {
+    /// Map a numeric discriminant (as sent from JS) back to a payout path.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::FiatCredit),
+            1 => Some(Self::CryptoInventory),
+            2 => Some(Self::TreasuryClaim),
+            _ => None,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Errors
 // ---------------------------------------------------------------------------
@@ -65,6 +97,23 @@ impl GravityQualification {
     }
 }
 
+/// The simulation's view of each live node's qualifications. Without a
+/// resource model, demonstrable capacity is bandwidth alone.
+pub fn node_qualifications(nodes: &[SimNode], resources: Option<&ResourceModel>) -> Vec<GravityQualification> {
+    nodes.iter()
+        .filter(|n| n.role != NodeRole::Disabled)
+        .map(|n| GravityQualification {
+            node_id: n.id,
+            upi_active: n.upi_active,
+            engauge_active: n.ngauge_running,
+            kyc_attested: n.kyc_valid,
+            caesar_active: n.role != NodeRole::Disabled,
+            demonstrable_capacity: resources.map_or(n.bandwidth >= resources::MIN_BANDWIDTH_MBPS, |r| r.demonstrable_capacity(n)),
+            active_routing_current_epoch: n.current_buffer_count > 0 || n.total_fees_earned > 0.0,
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Distribution results
 // ---------------------------------------------------------------------------
@@ -96,6 +145,27 @@ pub struct DissolutionResult {
 /// # Errors
 /// - `NoQualifiedNodes` if no node passes all six criteria.
 /// - `ZeroResidualValue` if `residual_value <= 0.0`.
+///
+/// ```
+/// use arena_engine::dissolution::{dissolve, DissolutionError, GravityQualification};
+///
+/// let node = |node_id, kyc_attested| GravityQualification {
+///     node_id,
+///     upi_active: true,
+///     engauge_active: true,
+///     kyc_attested,
+///     caesar_active: true,
+///     demonstrable_capacity: true,
+///     active_routing_current_epoch: true,
+/// };
+/// // Node 2's KYC has lapsed; node 0 holds a shard and takes double weight
+/// let nodes = [node(0, true), node(1, true), node(2, false)];
+/// let result = dissolve(300.0, &nodes, &[0]).unwrap();
+/// let shares: Vec<(u32, f64)> = result.distributions.iter().map(|d| (d.node_id, d.amount)).collect();
+/// assert_eq!(shares, vec![(0, 200.0), (1, 100.0)]);
+///
+/// assert_eq!(dissolve(300.0, &nodes[2..], &[]), Err(DissolutionError::NoQualifiedNodes));
+/// ```
 pub fn dissolve(
     residual_value: f64,
     qualified_nodes: &[GravityQualification],
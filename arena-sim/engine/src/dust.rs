@@ -0,0 +1,96 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Dust Mitigation
+
+/// Packets worth less than this (grams) are dust.
+pub const DUST_VALUE: f64 = 1.0;
+
+pub fn is_dust(value: f64) -> bool {
+    value < DUST_VALUE
+}
+
+// ---------------------------------------------------------------------------
+// DustGuard - spawn-time dust policy and its effect on other traffic
+// ---------------------------------------------------------------------------
+
+/// Rejects packets below `min_value` and charges dust a flat `surcharge`
+/// (grams) up front. Also tracks settlement latency of non-dust packets, the
+/// traffic a dust flood slows down. Both mitigations default to off.
+#[derive(Debug, Clone, Default)]
+pub struct DustGuard {
+    min_value: f64,
+    surcharge: f64,
+    pub rejected: u32,
+    pub surcharges: f64,
+    non_dust_settlements: u32,
+    non_dust_settle_ticks: u64,
+}
+
+impl DustGuard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_policy(&mut self, min_value: f64, surcharge: f64) {
+        self.min_value = min_value.max(0.0);
+        self.surcharge = surcharge.max(0.0);
+    }
+
+    /// Admit a packet of `amount`. Returns the surcharge to take from it, or
+    /// `None` when it is below the minimum value or would not survive the
+    /// surcharge.
+    pub fn admit(&mut self, amount: f64) -> Option<f64> {
+        let surcharge = if is_dust(amount) { self.surcharge } else { 0.0 };
+        if amount < self.min_value || (surcharge > 0.0 && amount <= surcharge) {
+            self.rejected += 1;
+            return None;
+        }
+        self.surcharges += surcharge;
+        Some(surcharge)
+    }
+
+    /// Record a settlement of a packet worth `original_value` that took `ticks`.
+    pub fn record_settlement(&mut self, original_value: f64, ticks: u64) {
+        if !is_dust(original_value) {
+            self.non_dust_settlements += 1;
+            self.non_dust_settle_ticks += ticks;
+        }
+    }
+
+    /// Mean ticks from spawn to settlement for non-dust packets.
+    pub fn non_dust_settle_ticks(&self) -> f64 {
+        if self.non_dust_settlements > 0 {
+            self.non_dust_settle_ticks as f64 / self.non_dust_settlements as f64
+        } else { 0.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_off_admits_everything() {
+        let mut guard = DustGuard::new();
+        assert_eq!(guard.admit(0.01), Some(0.0));
+        assert_eq!(guard.admit(500.0), Some(0.0));
+        assert_eq!((guard.rejected, guard.surcharges), (0, 0.0));
+    }
+
+    #[test]
+    fn test_minimum_value_and_surcharge() {
+        let mut guard = DustGuard::new();
+        guard.set_policy(0.4, 0.25);
+        assert_eq!(guard.admit(0.3), None);
+        assert_eq!(guard.admit(0.5), Some(0.25));
+        assert_eq!(guard.admit(5.0), Some(0.0));
+
+        guard.set_policy(0.0, 0.5);
+        assert_eq!(guard.admit(0.5), None, "Consumed whole by the surcharge");
+        assert_eq!((guard.rejected, guard.surcharges), (2, 0.25));
+
+        guard.record_settlement(0.5, 40);
+        guard.record_settlement(5.0, 10);
+        guard.record_settlement(50.0, 20);
+        assert_eq!(guard.non_dust_settle_ticks(), 15.0);
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Egress Bankruptcy & Exit
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::routing;
+use crate::types::{NodeRole, SimNode};
+
+/// Ticks an Egress may sit without routable liquidity before it exits.
+pub const DEFAULT_EXHAUSTED_TICKS: u64 = 200;
+
+// ---------------------------------------------------------------------------
+// EgressLifecycle - bankrupt egresses exit, recapitalized ones re-enter
+// ---------------------------------------------------------------------------
+
+/// An Egress whose inventory stays too low to be routed toward
+/// (`routing::is_liquid_egress`) for `exhausted_ticks` exits the egress
+/// business, becoming `exit_role` (Transit or Disabled). Once its inventory
+/// is recapitalized to `reentry_crypto` it re-enters as an Egress.
+#[derive(Debug, Clone)]
+pub struct EgressLifecycle {
+    pub exhausted_ticks: u64,
+    pub exit_role: NodeRole,
+    pub reentry_crypto: f64,
+    // Tick each currently dry Egress ran out of liquidity
+    dry_since: BTreeMap<u32, u64>,
+    // Exited Egress nodes eligible to re-enter
+    exited: BTreeSet<u32>,
+    pub exits: u32,
+    pub reentries: u32,
+}
+
+/// Role changes due this tick.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LifecycleChanges {
+    pub exits: Vec<u32>,
+    pub reentries: Vec<u32>,
+}
+
+impl EgressLifecycle {
+    /// `exit_role` other than Transit falls back to Disabled.
+    pub fn new(exhausted_ticks: u64, exit_role: NodeRole, reentry_crypto: f64) -> Self {
+        Self {
+            exhausted_ticks: exhausted_ticks.max(1),
+            exit_role: match exit_role {
+                NodeRole::Transit => NodeRole::Transit,
+                _ => NodeRole::Disabled,
+            },
+            reentry_crypto: reentry_crypto.max(0.0),
+            dry_since: BTreeMap::new(),
+            exited: BTreeSet::new(),
+            exits: 0,
+            reentries: 0,
+        }
+    }
+
+    /// Exited nodes still out of the egress business.
+    pub fn exited_count(&self) -> u32 {
+        self.exited.len() as u32
+    }
+
+    /// Advance the lifecycle to `tick`: the Egress nodes to exit and the
+    /// exited nodes to re-enter. The caller applies the role changes.
+    pub fn update(&mut self, nodes: &[SimNode], tick: u64) -> LifecycleChanges {
+        let mut changes = LifecycleChanges::default();
+
+        // Exited nodes re-roled by hand are no longer ours to bring back
+        let exit_role = self.exit_role;
+        self.exited.retain(|&id| nodes.get(id as usize).is_some_and(|n| n.role == exit_role));
+        for &id in &self.exited {
+            if nodes[id as usize].inventory_crypto >= self.reentry_crypto {
+                changes.reentries.push(id);
+            }
+        }
+        for id in &changes.reentries {
+            self.exited.remove(id);
+        }
+        self.reentries += changes.reentries.len() as u32;
+
+        self.dry_since.retain(|&id, _| nodes[id as usize].role == NodeRole::Egress);
+        for node in nodes.iter().filter(|n| n.role == NodeRole::Egress) {
+            if routing::is_liquid_egress(node) {
+                self.dry_since.remove(&node.id);
+                continue;
+            }
+            let since = *self.dry_since.entry(node.id).or_insert(tick);
+            if tick - since >= self.exhausted_ticks {
+                changes.exits.push(node.id);
+            }
+        }
+        for &id in &changes.exits {
+            self.dry_since.remove(&id);
+            self.exited.insert(id);
+        }
+        self.exits += changes.exits.len() as u32;
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn egress(id: u32, crypto: f64) -> SimNode {
+        SimNode { inventory_crypto: crypto, ..SimNode::test_default(id, NodeRole::Egress) }
+    }
+
+    #[test]
+    fn test_dry_egress_exits_and_reenters_when_recapitalized() {
+        let mut lifecycle = EgressLifecycle::new(3, NodeRole::Transit, 500.0);
+        let mut nodes = vec![egress(0, 0.0), egress(1, 1_000.0)];
+        for tick in 1..=3 {
+            assert_eq!(lifecycle.update(&nodes, tick), LifecycleChanges::default());
+        }
+        let changes = lifecycle.update(&nodes, 4);
+        assert_eq!(changes.exits, vec![0]);
+        nodes[0].role = NodeRole::Transit;
+
+        nodes[0].inventory_crypto = 499.0;
+        assert!(lifecycle.update(&nodes, 5).reentries.is_empty());
+        nodes[0].inventory_crypto = 500.0;
+        assert_eq!(lifecycle.update(&nodes, 6).reentries, vec![0]);
+        assert_eq!((lifecycle.exits, lifecycle.reentries, lifecycle.exited_count()), (1, 1, 0));
+    }
+}
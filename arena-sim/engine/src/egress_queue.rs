@@ -0,0 +1,165 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Egress Queue Preview
+//
+// A packet is heading to the liquid Egress nearest (Euclidean) its current
+// location, the target the router scores its hops against: the node holding
+// it, or the next hop of an in-flight packet. Corridor traffic only heads to
+// Egress nodes in its destination region. Depletion time divides the
+// Egress's inventory by an EMA of the inventory its settlements draw per tick.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::routing;
+use crate::types::{SimNode, SimPacket};
+
+/// Per-tick EMA weight of the latest tick's settlement draw.
+pub const DRAW_RATE_ALPHA: f64 = 0.05;
+
+// ---------------------------------------------------------------------------
+// EgressDrawRates - smoothed inventory drawn per tick, by Egress
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct EgressDrawRates {
+    rates: BTreeMap<u32, f64>,
+    // This tick's draws, folded into the EMA at end of tick
+    tick_draws: BTreeMap<u32, f64>,
+}
+
+impl EgressDrawRates {
+    pub fn record(&mut self, egress_id: u32, drawn: f64) {
+        *self.tick_draws.entry(egress_id).or_insert(0.0) += drawn;
+    }
+
+    /// Fold this tick's draws into every Egress's rate; Egress nodes that
+    /// settled nothing decay toward zero.
+    pub fn end_tick(&mut self) {
+        for &id in self.tick_draws.keys() {
+            self.rates.entry(id).or_insert(0.0);
+        }
+        for (id, rate) in self.rates.iter_mut() {
+            let drawn = self.tick_draws.get(id).copied().unwrap_or(0.0);
+            *rate += DRAW_RATE_ALPHA * (drawn - *rate);
+        }
+        self.tick_draws.clear();
+    }
+
+    /// Smoothed inventory drawn per tick at `egress_id`.
+    pub fn rate(&self, egress_id: u32) -> f64 {
+        self.rates.get(&egress_id).copied().unwrap_or(0.0)
+    }
+
+    /// Smoothed inventory drawn per tick across every Egress.
+    pub fn total_rate(&self) -> f64 {
+        self.rates.values().sum()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// EgressQueue
+// ---------------------------------------------------------------------------
+
+/// Packets currently heading to one Egress and how long its inventory lasts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct EgressQueue {
+    pub egress_id: u32,
+    pub inventory_crypto: f64,
+    pub packets: u32,
+    pub value: f64,
+    /// Packets and value by tier (L0..L3)
+    pub tier_packets: [u32; 4],
+    pub tier_value: [f64; 4],
+    /// Inventory its settlements drew per tick, smoothed (`DRAW_RATE_ALPHA`)
+    pub draw_rate: f64,
+    /// Ticks until the inventory runs out at `draw_rate`; None while
+    /// nothing is being drawn
+    pub expected_depletion_ticks: Option<f64>,
+    /// Inventory covers every queued packet's value
+    pub covered: bool,
+}
+
+impl EgressQueue {
+    /// Tally the packets `heading` to `egress_id`.
+    pub fn collect<'a>(
+        egress_id: u32,
+        inventory_crypto: f64,
+        draw_rate: f64,
+        heading: impl Iterator<Item = &'a SimPacket>,
+    ) -> Self {
+        let mut queue = Self {
+            egress_id,
+            inventory_crypto,
+            packets: 0,
+            value: 0.0,
+            tier_packets: [0; 4],
+            tier_value: [0.0; 4],
+            draw_rate,
+            expected_depletion_ticks: (draw_rate > f64::EPSILON)
+                .then(|| inventory_crypto.max(0.0) / draw_rate),
+            covered: true,
+        };
+        for p in heading {
+            queue.packets += 1;
+            queue.value += p.current_value;
+            queue.tier_packets[p.tier as usize] += 1;
+            queue.tier_value[p.tier as usize] += p.current_value;
+        }
+        queue.covered = inventory_crypto >= queue.value;
+        queue
+    }
+}
+
+/// The liquid Egress nearest each node, as the router targets it; None for
+/// every node when no Egress is liquid.
+pub fn nearest_liquid_egress(nodes: &[SimNode]) -> Vec<Option<u32>> {
+    nodes.iter()
+        .map(|n| routing::nearest_liquid_egress(nodes, n).map(|e| e.id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MarketTier, PacketStatus};
+
+    fn packet(id: u64, tier: MarketTier, value: f64) -> SimPacket {
+        SimPacket {
+            id, status: PacketStatus::InTransit, tier, ttl: 500, hop_limit: 20,
+            ..SimPacket::test_default(0, value)
+        }
+    }
+
+    #[test]
+    fn test_draw_rate_smooths_and_decays() {
+        let mut rates = EgressDrawRates::default();
+        rates.record(3, 100.0);
+        rates.end_tick();
+        assert!((rates.rate(3) - 100.0 * DRAW_RATE_ALPHA).abs() < 1e-12);
+        let after_draw = rates.rate(3);
+        rates.end_tick();
+        assert!((rates.rate(3) - after_draw * (1.0 - DRAW_RATE_ALPHA)).abs() < 1e-12);
+        assert_eq!(rates.rate(7), 0.0);
+    }
+
+    #[test]
+    fn test_queue_tallies_tier_mix_and_depletion() {
+        let packets = [
+            packet(0, MarketTier::L0, 10.0),
+            packet(1, MarketTier::L2, 30.0),
+            packet(2, MarketTier::L2, 20.0),
+        ];
+        let q = EgressQueue::collect(5, 40.0, 4.0, packets.iter());
+        assert_eq!(q.packets, 3);
+        assert_eq!(q.value, 60.0);
+        assert_eq!(q.tier_packets, [1, 0, 2, 0]);
+        assert_eq!(q.tier_value, [10.0, 0.0, 50.0, 0.0]);
+        assert_eq!(q.expected_depletion_ticks, Some(10.0));
+        assert!(!q.covered);
+
+        let idle = EgressQueue::collect(5, 40.0, 0.0, std::iter::empty());
+        assert_eq!((idle.packets, idle.expected_depletion_ticks, idle.covered), (0, None, true));
+    }
+}
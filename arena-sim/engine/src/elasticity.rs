@@ -0,0 +1,104 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Demand Elasticity
+//
+// retention = 1 / (1 + (realized_cost / reference_cost)^elasticity)
+//
+// realized_cost is an EMA (COST_EMA_ALPHA per tick) of the mean all-in cost
+// of this tick's settlements: (original - settled) / original, so fees,
+// demurrage and surge burn alike. Ticks without settlements leave it as is.
+// At reference_cost half of the offered volume is still spawned; elasticity
+// sets how sharply demand falls away around it (0 = perfectly inelastic).
+
+/// All-in cost at which half of the offered demand walks away.
+pub const DEFAULT_REFERENCE_COST: f64 = 0.20;
+/// Steepness of the curve around the reference cost.
+pub const DEFAULT_ELASTICITY: f64 = 4.0;
+/// Per-tick EMA weight of the latest realized cost.
+pub const COST_EMA_ALPHA: f64 = 0.05;
+
+/// Share of offered demand that is still spawned at all-in `cost`.
+pub fn demand_retention(cost: f64, elasticity: f64, reference_cost: f64) -> f64 {
+    if elasticity <= 0.0 || cost <= 0.0 {
+        return 1.0;
+    }
+    1.0 / (1.0 + (cost / reference_cost.max(f64::EPSILON)).powf(elasticity))
+}
+
+// ---------------------------------------------------------------------------
+// DemandElasticity - spawn volume following what users recently paid
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct DemandElasticity {
+    pub elasticity: f64,
+    pub reference_cost: f64,
+    pub realized_cost: f64,
+    // This tick's settlements, folded into the EMA at end of tick
+    tick_cost_sum: f64,
+    tick_settlements: u32,
+}
+
+impl Default for DemandElasticity {
+    fn default() -> Self {
+        Self::new(DEFAULT_ELASTICITY, DEFAULT_REFERENCE_COST)
+    }
+}
+
+impl DemandElasticity {
+    pub fn new(elasticity: f64, reference_cost: f64) -> Self {
+        Self {
+            elasticity: elasticity.max(0.0),
+            reference_cost,
+            realized_cost: 0.0,
+            tick_cost_sum: 0.0,
+            tick_settlements: 0,
+        }
+    }
+
+    /// Record a settlement: `original_value` minted, `settled_value` paid out.
+    pub fn record(&mut self, original_value: f64, settled_value: f64) {
+        if original_value <= 0.0 {
+            return;
+        }
+        self.tick_cost_sum += ((original_value - settled_value) / original_value).clamp(0.0, 1.0);
+        self.tick_settlements += 1;
+    }
+
+    /// Fold this tick's settlements into the realized cost.
+    pub fn end_tick(&mut self) {
+        if self.tick_settlements > 0 {
+            let tick_cost = self.tick_cost_sum / self.tick_settlements as f64;
+            self.realized_cost += COST_EMA_ALPHA * (tick_cost - self.realized_cost);
+        }
+        self.tick_cost_sum = 0.0;
+        self.tick_settlements = 0;
+    }
+
+    /// Share of offered demand spawned at the current realized cost.
+    pub fn retention(&self) -> f64 {
+        demand_retention(self.realized_cost, self.elasticity, self.reference_cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retention_falls_continuously_with_realized_cost() {
+        assert_eq!(demand_retention(0.0, 4.0, 0.2), 1.0);
+        assert_eq!(demand_retention(0.5, 0.0, 0.2), 1.0, "Inelastic demand never walks");
+        assert!((demand_retention(0.2, 4.0, 0.2) - 0.5).abs() < 1e-12);
+        let curve: Vec<f64> = (0..=30).map(|pct| demand_retention(pct as f64 / 100.0, 4.0, 0.2)).collect();
+        assert!(curve.windows(2).all(|w| w[1] < w[0]));
+
+        let mut demand = DemandElasticity::new(4.0, 0.2);
+        demand.record(100.0, 80.0);
+        demand.record(100.0, 60.0);
+        demand.end_tick();
+        assert!((demand.realized_cost - COST_EMA_ALPHA * 0.3).abs() < 1e-12);
+        demand.end_tick();
+        assert!((demand.realized_cost - COST_EMA_ALPHA * 0.3).abs() < 1e-12, "Idle ticks keep the cost");
+        assert!(demand.retention() < 1.0);
+    }
+}
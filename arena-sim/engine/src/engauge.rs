@@ -5,9 +5,11 @@ use crate::types::{NodeRole, SimNode};
 use serde::{Deserialize, Serialize};
 
 const MAX_WINDOW_SIZE: usize = 20;
-const SPECULATIVE_THRESHOLD: f64 = 0.3;
-const VELOCITY_FLOOR: f64 = 100.0;
-const VELOCITY_DIVISOR: f64 = 1000.0;
+/// Organic ratio below which activity is treated as speculative
+pub const SPECULATIVE_THRESHOLD: f64 = 0.3;
+// Velocity is float turnover per tick (settled value / average in-flight float)
+const VELOCITY_FLOOR: f64 = 0.05;
+const VELOCITY_DIVISOR: f64 = 0.5;
 const RATIO_FLOOR: f64 = 0.1;
 
 // ---------------------------------------------------------------------------
@@ -151,7 +153,7 @@ mod tests {
     #[test]
     fn test_low_velocity_always_organic() {
         let mut state = NGaugeState::new();
-        state.update(0.01, 50.0);
+        state.update(0.01, 0.01);
         assert_eq!(state.organic_ratio(), 1.0);
         assert!(!state.speculative_detected);
         assert!(state.should_relax_fees());
@@ -163,11 +165,11 @@ mod tests {
         let mut state = NGaugeState::new();
         // Push enough samples to establish a window
         for _ in 0..5 {
-            state.update(0.01, 500.0);
+            state.update(0.01, 5.0);
         }
-        // avg_activity = 0.01, avg_velocity = 500.0
-        // denominator = (500/1000).max(0.1) = 0.5
-        // organic_ratio = 0.01 / 0.5 = 0.02 < 0.3
+        // avg_activity = 0.01, avg_velocity = 5.0
+        // denominator = (5.0/0.5).max(0.1) = 10.0
+        // organic_ratio = 0.01 / 10.0 = 0.001 < 0.3
         assert!(state.organic_ratio() < SPECULATIVE_THRESHOLD);
         assert!(state.speculative_detected);
         assert!(state.should_increase_fees());
@@ -178,11 +180,11 @@ mod tests {
     fn test_high_velocity_high_activity_is_organic() {
         let mut state = NGaugeState::new();
         for _ in 0..5 {
-            state.update(0.8, 200.0);
+            state.update(0.8, 0.2);
         }
-        // avg_activity = 0.8, avg_velocity = 200.0
-        // denominator = (200/1000).max(0.1) = 0.2
-        // organic_ratio = 0.8 / 0.2 = 4.0 > 0.3
+        // avg_activity = 0.8, avg_velocity = 0.2
+        // denominator = (0.2/0.5).max(0.1) = 0.4
+        // organic_ratio = 0.8 / 0.4 = 2.0 > 0.3
         assert!(state.organic_ratio() > SPECULATIVE_THRESHOLD);
         assert!(!state.speculative_detected);
         assert!(state.should_relax_fees());
@@ -206,13 +208,13 @@ mod tests {
         let mut state = NGaugeState::new();
         // Start speculative
         for _ in 0..20 {
-            state.update(0.01, 500.0);
+            state.update(0.01, 5.0);
         }
         assert!(state.should_increase_fees());
 
         // Gradually introduce organic activity to fill the window
         for _ in 0..20 {
-            state.update(1.0, 200.0);
+            state.update(1.0, 0.2);
         }
         assert!(state.should_relax_fees());
         assert!(!state.should_increase_fees());
@@ -220,14 +222,14 @@ mod tests {
 
     #[test]
     fn test_compute_organic_ratio_free_fn_high_velocity() {
-        let ratio = compute_organic_ratio(0.5, 500.0);
-        // 0.5 / (500/1000).max(0.1) = 0.5 / 0.5 = 1.0
+        let ratio = compute_organic_ratio(0.5, 0.25);
+        // 0.5 / (0.25/0.5).max(0.1) = 0.5 / 0.5 = 1.0
         assert!((ratio - 1.0).abs() < f64::EPSILON);
     }
 
     #[test]
     fn test_compute_organic_ratio_free_fn_low_velocity() {
-        let ratio = compute_organic_ratio(0.01, 50.0);
+        let ratio = compute_organic_ratio(0.01, 0.01);
         assert!((ratio - 1.0).abs() < f64::EPSILON);
     }
 
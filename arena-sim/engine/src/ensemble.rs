@@ -0,0 +1,166 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Multi-Simulation Ensemble
+
+use serde::Serialize;
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::simulation::ArenaSimulation;
+use crate::types::WorldState;
+
+// ---------------------------------------------------------------------------
+// Envelope - spread of one metric across the ensemble
+// ---------------------------------------------------------------------------
+
+/// Min, max, mean and nearest-rank p10 / median / p90 of one metric over
+/// the ensemble's members on a tick.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Tsify)]
+pub struct Envelope {
+    pub min: f64,
+    pub p10: f64,
+    pub median: f64,
+    pub p90: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+impl Envelope {
+    pub fn from_samples(samples: &[f64]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let rank = |q: f64| sorted[((sorted.len() - 1) as f64 * q).round() as usize];
+        Self {
+            min: sorted[0],
+            p10: rank(0.1),
+            median: rank(0.5),
+            p90: rank(0.9),
+            max: sorted[sorted.len() - 1],
+            mean: sorted.iter().sum::<f64>() / sorted.len() as f64,
+        }
+    }
+}
+
+/// One lockstep tick of the ensemble, as uncertainty bands.
+#[derive(Debug, Clone, Serialize, Tsify)]
+pub struct EnsembleTick {
+    pub tick: u64,
+    pub members: u32,
+    pub fee_rate: Envelope,
+    pub peg_deviation: Envelope,
+    pub liquidity_lambda: Envelope,
+    pub active_value: Envelope,
+    pub held_count: Envelope,
+    pub settlement_count: Envelope,
+    pub revert_count: Envelope,
+    pub stress_index: Envelope,
+    pub panic_level: Envelope,
+}
+
+impl EnsembleTick {
+    fn from_states(states: &[WorldState]) -> Self {
+        let envelope = |metric: fn(&WorldState) -> f64| {
+            Envelope::from_samples(&states.iter().map(metric).collect::<Vec<f64>>())
+        };
+        Self {
+            tick: states.first().map_or(0, |s| s.current_tick),
+            members: states.len() as u32,
+            fee_rate: envelope(|s| s.current_fee_rate),
+            peg_deviation: envelope(|s| s.peg_deviation),
+            liquidity_lambda: envelope(|s| s.liquidity_lambda),
+            active_value: envelope(|s| s.active_value),
+            held_count: envelope(|s| s.held_count as f64),
+            settlement_count: envelope(|s| s.settlement_count as f64),
+            revert_count: envelope(|s| s.revert_count as f64),
+            stress_index: envelope(|s| s.stress_index),
+            panic_level: envelope(|s| s.panic_level),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// ArenaEnsemble - K seeded simulations advanced in lockstep
+// ---------------------------------------------------------------------------
+
+/// Independent simulations of the same network, member `k` running auto
+/// traffic seeded with `base_seed + k`. Market inputs and node events are
+/// applied to every member alike, so the spread between them is the
+/// uncertainty due to traffic alone.
+#[wasm_bindgen]
+pub struct ArenaEnsemble {
+    members: Vec<ArenaSimulation>,
+}
+
+#[wasm_bindgen]
+impl ArenaEnsemble {
+    #[wasm_bindgen(constructor)]
+    pub fn new(node_count: u32, members: u32, base_seed: u32) -> Self {
+        let members = (0..members.max(1))
+            .map(|k| {
+                let mut sim = ArenaSimulation::new(node_count);
+                sim.set_traffic_seed(base_seed.wrapping_add(k));
+                sim
+            })
+            .collect();
+        Self { members }
+    }
+
+    pub fn member_count(&self) -> u32 {
+        self.members.len() as u32
+    }
+
+    #[wasm_bindgen(unchecked_return_type = "EnsembleTick")]
+    pub fn tick(&mut self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.tick_core()).unwrap_or(JsValue::NULL)
+    }
+
+    pub fn set_gold_price(&mut self, val: f64) {
+        self.members.iter_mut().for_each(|sim| sim.set_gold_price(val));
+    }
+
+    pub fn set_demand_factor(&mut self, val: f64) {
+        self.members.iter_mut().for_each(|sim| sim.set_demand_factor(val));
+    }
+
+    pub fn set_panic_level(&mut self, val: f64) {
+        self.members.iter_mut().for_each(|sim| sim.set_panic_level(val));
+    }
+
+    pub fn kill_node(&mut self, node_id: u32) {
+        self.members.iter_mut().for_each(|sim| sim.kill_node(node_id));
+    }
+
+    pub fn apply_price_shock(&mut self, magnitude_pct: f64, duration_ticks: u32, shape: u32) {
+        self.members.iter_mut().for_each(|sim| sim.apply_price_shock(magnitude_pct, duration_ticks, shape));
+    }
+}
+
+impl ArenaEnsemble {
+    /// Advance every member one tick and summarize their states.
+    pub fn tick_core(&mut self) -> EnsembleTick {
+        let states: Vec<WorldState> = self.members.iter_mut()
+            .map(|sim| sim.tick_core().state)
+            .collect();
+        EnsembleTick::from_states(&states)
+    }
+
+    pub fn member(&self, k: usize) -> Option<&ArenaSimulation> {
+        self.members.get(k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_envelope_quantiles() {
+        let samples: Vec<f64> = (0..11).rev().map(f64::from).collect();
+        let e = Envelope::from_samples(&samples);
+        assert_eq!((e.min, e.p10, e.median, e.p90, e.max), (0.0, 1.0, 5.0, 9.0, 10.0));
+        assert_eq!(e.mean, 5.0);
+        assert_eq!(Envelope::from_samples(&[]), Envelope::default());
+    }
+}
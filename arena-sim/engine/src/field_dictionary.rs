@@ -0,0 +1,76 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - State Field Dictionary
+
+use serde::Serialize;
+use tsify::Tsify;
+
+/// How a field evolves over a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Tsify)]
+pub enum FieldKind {
+    /// A running total, count or mean since tick 0
+    Cumulative,
+    /// A level, rate or flag for the current tick
+    Instantaneous,
+}
+
+/// One WorldState or SimStats field, described by the `@field` annotation
+/// on its declaration in `types`.
+#[derive(Debug, Clone, Serialize, Tsify)]
+pub struct FieldSpec {
+    /// Struct the field belongs to: "WorldState" or "SimStats"
+    pub owner: &'static str,
+    pub name: &'static str,
+    pub rust_type: &'static str,
+    /// "value" is packet value units, "price" the gold price's units
+    pub unit: &'static str,
+    pub kind: FieldKind,
+    /// Engine function, module or whitepaper section defining the field
+    pub formula: &'static str,
+    pub description: &'static str,
+}
+
+// WORLD_STATE_FIELDS and SIM_STATS_FIELDS, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/field_dictionary.rs"));
+
+/// Every WorldState field, then every SimStats field, in declaration order.
+pub fn describe_state() -> impl Iterator<Item = &'static FieldSpec> {
+    WORLD_STATE_FIELDS.iter().chain(SIM_STATS_FIELDS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArenaSimulation;
+
+    fn keys(value: serde_json::Value) -> Vec<String> {
+        value.as_object().unwrap().keys().cloned().collect()
+    }
+
+    fn names(owner: &str) -> Vec<String> {
+        let mut names: Vec<String> = describe_state()
+            .filter(|f| f.owner == owner)
+            .map(|f| f.name.to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_dictionary_covers_every_serialized_field() {
+        let mut sim = ArenaSimulation::new(24);
+        sim.tick_core();
+        // serde_json maps are sorted by key
+        assert_eq!(names("WorldState"), keys(serde_json::to_value(&sim.state).unwrap()));
+        assert_eq!(names("SimStats"), keys(serde_json::to_value(sim.get_stats_core()).unwrap()));
+    }
+
+    #[test]
+    fn test_velocity_is_an_instantaneous_rate() {
+        let velocity = describe_state().find(|f| f.name == "network_velocity").unwrap();
+        assert_eq!(velocity.kind, FieldKind::Instantaneous);
+        assert_eq!(velocity.unit, "1/tick");
+        assert_eq!(velocity.formula, "simulation::compute_monetary_velocity");
+        let fees = describe_state().find(|f| f.name == "total_fees_collected").unwrap();
+        assert_eq!(fees.kind, FieldKind::Cumulative);
+    }
+}
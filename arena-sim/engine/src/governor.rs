@@ -33,6 +33,14 @@ const HEALTH_WEIGHT_LIQUIDITY: f64 = 0.1;
 const HIGH_VOLUME: f64 = 1_000_000.0;
 const LOW_LIQUIDITY: f64 = 100_000.0;
 
+// Simulation-scale pressure thresholds: velocity is float turnover per tick,
+// volume is settled USD per tick
+const HIGH_TURNOVER: f64 = 0.2;
+const LOW_TURNOVER: f64 = 0.005;
+const CONGESTED_VOLUME: f64 = 10_000.0;
+const IDLE_VOLUME: f64 = 100.0;
+const VACUUM_VOLUME: f64 = 1_000.0;
+
 const DEVIATION_THRESHOLD: f64 = 0.18;
 
 const REWARD_SPLIT_EGRESS: f64 = 0.80;
@@ -160,6 +168,9 @@ impl TierModifiers {
 
 // ─── Fee Caps ───────────────────────────────────────────────────────────────
 
+/// Float slack when checking a charged fee against its tier cap.
+pub const FEE_CAP_TOLERANCE: f64 = 1e-9;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeeCaps {
     pub l0: f64,
@@ -190,6 +201,21 @@ impl FeeCaps {
         }
     }
 
+    /// Tighten the cap for `tier` to `cap` (a fraction of packet value). A
+    /// cap looser than the current one is ignored: overrides only tighten.
+    pub fn tighten(&mut self, tier: MarketTier, cap: f64) {
+        if cap.is_nan() {
+            return;
+        }
+        let slot = match tier {
+            MarketTier::L0 => &mut self.l0,
+            MarketTier::L1 => &mut self.l1,
+            MarketTier::L2 => &mut self.l2,
+            MarketTier::L3 => &mut self.l3,
+        };
+        *slot = slot.min(cap.max(0.0));
+    }
+
     /// Clamp a fee so it never exceeds the constitutional cap for the tier,
     /// and never exceeds the packet value itself.
     pub fn clamp_fee(
@@ -201,6 +227,12 @@ impl FeeCaps {
         let cap = self.cap_for(tier) * packet_value;
         fee.min(cap).min(packet_value).max(0.0)
     }
+
+    /// What a packet of `packet_value` that has paid `consumed` in fees so
+    /// far may still be charged before its combined fees reach the tier cap.
+    pub fn headroom(&self, tier: MarketTier, consumed: f64, packet_value: f64) -> f64 {
+        (self.cap_for(tier) * packet_value - consumed).max(0.0)
+    }
 }
 
 // ─── Health-to-Fee Adjustment (core governor bracket mapping) ───────────────
@@ -345,8 +377,8 @@ impl GovernorPid {
 /// Classify the network pressure quadrant from simulation-scale metrics.
 ///
 /// Simulation-scale thresholds (tick-based, not real-world):
-///   - velocity: settled_count * 100 in simulation
-///   - volume: maps to network_velocity (tick-scaled)
+///   - velocity: settled value / average in-flight float, per tick
+///   - volume: settled value per tick (USD)
 ///   - liquidity_depth: lambda * 1_000_000
 ///
 /// Canonical core thresholds (governor/pid.rs, real-world scale):
@@ -360,25 +392,21 @@ fn classify_pressure(
     volume: f64,
     liquidity: f64,
 ) -> PressureQuadrant {
-    // Simulation-scale: velocity 80 ~ core HIGH_VELOCITY 1.5 txn/sec
-    // Simulation-scale: volume 500 ~ core HIGH_VOLUME 1_000_000 gold-g
     if deviation > DEVIATION_THRESHOLD && signed_error > 0.0 {
         // High positive deviation: check if congestion or bubble
-        // Simulation-scale: velocity > 80, volume > 500
         // Core-scale: velocity > HIGH_VELOCITY(1.5), volume > HIGH_VOLUME
-        if velocity > 80.0 && volume > 500.0 {
+        if velocity > HIGH_TURNOVER && volume > CONGESTED_VOLUME {
             PressureQuadrant::Bottleneck
         } else {
             PressureQuadrant::Bubble
         }
     } else if deviation > DEVIATION_THRESHOLD && signed_error <= 0.0 {
         PressureQuadrant::Crash
-    } else if velocity < 10.0 && volume < 50.0 {
-        // Simulation-scale: velocity < 10, volume < 50
+    } else if velocity < LOW_TURNOVER && volume < IDLE_VOLUME {
         // Core-scale: velocity < LOW_VELOCITY(0.3), volume < LOW_VOLUME
         PressureQuadrant::Stagnation
-    } else if liquidity > 500_000.0 && volume < 100.0 {
-        // Simulation-scale: liquidity > 500k, volume < 100
+    } else if liquidity > 500_000.0 && volume < VACUUM_VOLUME {
+        // Simulation-scale: liquidity > 500k
         // Core-scale: liquidity > HIGH_LIQUIDITY, volume < LOW_VOLUME
         PressureQuadrant::Vacuum
     } else {
@@ -406,7 +434,7 @@ pub fn compute_governor(
         current_gold_price: state.gold_price,
         target_gold_price: 2600.0, // canonical Caesar peg target
         market_volatility: volatility,
-        transaction_volume: state.network_velocity,
+        transaction_volume: state.settled_value_per_tick,
         liquidity_depth: lambda * 1_000_000.0, // scale lambda back up
         network_velocity: state.network_velocity,
         active_packets_by_tier: [
@@ -415,7 +443,7 @@ pub fn compute_governor(
             state.tier_distribution[2] as u64,
             state.tier_distribution[3] as u64,
         ],
-        in_transit_float: state.active_value,
+        in_transit_float: state.in_transit_float,
     };
 
     let mut pid = GovernorPid::default();
@@ -435,12 +463,10 @@ pub fn compute_governor(
     }
 
     // E7: Organic vs Speculative Detection
-    let organic_ratio = if state.network_velocity > 100.0 {
-        state.ngauge_activity_index
-            / (state.network_velocity / 1000.0).max(0.1)
-    } else {
-        1.0
-    };
+    let organic_ratio = crate::engauge::compute_organic_ratio(
+        state.ngauge_activity_index,
+        state.network_velocity,
+    );
 
     if organic_ratio < 0.3 {
         gov.fee_rate *= 1.5;
@@ -532,9 +558,9 @@ mod tests {
             current_gold_price: 2600.0,
             target_gold_price: 2600.0,
             market_volatility: 0.05,
-            transaction_volume: 200.0,
+            transaction_volume: 2_000.0,
             liquidity_depth: 500_000.0,
-            network_velocity: 50.0,
+            network_velocity: 0.05,
             active_packets_by_tier: [100, 50, 20, 5],
             in_transit_float: 10_000.0,
         }
@@ -545,7 +571,7 @@ mod tests {
             current_tick: 100,
             gold_price: 2600.0,
             peg_deviation: 0.0,
-            network_velocity: 200.0,
+            network_velocity: 0.2,
             demand_factor: 0.5,
             panic_level: 0.0,
             governance_quadrant: "D: GOLDEN ERA".into(),
@@ -570,6 +596,7 @@ mod tests {
             spawn_count: 0,
             organic_ratio: 1.0,
             surge_multiplier: 1.0,
+            liquidity_lambda: 1.0,
             circuit_breaker_active: false,
             ingress_throttle: 0.0,
             dissolved_count: 0,
@@ -580,6 +607,75 @@ mod tests {
             speculation_component: 0.0,
             float_component: 0.0,
             tier_fee_rates: [0.0; 4],
+            tier_effective_cost: [0.0; 4],
+            settled_value_per_tick: 0.0,
+            egress_top1_share: 0.0,
+            egress_top3_share: 0.0,
+            egress_concentration_alert: false,
+            verification_fees_paid: 0.0,
+            verification_shortfalls: 0,
+            ngauge_utilization: 0.0,
+            quadrant_ticks: [0; 6],
+            quadrant_transition_count: 0,
+            settlement_batches: 0,
+            egress_inventory_drawn: 0.0,
+            batch_inventory_saved: 0.0,
+            trust_rebates_paid: 0.0,
+            trusted_settlements: 0,
+            rebate_pool_balance: 0.0,
+            volume_discount_forgone: 0.0,
+            discounted_settlements: 0,
+            discounted_value_share: 0.0,
+            dust_rejected: 0,
+            dust_surcharges: 0.0,
+            non_dust_settle_ticks: 0.0,
+            payments_aggregated: 0,
+            aggregate_packets: 0,
+            aggregated_payments_settled: 0,
+            route_cache_hit_rate: 0.0,
+            route_cache_invalidations: 0,
+            path_cache_hit_rate: 0.0,
+            path_cache_invalidations: 0,
+            velocity_bonus_paid: 0.0,
+            velocity_bonus_treasury: 0.0,
+            quote_drift: 0.0,
+            quote_mismatches: 0,
+            stress_index: 0.0,
+            orbit_no_liquid_egress: 0,
+            orbit_hop_limit: 0,
+            orbit_disabled_destination: 0,
+            egress_exits: 0,
+            egress_reentries: 0,
+            exited_egress_count: 0,
+            in_transit_float: 0.0,
+            realized_cost: 0.0,
+            demand_retention: 1.0,
+            fee_cap_breaches: 0,
+            payout_spread: 0.0,
+            payout_slippage: 0.0,
+            revert_reasons: [0; crate::types::RevertReason::COUNT],
+            oracle_price: 2600.0,
+            oracle_staleness_ticks: 0,
+            retry_count: 0,
+            pending_retries: 0,
+            speculative_value_share: 0.0,
+            governor_decisions: 0,
+            fee_rate_reversals: 0,
+            peak_local_panic: 0.0,
+            panicked_nodes: 0,
+            throughput_deferred: 0,
+            egress_replenished: 0.0,
+            liquidity_runway_ticks: None,
+            rebalanced_volume: 0.0,
+            rebalancing_fees: 0.0,
+            rebalancing_in_flight: 0.0,
+            dropped_packets: 0,
+            censored_packets: 0,
+            misrouted_packets: 0,
+            route_concentration: 0.0,
+            fee_share_gini: 0.0,
+            cartel_steered_hops: 0,
+            cartel_fee_split: 0.0,
         }
     }
 
@@ -592,7 +688,7 @@ mod tests {
         assert_eq!(out.quadrant, "D: GOLDEN ERA");
         assert_eq!(out.status, "STABLE");
         // At peg (error=0), PID output is 0. Health score:
-        //   gold=10*0.4=4, vol=(1-0.05)*10*0.3=2.85, txn=200/1e6*10*0.2=0.0004,
+        //   gold=10*0.4=4, vol=(1-0.05)*10*0.3=2.85, txn=2000/1e6*10*0.2=0.004,
         //   liq=500k/100k*10*0.1=1.0 (capped) => raw~7.85 => bracket >= 7.5 => -0.006
         // final_adj = -0.006 + 0.0 = -0.006
         // fee_rate = 0.001 * (1.0 + (-0.006)) = 0.001 * 0.994 = 0.000994
@@ -633,7 +729,7 @@ mod tests {
     fn pid_stagnation_on_low_activity() {
         let mut pid = GovernorPid::default();
         let mut metrics = default_metrics();
-        metrics.network_velocity = 5.0;
+        metrics.network_velocity = 0.001;
         metrics.transaction_volume = 10.0;
         let out = pid.recalculate(&metrics);
 
@@ -646,8 +742,8 @@ mod tests {
         let mut pid = GovernorPid::default();
         let mut metrics = default_metrics();
         metrics.liquidity_depth = 600_000.0;
-        metrics.transaction_volume = 50.0;
-        metrics.network_velocity = 50.0;
+        metrics.transaction_volume = 500.0;
+        metrics.network_velocity = 0.05;
         let out = pid.recalculate(&metrics);
 
         assert_eq!(out.quadrant, "F: VACUUM");
@@ -658,8 +754,8 @@ mod tests {
         let mut pid = GovernorPid::default();
         let mut metrics = default_metrics();
         metrics.current_gold_price = 3400.0; // large positive deviation
-        metrics.network_velocity = 100.0;
-        metrics.transaction_volume = 600.0;
+        metrics.network_velocity = 0.3;
+        metrics.transaction_volume = 20_000.0;
         let out = pid.recalculate(&metrics);
 
         assert_eq!(out.quadrant, "E: BOTTLENECK");
@@ -711,7 +807,7 @@ mod tests {
         // Set ngauge low so the discount does not reduce the panic floor
         state.ngauge_activity_index = 0.2;
         // Set velocity low so organic ratio stays high (no speculation mult)
-        state.network_velocity = 50.0;
+        state.network_velocity = 0.01;
         let out = compute_governor(&state, 0.05, 0.8, 1.5);
 
         // Panic > 0.7 forces fee_rate >= 0.05
@@ -735,7 +831,7 @@ mod tests {
     fn compute_governor_speculation_detection() {
         let mut state = default_world_state();
         state.ngauge_activity_index = 0.01;
-        state.network_velocity = 5000.0;
+        state.network_velocity = 5.0;
         let out = compute_governor(&state, 0.05, 0.8, 1.0);
 
         // Organic ratio < 0.3 should inflate fee
@@ -861,6 +957,18 @@ mod tests {
         assert!((clamped - 5.0).abs() < 1e-9);
     }
 
+    #[test]
+    fn fee_caps_tighten_only() {
+        let mut caps = FeeCaps::default();
+        caps.tighten(MarketTier::L0, 0.025);
+        caps.tighten(MarketTier::L1, 0.5);
+        caps.tighten(MarketTier::L2, f64::NAN);
+        assert!((caps.cap_for(MarketTier::L0) - 0.025).abs() < 1e-12);
+        assert!((caps.cap_for(MarketTier::L1) - 0.02).abs() < 1e-12, "Looser caps are ignored");
+        assert!((caps.cap_for(MarketTier::L2) - 0.005).abs() < 1e-12);
+        assert!((caps.clamp_fee(MarketTier::L0, 10.0, 100.0) - 2.5).abs() < 1e-12);
+    }
+
     #[test]
     fn pid_zero_target_guard() {
         let mut pid = GovernorPid::default();
@@ -0,0 +1,150 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - End-of-Run Held Sweep
+//
+// A run can end with packets still Held: orbiting for a liquid Egress, past
+// their hop limit, or behind a Disabled node. The sweep classifies them by
+// age, tier and blocking reason, then resolves them under a `SweepPolicy`:
+// refunded to the sender as an orbit timeout would, or dissolved to the
+// qualified nodes as gravity dissolution would, whatever their age. What the
+// policy leaves behind is the residue, so a held count at the end of a run
+// says what was stuck, for how long, and whether anything could clear it.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::types::{OrbitReason, SimPacket};
+
+/// Upper edges (exclusive) of the held-age bands, in ticks since spawn;
+/// the last band is everything older.
+pub const AGE_BAND_EDGES: [u64; 3] = [100, 500, 2000];
+pub const AGE_BANDS: usize = AGE_BAND_EDGES.len() + 1;
+/// Orbit reasons, indexed by `OrbitReason` discriminant.
+pub const BLOCKING_REASONS: usize = 3;
+
+/// What the sweep does with Held packets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+pub enum SweepPolicy {
+    /// Classify only; every Held packet is residue
+    #[default]
+    ReportOnly = 0,
+    /// Refund every Held packet to its sender at its current value
+    Refund = 1,
+    /// Dissolve to qualified nodes; packets with none to dissolve to remain
+    Dissolve = 2,
+    /// Dissolve, refunding what cannot be dissolved
+    DissolveOrRefund = 3,
+}
+
+impl SweepPolicy {
+    /// Map a numeric discriminant (as sent from JS) back to a policy.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::ReportOnly),
+            1 => Some(Self::Refund),
+            2 => Some(Self::Dissolve),
+            3 => Some(Self::DissolveOrRefund),
+            _ => None,
+        }
+    }
+}
+
+/// Held packets at the sweep, and what became of them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct HeldSweepReport {
+    pub policy: SweepPolicy,
+    pub tick: u64,
+    pub held: u32,
+    pub held_value: f64,
+    /// Held packets by age band (see `AGE_BAND_EDGES`), tier (L0..L3) and
+    /// `OrbitReason`
+    pub by_age: [u32; AGE_BANDS],
+    pub by_tier: [u32; 4],
+    pub by_reason: [u32; BLOCKING_REASONS],
+    pub oldest_age: u64,
+    pub refunded: u32,
+    pub dissolved: u32,
+    pub resolved_value: f64,
+    /// Held packets the policy left in place, by `OrbitReason`
+    pub unresolved: u32,
+    pub unresolved_value: f64,
+    pub unresolved_by_reason: [u32; BLOCKING_REASONS],
+}
+
+/// Age band of a packet `age` ticks old.
+pub fn age_band(age: u64) -> usize {
+    AGE_BAND_EDGES.iter().position(|&edge| age < edge).unwrap_or(AGE_BAND_EDGES.len())
+}
+
+// Packets restored from a snapshot predating reasons had no route
+fn reason_index(p: &SimPacket) -> usize {
+    p.orbit_reason.unwrap_or(OrbitReason::NoLiquidEgress) as usize
+}
+
+impl HeldSweepReport {
+    pub fn new(policy: SweepPolicy, tick: u64) -> Self {
+        Self { policy, tick, ..Default::default() }
+    }
+
+    /// Classify Held packet `p`.
+    pub fn record_held(&mut self, p: &SimPacket) {
+        let age = self.tick.saturating_sub(p.spawn_tick);
+        self.held += 1;
+        self.held_value += p.current_value;
+        self.by_age[age_band(age)] += 1;
+        self.by_tier[p.tier as usize] += 1;
+        self.by_reason[reason_index(p)] += 1;
+        self.oldest_age = self.oldest_age.max(age);
+    }
+
+    pub fn record_refunded(&mut self, p: &SimPacket) {
+        self.refunded += 1;
+        self.resolved_value += p.current_value;
+    }
+
+    pub fn record_dissolved(&mut self, p: &SimPacket) {
+        self.dissolved += 1;
+        self.resolved_value += p.current_value;
+    }
+
+    pub fn record_unresolved(&mut self, p: &SimPacket) {
+        self.unresolved += 1;
+        self.unresolved_value += p.current_value;
+        self.unresolved_by_reason[reason_index(p)] += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MarketTier, PacketStatus};
+
+    fn held(spawn_tick: u64, tier: MarketTier, reason: Option<OrbitReason>) -> SimPacket {
+        SimPacket {
+            id: 1, current_value: 40.0, status: PacketStatus::Held,
+            orbit_start_tick: Some(spawn_tick), orbit_reason: reason, tier, spawn_tick,
+            ..SimPacket::test_default(0, 100.0)
+        }
+    }
+
+    #[test]
+    fn test_classifies_by_age_tier_and_reason() {
+        assert_eq!([0, 99, 100, 499, 500, 1999, 2000, 9000].map(age_band), [0, 0, 1, 1, 2, 2, 3, 3]);
+
+        let mut report = HeldSweepReport::new(SweepPolicy::Dissolve, 3000);
+        let a = held(2950, MarketTier::L1, Some(OrbitReason::HopLimit));
+        let b = held(200, MarketTier::L3, None);
+        report.record_held(&a);
+        report.record_held(&b);
+        report.record_dissolved(&a);
+        report.record_unresolved(&b);
+
+        assert_eq!((report.held, report.held_value, report.oldest_age), (2, 80.0, 2800));
+        assert_eq!(report.by_age, [1, 0, 0, 1]);
+        assert_eq!(report.by_tier, [0, 1, 0, 1]);
+        assert_eq!(report.by_reason, [1, 1, 0]);
+        assert_eq!((report.dissolved, report.resolved_value), (1, 40.0));
+        assert_eq!((report.unresolved, report.unresolved_by_reason), (1, [1, 0, 0]));
+        assert_eq!(SweepPolicy::from_u32(3), Some(SweepPolicy::DissolveOrRefund));
+        assert_eq!(SweepPolicy::from_u32(4), None);
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Per-Tick Histograms
+
+use crate::types::{NodeRole, SimNode, SimPacket};
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+/// Log-decade value buckets (USD): <1, 1-10, ..., 100k-1M, >=1M.
+const VALUE_EDGES: [f64; 8] = [0.0, 1.0, 10.0, 100.0, 1_000.0, 10_000.0, 100_000.0, 1_000_000.0];
+/// Packet age buckets (ticks since spawn).
+const AGE_EDGES: [f64; 8] = [0.0, 10.0, 50.0, 100.0, 500.0, 1_000.0, 5_000.0, 10_000.0];
+/// Node buffer occupancy buckets (packets held).
+const OCCUPANCY_EDGES: [f64; 7] = [0.0, 1.0, 5.0, 10.0, 50.0, 100.0, 500.0];
+
+// ---------------------------------------------------------------------------
+// Histogram
+// ---------------------------------------------------------------------------
+
+/// Fixed-bucket histogram. Bucket `i` covers `[edges[i], edges[i + 1])`;
+/// the last bucket is open-ended.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct Histogram {
+    pub edges: Vec<f64>,
+    pub counts: Vec<u32>,
+}
+
+impl Histogram {
+    pub fn new(edges: &[f64]) -> Self {
+        Self {
+            edges: edges.to_vec(),
+            counts: vec![0; edges.len()],
+        }
+    }
+
+    /// Add a sample. Values below the first edge land in bucket 0.
+    pub fn record(&mut self, value: f64) {
+        let idx = self.edges.partition_point(|&e| e <= value).saturating_sub(1);
+        self.counts[idx] += 1;
+    }
+
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// Upper-bound estimate of quantile `q` in [0, 1]: the upper edge of the
+    /// bucket containing it (the last edge for the open-ended bucket).
+    pub fn quantile(&self, q: f64) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            return 0.0;
+        }
+        let rank = (q.clamp(0.0, 1.0) * total as f64).ceil().max(1.0) as u32;
+        let mut seen = 0;
+        for (i, &c) in self.counts.iter().enumerate() {
+            seen += c;
+            if seen >= rank {
+                return *self.edges.get(i + 1).unwrap_or(&self.edges[i]);
+            }
+        }
+        *self.edges.last().unwrap_or(&0.0)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TickHistograms - compact chart data attached to every TickResult
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct TickHistograms {
+    /// Active packet value, one histogram per tier (L0..L3).
+    pub value_by_tier: Vec<Histogram>,
+    /// Active packet age in ticks.
+    pub packet_age: Histogram,
+    /// Buffer occupancy across non-disabled nodes.
+    pub buffer_occupancy: Histogram,
+}
+
+/// Build the per-tick histograms from the active packet set and node fleet.
+pub fn compute_histograms<'a>(
+    packets: impl Iterator<Item = &'a SimPacket>,
+    nodes: &[SimNode],
+    current_tick: u64,
+) -> TickHistograms {
+    let mut value_by_tier = vec![Histogram::new(&VALUE_EDGES); 4];
+    let mut packet_age = Histogram::new(&AGE_EDGES);
+    for p in packets {
+        value_by_tier[p.tier as usize].record(p.current_value);
+        packet_age.record(current_tick.saturating_sub(p.spawn_tick) as f64);
+    }
+
+    let mut buffer_occupancy = Histogram::new(&OCCUPANCY_EDGES);
+    for n in nodes.iter().filter(|n| n.role != NodeRole::Disabled) {
+        buffer_occupancy.record(n.current_buffer_count as f64);
+    }
+
+    TickHistograms {
+        value_by_tier,
+        packet_age,
+        buffer_occupancy,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_bucket_boundaries() {
+        let mut h = Histogram::new(&[0.0, 1.0, 10.0]);
+        h.record(0.0);
+        h.record(0.99);
+        h.record(1.0);
+        h.record(9.99);
+        h.record(10.0);
+        h.record(1e9);
+        assert_eq!(h.counts, vec![2, 2, 2]);
+        assert_eq!(h.total(), 6);
+    }
+
+    #[test]
+    fn test_quantile_upper_edges() {
+        let mut h = Histogram::new(&[0.0, 1.0, 10.0, 100.0]);
+        for v in [0.5, 0.5, 5.0, 50.0] {
+            h.record(v);
+        }
+        assert_eq!(h.quantile(0.5), 1.0);
+        assert_eq!(h.quantile(0.75), 10.0);
+        assert_eq!(h.quantile(1.0), 100.0);
+        assert_eq!(Histogram::new(&[0.0]).quantile(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_negative_values_land_in_first_bucket() {
+        let mut h = Histogram::new(&[0.0, 1.0]);
+        h.record(-5.0);
+        assert_eq!(h.counts, vec![1, 0]);
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Per-Hop Latency & Fee Attribution
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+// ---------------------------------------------------------------------------
+// NodeHopStats - one node's contribution to hop latency and hop fees
+// ---------------------------------------------------------------------------
+
+/// A hop's dwell is the forwarding node's custody of the packet, from
+/// receipt until the next hop receives it (buffer wait plus link and
+/// verification latency). The hop fee goes to the node forwarded into,
+/// which earns it net of rebate-pool funding. Shares are of all traced
+/// dwell ticks and hop fees.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct NodeHopStats {
+    pub node_id: u32,
+    pub hops_forwarded: u32,
+    pub dwell_ticks: u64,
+    pub max_dwell_ticks: u64,
+    pub mean_dwell_ticks: f64,
+    pub dwell_share: f64,
+    pub hops_received: u32,
+    pub fees_earned: f64,
+    pub fee_share: f64,
+}
+
+// ---------------------------------------------------------------------------
+// HopTrace
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct HopTrace {
+    nodes: BTreeMap<u32, NodeHopStats>,
+    total_dwell_ticks: u64,
+    total_fees: f64,
+}
+
+impl HopTrace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a hop: `from` held the packet for `dwell_ticks` until `to`
+    /// received it, and `to` earned `fee`.
+    pub fn record_hop(&mut self, from: u32, dwell_ticks: u64, to: u32, fee: f64) {
+        let sender = self.entry(from);
+        sender.hops_forwarded += 1;
+        sender.dwell_ticks += dwell_ticks;
+        sender.max_dwell_ticks = sender.max_dwell_ticks.max(dwell_ticks);
+        let receiver = self.entry(to);
+        receiver.hops_received += 1;
+        receiver.fees_earned += fee;
+        self.total_dwell_ticks += dwell_ticks;
+        self.total_fees += fee;
+    }
+
+    fn entry(&mut self, node_id: u32) -> &mut NodeHopStats {
+        self.nodes.entry(node_id).or_insert_with(|| NodeHopStats { node_id, ..Default::default() })
+    }
+
+    /// Every node seen in a traced hop, by id.
+    pub fn node_stats(&self) -> Vec<NodeHopStats> {
+        let share = |part: f64, total: f64| if total > 0.0 { part / total } else { 0.0 };
+        self.nodes.values()
+            .map(|s| NodeHopStats {
+                mean_dwell_ticks: share(s.dwell_ticks as f64, f64::from(s.hops_forwarded)),
+                dwell_share: share(s.dwell_ticks as f64, self.total_dwell_ticks as f64),
+                fee_share: share(s.fees_earned, self.total_fees),
+                ..s.clone()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dwell_goes_to_sender_and_fee_to_receiver() {
+        let mut trace = HopTrace::new();
+        trace.record_hop(0, 2, 4, 1.0);
+        trace.record_hop(4, 6, 8, 3.0);
+        trace.record_hop(4, 0, 8, 0.0);
+
+        let stats = trace.node_stats();
+        assert_eq!(stats.iter().map(|s| s.node_id).collect::<Vec<_>>(), vec![0, 4, 8]);
+        let relay = &stats[1];
+        assert_eq!((relay.hops_forwarded, relay.dwell_ticks, relay.max_dwell_ticks), (2, 6, 6));
+        assert_eq!((relay.mean_dwell_ticks, relay.dwell_share), (3.0, 0.75));
+        assert_eq!((relay.hops_received, relay.fees_earned, relay.fee_share), (1, 1.0, 0.25));
+        assert_eq!((stats[2].hops_forwarded, stats[2].fee_share), (0, 0.75));
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Initial Node Inventory
+
+use crate::types::{MarketTier, NodeRole};
+
+/// Fiat every node starts with; fiat only accumulates fee payouts.
+pub const INITIAL_FIAT: f64 = 10_000.0;
+/// Crypto a non-Egress node starts with on the 24-node reference network.
+pub const BASE_CRYPTO: f64 = 1_000.0;
+/// Egress nodes are well-capitalized settlement providers (500x base).
+pub const EGRESS_MULTIPLIER: f64 = 500.0;
+/// Node count of the reference network the base inventory is tuned for.
+pub const REFERENCE_NODES: f64 = 24.0;
+/// Arrivals per Egress per tick on the reference network at demand 0.5
+/// (5 packets per unit demand over its 6 Egress nodes).
+pub const REFERENCE_ARRIVALS_PER_EGRESS: f64 = 0.5 * 5.0 / 6.0;
+/// Mean packet value of the engine's own traffic (tiers drawn uniformly).
+pub const AUTO_TRAFFIC_MEAN_VALUE: f64 = (5.0 + 524.5 + 50_499.5 + 549_999.5) / 4.0;
+
+/// How node inventory is sized when the network is built.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InventoryPolicy {
+    /// Base inventory scaled by node count over the reference network
+    #[default]
+    NetworkScaled = 0,
+    /// The reference network's per-node inventory at every size
+    PerNode = 1,
+    /// Base inventory scaled by expected arrivals per Egress against the
+    /// reference network, so liquidity per unit demand is size-independent
+    DemandProportional = 2,
+    /// Egress reserves match the ingress capital expected over one L1 TTL
+    /// (L_in = L_out on the whitepaper's liquidity axis), so commercial
+    /// traffic settles in time even if no value flows back to Egress
+    Whitepaper = 3,
+}
+
+impl InventoryPolicy {
+    /// Map a numeric discriminant (as sent from JS) back to a policy.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::NetworkScaled),
+            1 => Some(Self::PerNode),
+            2 => Some(Self::DemandProportional),
+            3 => Some(Self::Whitepaper),
+            _ => None,
+        }
+    }
+}
+
+/// Expected traffic the demand-driven policies size inventory against.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpectedDemand {
+    /// Packets arriving network-wide per tick
+    pub arrivals_per_tick: f64,
+    pub mean_packet_value: f64,
+}
+
+impl ExpectedDemand {
+    /// The engine's own traffic at `demand_factor`.
+    pub fn auto_traffic(demand_factor: f64) -> Self {
+        Self { arrivals_per_tick: demand_factor * 5.0, mean_packet_value: AUTO_TRAFFIC_MEAN_VALUE }
+    }
+}
+
+/// Starting `(fiat, crypto)` for a node of `role` in a network of
+/// `node_count` nodes, `egress_count` of them Egress.
+pub fn initial_inventory(
+    policy: InventoryPolicy,
+    role: NodeRole,
+    node_count: u32,
+    egress_count: u32,
+    demand: ExpectedDemand,
+) -> (f64, f64) {
+    let egresses = f64::from(egress_count.max(1));
+    let base_crypto = match policy {
+        InventoryPolicy::NetworkScaled => BASE_CRYPTO * (f64::from(node_count) / REFERENCE_NODES).max(1.0),
+        InventoryPolicy::PerNode | InventoryPolicy::Whitepaper => BASE_CRYPTO,
+        InventoryPolicy::DemandProportional => {
+            BASE_CRYPTO * demand.arrivals_per_tick.max(0.0) / egresses / REFERENCE_ARRIVALS_PER_EGRESS
+        }
+    };
+    let crypto = match (policy, role) {
+        (InventoryPolicy::Whitepaper, NodeRole::Egress) => {
+            let inflow = demand.arrivals_per_tick.max(0.0) * demand.mean_packet_value.max(0.0);
+            inflow * MarketTier::L1.ttl_ticks() as f64 / egresses
+        }
+        (_, NodeRole::Egress) => base_crypto * EGRESS_MULTIPLIER,
+        _ => base_crypto,
+    };
+    (INITIAL_FIAT, crypto)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REFERENCE_DEMAND: ExpectedDemand = ExpectedDemand { arrivals_per_tick: 2.5, mean_packet_value: 100.0 };
+
+    #[test]
+    fn test_policies_agree_on_the_reference_network() {
+        for policy in [InventoryPolicy::NetworkScaled, InventoryPolicy::PerNode, InventoryPolicy::DemandProportional] {
+            let (fiat, crypto) = initial_inventory(policy, NodeRole::Egress, 24, 6, REFERENCE_DEMAND);
+            assert_eq!(fiat, INITIAL_FIAT);
+            assert!((crypto - 500_000.0).abs() < 1e-6, "{:?}: {}", policy, crypto);
+        }
+    }
+
+    #[test]
+    fn test_demand_proportional_holds_liquidity_per_arrival_across_sizes() {
+        // 10x the Egress nodes, 3x the traffic: each Egress sees 0.3x the flow
+        let demand = ExpectedDemand { arrivals_per_tick: 7.5, ..REFERENCE_DEMAND };
+        let (_, crypto) = initial_inventory(InventoryPolicy::DemandProportional, NodeRole::Egress, 240, 60, demand);
+        assert!((crypto - 150_000.0).abs() < 1e-6);
+        let (_, scaled) = initial_inventory(InventoryPolicy::NetworkScaled, NodeRole::Egress, 240, 60, demand);
+        assert!((scaled - 5_000_000.0).abs() < 1e-6);
+        let (_, flat) = initial_inventory(InventoryPolicy::PerNode, NodeRole::Transit, 240, 60, demand);
+        assert_eq!(flat, BASE_CRYPTO);
+    }
+
+    #[test]
+    fn test_whitepaper_reserves_cover_one_l1_ttl_of_inflow() {
+        let (_, crypto) = initial_inventory(InventoryPolicy::Whitepaper, NodeRole::Egress, 24, 6, REFERENCE_DEMAND);
+        let reserves = crypto * 6.0;
+        assert!((reserves - 2.5 * 100.0 * 500.0).abs() < 1e-6);
+        let (_, relay) = initial_inventory(InventoryPolicy::Whitepaper, NodeRole::Ingress, 24, 6, REFERENCE_DEMAND);
+        assert_eq!(relay, BASE_CRYPTO);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Settlement Latency
+
+use crate::histogram::Histogram;
+use crate::types::MarketTier;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+/// Spawn-to-settlement latency buckets (ticks), fine where user-facing
+/// service levels sit and coarse in the tail.
+const LATENCY_EDGES: [f64; 20] = [
+    0.0, 1.0, 2.0, 3.0, 5.0, 10.0, 15.0, 20.0, 30.0, 40.0, 50.0, 75.0, 100.0, 150.0, 200.0,
+    300.0, 500.0, 1_000.0, 2_000.0, 5_000.0,
+];
+
+// ---------------------------------------------------------------------------
+// TierLatencyStats - how long users waited, per tier
+// ---------------------------------------------------------------------------
+
+/// Ticks from spawn to settlement for the packets settled in one tier.
+/// Quantiles are upper bucket edges (see `Histogram::quantile`), so a
+/// service level checked against them errs strict.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct TierLatencyStats {
+    pub tier: MarketTier,
+    pub settlements: u32,
+    pub mean_ticks: f64,
+    pub p50_ticks: f64,
+    pub p95_ticks: f64,
+    pub p99_ticks: f64,
+    pub max_ticks: u64,
+    pub ticks: Histogram,
+}
+
+// ---------------------------------------------------------------------------
+// SettlementLatency - running per-tier histograms
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct SettlementLatency {
+    ticks: Vec<Histogram>,
+    sum_ticks: [u64; 4],
+    max_ticks: [u64; 4],
+}
+
+impl Default for SettlementLatency {
+    fn default() -> Self {
+        Self {
+            ticks: vec![Histogram::new(&LATENCY_EDGES); 4],
+            sum_ticks: [0; 4],
+            max_ticks: [0; 4],
+        }
+    }
+}
+
+impl SettlementLatency {
+    /// Record `payments` settlements in `tier`, `ticks` after their spawn.
+    pub fn record(&mut self, tier: MarketTier, ticks: u64, payments: u32) {
+        let t = tier as usize;
+        for _ in 0..payments {
+            self.ticks[t].record(ticks as f64);
+        }
+        self.sum_ticks[t] += ticks * u64::from(payments);
+        self.max_ticks[t] = self.max_ticks[t].max(ticks);
+    }
+
+    /// Quantile `q` of `tier`'s latency, as an upper bucket edge.
+    pub fn quantile(&self, tier: MarketTier, q: f64) -> f64 {
+        self.ticks[tier as usize].quantile(q)
+    }
+
+    pub fn stats(&self) -> Vec<TierLatencyStats> {
+        [MarketTier::L0, MarketTier::L1, MarketTier::L2, MarketTier::L3]
+            .into_iter()
+            .map(|tier| {
+                let t = tier as usize;
+                let settlements = self.ticks[t].total();
+                TierLatencyStats {
+                    tier,
+                    settlements,
+                    mean_ticks: if settlements == 0 { 0.0 } else { self.sum_ticks[t] as f64 / settlements as f64 },
+                    p50_ticks: self.ticks[t].quantile(0.50),
+                    p95_ticks: self.ticks[t].quantile(0.95),
+                    p99_ticks: self.ticks[t].quantile(0.99),
+                    max_ticks: self.max_ticks[t],
+                    ticks: self.ticks[t].clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantiles_by_tier() {
+        let mut latency = SettlementLatency::default();
+        for ticks in 1..=19 {
+            latency.record(MarketTier::L0, ticks, 1);
+        }
+        latency.record(MarketTier::L0, 60, 1);
+        latency.record(MarketTier::L2, 400, 3);
+
+        let stats = latency.stats();
+        let l0 = &stats[0];
+        assert_eq!((l0.settlements, l0.max_ticks), (20, 60));
+        assert!((l0.mean_ticks - 250.0 / 20.0).abs() < 1e-12);
+        // 19 of 20 settle within 20 ticks: p95 sits in the 15-20 bucket
+        assert_eq!((l0.p50_ticks, l0.p95_ticks, l0.p99_ticks), (15.0, 20.0, 75.0));
+        assert_eq!(stats[1].settlements, 0);
+        assert_eq!((stats[2].settlements, stats[2].p50_ticks), (3, 500.0));
+        assert_eq!(latency.quantile(MarketTier::L2, 0.95), 500.0);
+    }
+}
@@ -8,6 +8,63 @@ pub mod governor;
 pub mod engauge;
 pub mod conservation;
 pub mod dissolution;
+pub mod histogram;
+pub mod cost;
+pub mod cohort;
+pub mod corridor;
+pub mod hop_trace;
+pub mod slippage;
+pub mod inventory;
+pub mod price_shock;
+pub mod receipts;
+pub mod regional_price;
+pub mod retry;
+pub mod speculation;
+pub mod field_dictionary;
+pub mod analytic;
+pub mod concentration;
+pub mod control_loop;
+pub mod topology;
+pub mod held_sweep;
+pub mod pacing;
+pub mod latency;
+pub mod liquidity_provider;
+pub mod resources;
+pub mod backlog;
+pub mod asset_ledger;
+pub mod capacity_router;
+pub mod throughput;
+pub mod priority_lanes;
+pub mod verification;
+pub mod availability;
+pub mod batching;
+pub mod compaction;
+pub mod checkpoint;
+pub mod discount;
+pub mod dust;
+pub mod aggregation;
+pub mod ratelimit;
+pub mod rebate;
+pub mod route_cache;
+pub mod routing_policy;
+pub mod transit_rewards;
+pub mod velocity_bonus;
+pub mod quote;
+pub mod stress;
+pub mod tier_ledger;
+pub mod egress_exit;
+pub mod egress_queue;
+pub mod panic_dynamics;
+pub mod panic_contagion;
+pub mod replenishment;
+pub mod rebalancing;
+pub mod adversary;
+pub mod collusion;
+pub mod oracle;
+pub mod volatility;
+pub mod elasticity;
+pub mod rng;
+pub mod ensemble;
 
 // Vendored core Caesar modules (production code, adapted for arena)
 pub mod core_types;
@@ -20,9 +77,10 @@ pub mod adapter;
 
 pub use types::*;
 pub use simulation::ArenaSimulation;
+pub use ensemble::ArenaEnsemble;
 
 use wasm_bindgen::prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 #[wasm_bindgen]
 extern "C" {
@@ -36,92 +94,25 @@ extern "C" {
 impl ArenaSimulation {
     #[wasm_bindgen(constructor)]
     pub fn new(node_count: u32) -> Self {
+        Self::from_topology(&topology::TopologyBuilder::new(node_count))
+    }
+
+    /// Build the network `topology` describes instead of the default grid.
+    pub fn from_topology(topology: &topology::TopologyBuilder) -> Self {
         #[cfg(target_arch = "wasm32")]
         std::panic::set_hook(Box::new(console_error_panic_hook::hook));
 
-        let mut nodes = Vec::new();
-        let mut node_buffers = HashMap::new();
-        let grid_width = 6;
-        let grid_height = 4;
-
-        for i in 0..node_count {
-            let role = match i % 4 {
-                0 => NodeRole::Ingress,
-                1 => NodeRole::Egress,
-                2 => NodeRole::Transit,
-                _ => NodeRole::NGauge,
-            };
-            // E9: Assign strategy cyclically
-            let strategy = match i % 3 {
-                0 => NodeStrategy::RiskAverse,
-                1 => NodeStrategy::Greedy,
-                _ => NodeStrategy::Passive,
-            };
-            let gx = (i % grid_width) as f64;
-            let gy = (i / grid_width) as f64;
-
-            let mut neighbors = Vec::new();
-            let row = i / grid_width;
-            let col = i % grid_width;
-            if col > 0 && (i - 1) < node_count { neighbors.push(i - 1); }
-            if col < grid_width - 1 && (i + 1) < node_count { neighbors.push(i + 1); }
-            if row > 0 && (i - grid_width) < node_count { neighbors.push(i - grid_width); }
-            if row < grid_height - 1 && (i + grid_width) < node_count {
-                neighbors.push(i + grid_width);
-            }
-
-            // Scale initial node inventory with network size
-            let base_crypto = 1000.0 * (node_count as f64 / 24.0).max(1.0);
-            // Egress nodes are well-capitalized settlement providers (500x base)
-            let inventory_crypto = if role == NodeRole::Egress {
-                base_crypto * 500.0
-            } else {
-                base_crypto
-            };
-
-            nodes.push(SimNode {
-                id: i, role, x: gx, y: gy,
-                inventory_fiat: 10000.0, inventory_crypto: inventory_crypto,
-                current_buffer_count: 0,
-                neighbors, distance_to_egress: u32::MAX,
-                total_fees_earned: 0.0, accumulated_work: 0.0,
-                strategy,
-                pressure: 0.0,
-                // v0.2 fields
-                transit_fee: 0.01,
-                bandwidth: 100.0,
-                latency: 1.0,
-                uptime: 1.0,
-                tier_preference: None,
-                upi_active: true,
-                ngauge_running: true,
-                kyc_valid: true,
-            });
-            node_buffers.insert(i, Vec::new());
-        }
+        let mut nodes = topology.build_nodes();
+        let node_buffers: BTreeMap<u32, Vec<SimPacket>> = nodes.iter().map(|n| (n.id, Vec::new())).collect();
 
-        // BFS to calculate distances
-        let mut queue = std::collections::VecDeque::new();
-        for node in &mut nodes {
-            if node.role == NodeRole::Egress {
-                node.distance_to_egress = 0;
-                queue.push_back(node.id);
-            }
-        }
-        while let Some(current_id) = queue.pop_front() {
-            let current_dist = nodes[current_id as usize].distance_to_egress;
-            let neighbors = nodes[current_id as usize].neighbors.clone();
-            for neighbor_id in neighbors {
-                let neighbor = &mut nodes[neighbor_id as usize];
-                if neighbor.distance_to_egress == u32::MAX {
-                    neighbor.distance_to_egress = current_dist + 1;
-                    queue.push_back(neighbor_id);
-                }
-            }
-        }
+        // Hop distances to Egress, kept current on role changes
+        routing::compute_egress_distances(&mut nodes);
+        let mut topology_issues = topology.link_issues();
+        topology_issues.extend(topology::check_nodes(&nodes));
+        let asset_ledger = asset_ledger::AssetLedger::opening(&nodes);
 
         Self {
-            nodes, packets: Vec::new(), message_queue: Vec::new(),
+            nodes, message_queue: Vec::new(),
             state: WorldState {
                 current_tick: 0, gold_price: 2600.0, peg_deviation: 0.0,
                 network_velocity: 0.0, demand_factor: 0.2, panic_level: 0.0,
@@ -137,6 +128,7 @@ impl ArenaSimulation {
                 spawn_count: 0,
                 organic_ratio: 1.0,
                 surge_multiplier: 1.0,
+                liquidity_lambda: 1.0,
                 // v0.2 fields
                 circuit_breaker_active: false,
                 ingress_throttle: 0.0,
@@ -148,88 +140,334 @@ impl ArenaSimulation {
                 speculation_component: 0.0,
                 float_component: 0.0,
                 tier_fee_rates: [0.0; 4],
+                tier_effective_cost: [0.0; 4],
+                settled_value_per_tick: 0.0,
+                egress_top1_share: 0.0,
+                egress_top3_share: 0.0,
+                egress_concentration_alert: false,
+                verification_fees_paid: 0.0,
+                verification_shortfalls: 0,
+                ngauge_utilization: 0.0,
+                quadrant_ticks: [0; 6],
+                quadrant_transition_count: 0,
+                settlement_batches: 0,
+                egress_inventory_drawn: 0.0,
+                batch_inventory_saved: 0.0,
+                trust_rebates_paid: 0.0,
+                trusted_settlements: 0,
+                rebate_pool_balance: 0.0,
+                volume_discount_forgone: 0.0,
+                discounted_settlements: 0,
+                discounted_value_share: 0.0,
+                dust_rejected: 0,
+                dust_surcharges: 0.0,
+                non_dust_settle_ticks: 0.0,
+                payments_aggregated: 0,
+                aggregate_packets: 0,
+                aggregated_payments_settled: 0,
+                route_cache_hit_rate: 0.0,
+                route_cache_invalidations: 0,
+                path_cache_hit_rate: 0.0,
+                path_cache_invalidations: 0,
+                velocity_bonus_paid: 0.0,
+                velocity_bonus_treasury: 0.0,
+                quote_drift: 0.0,
+                quote_mismatches: 0,
+                stress_index: 0.0,
+                orbit_no_liquid_egress: 0,
+                orbit_hop_limit: 0,
+                orbit_disabled_destination: 0,
+                egress_exits: 0,
+                egress_reentries: 0,
+                exited_egress_count: 0,
+                in_transit_float: 0.0,
+                realized_cost: 0.0,
+                demand_retention: 1.0,
+                fee_cap_breaches: 0,
+                payout_spread: 0.0,
+                payout_slippage: 0.0,
+                revert_reasons: [0; RevertReason::COUNT],
+                oracle_price: 2600.0,
+                oracle_staleness_ticks: 0,
+                retry_count: 0,
+                pending_retries: 0,
+                speculative_value_share: 0.0,
+                governor_decisions: 0,
+                fee_rate_reversals: 0,
+                peak_local_panic: 0.0,
+                panicked_nodes: 0,
+                throughput_deferred: 0,
+                egress_replenished: 0.0,
+                liquidity_runway_ticks: None,
+                rebalanced_volume: 0.0,
+                rebalancing_fees: 0.0,
+                rebalancing_in_flight: 0.0,
+                dropped_packets: 0,
+                censored_packets: 0,
+                misrouted_packets: 0,
+                route_concentration: 0.0,
+                fee_share_gini: 0.0,
+                cartel_steered_hops: 0,
+                cartel_fee_split: 0.0,
             },
-            node_buffers, total_input: 0.0, total_output: 0.0,
-            total_burned: 0.0, total_fees: 0.0,
+            node_buffers,
+            total_input: conservation::CompensatedSum::new(),
+            total_output: conservation::CompensatedSum::new(),
+            total_burned: conservation::CompensatedSum::new(),
+            total_fees: conservation::CompensatedSum::new(),
             total_rewards_egress: 0.0, total_rewards_transit: 0.0,
-            packet_id_counter: 0, max_active_packets: 1000,
+            packet_id_counter: 0,
             last_gold_price: 2600.0,
             settlement_count: 0, revert_count: 0,
             total_settlement_hops: 0, total_settlement_time: 0,
-            gold_price_history: vec![2600.0],
+            volatility_model: volatility::VolatilityModel::new(
+                volatility::VolatilityEstimator::RollingCv, volatility::DEFAULT_WINDOW, 2600.0),
             lambda_ema: 1.0,
+            float_ema: 0.0,
+            tick_settled_value: 0.0,
             conservation_law: conservation::ConservationLaw::default(),
             engauge_state: engauge::NGaugeState::default(),
             core_pid: crate::core_governor::pid::GovernorPid::new(),
+            peg_target: adapter::DEFAULT_PEG_TARGET,
             core_conservation: crate::core_conservation::ConservationLaw::new(
                 crate::adapter::to_decimal(1000.0), // High threshold — parallel validation only
             ),
+            settlement_costs: cost::SettlementCostTracker::new(),
+            egress_concentration: concentration::EgressConcentration::new(),
+            route_concentration: concentration::RouteConcentration::new(),
+            egress_draws: egress_queue::EgressDrawRates::default(),
+            verification: verification::VerificationMarket::new(),
+            total_verification_fees: 0.0,
+            verification_shortfalls: 0,
+            kyc_validity_ticks: None,
+            governance_pressure: core_governor::PressureQuadrant::GoldenEra,
+            quadrant_transitions: Vec::new(),
+            settlement_batch_window: None,
+            batch_ledger: batching::BatchLedger::new(),
+            demurrage_grace_ticks: 0,
+            rebate_pool: rebate::RebatePool::new(),
+            volume_discounts: None,
+            rate_limiter: None,
+            dust_guard: dust::DustGuard::new(),
+            aggregator: None,
+            aggregated_payments_settled: 0,
+            compacted_entries: 0,
+            route_window: None,
+            routing_mode: routing::RoutingMode::default(),
+            route_scoring: routing::RouteScoring::default(),
+            capacity_router: None,
+            packets_per_mbps: 0.0,
+            priority_lanes: None,
+            routing_policy: routing_policy::RoutingPolicy::default(),
+            route_cache: None,
+            path_cache: route_cache::PathCache::new(),
+            transit_weighting: transit_rewards::TransitWeighting::default(),
+            transit_holds: transit_rewards::HoldLedger::new(),
+            velocity_bonus: velocity_bonus::VelocityBonus::default(),
+            tier_fee_modifiers: [1.0; 4],
+            pricing_mode: quote::PricingMode::default(),
+            quote_drift: 0.0,
+            quote_mismatches: 0,
+            fee_cap_breaches: 0,
+            stress: stress::StressTracker::new(),
+            tier_ledger: tier_ledger::TierLedger::new(),
+            egress_lifecycle: None,
+            panic_model: None,
+            panic_contagion: None,
+            replenishment: None,
+            rebalancing: None,
+            demand_elasticity: elasticity::DemandElasticity::default(),
+            cohorts: cohort::CohortTracker::default(),
+            traffic_rng: None,
+            master_seed: None,
+            latency_jitter_ticks: 0,
+            latency_rng: rng::SplitMix64::new(rng::stream_seed(0, rng::Stream::Latency)),
+            frozen_fees: None,
+            hop_trace: None,
+            payout_slippage: None,
+            price_shocks: price_shock::PriceShocks::default(),
+            revert_log: receipts::RevertLog::default(),
+            corridors: corridor::Corridors::default(),
+            oracle: oracle::PriceOracle::new(2600.0),
+            retries: retry::RetryQueue::default(),
+            regional_prices: regional_price::RegionalPrices::default(),
+            speculators: speculation::Speculators::default(),
+            liquidity_providers: liquidity_provider::LiquidityProviders::default(),
+            control_loop: control_loop::ControlLoop::default(),
+            log_governor_io: false,
+            governor_io: None,
+            last_node_updates: Vec::new(),
+            removed_nodes: BTreeSet::new(),
+            pacer: None,
+            severed_links: Vec::new(),
+            checkpoints: None,
+            censored_origins: BTreeSet::new(),
+            cartel: None,
+            node_fee_caps: BTreeMap::new(),
+            settlement_latency: latency::SettlementLatency::default(),
+            topology_issues,
+            topology: topology.clone(),
+            resources: None,
+            asset_ledger,
         }
     }
 
+    #[wasm_bindgen(unchecked_return_type = "TickResult")]
     pub fn tick(&mut self) -> JsValue {
         let result = self.tick_core();
         serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
     }
 
+    /// Returns the new packet id, `ratelimit::REJECTED_PACKET_ID` when the
+    /// ingress rate limiter or dust policy turns the packet away, or
+    /// `aggregation::AGGREGATED_PACKET_ID` when it is held for aggregation.
     pub fn spawn_packet(&mut self, node_id: u32, amount: f64) -> u64 {
-        let p_id = self.packet_id_counter;
-        self.packet_id_counter += 1;
-        let tier = MarketTier::from_value(amount);
-        let p = SimPacket {
-            id: p_id, original_value: amount, current_value: amount,
-            arrival_tick: self.state.current_tick, status: PacketStatus::Minted,
-            origin_node: node_id, target_node: None, hops: 0,
-            route_history: vec![node_id],
-            orbit_start_tick: None,
-            tier,
-            ttl: self.state.current_tick + tier.ttl_ticks(),
-            hop_limit: tier.hop_limit(),
-            fee_budget: tier.fee_cap() * amount,
-            fees_consumed: 0.0,
-            fee_schedule: Vec::new(),
-            spawn_tick: self.state.current_tick,
+        let Some(surcharge) = self.admit_spawn(node_id, amount) else {
+            return ratelimit::REJECTED_PACKET_ID;
         };
-        self.total_input += amount;
-        self.node_buffers.entry(node_id).or_default().push(p);
-        self.nodes[node_id as usize].current_buffer_count += 1;
-        p_id
+        let tick = self.state.current_tick;
+        if let Some(agg) = self.aggregator.as_mut() {
+            if agg.offer(node_id, amount, surcharge, tick) {
+                return aggregation::AGGREGATED_PACKET_ID;
+            }
+        }
+        self.mint_packet(node_id, amount, surcharge, Vec::new(), None)
+    }
+
+    /// Pre-load a congested network before the first tick: `packets` split
+    /// by `tier_mix` (weights of L0..L3; missing entries are 0), aged evenly
+    /// over `min_age_ticks..=max_age_ticks`, with `in_transit_share` already
+    /// on a link. Returns the packets seeded (0 once the clock has started).
+    pub fn seed_backlog(
+        &mut self,
+        packets: u32,
+        tier_mix: Vec<f64>,
+        min_age_ticks: u32,
+        max_age_ticks: u32,
+        in_transit_share: f64,
+    ) -> u32 {
+        let mut mix = [0.0; 4];
+        for (m, &w) in mix.iter_mut().zip(&tier_mix) {
+            *m = w;
+        }
+        self.seed_backlog_core(&backlog::BacklogSpec {
+            packets,
+            tier_mix: mix,
+            min_age_ticks: min_age_ticks.into(),
+            max_age_ticks: max_age_ticks.into(),
+            in_transit_share,
+        })
     }
 
+    #[wasm_bindgen(unchecked_return_type = "SimNode[]")]
     pub fn get_nodes(&self) -> JsValue {
         serde_wasm_bindgen::to_value(&self.nodes).unwrap_or(JsValue::NULL)
     }
 
-    pub fn set_gold_price(&mut self, val: f64) { self.state.gold_price = val; }
+    /// Filtered, paginated node listing for large fleets. `role` is the
+    /// numeric `NodeRole` discriminant; an unknown role matches nothing.
+    #[wasm_bindgen(unchecked_return_type = "SimNode[]")]
+    pub fn get_nodes_filtered(
+        &self,
+        role: Option<u32>,
+        min_pressure: Option<f64>,
+        offset: u32,
+        limit: u32,
+    ) -> JsValue {
+        let role = match role.map(NodeRole::from_u32) {
+            Some(None) => {
+                return serde_wasm_bindgen::to_value(&Vec::<SimNode>::new()).unwrap_or(JsValue::NULL)
+            }
+            r => r.flatten(),
+        };
+        let nodes = self.get_nodes_filtered_core(role, min_pressure, offset as usize, limit as usize);
+        serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
+    }
+
+    /// Every node's pressure, indexed by node id, as a `Float64Array` copied
+    /// straight out of wasm memory: the visualization hot path without serde.
+    pub fn get_pressures_f64(&self) -> Vec<f64> {
+        self.nodes.iter().map(|n| n.pressure).collect()
+    }
+
+    /// Every node's trust score, indexed by node id, as a `Float64Array`.
+    pub fn get_trust_f64(&self) -> Vec<f64> {
+        self.nodes.iter().map(|n| n.trust_score).collect()
+    }
+
+    /// Every node's crypto inventory, indexed by node id, as a `Float64Array`.
+    pub fn get_inventory_crypto_f64(&self) -> Vec<f64> {
+        self.nodes.iter().map(|n| n.inventory_crypto).collect()
+    }
+
+    /// Every node's buffered packet count, indexed by node id, as a `Uint32Array`.
+    pub fn get_buffer_counts_u32(&self) -> Vec<u32> {
+        self.nodes.iter().map(|n| n.current_buffer_count).collect()
+    }
+
+    pub fn set_gold_price(&mut self, val: f64) {
+        self.state.gold_price = val;
+        self.price_shocks.rebase();
+    }
     pub fn set_demand_factor(&mut self, val: f64) { self.state.demand_factor = val; }
     pub fn set_panic_level(&mut self, val: f64) { self.state.panic_level = val; }
 
+    #[wasm_bindgen(unchecked_return_type = "SimStats")]
     pub fn get_stats(&self) -> JsValue {
-        let orbit_count = self.node_buffers.values().flatten()
-            .filter(|p| p.status == PacketStatus::Held)
-            .count() as u32;
-        let active_val: f64 = self.node_buffers.values().flatten()
-            .map(|p| p.current_value).sum::<f64>()
-            + self.message_queue.iter().map(|p| p.current_value).sum::<f64>();
-        let stats = SimStats {
-            total_input: self.total_input,
-            total_output: self.total_output,
-            total_burned: self.total_burned,
-            total_fees: self.total_fees,
-            total_leaked: (self.total_input
-                - (self.total_output + self.total_burned
-                    + self.total_fees + active_val)).abs(),
-            settlement_count: self.settlement_count,
-            revert_count: self.revert_count,
-            orbit_count,
-            avg_hops: if self.settlement_count > 0 {
-                self.total_settlement_hops as f64 / self.settlement_count as f64
-            } else { 0.0 },
-            avg_time_to_settle: if self.settlement_count > 0 {
-                self.total_settlement_time as f64 / self.settlement_count as f64
-            } else { 0.0 },
-        };
-        serde_wasm_bindgen::to_value(&stats).unwrap_or(JsValue::NULL)
+        serde_wasm_bindgen::to_value(&self.get_stats_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Unit, kind (cumulative or instantaneous) and formula reference of
+    /// every WorldState and SimStats field.
+    #[wasm_bindgen(unchecked_return_type = "FieldSpec[]")]
+    pub fn describe_state(&self) -> JsValue {
+        let fields: Vec<_> = field_dictionary::describe_state().collect();
+        serde_wasm_bindgen::to_value(&fields).unwrap_or(JsValue::NULL)
+    }
+
+    /// Estimated size of the packet containers and histories that grow with
+    /// run length; compaction every `COMPACTION_INTERVAL_TICKS` keeps it flat.
+    #[wasm_bindgen(unchecked_return_type = "MemoryFootprint")]
+    pub fn get_memory_footprint(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_memory_footprint_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Per-tier effective all-in cost and implied spread of settled packets.
+    #[wasm_bindgen(unchecked_return_type = "TierCostStats[]")]
+    pub fn get_settlement_costs(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_settlement_costs_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Per-tier spawn-to-settlement latency of settled packets, with p50/p95/p99.
+    #[wasm_bindgen(unchecked_return_type = "TierLatencyStats[]")]
+    pub fn get_settlement_latency(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_settlement_latency_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Problems with the network found when it was built: stranded Ingress,
+    /// unreachable Egress, missing roles, malformed explicit links.
+    #[wasm_bindgen(unchecked_return_type = "TopologyIssue[]")]
+    pub fn get_topology_issues(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(self.get_topology_issues_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// The same check on the network as it stands now.
+    #[wasm_bindgen(unchecked_return_type = "TopologyIssue[]")]
+    pub fn check_topology(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.check_topology_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Spawn cohorts and how their packets resolved by age (vintage analysis).
+    #[wasm_bindgen(unchecked_return_type = "CohortStats[]")]
+    pub fn get_cohorts(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_cohorts_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Per-node hop dwell and hop fee attribution; empty unless hop tracing
+    /// is on.
+    #[wasm_bindgen(unchecked_return_type = "NodeHopStats[]")]
+    pub fn get_hop_stats(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_hop_stats_core()).unwrap_or(JsValue::NULL)
     }
 
     pub fn kill_node(&mut self, node_id: u32) {
@@ -248,9 +486,190 @@ impl ArenaSimulation {
                     }
                 }
             }
+            routing::update_egress_distances(&mut self.nodes, node_id);
+            self.invalidate_route_cache();
+        }
+    }
+
+    /// Bring a Disabled node back online with the given `NodeRole` discriminant.
+    /// Its trust score is rebuilt from its availability, not restored.
+    pub fn revive_node(&mut self, node_id: u32, role: u32) {
+        let role = match NodeRole::from_u32(role) {
+            Some(NodeRole::Disabled) | None => return,
+            Some(r) => r,
+        };
+        if self.removed_nodes.contains(&node_id) {
+            return;
+        }
+        if let Some(node) = self.nodes.get_mut(node_id as usize) {
+            if node.role == NodeRole::Disabled {
+                node.role = role;
+                node.current_buffer_count = 0;
+                self.node_buffers.entry(node_id).or_default();
+                routing::update_egress_distances(&mut self.nodes, node_id);
+                self.invalidate_route_cache();
+            }
+        }
+    }
+
+    /// Join a node to the running network with the given `NodeRole` and
+    /// `NodeStrategy` discriminants, linked both ways to each live node in
+    /// `neighbors`. It starts with the network-scaled inventory for the
+    /// network it joins and the site of its first neighbor, or a new site of
+    /// its own. Returns its id, or `u32::MAX` for an unknown role or strategy.
+    pub fn add_node(&mut self, role: u32, strategy: u32, neighbors: Vec<u32>) -> u32 {
+        let (Some(role), Some(strategy)) = (NodeRole::from_u32(role), NodeStrategy::from_u32(strategy)) else {
+            return u32::MAX;
+        };
+        let id = self.nodes.len() as u32;
+        let mut links: Vec<u32> = Vec::new();
+        for n in neighbors {
+            if (n as usize) < self.nodes.len() && !self.removed_nodes.contains(&n) && !links.contains(&n) {
+                links.push(n);
+            }
+        }
+        for &n in &links {
+            self.nodes[n as usize].neighbors.push(id);
+        }
+        // Just off the centroid of its neighbors, or at the origin alone
+        let position = if links.is_empty() {
+            (0.0, 0.0)
+        } else {
+            let k = links.len() as f64;
+            let (sx, sy) = links.iter()
+                .fold((0.0, 0.0), |(sx, sy), &n| (sx + self.nodes[n as usize].x, sy + self.nodes[n as usize].y));
+            (sx / k + 0.5, sy / k + 0.5)
+        };
+        let live = self.nodes.iter().filter(|n| n.role != NodeRole::Disabled);
+        let node_count = live.clone().count() as u32 + 1;
+        let egress_count = live.filter(|n| n.role == NodeRole::Egress).count() as u32
+            + u32::from(role == NodeRole::Egress);
+        let site = match links.first() {
+            Some(&n) => self.nodes[n as usize].site,
+            None => self.nodes.iter().map(|n| n.site + 1).max().unwrap_or(0),
+        };
+        let mut node = topology::new_node(id, role, strategy, links, position, node_count, egress_count);
+        node.site = site;
+        self.asset_ledger.provision(node.inventory_fiat, node.inventory_crypto);
+        self.nodes.push(node);
+        self.node_buffers.insert(id, Vec::new());
+        routing::compute_egress_distances(&mut self.nodes);
+        self.invalidate_route_cache();
+        id
+    }
+
+    /// Take a node out of the network for good: its links are cut on both
+    /// sides and its buffered packets are spread round-robin over its live
+    /// former neighbors, while packets in flight to it land on the first.
+    /// With no live neighbor, both are refunded. Its id stays reserved,
+    /// Disabled, so ids keep indexing nodes.
+    /// Returns whether a node was removed.
+    pub fn remove_node(&mut self, node_id: u32) -> bool {
+        if node_id as usize >= self.nodes.len() || !self.removed_nodes.insert(node_id) {
+            return false;
+        }
+        let node = &mut self.nodes[node_id as usize];
+        node.role = NodeRole::Disabled;
+        node.current_buffer_count = 0;
+        let former = std::mem::take(&mut node.neighbors);
+        for &n in &former {
+            self.nodes[n as usize].neighbors.retain(|&m| m != node_id);
+        }
+        let heirs: Vec<u32> = former.into_iter()
+            .filter(|&n| self.nodes[n as usize].role != NodeRole::Disabled)
+            .collect();
+
+        let tick = self.state.current_tick;
+        let packets = self.node_buffers.remove(&node_id).unwrap_or_default();
+        for (i, mut p) in packets.into_iter().enumerate() {
+            if heirs.is_empty() {
+                self.refund(&mut p, tick, RevertReason::NodeRemoved);
+                continue;
+            }
+            let dest = heirs[i % heirs.len()];
+            p.target_node = None;
+            p.status = PacketStatus::Minted;
+            self.nodes[dest as usize].current_buffer_count += 1;
+            self.node_buffers.entry(dest).or_default().push(p);
+        }
+        // In-flight packets land on an heir; with none, they are refunded
+        // like the buffered ones rather than held where no cycle reaches
+        match heirs.first() {
+            Some(&dest) => {
+                for p in self.message_queue.iter_mut().filter(|p| p.target_node == Some(node_id)) {
+                    p.target_node = Some(dest);
+                }
+            }
+            None => {
+                let (stranded, in_flight): (Vec<_>, Vec<_>) = std::mem::take(&mut self.message_queue)
+                    .into_iter()
+                    .partition(|p| p.target_node == Some(node_id));
+                self.message_queue = in_flight;
+                for mut p in stranded {
+                    self.refund(&mut p, tick, RevertReason::NodeRemoved);
+                }
+            }
         }
+
+        routing::compute_egress_distances(&mut self.nodes);
+        self.invalidate_route_cache();
+        true
     }
 
+    /// Island `group_a` from `group_b` by cutting every link between them
+    /// until `heal_partition`. Returns the number of directed links cut.
+    pub fn partition_network(&mut self, group_a: Vec<u32>, group_b: Vec<u32>) -> u32 {
+        self.partition_network_core(&group_a, &group_b)
+    }
+
+    /// Restore the links cut by `partition_network`; packets stranded on
+    /// either side route across again. Returns the number of directed links
+    /// restored.
+    pub fn heal_partition(&mut self) -> u32 {
+        self.heal_partition_core()
+    }
+
+    /// The most recent governor quadrant changes (up to
+    /// `MAX_QUADRANT_TRANSITIONS`), oldest first.
+    #[wasm_bindgen(unchecked_return_type = "QuadrantTransition[]")]
+    pub fn get_quadrant_transitions(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(self.get_quadrant_transitions_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// The most recent revert receipts (up to `receipts::MAX_REVERT_RECEIPTS`), oldest first.
+    #[wasm_bindgen(unchecked_return_type = "RevertReceipt[]")]
+    pub fn get_revert_receipts(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_revert_receipts_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Refund a packet buffered at a node to its sender at its current
+    /// value, as the sender withdrawing it. Packets on a link can't be
+    /// recalled. Returns whether the packet was cancelled.
+    pub fn cancel_packet(&mut self, packet_id: u64) -> bool {
+        let tick = self.state.current_tick;
+        let found = self.node_buffers.iter_mut()
+            .find_map(|(&node_id, buf)| {
+                buf.iter().position(|p| p.id == packet_id).map(|i| (node_id, buf.remove(i)))
+            });
+        let Some((node_id, mut p)) = found else {
+            return false;
+        };
+        self.refund(&mut p, tick, RevertReason::Cancelled);
+        let node = &mut self.nodes[node_id as usize];
+        node.current_buffer_count = node.current_buffer_count.saturating_sub(1);
+        true
+    }
+
+    /// End-of-run sweep of Held packets by `SweepPolicy` discriminant:
+    /// 0 = classify only, 1 = refund, 2 = dissolve, 3 = dissolve, refunding
+    /// what cannot be dissolved. Unknown values classify only.
+    #[wasm_bindgen(unchecked_return_type = "HeldSweepReport")]
+    pub fn sweep_held(&mut self, policy: u32) -> JsValue {
+        let policy = held_sweep::SweepPolicy::from_u32(policy).unwrap_or_default();
+        serde_wasm_bindgen::to_value(&self.sweep_held_core(policy)).unwrap_or(JsValue::NULL)
+    }
+
+    #[wasm_bindgen(unchecked_return_type = "SimPacket | null")]
     pub fn get_packet(&self, packet_id: u64) -> JsValue {
         let packet = self.node_buffers.values()
             .flat_map(|b| b.iter())
@@ -262,22 +681,847 @@ impl ArenaSimulation {
         }
     }
 
-    /// Run N ticks without returning results (fast batch mode for benchmarking)
-    pub fn run_batch(&mut self, ticks: u32) {
-        for _ in 0..ticks {
-            self.tick_core();
+    /// Active packets filtered by numeric `PacketStatus` / `MarketTier`
+    /// discriminants and node, capped at `limit`. Unknown codes match nothing.
+    #[wasm_bindgen(unchecked_return_type = "SimPacket[]")]
+    pub fn get_active_packets(
+        &self,
+        status: Option<u32>,
+        tier: Option<u32>,
+        node_id: Option<u32>,
+        limit: u32,
+    ) -> JsValue {
+        let empty = || serde_wasm_bindgen::to_value(&Vec::<SimPacket>::new()).unwrap_or(JsValue::NULL);
+        let status = match status.map(PacketStatus::from_u32) {
+            Some(None) => return empty(),
+            s => s.flatten(),
+        };
+        let tier = match tier.map(MarketTier::from_u32) {
+            Some(None) => return empty(),
+            t => t.flatten(),
+        };
+        let packets = self.get_active_packets_core(status, tier, node_id, limit as usize);
+        serde_wasm_bindgen::to_value(&packets).unwrap_or(JsValue::NULL)
+    }
+
+    /// Packets heading to Egress `node_id` by value and tier, with the ticks
+    /// until its inventory runs out at its current draw rate. Null unless
+    /// the node is an Egress.
+    #[wasm_bindgen(unchecked_return_type = "EgressQueue | null")]
+    pub fn get_egress_queue(&self, node_id: u32) -> JsValue {
+        match self.get_egress_queue_core(node_id) {
+            Some(q) => serde_wasm_bindgen::to_value(&q).unwrap_or(JsValue::NULL),
+            None => JsValue::NULL,
+        }
+    }
+
+    /// Run N ticks without per-tick results (fast batch mode for benchmarking),
+    /// returning a `BatchSummary` of what happened over the batch.
+    #[wasm_bindgen(unchecked_return_type = "BatchSummary")]
+    pub fn run_batch(&mut self, ticks: u32) -> JsValue {
+        let summary = self.run_batch_core(ticks);
+        serde_wasm_bindgen::to_value(&summary).unwrap_or(JsValue::NULL)
+    }
+
+    /// Run the ticks due at host time `now_ms` under real-time pacing (see
+    /// `set_tick_interval_ms`), returning their `BatchSummary`. Call it from
+    /// the host's frame or timer loop; it runs nothing while pacing is off.
+    #[wasm_bindgen(unchecked_return_type = "BatchSummary")]
+    pub fn advance_to(&mut self, now_ms: f64) -> JsValue {
+        let summary = self.advance_to_core(now_ms);
+        serde_wasm_bindgen::to_value(&summary).unwrap_or(JsValue::NULL)
+    }
+
+    #[wasm_bindgen(unchecked_return_type = "PacingStats | null")]
+    pub fn get_pacing_stats(&self) -> JsValue {
+        match self.get_pacing_stats_core() {
+            Some(stats) => serde_wasm_bindgen::to_value(&stats).unwrap_or(JsValue::NULL),
+            None => JsValue::NULL,
+        }
+    }
+
+    /// Tick until `metric` is stationary (see `run_until_converged_core`).
+    /// Unknown metric names return null without advancing the simulation.
+    #[wasm_bindgen(unchecked_return_type = "ConvergenceResult | null")]
+    pub fn run_until_converged(
+        &mut self,
+        metric: &str,
+        tolerance: f64,
+        window: u32,
+        max_ticks: u32,
+    ) -> JsValue {
+        match ConvergenceMetric::from_name(metric) {
+            Some(m) => {
+                let result = self.run_until_converged_core(m, tolerance, window as usize, max_ticks as u64);
+                serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+            }
+            None => JsValue::NULL,
         }
     }
 
     pub fn set_node_crypto(&mut self, node_id: u32, val: f64) {
         if let Some(node) = self.nodes.get_mut(node_id as usize) {
+            self.asset_ledger.provision(0.0, val - node.inventory_crypto);
             node.inventory_crypto = val;
         }
     }
 
-    /// Reset simulation to initial state
+    /// Move gold by `magnitude_pct` percent from next tick, shaped by
+    /// `ShockShape` discriminant over `duration_ticks`: 0 = step (held, then
+    /// reverted), 1 = spike (decaying back), 2 = ramp (built up, then held).
+    /// Shocks compose with each other and with whatever sets the spot price.
+    /// Unknown shapes are ignored.
+    pub fn apply_price_shock(&mut self, magnitude_pct: f64, duration_ticks: u32, shape: u32) {
+        if let Some(shape) = price_shock::ShockShape::from_u32(shape) {
+            self.price_shocks.push(price_shock::PriceShock {
+                magnitude: magnitude_pct / 100.0,
+                start_tick: self.state.current_tick + 1,
+                duration_ticks: u64::from(duration_ticks),
+                shape,
+            });
+        }
+    }
+
+    /// Freeze the gold price feed for `duration_ticks` from next tick: the
+    /// governor keeps seeing the last published price while gold moves on.
+    /// Once the price is `pid::ORACLE_STALE_PERIODS` old the governor holds its
+    /// parameters with a widening fee premium and raises verification
+    /// complexity until the feed returns.
+    pub fn apply_oracle_outage(&mut self, duration_ticks: u32) {
+        self.oracle.begin_outage(self.state.current_tick + 1, u64::from(duration_ticks));
+    }
+
+    /// Re-size every node's starting inventory by `InventoryPolicy`
+    /// discriminant: 0 = scaled with node count (default), 1 = the 24-node
+    /// network's per-node inventory, 2 = proportional to expected arrivals
+    /// per Egress, 3 = whitepaper-calibrated Egress reserves. Demand-driven
+    /// policies expect `arrivals_per_tick` packets of `mean_packet_value`;
+    /// non-positive values fall back to the engine's own traffic at the
+    /// current demand. Overwrites inventory, so call it before the first tick.
+    /// Unknown values are ignored.
+    pub fn set_initial_inventory(&mut self, policy: u32, arrivals_per_tick: f64, mean_packet_value: f64) {
+        let Some(policy) = inventory::InventoryPolicy::from_u32(policy) else {
+            return;
+        };
+        let auto = inventory::ExpectedDemand::auto_traffic(self.state.demand_factor);
+        let demand = inventory::ExpectedDemand {
+            arrivals_per_tick: if arrivals_per_tick > 0.0 { arrivals_per_tick } else { auto.arrivals_per_tick },
+            mean_packet_value: if mean_packet_value > 0.0 { mean_packet_value } else { auto.mean_packet_value },
+        };
+        let node_count = self.nodes.len() as u32;
+        let egress_count = self.nodes.iter().filter(|n| n.role == NodeRole::Egress).count() as u32;
+        for node in &mut self.nodes {
+            let (fiat, crypto) = inventory::initial_inventory(policy, node.role, node_count, egress_count, demand);
+            self.asset_ledger.provision(fiat - node.inventory_fiat, crypto - node.inventory_crypto);
+            node.inventory_fiat = fiat;
+            node.inventory_crypto = crypto;
+        }
+    }
+
+    // Dissolution-qualification attributes (see `dissolution::GravityQualification`)
+
+    pub fn set_node_upi_active(&mut self, node_id: u32, active: bool) {
+        if let Some(node) = self.nodes.get_mut(node_id as usize) {
+            node.upi_active = active;
+        }
+    }
+
+    pub fn set_node_ngauge_running(&mut self, node_id: u32, running: bool) {
+        if let Some(node) = self.nodes.get_mut(node_id as usize) {
+            node.ngauge_running = running;
+        }
+    }
+
+    /// Set KYC status. A fresh attestation restarts the expiry clock.
+    pub fn set_node_kyc_valid(&mut self, node_id: u32, valid: bool) {
+        let tick = self.state.current_tick;
+        if let Some(node) = self.nodes.get_mut(node_id as usize) {
+            node.kyc_valid = valid;
+            if valid {
+                node.kyc_attested_tick = tick;
+            }
+        }
+    }
+
+    pub fn set_node_bandwidth(&mut self, node_id: u32, bandwidth: f64) {
+        if let Some(node) = self.nodes.get_mut(node_id as usize) {
+            node.bandwidth = bandwidth.max(0.0);
+        }
+    }
+
+    /// Set `node_id`'s behavior by `NodeStrategy` discriminant, adversaries
+    /// (3-6) included; unknown strategies are ignored.
+    pub fn set_node_strategy(&mut self, node_id: u32, strategy: u32) {
+        if let (Some(node), Some(strategy)) = (self.nodes.get_mut(node_id as usize), NodeStrategy::from_u32(strategy)) {
+            node.strategy = strategy;
+        }
+    }
+
+    /// Origins whose packets SelectiveCensor nodes refuse (none by default).
+    pub fn set_censored_origins(&mut self, origins: Vec<u32>) {
+        self.censored_origins = origins.into_iter().collect();
+    }
+
+    /// Make `members` a colluding cartel, replacing any before it; an empty
+    /// list disbands it. Disabled or removed nodes are left out, and members
+    /// leave once they go down. Fees earned before forming are not split.
+    pub fn set_cartel(&mut self, members: Vec<u32>) {
+        self.cartel = (!members.is_empty()).then(|| collusion::Cartel::new(members, &self.nodes));
+    }
+
+    /// Cap the packets `node_id` settles or forwards per tick; the rest wait
+    /// in its buffer for the next. 0 removes the cap.
+    pub fn set_node_max_packets_per_tick(&mut self, node_id: u32, max: u32) {
+        if let Some(node) = self.nodes.get_mut(node_id as usize) {
+            node.max_packets_per_tick = max;
+        }
+    }
+
+    /// Hold every node to `bandwidth x packets_per_mbps` settlements and
+    /// forwards per tick, on top of any per-node cap. 0 (default) leaves
+    /// bandwidth unenforced.
+    pub fn set_packets_per_mbps(&mut self, packets_per_mbps: f64) {
+        self.packets_per_mbps = packets_per_mbps.max(0.0);
+    }
+
+    /// Serve capped node buffers L3 first down to L0, except that a packet
+    /// waiting `starvation_ticks` or more at a node goes ahead of every
+    /// tier. 0 (default) serves them in arrival order.
+    pub fn set_priority_lanes(&mut self, starvation_ticks: u32) {
+        self.priority_lanes = (starvation_ticks > 0)
+            .then(|| priority_lanes::PriorityLanes::new(u64::from(starvation_ticks)));
+    }
+
+    /// Pay dissolution shares by `DissolutionPayout` discriminant: 0 = fiat
+    /// credit (default), 1 = crypto inventory, 2 = treasury claim. Unknown
+    /// values are ignored.
+    pub fn set_dissolution_payout(&mut self, payout: u32) {
+        if let Some(payout) = dissolution::DissolutionPayout::from_u32(payout) {
+            self.asset_ledger.set_payout(payout);
+        }
+    }
+
+    /// Checkpoint the network every `every_ticks` ticks, whole every
+    /// `keyframe_every` checkpoints and as a delta on the one before
+    /// otherwise (1 = always whole). 0 ticks turns checkpoints off and drops
+    /// those taken.
+    pub fn set_checkpointing(&mut self, every_ticks: u32, keyframe_every: u32) {
+        self.checkpoints = (every_ticks > 0)
+            .then(|| checkpoint::CheckpointLog::new(u64::from(every_ticks), keyframe_every));
+    }
+
+    /// The checkpoint taken at `tick`, replayed from its keyframe; null if
+    /// none was taken then.
+    #[wasm_bindgen(unchecked_return_type = "Checkpoint | null")]
+    pub fn get_checkpoint(&self, tick: u64) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_checkpoint_core(tick)).unwrap_or(JsValue::NULL)
+    }
+
+    /// Checkpoints and keyframes taken, and the nodes and packets stored
+    /// against keeping each whole; null while checkpoints are off.
+    #[wasm_bindgen(unchecked_return_type = "CheckpointStats | null")]
+    pub fn get_checkpoint_stats(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_checkpoint_stats_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Node fiat and crypto inventories reconciled against the flows that
+    /// moved them, and the dissolution owed as treasury claims.
+    #[wasm_bindgen(unchecked_return_type = "AssetLedgerReport")]
+    pub fn get_asset_ledger(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_asset_ledger_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// KYC attestations lapse `ticks` after they were made; `None` disables expiry.
+    pub fn set_kyc_validity_ticks(&mut self, ticks: Option<u32>) {
+        self.kyc_validity_ticks = ticks.map(u64::from);
+    }
+
+    /// Batch egress settlements per corridor over windows of `ticks`;
+    /// `None` (or 0) settles each packet on arrival.
+    pub fn set_settlement_batch_window(&mut self, ticks: Option<u32>) {
+        self.settlement_batch_window = ticks.filter(|&t| t > 0).map(u64::from);
+    }
+
+    /// Pace `advance_to` at one tick every `interval_ms` of host time,
+    /// catching up at most `max_catch_up` ticks after a stall (default
+    /// `pacing::DEFAULT_MAX_CATCH_UP_TICKS`). None or 0 turns pacing off.
+    pub fn set_tick_interval_ms(&mut self, interval_ms: Option<f64>, max_catch_up: Option<u32>) {
+        self.pacer = interval_ms.filter(|&ms| ms > 0.0).map(|ms| {
+            pacing::RealTimePacer::new(ms, max_catch_up.unwrap_or(pacing::DEFAULT_MAX_CATCH_UP_TICKS))
+        });
+    }
+
+    /// Packets do not decay for their first `ticks` ticks after spawn (0 = no grace).
+    pub fn set_demurrage_grace_ticks(&mut self, ticks: u32) {
+        self.demurrage_grace_ticks = u64::from(ticks);
+    }
+
+    /// Cap the fees node `node_id` charges on `MarketTier` discriminant
+    /// `tier` packets at `cap` of packet value, for jurisdiction pilots (a
+    /// regulated Egress at half the L0 cap is 0.025). Caps only tighten: one
+    /// looser than the constitutional cap, or an earlier override, is ignored.
+    pub fn set_node_fee_cap(&mut self, node_id: u32, tier: u32, cap: f64) {
+        let Some(tier) = MarketTier::from_u32(tier) else {
+            return;
+        };
+        if (node_id as usize) < self.nodes.len() {
+            self.node_fee_caps.entry(node_id).or_default().tighten(tier, cap);
+        }
+    }
+
+    /// Drop node `node_id`'s pilot fee caps, back to the constitutional caps.
+    pub fn clear_node_fee_caps(&mut self, node_id: u32) {
+        self.node_fee_caps.remove(&node_id);
+    }
+
+    /// Origins whose rolling settled volume reaches `threshold` pay `rate`
+    /// (0..1) less base settlement fee; a rate of 0 disables discounts.
+    pub fn set_volume_discount(&mut self, threshold: f64, rate: f64) {
+        self.volume_discounts =
+            (rate > 0.0).then(|| discount::VolumeDiscounts::new(threshold, rate));
+    }
+
+    /// Token-bucket limit per ingress node: `rate` packets per tick with
+    /// bursts of up to `burst`, for both auto and API spawns. A rate of 0
+    /// removes the limit.
+    pub fn set_ingress_rate_limit(&mut self, rate: f64, burst: f64) {
+        self.rate_limiter =
+            (rate > 0.0).then(|| ratelimit::IngressRateLimiter::new(rate, burst));
+    }
+
+    /// Dust mitigation: reject spawns worth less than `min_value` and charge
+    /// packets under `dust::DUST_VALUE` a flat `surcharge` up front. Zero
+    /// turns either off.
+    pub fn set_dust_policy(&mut self, min_value: f64, surcharge: f64) {
+        self.dust_guard.set_policy(min_value, surcharge);
+    }
+
+    /// Hold payments worth less than `threshold` at their ingress and route
+    /// each ingress's batch as one packet after `max_wait_ticks`. A threshold
+    /// of 0 turns aggregation off; payments still held are routed at once.
+    pub fn set_dust_aggregation(&mut self, threshold: f64, max_wait_ticks: u32) {
+        if let Some(mut agg) = self.aggregator.take() {
+            for a in agg.release(u64::MAX) {
+                self.mint_packet(a.node_id, a.amount, a.surcharge, a.manifest, None);
+            }
+        }
+        self.aggregator = (threshold > 0.0).then(|| {
+            aggregation::DustAggregator::new(threshold, u64::from(max_wait_ticks))
+        });
+    }
+
+    /// Bound each packet's `route_history` to `entries`: its origin plus the
+    /// most recent hops (minimum 2). `route_digest` still covers the full
+    /// path for audit, and `route_visits` for fee splits, rebates and
+    /// dissolution. 0 keeps full histories.
+    pub fn set_route_window(&mut self, entries: u32) {
+        self.route_window = (entries > 0).then(|| (entries as usize).max(2));
+    }
+
+    /// Choose the router's distance term by `RoutingMode` discriminant:
+    /// 0 = Euclidean distance to the nearest liquid Egress (default),
+    /// 1 = hop distance to the nearest live Egress, 2 = congestion-weighted
+    /// shortest path to the target Egress, with paths cached per (origin,
+    /// Egress) until the topology changes. Unknown values are ignored.
+    pub fn set_routing_mode(&mut self, mode: u32) {
+        if let Some(mode) = routing::RoutingMode::from_u32(mode) {
+            self.routing_mode = mode;
+            self.invalidate_route_cache();
+        }
+    }
+
+    /// Choose how neighbors are scored by `RouteScoring` discriminant:
+    /// 0 = trust-weighted (default), 1 = capacity-only, matching
+    /// `core_routing`, which takes no trust input. Unknown values are ignored.
+    pub fn set_route_scoring(&mut self, scoring: u32) {
+        if let Some(scoring) = routing::RouteScoring::from_u32(scoring) {
+            self.route_scoring = scoring;
+            self.invalidate_route_cache();
+        }
+    }
+
+    /// Choose which router picks next hops by `RoutingRegime` discriminant:
+    /// 0 = legacy `find_next_hop` (default), 1 = core's capacity-based
+    /// `PacketRouter` over every live neighbor, 2 = hybrid, the core router
+    /// over neighbors closer to an Egress. Unknown values are ignored.
+    pub fn set_routing_regime(&mut self, regime: u32) {
+        if let Some(regime) = capacity_router::RoutingRegime::from_u32(regime) {
+            self.capacity_router = capacity_router::CapacityRouter::new(regime);
+        }
+    }
+
+    /// Choose what happens once a packet exceeds its hop limit, by
+    /// `HopExhaustion` discriminant: 0 = hold it in orbit (default),
+    /// 1 = refund it immediately, 2 = hold it for `backoff_base_ticks`
+    /// (doubling per renewal, capped at `backoff_max_ticks`) and then grant
+    /// another tier's worth of hops. Unknown values are ignored.
+    pub fn set_hop_exhaustion(&mut self, policy: u32, backoff_base_ticks: u32, backoff_max_ticks: u32) {
+        if let Some(policy) = routing_policy::HopExhaustion::from_u32(policy) {
+            self.routing_policy.hop_exhaustion = policy;
+            self.routing_policy.backoff_base_ticks = u64::from(backoff_base_ticks.max(1));
+            self.routing_policy.backoff_max_ticks = u64::from(backoff_max_ticks.max(backoff_base_ticks));
+        }
+    }
+
+    /// Set how long Held packets may orbit before they are refunded: L0-L2
+    /// for `ttl_fraction` of their tier TTL (default 0.5), L3 for
+    /// `l3_ticks` (default 5500, past dissolution).
+    pub fn set_orbit_timeouts(&mut self, ttl_fraction: f64, l3_ticks: u32) {
+        self.routing_policy.orbit_timeout_ttl_fraction = ttl_fraction.max(0.0);
+        self.routing_policy.l3_orbit_timeout_ticks = u64::from(l3_ticks);
+    }
+
+    /// Cache each node's preferred next hop instead of rescoring every
+    /// packet; entries are dropped on topology, liquidity or congestion
+    /// changes (see `route_cache::RouteCache`). Off by default.
+    pub fn set_route_cache(&mut self, enabled: bool) {
+        self.route_cache = enabled.then(route_cache::RouteCache::new);
+    }
+
+    /// Split the settlement fee's transit pool by `TransitWeighting`
+    /// discriminant: 0 = equally (default), 1 = by ticks each relay held the
+    /// packet, 2 = by relay bandwidth. Unknown values are ignored.
+    pub fn set_transit_weighting(&mut self, weighting: u32) {
+        if let Some(weighting) = transit_rewards::TransitWeighting::from_u32(weighting) {
+            self.transit_weighting = weighting;
+        }
+    }
+
+    /// Settlements routed in at most `fast_hops` hops earn `rate` extra on
+    /// their fee payouts; those over `slow_hops` give up `rate`, which funds
+    /// the bonuses. Resets the bonus treasury; a zero rate disables both.
+    pub fn set_velocity_bonus(&mut self, fast_hops: u32, slow_hops: u32, rate: f64) {
+        self.velocity_bonus = velocity_bonus::VelocityBonus::new(fast_hops, slow_hops, rate);
+    }
+
+    /// Price settlement fees by `PricingMode` discriminant: 0 = governor
+    /// pricing at settlement (default), 1 = the quote snapshotted at spawn.
+    /// Unknown values are ignored.
+    pub fn set_pricing_mode(&mut self, mode: u32) {
+        if let Some(mode) = quote::PricingMode::from_u32(mode) {
+            self.pricing_mode = mode;
+        }
+    }
+
+    /// Egress nodes left without routable liquidity for `exhausted_ticks`
+    /// exit, becoming the `NodeRole` discriminant `exit_role` (2 = Transit,
+    /// anything else Disabled), and re-enter as Egress once recapitalized to
+    /// `reentry_crypto`. 0 ticks turns exits off; exited nodes keep their role.
+    pub fn set_egress_exit(&mut self, exhausted_ticks: u32, exit_role: u32, reentry_crypto: f64) {
+        self.egress_lifecycle = (exhausted_ticks > 0).then(|| {
+            let role = NodeRole::from_u32(exit_role).unwrap_or(NodeRole::Disabled);
+            egress_exit::EgressLifecycle::new(u64::from(exhausted_ticks), role, reentry_crypto)
+        });
+    }
+
+    /// Retune the core governor's PID gains (defaults Kp=0.5, Ki=0.1,
+    /// Kd=0.05) for gain sweeps. Its integral carries over, so set gains
+    /// before the first tick for a clean run. Non-finite gains are ignored.
+    pub fn set_governor_gains(&mut self, kp: f64, ki: f64, kd: f64) {
+        if [kp, ki, kd].iter().all(|g| g.is_finite()) {
+            self.core_pid.set_gains(
+                crate::adapter::to_decimal(kp),
+                crate::adapter::to_decimal(ki),
+                crate::adapter::to_decimal(kd),
+            );
+        }
+    }
+
+    /// Gain-schedule the core governor: cycles in `quadrant` (label letter
+    /// A..F as 0..5, e.g. 1 = Crash, 3 = Golden Era) run on these gains
+    /// instead of the base gains. Unknown quadrants and non-finite gains
+    /// are ignored.
+    pub fn set_quadrant_gains(&mut self, quadrant: u32, kp: f64, ki: f64, kd: f64) {
+        let Some(quadrant) = crate::core_governor::PressureQuadrant::from_u32(quadrant) else {
+            return;
+        };
+        if [kp, ki, kd].iter().all(|g| g.is_finite()) {
+            let mut schedule = self.core_pid.gain_schedule().clone();
+            schedule.set(quadrant, Some(crate::core_governor::pid::PidGains::new(
+                crate::adapter::to_decimal(kp),
+                crate::adapter::to_decimal(ki),
+                crate::adapter::to_decimal(kd),
+            )));
+            self.core_pid.set_gain_schedule(schedule);
+        }
+    }
+
+    /// Back to the base gains in every quadrant.
+    pub fn clear_gain_schedule(&mut self) {
+        self.core_pid.set_gain_schedule(Default::default());
+    }
+
+    /// Steer the fee with the model-predictive planner instead of the PID,
+    /// looking `horizon` governor cycles ahead (clamped to
+    /// `mpc::MAX_HORIZON`) and weighing congestion by `congestion_weight`
+    /// against peg error (non-finite or negative keeps the default 1). A
+    /// horizon of 0 hands the fee back to the PID.
+    pub fn set_governor_mpc(&mut self, horizon: u32, congestion_weight: f64) {
+        use crate::core_governor::MpcModel;
+        let model = (horizon > 0).then(|| {
+            let default = MpcModel::default();
+            MpcModel {
+                horizon,
+                congestion_weight: if congestion_weight.is_finite() && congestion_weight >= 0.0 {
+                    crate::adapter::to_decimal(congestion_weight)
+                } else {
+                    default.congestion_weight
+                },
+                ..default
+            }
+        });
+        self.core_pid.set_mpc(model);
+    }
+
+    /// Governor cycles a new pressure classification must hold before the
+    /// quadrant (and with it fees, demurrage and scheduled gains) switches.
+    /// 0 or 1 (the default) switches on the first differing cycle.
+    pub fn set_quadrant_hysteresis(&mut self, cycles: u32) {
+        self.core_pid.set_quadrant_debounce(cycles);
+    }
+
+    /// Gold price (USD) the governor steers toward (default 2600). Prices
+    /// that are not positive and finite are ignored.
+    pub fn set_peg_target(&mut self, price: f64) {
+        if price.is_finite() && price > 0.0 {
+            self.peg_target = price;
+        }
+    }
+
+    /// PID gains, peg target, integral and last error, last adjustment and the
+    /// MPC planner's horizon and predictions.
+    #[wasm_bindgen(unchecked_return_type = "GovernorState")]
+    pub fn get_governor_state(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_governor_state_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Weight of in-transit float build-up in the governor's Bottleneck
+    /// classification: a Bottleneck once `weight * float / settled value per
+    /// tick` reaches 1. 0 (default) ignores the float.
+    pub fn set_float_pressure_weight(&mut self, weight: f64) {
+        self.core_pid.set_float_weight(crate::adapter::to_decimal(weight));
+    }
+
+    /// Let panic_level evolve with network performance: reverts, slow
+    /// settlements and circuit-breaker trips raise it by `coupling` times
+    /// their shock, sustained fast settlements ease it by `calm_rate` per
+    /// tick, plus `noise`-wide seeded jitter. The level set by
+    /// `set_panic_level` is the starting point. Coupling 0 turns it off.
+    /// Under a master seed, set before or after, the jitter draws from its
+    /// chaos stream and `seed` is ignored.
+    pub fn set_panic_dynamics(&mut self, coupling: f64, calm_rate: f64, noise: f64, seed: u32) {
+        let seed = self.master_seed
+            .map_or(u64::from(seed), |master| rng::stream_seed(master, rng::Stream::Chaos));
+        self.panic_model = (coupling > 0.0).then(|| {
+            panic_dynamics::PanicModel::new(coupling, calm_rate, noise, seed)
+        });
+    }
+
+    /// Panic contagion: each TTL, orbit-timeout or hop-limit revert at a
+    /// node raises its local panic by `revert_weight`; every tick a node
+    /// takes on `spread` x its most panicked live neighbor's level where
+    /// that is higher, then sheds `decay` of it. A node past
+    /// `panic_contagion::RISK_AVERSE_LEVEL` turns RiskAverse, and local
+    /// panic adds to the volatility RiskAverse nodes hold traffic on.
+    /// Weight 0 turns it off.
+    pub fn set_panic_contagion(&mut self, revert_weight: f64, spread: f64, decay: f64) {
+        self.panic_contagion = (revert_weight > 0.0).then(|| {
+            panic_contagion::PanicContagion::new(revert_weight, spread, decay)
+        });
+    }
+
+    /// A node's local panic level (0 with contagion off).
+    pub fn get_local_panic(&self, node_id: u32) -> f64 {
+        self.panic_contagion.as_ref().map_or(0.0, |c| c.level(node_id))
+    }
+
+    /// Demand elasticity: the share of offered traffic spawned falls as
+    /// `1 / (1 + (cost / reference_cost)^elasticity)` of the recent realized
+    /// all-in cost. Elasticity 0 makes demand perfectly inelastic. Defaults
+    /// are `elasticity::DEFAULT_ELASTICITY` and `DEFAULT_REFERENCE_COST`.
+    pub fn set_demand_elasticity(&mut self, elasticity: f64, reference_cost: f64) {
+        self.demand_elasticity.elasticity = elasticity.max(0.0);
+        self.demand_elasticity.reference_cost = reference_cost;
+    }
+
+    /// Width of spawn cohorts (and of their age buckets) in ticks. Set it
+    /// before traffic starts: it restarts cohort tracking. 0 is ignored.
+    pub fn set_cohort_ticks(&mut self, ticks: u32) {
+        if ticks > 0 {
+            self.cohorts = cohort::CohortTracker::new(u64::from(ticks));
+        }
+    }
+
+    /// Seed the auto traffic: Poisson arrivals with randomly drawn ingress,
+    /// tier and amount, reproducible per seed. Without a seed traffic follows
+    /// the deterministic tick/id cycle.
+    pub fn set_traffic_seed(&mut self, seed: u32) {
+        self.traffic_rng = Some(rng::SplitMix64::new(u64::from(seed)));
+    }
+
+    /// Seed every stochastic component from one master seed, each on its own
+    /// stream (`rng::Stream`): traffic as `set_traffic_seed(seed)` would,
+    /// panic dynamics noise, latency jitter and retry decisions. Turning one
+    /// component on or up leaves the others' draws unchanged, and the order
+    /// components and the seed are set in does not matter.
+    pub fn set_master_seed(&mut self, seed: u32) {
+        let master = u64::from(seed);
+        self.master_seed = Some(master);
+        self.traffic_rng = Some(rng::SplitMix64::new(rng::stream_seed(master, rng::Stream::Traffic)));
+        self.latency_rng = rng::SplitMix64::new(rng::stream_seed(master, rng::Stream::Latency));
+        self.retries.seed(rng::stream_seed(master, rng::Stream::Retry));
+        if let Some(model) = self.panic_model.as_mut() {
+            model.reseed(rng::stream_seed(master, rng::Stream::Chaos));
+        }
+    }
+
+    /// Add up to `max_ticks` of uniform random latency to every hop, drawn
+    /// from the latency stream (see `set_master_seed`). 0 (default) is off.
+    pub fn set_latency_jitter(&mut self, max_ticks: u32) {
+        self.latency_jitter_ticks = u64::from(max_ticks);
+    }
+
+    /// Have senders re-send reverted payments: each TTL, orbit-timeout or
+    /// hop-limit revert is retried with `probability` after `delay_ticks`,
+    /// for its refunded value at its original Ingress. With a master seed
+    /// each decision is drawn; otherwise a fixed share of reverts is retried.
+    /// Cancellations are never retried. Probability 0 (default) is off.
+    pub fn set_retry_model(&mut self, probability: f64, delay_ticks: u32) {
+        self.retries.set_model(probability, u64::from(delay_ticks));
+    }
+
+    /// Have the governor decide every `interval_ticks` (1, the default, is
+    /// every tick) with each decision taking effect `actuation_lag_ticks`
+    /// later (default 0); the network keeps the parameters in effect until
+    /// then. See `WorldState::fee_rate_reversals` for the loop's oscillation.
+    pub fn set_control_loop(&mut self, interval_ticks: u32, actuation_lag_ticks: u32) {
+        self.control_loop.configure(u64::from(interval_ticks), u64::from(actuation_lag_ticks));
+    }
+
+    /// Log each governor decision's core inputs and outputs (see
+    /// `get_governor_io`). Off by default.
+    pub fn set_governor_logging(&mut self, enabled: bool) {
+        self.log_governor_io = enabled;
+        if !enabled {
+            self.governor_io = None;
+        }
+    }
+
+    /// The metrics the core PID saw this tick, the parameters it returned
+    /// and the decision after overrides; null if it did not decide this
+    /// tick or logging is off.
+    #[wasm_bindgen(unchecked_return_type = "GovernorIo | null")]
+    pub fn get_governor_io(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.governor_io).unwrap_or(JsValue::NULL)
+    }
+
+    /// Freeze the governor's fees: the base fee rate and tier modifiers stay
+    /// at their current values, whatever the PID and overrides would set,
+    /// until unfrozen. Demurrage keeps following the governor.
+    pub fn set_fee_freeze(&mut self, frozen: bool) {
+        self.frozen_fees = frozen.then_some((self.state.current_fee_rate, self.tier_fee_modifiers));
+    }
+
+    /// Trace every hop: how long packets wait at each node before being
+    /// forwarded, and the hop fees each node earns. Off by default;
+    /// switching it on starts an empty trace.
+    pub fn set_hop_tracing(&mut self, enabled: bool) {
+        self.hop_trace = enabled.then(hop_trace::HopTrace::new);
+    }
+
+    /// Account what running each node costs: pledged storage (PoSpace),
+    /// bandwidth and energy, at default prices, netted against its fees in
+    /// `get_node_pnl`. Dissolution's demonstrable capacity then also needs
+    /// the storage minimum. Off by default; switching it on starts empty.
+    pub fn set_resource_model(&mut self, enabled: bool) {
+        self.resources = enabled.then(|| resources::ResourceModel::new(resources::ResourcePrices::default()));
+    }
+
+    /// Unit prices of the resource model, in the packets' value unit.
+    /// Ignored while the model is off, or if any price is negative or not
+    /// finite.
+    pub fn set_resource_prices(&mut self, storage_per_gb_day: f64, bandwidth_per_mbps_day: f64, energy_per_kwh: f64) {
+        let prices = [storage_per_gb_day, bandwidth_per_mbps_day, energy_per_kwh];
+        if let (Some(model), true) = (&mut self.resources, prices.iter().all(|p| p.is_finite() && *p >= 0.0)) {
+            model.set_prices(resources::ResourcePrices { storage_per_gb_day, bandwidth_per_mbps_day, energy_per_kwh });
+        }
+    }
+
+    /// Storage (GB) `node_id` pledges in place of its role default. Ignored
+    /// while the resource model is off.
+    pub fn set_node_storage_pledge(&mut self, node_id: u32, gb: f64) {
+        if let Some(model) = &mut self.resources {
+            if (node_id as usize) < self.nodes.len() && gb.is_finite() {
+                model.set_storage_pledge(node_id, gb);
+            }
+        }
+    }
+
+    /// Per-node resources, running costs and fees; empty while the resource
+    /// model is off.
+    #[wasm_bindgen(unchecked_return_type = "NodePnl[]")]
+    pub fn get_node_pnl(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_node_pnl_core()).unwrap_or(JsValue::NULL)
+    }
+
+    #[wasm_bindgen(unchecked_return_type = "ResourceSummary | undefined")]
+    pub fn get_resource_summary(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.get_resource_summary_core()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Charge beneficiaries an FX spread when egress pays out: `base_spread`
+    /// (0..1) widening by `volatility_coefficient` per unit of rolling gold
+    /// volatility, capped at `max_spread`. The spread counts towards peg
+    /// deviation and realized cost. Zero base and coefficient turn it off.
+    pub fn set_payout_slippage(&mut self, base_spread: f64, volatility_coefficient: f64, max_spread: f64) {
+        self.payout_slippage = (base_spread > 0.0 || volatility_coefficient > 0.0).then(|| {
+            slippage::PayoutSlippage::new(base_spread, volatility_coefficient, max_spread)
+        });
+    }
+
+    /// Define a spawn corridor from sites `[origin_first_site,
+    /// origin_end_site)` to sites `[destination_first_site,
+    /// destination_end_site)` and return its index. Once any corridor exists
+    /// auto traffic is spawned per corridor (default demand
+    /// `corridor::DEFAULT_BASE_RATE`, even tier mix) and only settles in its
+    /// destination region; `get_stats` reports each corridor separately.
+    pub fn add_corridor(
+        &mut self,
+        name: String,
+        origin_first_site: u32,
+        origin_end_site: u32,
+        destination_first_site: u32,
+        destination_end_site: u32,
+    ) -> u32 {
+        self.corridors.add(corridor::Corridor::new(
+            name,
+            corridor::Region::new(origin_first_site, origin_end_site),
+            corridor::Region::new(destination_first_site, destination_end_site),
+        ))
+    }
+
+    /// Spawn `base_rate` packets per tick along a corridor, swinging by
+    /// `amplitude` (fraction of the base) over `period_ticks`; a period of 0
+    /// keeps the rate flat. Unknown corridors are ignored.
+    pub fn set_corridor_demand(&mut self, index: u32, base_rate: f64, amplitude: f64, period_ticks: u32) {
+        if let Some(c) = self.corridors.get_mut(index) {
+            c.demand = corridor::DemandCurve {
+                base_rate,
+                amplitude,
+                period_ticks: u64::from(period_ticks),
+            };
+        }
+    }
+
+    /// Relative weights of L0..L3 among a corridor's packets. Unknown
+    /// corridors are ignored.
+    pub fn set_corridor_tier_mix(&mut self, index: u32, l0: f64, l1: f64, l2: f64, l3: f64) {
+        if let Some(c) = self.corridors.get_mut(index) {
+            c.tier_mix = [l0, l1, l2, l3];
+        }
+    }
+
+    /// Drop every corridor and its stats, returning to network-wide auto
+    /// traffic. Packets already spawned keep their destination.
+    pub fn clear_corridors(&mut self) {
+        self.corridors.clear();
+    }
+
+    /// Price gold in sites `[first_site, end_site)` at `premium_pct` over
+    /// the oracle (negative for a discount), e.g. a crisis country's local
+    /// premium. Settlements are tagged with the regions they cross; see
+    /// `SimStats::regional_prices`. Re-pricing a region keeps its totals.
+    pub fn set_regional_premium(&mut self, first_site: u32, end_site: u32, premium_pct: f64) {
+        self.regional_prices.set_premium(
+            corridor::Region::new(first_site, end_site), premium_pct / 100.0);
+    }
+
+    /// Drop every regional premium and its stats.
+    pub fn clear_regional_premiums(&mut self) {
+        self.regional_prices.clear();
+    }
+
+    /// Replace the speculator population with `count` agents of
+    /// `speculation::SPECULATOR_BANKROLL` each, spread over the Ingress
+    /// nodes, sending `aggressiveness` (0-1) of their idle bankroll every
+    /// tick. 0 removes them. See `SimStats::speculation` for how the
+    /// organic-ratio detector scores against them.
+    pub fn set_speculators(&mut self, count: u32, aggressiveness: f64) {
+        let ingress_nodes: Vec<u32> = self.nodes.iter()
+            .filter(|n| n.role == NodeRole::Ingress)
+            .map(|n| n.id)
+            .collect();
+        self.speculators.configure(count, aggressiveness, &ingress_nodes);
+    }
+
+    /// Replace the liquidity provider population with `count` agents of
+    /// `bankroll` crypto each. Every `liquidity_provider::REVIEW_TICKS` an
+    /// idle provider picks the Egress with the highest fee yield (fees per
+    /// tick over crypto inventory) and, while that beats `hurdle_rate`,
+    /// supplies up to the inventory that brings it down to the hurdle; it
+    /// withdraws its stake once yield falls under half the hurdle. 0
+    /// removes them. See `SimStats::liquidity`.
+    pub fn set_liquidity_providers(&mut self, count: u32, bankroll: f64, hurdle_rate: f64) {
+        self.liquidity_providers.configure(count, bankroll, hurdle_rate);
+    }
+
+    /// Top up every live Egress each tick by `ReplenishmentPolicy`
+    /// discriminant: 0 = none (default), 1 = a fixed `rate` of crypto,
+    /// 2 = `rate` x the fees it earned that tick, 3 = outside liquidity at
+    /// `rate` x how far it has fallen below its inventory when the policy
+    /// was set. A rate of 0 turns it off; unknown policies are ignored. See
+    /// `WorldState::liquidity_runway_ticks`.
+    pub fn set_replenishment_policy(&mut self, policy: u32, rate: f64) {
+        if let Some(policy) = replenishment::ReplenishmentPolicy::from_u32(policy) {
+            self.replenishment = (policy != replenishment::ReplenishmentPolicy::None && rate > 0.0)
+                .then(|| replenishment::Replenishment::new(policy, rate));
+        }
+    }
+
+    /// Let each Egress whose queued value outruns its crypto inventory borrow
+    /// up to `max_share` of the spare inventory of the best-stocked Egress
+    /// within `max_hops` links each tick. Transfers land after the latency a
+    /// packet takes over those links and the borrower pays `fee_rate` of each
+    /// back to the lender. A share of 0 stops new transfers; those already
+    /// sent still land.
+    pub fn set_rebalancing(&mut self, max_share: f64, fee_rate: f64, max_hops: u32) {
+        match self.rebalancing.as_mut() {
+            Some(r) => r.configure(max_share, fee_rate, max_hops),
+            None if max_share > 0.0 => {
+                self.rebalancing = Some(rebalancing::Rebalancing::new(max_share, fee_rate, max_hops));
+            }
+            None => {}
+        }
+    }
+
+    /// Estimate the volatility the governor sees by `VolatilityEstimator`
+    /// discriminant over a `window` of ticks: 0 = coefficient of variation of
+    /// prices (default, 20 ticks), 1 = EWMA of log returns, 2 = Parkinson
+    /// high/low range, 3 = GARCH-lite. Unknown estimators are ignored.
+    pub fn set_volatility_estimator(&mut self, estimator: u32, window: u32) {
+        if let Some(estimator) = volatility::VolatilityEstimator::from_u32(estimator) {
+            self.volatility_model.configure(estimator, window);
+        }
+    }
+
+    /// Reset simulation to initial state: the network it was built from,
+    /// before any nodes were added or removed, and default settings.
     pub fn reset(&mut self) {
-        *self = ArenaSimulation::new(self.nodes.len() as u32);
+        *self = ArenaSimulation::from_topology(&self.topology);
+    }
+
+    /// Independent copy of the whole simulation (nodes, packets, governor
+    /// and trackers), to seed a follow-on scenario or fork a what-if run.
+    pub fn snapshot(&self) -> ArenaSimulation {
+        self.clone()
+    }
+
+    /// Branch a counterfactual from the current tick: the fork starts from
+    /// identical state, seeded traffic and panic generators included, so
+    /// with the same inputs it replays the original tick for tick until an
+    /// intervention (e.g. `set_fee_freeze`) is applied to one side.
+    pub fn fork(&self) -> ArenaSimulation {
+        self.snapshot()
     }
 
 }
@@ -0,0 +1,248 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Liquidity Provider Agents
+//
+// The whitepaper holds that fee incentives draw liquidity to Egress nodes
+// during droughts. Providers make that endogenous rather than asserted:
+// each holds a crypto bankroll and, every REVIEW_TICKS, weighs each live
+// Egress's fee yield
+//
+//   yield = fees per tick over the last review window / inventory_crypto
+//
+// An idle provider picks the best-yielding Egress; while its Egress yields
+// more than `hurdle_rate` it supplies from its bankroll up to the inventory
+// at which yield falls to the hurdle (fees per tick / hurdle), and once it yields
+// under EXIT_FRACTION of the hurdle it withdraws, the gap keeping it from
+// flapping. An Egress drained by settlements it earns fees on is where
+// yield is highest, so capital flows to the drought.
+//
+// A provider owns a pro-rata share of its Egress's crypto inventory:
+// settlements draw its stake down and netting credits build it up along
+// with the node's own, and the same share of the node's fees is credited
+// to it as yield earned. Fees stay with the node in the value ledger; the
+// inventory moved is recorded in the asset ledger.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::asset_ledger::AssetLedger;
+use crate::types::{NodeRole, SimNode};
+
+/// Ticks between provider decisions.
+pub const REVIEW_TICKS: u64 = 10;
+/// Share of the hurdle rate below which a supplying provider withdraws.
+pub const EXIT_FRACTION: f64 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Provider {
+    idle: f64,
+    /// Egress supplied and the share of its crypto inventory owned
+    placement: Option<(u32, f64)>,
+}
+
+/// What the providers supplied, withdrew and earned.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct LiquidityStats {
+    pub providers: u32,
+    pub hurdle_rate: f64,
+    pub deposits: u32,
+    pub withdrawals: u32,
+    pub supplied: f64,
+    pub withdrawn: f64,
+    /// Providers' stakes in Egress inventory now, and how many are supplying
+    pub staked: f64,
+    pub supplying: u32,
+    /// Providers' share of their Egress nodes' fees
+    pub fees_earned: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LiquidityProviders {
+    agents: Vec<Provider>,
+    hurdle_rate: f64,
+    /// By node id: fees per tick over the last review window, fees earned
+    /// so far this window, and cumulative fees when last seen
+    fee_rate: Vec<f64>,
+    window_fees: Vec<f64>,
+    last_fees: Vec<f64>,
+    stats: LiquidityStats,
+}
+
+impl LiquidityProviders {
+    /// Replace the population with `count` providers of `bankroll` each,
+    /// idle until their first review. Stakes of the old population are
+    /// left in place.
+    pub fn configure(&mut self, count: u32, bankroll: f64, hurdle_rate: f64) {
+        self.agents = vec![Provider { idle: bankroll.max(0.0), placement: None }; count as usize];
+        self.hurdle_rate = hurdle_rate.max(0.0);
+        self.stats.providers = count;
+        self.stats.hurdle_rate = self.hurdle_rate;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.agents.is_empty()
+    }
+
+    /// `node`'s fee yield per tick on its crypto inventory.
+    pub fn yield_at(&self, node: &SimNode) -> f64 {
+        let fees = self.fee_rate.get(node.id as usize).copied().unwrap_or(0.0);
+        fees / node.inventory_crypto.max(1.0)
+    }
+
+    /// Crypto inventory at which `node` would yield the hurdle rate.
+    fn target_inventory(&self, node: &SimNode) -> f64 {
+        let fees = self.fee_rate.get(node.id as usize).copied().unwrap_or(0.0);
+        if self.hurdle_rate > 0.0 { fees / self.hurdle_rate } else { f64::INFINITY }
+    }
+
+    /// Once per tick, after the node cycle: track fees, credit providers
+    /// their share, and on review ticks withdraw and supply.
+    pub fn update(&mut self, nodes: &mut [SimNode], ledger: &mut AssetLedger, tick: u64) {
+        self.fee_rate.resize(nodes.len(), 0.0);
+        self.window_fees.resize(nodes.len(), 0.0);
+        self.last_fees.resize(nodes.len(), 0.0);
+        for (i, node) in nodes.iter().enumerate() {
+            let earned = node.total_fees_earned - self.last_fees[i];
+            self.last_fees[i] = node.total_fees_earned;
+            self.window_fees[i] += earned;
+            for (_, share) in self.agents.iter().filter_map(|a| a.placement).filter(|&(e, _)| e as usize == i) {
+                self.stats.fees_earned += earned * share;
+            }
+        }
+
+        if tick.is_multiple_of(REVIEW_TICKS) {
+            for (rate, window) in self.fee_rate.iter_mut().zip(self.window_fees.iter_mut()) {
+                *rate = std::mem::take(window) / REVIEW_TICKS as f64;
+            }
+            for a in 0..self.agents.len() {
+                let Some((egress, _)) = self.agents[a].placement else {
+                    continue;
+                };
+                let node = &nodes[egress as usize];
+                if node.role != NodeRole::Egress || self.yield_at(node) < EXIT_FRACTION * self.hurdle_rate {
+                    self.withdraw(a, nodes, ledger);
+                }
+            }
+            for a in 0..self.agents.len() {
+                if self.agents[a].idle <= 0.0 {
+                    continue;
+                }
+                let egress = match self.agents[a].placement {
+                    Some((egress, _)) => Some(egress),
+                    None => nodes.iter()
+                        .filter(|n| n.role == NodeRole::Egress)
+                        .max_by(|x, y| self.yield_at(x).total_cmp(&self.yield_at(y)))
+                        .map(|n| n.id),
+                };
+                let Some(egress) = egress else {
+                    continue;
+                };
+                let node = &nodes[egress as usize];
+                let amount = (self.target_inventory(node) - node.inventory_crypto).min(self.agents[a].idle);
+                if self.yield_at(node) > self.hurdle_rate && amount > 0.0 {
+                    self.supply(a, egress, amount, nodes, ledger);
+                }
+            }
+        }
+
+        self.stats.staked = self.agents.iter()
+            .filter_map(|a| a.placement)
+            .map(|(e, share)| share * nodes[e as usize].inventory_crypto)
+            .sum();
+        self.stats.supplying = self.agents.iter().filter(|a| a.placement.is_some()).count() as u32;
+    }
+
+    /// Scale every other stake at `egress` as its inventory goes from `before` to `after`.
+    fn dilute(&mut self, egress: u32, before: f64, after: f64) {
+        let scale = if after > 0.0 { before / after } else { 0.0 };
+        for (e, share) in self.agents.iter_mut().filter_map(|a| a.placement.as_mut()) {
+            if *e == egress {
+                *share *= scale;
+            }
+        }
+    }
+
+    /// Supply `amount` to `egress`, adding to the provider's stake there.
+    fn supply(&mut self, a: usize, egress: u32, amount: f64, nodes: &mut [SimNode], ledger: &mut AssetLedger) {
+        let share = self.agents[a].placement.take().map_or(0.0, |(_, share)| share);
+        self.agents[a].idle -= amount;
+        let node = &mut nodes[egress as usize];
+        let before = node.inventory_crypto.max(0.0);
+        node.inventory_crypto += amount;
+        let after = node.inventory_crypto;
+        ledger.record_supply(amount);
+        self.dilute(egress, before, after);
+        self.agents[a].placement = Some((egress, (share * before + amount) / after));
+        self.stats.deposits += 1;
+        self.stats.supplied += amount;
+    }
+
+    fn withdraw(&mut self, a: usize, nodes: &mut [SimNode], ledger: &mut AssetLedger) {
+        let Some((egress, share)) = self.agents[a].placement.take() else {
+            return;
+        };
+        let node = &mut nodes[egress as usize];
+        let before = node.inventory_crypto.max(0.0);
+        let amount = share * before;
+        node.inventory_crypto -= amount;
+        let after = node.inventory_crypto;
+        ledger.record_supply(-amount);
+        self.dilute(egress, before, after);
+        self.agents[a].idle += amount;
+        self.stats.withdrawals += 1;
+        self.stats.withdrawn += amount;
+    }
+
+    pub fn stats(&self) -> LiquidityStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArenaSimulation;
+
+    #[test]
+    fn test_providers_chase_yield_and_leave_when_it_falls() {
+        let mut sim = ArenaSimulation::new(24);
+        let dry = sim.nodes.iter().find(|n| n.role == NodeRole::Egress).unwrap().id as usize;
+        sim.nodes[dry].inventory_crypto = 0.0;
+        let mut ledger = AssetLedger::opening(&sim.nodes);
+        let mut lps = LiquidityProviders::default();
+        lps.configure(2, 3000.0, 1e-3);
+        let mut tick = 0;
+        let mut run = |sim: &mut ArenaSimulation, lps: &mut LiquidityProviders, ledger: &mut AssetLedger, fees: f64| {
+            for _ in 0..REVIEW_TICKS {
+                tick += 1;
+                sim.nodes[dry].total_fees_earned += fees;
+                lps.update(&mut sim.nodes, ledger, tick);
+            }
+        };
+
+        // The dry Egress earns 5 per tick, the hurdle on 5000 of inventory:
+        // the first provider supplies all 3000, the second the other 2000
+        run(&mut sim, &mut lps, &mut ledger, 5.0);
+        let stats = lps.stats();
+        assert_eq!((stats.deposits, stats.supplying), (2, 2));
+        assert!((sim.nodes[dry].inventory_crypto - 5000.0).abs() < 1e-9);
+
+        // Settlements draw half of it, and the stakes with it; the
+        // providers earn all the node's fees and the second tops up
+        sim.nodes[dry].inventory_crypto = 2500.0;
+        ledger.record_settlement(2500.0);
+        run(&mut sim, &mut lps, &mut ledger, 5.0);
+        let stats = lps.stats();
+        assert_eq!(stats.deposits, 3);
+        assert!((stats.fees_earned - 50.0).abs() < 1e-9);
+        assert!((stats.staked - 3500.0).abs() < 1e-9);
+
+        // Fees stop: both withdraw what is left and stay out
+        run(&mut sim, &mut lps, &mut ledger, 0.0);
+        run(&mut sim, &mut lps, &mut ledger, 0.0);
+        let stats = lps.stats();
+        assert_eq!((stats.deposits, stats.withdrawals, stats.supplying), (3, 2, 0));
+        assert!((stats.withdrawn - 3500.0).abs() < 1e-9);
+        assert!(sim.nodes[dry].inventory_crypto.abs() < 1e-9);
+        assert!(ledger.report(&sim.nodes).crypto.residual < 1e-6);
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Gold Price Oracle Feed
+//
+// The governor steers on the price the oracle last published, not on the
+// market price directly. The feed publishes every tick unless it is in an
+// outage, during which the published price freezes and its staleness grows;
+// past `core_governor::pid::ORACLE_STALE_PERIODS` the governor holds its
+// parameters (see `GovernorPid::recalculate`).
+
+/// Extra verification complexity demanded while the governor holds on a
+/// stale price.
+pub const STALE_VERIFICATION_COMPLEXITY: u64 = 2;
+
+#[derive(Debug, Clone)]
+pub struct PriceOracle {
+    price: f64,
+    last_update_tick: u64,
+    // First tick the feed publishes again (None = no outage scheduled)
+    outage_until: Option<u64>,
+}
+
+impl PriceOracle {
+    pub fn new(price: f64) -> Self {
+        Self { price, last_update_tick: 0, outage_until: None }
+    }
+
+    /// Publish the market price at `tick`, unless the feed is out.
+    pub fn publish(&mut self, price: f64, tick: u64) {
+        if self.outage_until.is_some_and(|until| tick < until) {
+            return;
+        }
+        self.outage_until = None;
+        self.price = price;
+        self.last_update_tick = tick;
+    }
+
+    /// Freeze the feed for ticks `[from_tick, from_tick + duration_ticks)`.
+    pub fn begin_outage(&mut self, from_tick: u64, duration_ticks: u64) {
+        self.outage_until = Some(from_tick + duration_ticks);
+    }
+
+    /// Last published price.
+    pub fn price(&self) -> f64 {
+        self.price
+    }
+
+    /// Ticks since the last publication.
+    pub fn staleness(&self, tick: u64) -> u64 {
+        tick.saturating_sub(self.last_update_tick)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outage_freezes_price_and_ages_it() {
+        let mut oracle = PriceOracle::new(2600.0);
+        oracle.publish(2610.0, 1);
+        assert_eq!((oracle.price(), oracle.staleness(1)), (2610.0, 0));
+
+        oracle.begin_outage(2, 3);
+        for tick in 2..5 {
+            oracle.publish(2000.0, tick);
+        }
+        assert_eq!((oracle.price(), oracle.staleness(4)), (2610.0, 3));
+
+        oracle.publish(2000.0, 5);
+        assert_eq!((oracle.price(), oracle.staleness(5)), (2000.0, 0));
+    }
+}
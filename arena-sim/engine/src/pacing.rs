@@ -0,0 +1,109 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Real-Time Pacing
+//
+// Demos want ticks at a human-watchable pace, not as fast as the host can
+// run them. The pacer turns host time into a count of ticks due, one per
+// `tick_interval_ms`. It reads no clock itself: the caller passes its own
+// milliseconds (`performance.now()` in the browser, an `Instant` natively),
+// so the engine stays deterministic and wasm-safe.
+//
+// When the host stalls, the ticks it missed are run back to back on the next
+// call, up to `max_catch_up` at once. A longer stall drops the rest of the
+// backlog and re-anchors the clock, so playback resumes at pace instead of
+// sprinting through the gap.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+/// Ticks run back to back after a stall before the backlog is dropped.
+pub const DEFAULT_MAX_CATCH_UP_TICKS: u32 = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealTimePacer {
+    tick_interval_ms: f64,
+    max_catch_up: u32,
+    // Host time the next tick falls due; None until the first call
+    next_due_ms: Option<f64>,
+    dropped_ticks: u64,
+    catch_ups: u32,
+}
+
+/// Pacer counters, for showing a demo running behind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct PacingStats {
+    pub tick_interval_ms: f64,
+    /// Calls that ran more than one tick to catch up
+    pub catch_ups: u32,
+    /// Ticks given up after stalls longer than the catch-up allowance
+    pub dropped_ticks: u64,
+}
+
+impl RealTimePacer {
+    /// One tick every `tick_interval_ms` (floored at 1ms), catching up at
+    /// most `max_catch_up` ticks (at least 1) per call.
+    pub fn new(tick_interval_ms: f64, max_catch_up: u32) -> Self {
+        Self {
+            tick_interval_ms: tick_interval_ms.max(1.0),
+            max_catch_up: max_catch_up.max(1),
+            next_due_ms: None,
+            dropped_ticks: 0,
+            catch_ups: 0,
+        }
+    }
+
+    /// Ticks due at host time `now_ms`. The first call anchors the clock
+    /// and runs one tick.
+    pub fn due(&mut self, now_ms: f64) -> u32 {
+        let next = *self.next_due_ms.get_or_insert(now_ms);
+        if now_ms < next {
+            return 0;
+        }
+        let owed = ((now_ms - next) / self.tick_interval_ms).floor() as u64 + 1;
+        let run = owed.min(u64::from(self.max_catch_up));
+        if run > 1 {
+            self.catch_ups += 1;
+        }
+        self.next_due_ms = Some(if owed > run {
+            self.dropped_ticks += owed - run;
+            now_ms + self.tick_interval_ms
+        } else {
+            next + run as f64 * self.tick_interval_ms
+        });
+        run as u32
+    }
+
+    /// Milliseconds from `now_ms` until the next tick falls due (0 if due).
+    pub fn until_next_ms(&self, now_ms: f64) -> f64 {
+        self.next_due_ms.map_or(0.0, |next| (next - now_ms).max(0.0))
+    }
+
+    pub fn stats(&self) -> PacingStats {
+        PacingStats {
+            tick_interval_ms: self.tick_interval_ms,
+            catch_ups: self.catch_ups,
+            dropped_ticks: self.dropped_ticks,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paces_catches_up_and_drops_long_stalls() {
+        let mut pacer = RealTimePacer::new(100.0, 5);
+        assert_eq!(pacer.due(1000.0), 1, "First call anchors and ticks");
+        assert_eq!(pacer.due(1050.0), 0);
+        assert_eq!(pacer.until_next_ms(1050.0), 50.0);
+        assert_eq!(pacer.due(1100.0), 1);
+        // A 350ms stall: three ticks owed, run back to back
+        assert_eq!(pacer.due(1450.0), 3);
+        assert_eq!(pacer.until_next_ms(1450.0), 50.0, "Catch-up keeps the original cadence");
+        // A 2s stall: 21 owed, 5 run, the rest dropped and the clock re-anchored
+        assert_eq!(pacer.due(3500.0), 5);
+        assert_eq!(pacer.until_next_ms(3500.0), 100.0);
+        assert_eq!(pacer.due(3600.0), 1);
+        assert_eq!(pacer.stats(), PacingStats { tick_interval_ms: 100.0, catch_ups: 2, dropped_ticks: 16 });
+    }
+}
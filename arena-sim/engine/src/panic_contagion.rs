@@ -0,0 +1,128 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Panic Contagion
+//
+// A local panic level per node, for confidence crises that start somewhere
+// rather than everywhere at once (the global `panic_level` knob):
+//
+//   level' = clamp((1 - decay) * max(level, spread * max_n level_n)
+//                  + revert_weight * reverts, 0, 1)
+//
+//   max_n level_n = the most panicked live neighbor, last tick
+//   reverts       = packets reverted at this node this tick
+//
+// With spread < 1 panic fades geometrically with distance from its source
+// (at most spread^d of it d hops away), and with decay > 0 it fades in time
+// once the reverts stop. A node at or above RISK_AVERSE_LEVEL turns
+// RiskAverse whatever its configured strategy.
+
+use crate::types::{NodeRole, NodeStrategy, SimNode};
+
+/// Local panic at or above which a node behaves RiskAverse.
+pub const RISK_AVERSE_LEVEL: f64 = 0.5;
+
+/// Per-node panic raised by local reverts and passed on to neighbors.
+#[derive(Debug, Clone)]
+pub struct PanicContagion {
+    pub revert_weight: f64,
+    pub spread: f64,
+    pub decay: f64,
+    /// Panic level by node id
+    levels: Vec<f64>,
+    /// This tick's reverts by node id
+    reverts: Vec<u32>,
+}
+
+impl PanicContagion {
+    pub fn new(revert_weight: f64, spread: f64, decay: f64) -> Self {
+        Self {
+            revert_weight: revert_weight.max(0.0),
+            spread: spread.clamp(0.0, 1.0),
+            decay: decay.clamp(0.0, 1.0),
+            levels: Vec::new(),
+            reverts: Vec::new(),
+        }
+    }
+
+    /// A packet was reverted at `node_id` this tick.
+    pub fn on_revert(&mut self, node_id: u32) {
+        let i = node_id as usize;
+        if i >= self.reverts.len() {
+            self.reverts.resize(i + 1, 0);
+        }
+        self.reverts[i] += 1;
+    }
+
+    pub fn level(&self, node_id: u32) -> f64 {
+        self.levels.get(node_id as usize).copied().unwrap_or(0.0)
+    }
+
+    /// `base` as a node at `node_id`'s panic level plays it; adversaries
+    /// keep attacking whatever the panic.
+    pub fn strategy(&self, node_id: u32, base: NodeStrategy) -> NodeStrategy {
+        if self.level(node_id) >= RISK_AVERSE_LEVEL && !base.is_adversarial() { NodeStrategy::RiskAverse } else { base }
+    }
+
+    /// Once per tick, after the node cycle: spread, decay and raise every
+    /// node's level from last tick's levels and this tick's reverts.
+    pub fn end_tick(&mut self, nodes: &[SimNode]) {
+        self.levels.resize(nodes.len(), 0.0);
+        self.reverts.resize(nodes.len(), 0);
+        let previous = self.levels.clone();
+        for (node, level) in nodes.iter().zip(self.levels.iter_mut()) {
+            let inflow = node.neighbors.iter()
+                .filter(|&&n| nodes[n as usize].role != NodeRole::Disabled)
+                .map(|&n| previous[n as usize])
+                .fold(0.0, f64::max);
+            let raised = self.revert_weight * f64::from(self.reverts[node.id as usize]);
+            *level = ((1.0 - self.decay) * level.max(self.spread * inflow) + raised).clamp(0.0, 1.0);
+        }
+        self.reverts.iter_mut().for_each(|r| *r = 0);
+    }
+
+    /// Highest local panic level.
+    pub fn peak(&self) -> f64 {
+        self.levels.iter().copied().fold(0.0, f64::max)
+    }
+
+    /// Nodes at or above `RISK_AVERSE_LEVEL`.
+    pub fn panicked_count(&self) -> u32 {
+        self.levels.iter().filter(|&&l| l >= RISK_AVERSE_LEVEL).count() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::TopologyBuilder;
+    use crate::ArenaSimulation;
+
+    #[test]
+    fn test_panic_spreads_with_distance_and_fades_in_time() {
+        // A line 0 - 1 - 2 - 3
+        let sim = ArenaSimulation::from_topology(&TopologyBuilder::new(4)
+            .edges(vec![0, 1, 1, 2, 2, 3])
+            .roles(vec![0, 2, 2, 1]));
+        let mut contagion = PanicContagion::new(0.2, 0.5, 0.1);
+        for _ in 0..5 {
+            contagion.on_revert(0);
+        }
+        contagion.end_tick(&sim.nodes);
+        assert!((contagion.level(0) - 1.0).abs() < 1e-12);
+        assert_eq!(contagion.level(1), 0.0, "Neighbors catch it a tick later");
+        assert_eq!(contagion.strategy(0, NodeStrategy::Greedy), NodeStrategy::RiskAverse);
+
+        contagion.end_tick(&sim.nodes);
+        assert!((contagion.level(0) - 0.9).abs() < 1e-12);
+        assert!((contagion.level(1) - 0.45).abs() < 1e-12);
+        contagion.end_tick(&sim.nodes);
+        assert!(contagion.level(2) < contagion.level(1) && contagion.level(2) > 0.0);
+        assert_eq!(contagion.strategy(1, NodeStrategy::Greedy), NodeStrategy::Greedy);
+
+        // No more reverts: every level fades
+        for _ in 0..100 {
+            contagion.end_tick(&sim.nodes);
+        }
+        assert!(contagion.peak() < 0.01);
+        assert_eq!(contagion.panicked_count(), 0);
+    }
+}
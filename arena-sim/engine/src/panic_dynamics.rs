@@ -0,0 +1,148 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Endogenous Panic Dynamics
+//
+// panic' = clamp(panic + coupling * shock - calm + noise, 0, 1)
+//
+//   shock = REVERT_WEIGHT  * revert_rate                        (this tick)
+//         + LATENCY_WEIGHT * latency_excess                     (this tick)
+//         + BREAKER_WEIGHT * [circuit breaker tripped this tick]
+//   calm  = calm_rate * panic, once settlements have been fast and revert-free
+//           for CALM_STREAK_TICKS consecutive ticks
+//   noise = uniform in [-noise, noise], from a seeded generator
+//
+// revert_rate    = reverts / (settlements + reverts)
+// latency_excess = (mean ticks to settle - FAST_SETTLE_TICKS) / FAST_SETTLE_TICKS,
+//                  clamped to [0, 1]
+
+use crate::rng::SplitMix64;
+
+/// Mean ticks to settle at or below which a settlement counts as fast.
+pub const FAST_SETTLE_TICKS: f64 = 5.0;
+/// Consecutive fast, revert-free ticks before panic starts to ease.
+pub const CALM_STREAK_TICKS: u32 = 10;
+
+pub const REVERT_WEIGHT: f64 = 0.05;
+pub const LATENCY_WEIGHT: f64 = 0.02;
+pub const BREAKER_WEIGHT: f64 = 0.1;
+
+// ---------------------------------------------------------------------------
+// PanicModel - user confidence reacting to protocol behaviour
+// ---------------------------------------------------------------------------
+
+/// Drives `panic_level` from the network's own performance. Fed the
+/// cumulative settlement, revert and settle-time counters and the latched
+/// circuit-breaker flag once per tick; only the trip itself is a shock.
+/// The noise stream is deterministic for a given seed.
+#[derive(Debug, Clone)]
+pub struct PanicModel {
+    pub coupling: f64,
+    pub calm_rate: f64,
+    pub noise: f64,
+    rng: SplitMix64,
+    fast_streak: u32,
+    last_breaker: bool,
+    last_settlements: u32,
+    last_reverts: u32,
+    last_settle_time: u64,
+}
+
+impl PanicModel {
+    pub fn new(coupling: f64, calm_rate: f64, noise: f64, seed: u64) -> Self {
+        Self {
+            coupling: coupling.max(0.0),
+            calm_rate: calm_rate.clamp(0.0, 1.0),
+            noise: noise.max(0.0),
+            rng: SplitMix64::new(seed),
+            fast_streak: 0,
+            last_breaker: false,
+            last_settlements: 0,
+            last_reverts: 0,
+            last_settle_time: 0,
+        }
+    }
+
+    /// Restart the noise stream from `seed`.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = SplitMix64::new(seed);
+    }
+
+    /// Next tick's panic level from the current one.
+    pub fn update(
+        &mut self,
+        panic: f64,
+        settlements: u32,
+        reverts: u32,
+        settle_time: u64,
+        breaker_active: bool,
+    ) -> f64 {
+        let settled = settlements.saturating_sub(self.last_settlements);
+        let reverted = reverts.saturating_sub(self.last_reverts);
+        let settled_time = settle_time.saturating_sub(self.last_settle_time);
+        self.last_settlements = settlements;
+        self.last_reverts = reverts;
+        self.last_settle_time = settle_time;
+        let breaker_tripped = breaker_active && !self.last_breaker;
+        self.last_breaker = breaker_active;
+
+        let finished = settled + reverted;
+        let revert_rate = if finished == 0 { 0.0 } else { reverted as f64 / finished as f64 };
+        let mean_settle = if settled == 0 { 0.0 } else { settled_time as f64 / settled as f64 };
+        let latency_excess =
+            ((mean_settle - FAST_SETTLE_TICKS) / FAST_SETTLE_TICKS).clamp(0.0, 1.0);
+
+        let shock = REVERT_WEIGHT * revert_rate
+            + LATENCY_WEIGHT * latency_excess
+            + if breaker_tripped { BREAKER_WEIGHT } else { 0.0 };
+
+        let fast = settled > 0 && reverted == 0 && mean_settle <= FAST_SETTLE_TICKS;
+        self.fast_streak = if fast { self.fast_streak + 1 } else { 0 };
+        let calm = if self.fast_streak >= CALM_STREAK_TICKS { self.calm_rate * panic } else { 0.0 };
+
+        let noise = self.noise * (2.0 * self.rng.next_f64() - 1.0);
+        (panic + self.coupling * shock - calm + noise).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverts_raise_panic_and_fast_settlements_calm_it() {
+        let mut model = PanicModel::new(1.0, 0.1, 0.0, 7);
+        // 10 reverts, no settlements: full revert-rate shock
+        let panicked = model.update(0.2, 0, 10, 0, false);
+        assert!((panicked - (0.2 + REVERT_WEIGHT)).abs() < 1e-12);
+        // Breaker trip on top of slow (2x fast) settlements
+        let slower = model.update(panicked, 5, 10, 100, true);
+        assert!((slower - (panicked + LATENCY_WEIGHT + BREAKER_WEIGHT)).abs() < 1e-12);
+
+        // Fast settlements hold panic until the streak is long enough; the
+        // still-latched breaker is not a fresh shock
+        let mut panic = slower;
+        for tick in 1..=CALM_STREAK_TICKS {
+            let next = model.update(panic, 5 + tick * 4, 10, 100 + u64::from(tick) * 8, true);
+            if tick < CALM_STREAK_TICKS {
+                assert_eq!(next, panic);
+            } else {
+                assert!((next - panic * 0.9).abs() < 1e-12);
+            }
+            panic = next;
+        }
+    }
+
+    #[test]
+    fn test_noise_is_bounded_and_seeded() {
+        let walk = |seed| {
+            let mut model = PanicModel::new(0.0, 0.0, 0.05, seed);
+            (0..100).scan(0.5, |p, _| {
+                let next = model.update(*p, 0, 0, 0, false);
+                assert!((next - *p).abs() <= 0.05);
+                *p = next;
+                Some(next)
+            }).collect::<Vec<f64>>()
+        };
+        assert_eq!(walk(1), walk(1));
+        assert_ne!(walk(1), walk(2));
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Gold Price Shocks
+//
+// gold = spot × Π (1 + magnitude × profile(age / duration))
+//
+//   Step:  profile = 1 while age < duration, then 0
+//   Spike: profile = 1 - age / duration, decaying to 0 at duration
+//   Ramp:  profile = min(age / duration, 1), holding the full move after
+//
+// `spot` is the price last set by the caller (a curve, a slider, a script),
+// so shocks ride on top of whatever drives the price.
+
+/// How a shock's move unfolds over its duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShockShape {
+    /// The full move at once, reverting after the duration
+    Step = 0,
+    /// The full move at once, decaying linearly back over the duration
+    Spike = 1,
+    /// Building linearly to the full move over the duration, then holding it
+    Ramp = 2,
+}
+
+impl ShockShape {
+    /// Map a numeric discriminant (as sent from JS) back to a shape.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Step),
+            1 => Some(Self::Spike),
+            2 => Some(Self::Ramp),
+            _ => None,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// PriceShock - one scripted move
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceShock {
+    /// Fractional move at full strength (-0.3 = 30% down)
+    pub magnitude: f64,
+    pub start_tick: u64,
+    pub duration_ticks: u64,
+    pub shape: ShockShape,
+}
+
+impl PriceShock {
+    /// Multiplier on spot at `tick`. Never negative.
+    pub fn factor(&self, tick: u64) -> f64 {
+        if tick < self.start_tick {
+            return 1.0;
+        }
+        let age = (tick - self.start_tick) as f64;
+        let duration = self.duration_ticks.max(1) as f64;
+        let profile = match self.shape {
+            ShockShape::Step => if age < duration { 1.0 } else { 0.0 },
+            ShockShape::Spike => (1.0 - age / duration).max(0.0),
+            ShockShape::Ramp => ((age + 1.0) / duration).min(1.0),
+        };
+        (1.0 + self.magnitude * profile).max(0.0)
+    }
+
+    /// Whether the shock has run its course and no longer moves the price.
+    pub fn is_spent(&self, tick: u64) -> bool {
+        self.shape != ShockShape::Ramp && tick >= self.start_tick + self.duration_ticks.max(1)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// PriceShocks - active shocks and the factor already applied to spot
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct PriceShocks {
+    shocks: Vec<PriceShock>,
+    /// Combined factor folded into the current gold price
+    applied: f64,
+}
+
+impl Default for PriceShocks {
+    fn default() -> Self {
+        Self { shocks: Vec::new(), applied: 1.0 }
+    }
+}
+
+impl PriceShocks {
+    pub fn push(&mut self, shock: PriceShock) {
+        self.shocks.push(shock);
+    }
+
+    /// The caller set a fresh spot price, which carries no shock yet.
+    pub fn rebase(&mut self) {
+        self.applied = 1.0;
+    }
+
+    /// Shocked gold price at `tick` from the current (possibly already
+    /// shocked) `price`. Spent shocks are dropped.
+    pub fn apply(&mut self, price: f64, tick: u64) -> f64 {
+        if self.shocks.is_empty() && self.applied == 1.0 {
+            return price;
+        }
+        let factor: f64 = self.shocks.iter().map(|s| s.factor(tick)).product();
+        self.shocks.retain(|s| !s.is_spent(tick));
+        // A shock that took spot to zero leaves nothing to rebase from
+        let spot = if self.applied > 0.0 { price / self.applied } else { price };
+        self.applied = factor;
+        spot * factor
+    }
+
+    pub fn active(&self) -> usize {
+        self.shocks.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shock(shape: ShockShape) -> PriceShock {
+        PriceShock { magnitude: -0.4, start_tick: 10, duration_ticks: 4, shape }
+    }
+
+    #[test]
+    fn test_shapes_over_their_duration() {
+        let factors = |shape| (9..16).map(|t| shock(shape).factor(t)).collect::<Vec<_>>();
+        let close = |a: &[f64], b: &[f64]| a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-12);
+        assert!(close(&factors(ShockShape::Step), &[1.0, 0.6, 0.6, 0.6, 0.6, 1.0, 1.0]));
+        assert!(close(&factors(ShockShape::Spike), &[1.0, 0.6, 0.7, 0.8, 0.9, 1.0, 1.0]));
+        assert!(close(&factors(ShockShape::Ramp), &[1.0, 0.9, 0.8, 0.7, 0.6, 0.6, 0.6]));
+        assert!(shock(ShockShape::Spike).is_spent(14) && !shock(ShockShape::Ramp).is_spent(100));
+    }
+
+    #[test]
+    fn test_shocks_ride_on_a_held_or_refreshed_spot() {
+        let mut shocks = PriceShocks::default();
+        shocks.push(shock(ShockShape::Step));
+        // Held spot: the factor is swapped, not compounded
+        let mut price = 100.0;
+        for tick in 9..16 {
+            price = shocks.apply(price, tick);
+        }
+        assert!((price - 100.0).abs() < 1e-9);
+        assert_eq!(shocks.active(), 0);
+
+        // Refreshed spot from a curve each tick
+        shocks.push(shock(ShockShape::Ramp));
+        shocks.rebase();
+        assert!((shocks.apply(200.0, 11) - 160.0).abs() < 1e-9);
+        shocks.rebase();
+        assert!((shocks.apply(210.0, 12) - 147.0).abs() < 1e-9);
+    }
+}
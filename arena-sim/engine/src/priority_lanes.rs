@@ -0,0 +1,83 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Priority Lanes
+//
+// When a node has more packets than its throughput cap lets it settle or
+// forward this tick (see `throughput`), the buffer is served by lane rather
+// than in arrival order:
+//
+//   lane 0       packets that have waited starvation_ticks or more here
+//   lanes 1..=4  L3, L2, L1, L0
+//
+// Order within a lane is kept, so starved packets go oldest first and each
+// tier stays FIFO. The starvation guard bounds how long L0 can be passed
+// over: once a retail packet has waited `starvation_ticks` it jumps every
+// tier. A node under its cap serves every packet anyway and is left alone.
+
+use crate::types::SimPacket;
+
+/// Tier-first service order for capped node buffers, with a starvation guard.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriorityLanes {
+    /// Ticks at a node after which a packet is served ahead of every tier
+    pub starvation_ticks: u64,
+}
+
+impl PriorityLanes {
+    pub fn new(starvation_ticks: u64) -> Self {
+        Self { starvation_ticks }
+    }
+
+    /// The lane `p` is served from at `current_tick`; lower goes first.
+    pub fn lane(&self, p: &SimPacket, current_tick: u64) -> u8 {
+        if current_tick.saturating_sub(p.arrival_tick) >= self.starvation_ticks {
+            0
+        } else {
+            4 - p.tier as u8
+        }
+    }
+
+    /// Reorder `buf` for service when only `capacity` of it fits this tick.
+    pub fn order(&self, buf: &mut [SimPacket], capacity: u32, current_tick: u64) {
+        if buf.len() > capacity as usize {
+            buf.sort_by_key(|p| match self.lane(p, current_tick) {
+                0 => (0, p.arrival_tick),
+                lane => (lane, 0),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MarketTier;
+    use crate::ArenaSimulation;
+
+    #[test]
+    fn test_lanes_serve_high_tiers_first_until_retail_starves() {
+        let mut sim = ArenaSimulation::new(4);
+        for amount in [5.0, 50_000.0, 500.0, 5.0] {
+            sim.spawn_packet(0, amount);
+        }
+        let mut buf = sim.node_buffers[&0].clone();
+        for p in buf.iter_mut() {
+            p.arrival_tick = 5;
+        }
+        buf[3].arrival_tick = 0;
+        let lanes = PriorityLanes::new(10);
+        let tiers = |buf: &[SimPacket]| buf.iter().map(|p| p.tier).collect::<Vec<_>>();
+
+        // Under the cap nothing moves
+        lanes.order(&mut buf, 4, 5);
+        assert_eq!(tiers(&buf), [MarketTier::L0, MarketTier::L2, MarketTier::L1, MarketTier::L0]);
+
+        lanes.order(&mut buf, 1, 5);
+        assert_eq!(tiers(&buf), [MarketTier::L2, MarketTier::L1, MarketTier::L0, MarketTier::L0]);
+
+        // At tick 10 the packet that arrived at tick 0 has starved
+        let starved = buf[3].id;
+        lanes.order(&mut buf, 1, 10);
+        assert_eq!(buf[0].id, starved);
+        assert_eq!(tiers(&buf[1..]), [MarketTier::L2, MarketTier::L1, MarketTier::L0]);
+    }
+}
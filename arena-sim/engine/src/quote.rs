@@ -0,0 +1,73 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Fee Quotes
+
+use crate::types::{GovernorSnapshot, MarketTier, SimPacket};
+
+/// Fee differences below this (grams) count as honoring the quote.
+pub const QUOTE_TOLERANCE: f64 = 1e-12;
+
+/// Which governor pricing a settlement fee is charged under.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PricingMode {
+    /// The fee rate and tier modifiers in force at settlement
+    #[default]
+    Spot = 0,
+    /// The pricing snapshotted when the packet was minted (cost certainty)
+    Quoted = 1,
+}
+
+impl PricingMode {
+    /// Map a numeric discriminant (as sent from JS) back to a mode.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Spot),
+            1 => Some(Self::Quoted),
+            _ => None,
+        }
+    }
+}
+
+/// Snapshot of the pricing a packet of `tier` gets at `tick`.
+pub fn snapshot(
+    tick: u64,
+    fee_rate: f64,
+    tier_modifiers: &[f64; 4],
+    quadrant: &str,
+    tier: MarketTier,
+) -> GovernorSnapshot {
+    GovernorSnapshot {
+        tick,
+        fee_rate,
+        tier_modifier: tier_modifiers[tier as usize],
+        quadrant: quadrant.chars().next().unwrap_or('?'),
+    }
+}
+
+/// The pricing the packet was quoted at spawn.
+pub fn spawn_quote(p: &SimPacket) -> Option<&GovernorSnapshot> {
+    p.governor_snapshots.first()
+}
+
+/// Settlement fee for a packet of `value` priced under `snapshot`.
+pub fn quoted_fee(snapshot: &GovernorSnapshot, tier: MarketTier, value: f64) -> f64 {
+    crate::adapter::calculate_quoted_fee_via_core(
+        snapshot.fee_rate, snapshot.tier_modifier, &tier, value,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_governor::pid::GovernorPid;
+
+    #[test]
+    fn test_quoted_fee_reproduces_governor_pricing() {
+        let governor = GovernorPid::new();
+        for (tier, value) in [(MarketTier::L0, 5.0), (MarketTier::L1, 0.2), (MarketTier::L3, 250_000.0)] {
+            let quote = snapshot(7, 0.004, &[1.0; 4], "D: Golden Era", tier);
+            assert_eq!(quote.quadrant, 'D');
+            let spot = crate::adapter::calculate_fee_via_core(&governor, &tier, 0.004, value);
+            assert_eq!(quoted_fee(&quote, tier, value), spot);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Inter-Node Liquidity Rebalancing
+//
+// The whitepaper claims liquidity gravitates to demand. With rebalancing on,
+// an Egress whose queued demand outruns its crypto inventory borrows from
+// the Egress peer within max_hops network links that has the most to spare,
+// once a tick after settlement:
+//
+//   need     = queued value - inventory_crypto - crypto already on its way in
+//   surplus  = inventory_crypto - queued value
+//   borrowed = min(need, max_share x surplus of the best-placed peer)
+//
+// This is Egress pressure (inventory against queued demand) in value terms.
+// Egress rarely link to each other directly, so the transfer crosses the
+// fewest links between the two, through any node still up. The lender's
+// inventory leaves at once and lands after the latency a packet takes over
+// the same links, 1 + distance ticks a hop. On landing the borrower
+// keeps borrowed x (1 - fee_rate) and the fee goes back into the lender's
+// inventory, so no crypto is made or lost, only held in flight; the asset
+// ledger carries what is in flight until it lands.
+
+use std::collections::VecDeque;
+
+use crate::asset_ledger::AssetLedger;
+use crate::types::{NodeRole, SimNode};
+
+/// Crypto on its way from one Egress to another.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transfer {
+    pub from: u32,
+    pub to: u32,
+    pub amount: f64,
+    pub arrival_tick: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Rebalancing {
+    /// Share of a lender's surplus it lends in one tick
+    pub max_share: f64,
+    /// Share of each transfer the borrower pays the lender
+    pub fee_rate: f64,
+    /// Links a transfer may cross
+    pub max_hops: u32,
+    /// Transfers sent, each landing at its `arrival_tick`
+    pub in_flight: Vec<Transfer>,
+    /// Crypto landed so far, and the fees paid out of it
+    pub rebalanced: f64,
+    pub fees: f64,
+}
+
+impl Rebalancing {
+    pub fn new(max_share: f64, fee_rate: f64, max_hops: u32) -> Self {
+        Self {
+            max_share: max_share.clamp(0.0, 1.0),
+            fee_rate: fee_rate.clamp(0.0, 1.0),
+            max_hops,
+            in_flight: Vec::new(),
+            rebalanced: 0.0,
+            fees: 0.0,
+        }
+    }
+
+    /// New terms for transfers from here on; those in flight land as sent.
+    pub fn configure(&mut self, max_share: f64, fee_rate: f64, max_hops: u32) {
+        self.max_share = max_share.clamp(0.0, 1.0);
+        self.fee_rate = fee_rate.clamp(0.0, 1.0);
+        self.max_hops = max_hops;
+    }
+
+    /// Crypto sent and not yet landed.
+    pub fn in_flight_value(&self) -> f64 {
+        self.in_flight.iter().map(|t| t.amount).sum()
+    }
+
+    /// Once per tick: land transfers due by `current_tick`, then start new
+    /// ones. `queued[i]` is the value waiting in node i's buffer.
+    pub fn update(
+        &mut self,
+        nodes: &mut [SimNode],
+        queued: &[f64],
+        ledger: &mut AssetLedger,
+        current_tick: u64,
+    ) {
+        let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.in_flight)
+            .into_iter()
+            .partition(|t| t.arrival_tick <= current_tick);
+        self.in_flight = pending;
+        for t in due {
+            let fee = t.amount * self.fee_rate;
+            nodes[t.to as usize].inventory_crypto += t.amount - fee;
+            nodes[t.from as usize].inventory_crypto += fee;
+            ledger.record_rebalance_landed(t.amount);
+            self.rebalanced += t.amount;
+            self.fees += fee;
+        }
+
+        let mut incoming = vec![0.0; nodes.len()];
+        for t in &self.in_flight {
+            incoming[t.to as usize] += t.amount;
+        }
+        let surplus = |n: &SimNode| n.inventory_crypto - queued.get(n.id as usize).copied().unwrap_or(0.0);
+        for i in 0..nodes.len() {
+            let node = &nodes[i];
+            let need = -surplus(node) - incoming[i];
+            if node.role != NodeRole::Egress || need <= f64::EPSILON {
+                continue;
+            }
+            // Most to spare first, then the sooner to land, then the lower id
+            let lender = peers_within(nodes, i, self.max_hops).into_iter()
+                .filter(|&(j, _)| nodes[j].role == NodeRole::Egress && surplus(&nodes[j]) > f64::EPSILON)
+                .max_by(|&(a, la), &(b, lb)| {
+                    surplus(&nodes[a]).total_cmp(&surplus(&nodes[b]))
+                        .then(lb.cmp(&la))
+                        .then(b.cmp(&a))
+                });
+            let Some((lender, latency)) = lender else {
+                continue;
+            };
+            let amount = need.min(self.max_share * surplus(&nodes[lender]));
+            if amount <= f64::EPSILON {
+                continue;
+            }
+            let transfer = Transfer {
+                from: lender as u32,
+                to: i as u32,
+                amount,
+                arrival_tick: current_tick + latency,
+            };
+            nodes[transfer.from as usize].inventory_crypto -= amount;
+            ledger.record_rebalance_sent(amount);
+            incoming[i] += amount;
+            self.in_flight.push(transfer);
+        }
+    }
+}
+
+/// Nodes within `max_hops` links of `from` over nodes still up, each with
+/// the ticks a packet takes over the fewest links there.
+fn peers_within(nodes: &[SimNode], from: usize, max_hops: u32) -> Vec<(usize, u64)> {
+    let mut seen = vec![None; nodes.len()];
+    seen[from] = Some((0u32, 0u64));
+    let mut queue = VecDeque::from([from]);
+    let mut peers = Vec::new();
+    while let Some(at) = queue.pop_front() {
+        let (hops, latency) = seen[at].unwrap_or_default();
+        if hops == max_hops {
+            continue;
+        }
+        for &next in &nodes[at].neighbors {
+            let next = next as usize;
+            if next >= nodes.len() || seen[next].is_some() || nodes[next].role == NodeRole::Disabled {
+                continue;
+            }
+            let (a, b) = (&nodes[at], &nodes[next]);
+            let distance = ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+            let reached = (hops + 1, latency + 1 + distance as u64);
+            seen[next] = Some(reached);
+            peers.push((next, reached.1));
+            queue.push_back(next);
+        }
+    }
+    peers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArenaSimulation;
+
+    #[test]
+    fn test_short_egress_borrows_from_a_linked_peer_and_repays_the_fee() {
+        let mut sim = ArenaSimulation::new(8);
+        for n in sim.nodes.iter_mut() {
+            n.role = NodeRole::Transit;
+            n.neighbors.clear();
+            n.x = 0.0;
+            n.y = 0.0;
+        }
+        for (id, inventory) in [(0, 100.0), (1, 1_000.0), (2, 5_000.0)] {
+            sim.nodes[id].role = NodeRole::Egress;
+            sim.nodes[id].inventory_crypto = inventory;
+        }
+        // Node 2 is two links from node 0, through Transit node 3
+        sim.nodes[0].neighbors = vec![1, 3];
+        sim.nodes[1].neighbors = vec![0];
+        sim.nodes[2].neighbors = vec![3];
+        sim.nodes[3].neighbors = vec![0, 2];
+        sim.nodes[1].x = 3.0;
+        assert_eq!(peers_within(&sim.nodes, 0, 1), [(1, 4), (3, 1)]);
+        assert_eq!(peers_within(&sim.nodes, 0, 2), [(1, 4), (3, 1), (2, 2)]);
+        let mut ledger = AssetLedger::opening(&sim.nodes);
+        let mut queued = vec![0.0; sim.nodes.len()];
+        queued[0] = 500.0;
+
+        // Node 0 is 400 short; half of node 1's 1000 spare covers it. Node 2
+        // has more to spare but is out of reach
+        let mut rebalancing = Rebalancing::new(0.5, 0.01, 1);
+        rebalancing.update(&mut sim.nodes, &queued, &mut ledger, 1);
+        assert_eq!(rebalancing.in_flight.len(), 1);
+        assert_eq!(rebalancing.in_flight[0].from, 1);
+        assert_eq!(rebalancing.in_flight[0].arrival_tick, 5);
+        assert_eq!(sim.nodes[1].inventory_crypto, 600.0);
+        assert!(ledger.report(&sim.nodes).crypto.residual < 1e-9);
+
+        // Nothing more is borrowed while the first transfer is on its way
+        for tick in 2..5 {
+            rebalancing.update(&mut sim.nodes, &queued, &mut ledger, tick);
+            assert_eq!(rebalancing.in_flight_value(), 400.0);
+        }
+        // By the time it lands node 0's queue has settled
+        queued[0] = 0.0;
+        rebalancing.update(&mut sim.nodes, &queued, &mut ledger, 5);
+        assert!(rebalancing.in_flight.is_empty());
+        assert!((sim.nodes[0].inventory_crypto - 496.0).abs() < 1e-9);
+        assert!((sim.nodes[1].inventory_crypto - 604.0).abs() < 1e-9);
+        assert!((rebalancing.fees - 4.0).abs() < 1e-9);
+        assert!(ledger.report(&sim.nodes).crypto.residual < 1e-9);
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Trust Fee Rebates
+
+use crate::types::SimNode;
+
+/// Minimum trust score of every node on a route for it to earn a rebate.
+pub const REBATE_TRUST_THRESHOLD: f64 = 0.9;
+/// Share of every transit hop fee paid into the rebate pool.
+pub const TRANSIT_POOL_SHARE: f64 = 0.05;
+/// Share of a trusted packet's fees refunded at settlement, while the pool lasts.
+pub const TRUST_REBATE_SHARE: f64 = 0.1;
+
+/// True when every node the packet visited is at or above the trust threshold.
+pub fn route_is_trusted(nodes: &[SimNode], route: &[u32]) -> bool {
+    route.iter().all(|&id| {
+        nodes.get(id as usize)
+            .is_some_and(|n| n.trust_score >= REBATE_TRUST_THRESHOLD)
+    })
+}
+
+// ---------------------------------------------------------------------------
+// RebatePool - transit-funded discounts for high-trust routes
+// ---------------------------------------------------------------------------
+
+/// Fee value held back from transit nodes to fund rebates. The balance is
+/// still counted as collected fees until a rebate returns it to a packet.
+#[derive(Debug, Clone, Default)]
+pub struct RebatePool {
+    pub balance: f64,
+    pub paid: f64,
+    pub trusted_settlements: u32,
+}
+
+impl RebatePool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Withhold the pool's share of a transit hop fee. Returns the amount
+    /// withheld; the transit node earns the rest.
+    pub fn fund(&mut self, hop_fee: f64) -> f64 {
+        let cut = (hop_fee * TRANSIT_POOL_SHARE).max(0.0);
+        self.balance += cut;
+        cut
+    }
+
+    /// Rebate for a settling packet that paid `fees_paid` in total. Only
+    /// routes made entirely of high-trust nodes qualify, and the rebate never
+    /// exceeds the pool balance.
+    pub fn claim(&mut self, nodes: &[SimNode], route: &[u32], fees_paid: f64) -> f64 {
+        if !route_is_trusted(nodes, route) {
+            return 0.0;
+        }
+        self.trusted_settlements += 1;
+        let rebate = (fees_paid * TRUST_REBATE_SHARE).clamp(0.0, self.balance);
+        self.balance -= rebate;
+        self.paid += rebate;
+        rebate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::NodeRole;
+
+    fn node(id: u32, trust_score: f64) -> SimNode {
+        SimNode { trust_score, ..SimNode::test_default(id, NodeRole::Transit) }
+    }
+
+    #[test]
+    fn test_rebate_requires_every_hop_trusted() {
+        let nodes = vec![node(0, 1.0), node(1, 0.95), node(2, 0.5)];
+        let mut pool = RebatePool::new();
+        assert_eq!(pool.fund(100.0), 5.0);
+
+        assert_eq!(pool.claim(&nodes, &[0, 2, 1], 10.0), 0.0);
+        assert_eq!(pool.claim(&nodes, &[0, 7], 10.0), 0.0);
+        assert!((pool.claim(&nodes, &[0, 1], 10.0) - 1.0).abs() < 1e-12);
+        assert_eq!(pool.trusted_settlements, 1);
+    }
+
+    #[test]
+    fn test_rebate_capped_at_pool_balance() {
+        let nodes = vec![node(0, 1.0)];
+        let mut pool = RebatePool::new();
+        pool.fund(5.0);
+        assert_eq!(pool.claim(&nodes, &[0], 10.0), 0.25);
+        assert_eq!(pool.claim(&nodes, &[0], 10.0), 0.0);
+        assert_eq!(pool.balance, 0.0);
+        assert_eq!((pool.paid, pool.trusted_settlements), (0.25, 2));
+    }
+}
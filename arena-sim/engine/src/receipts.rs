@@ -0,0 +1,107 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Revert Receipts
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::types::{MarketTier, RevertReason, SimPacket};
+
+/// Receipts retained for inspection; counts by reason cover every revert.
+pub const MAX_REVERT_RECEIPTS: usize = 10_000;
+
+// ---------------------------------------------------------------------------
+// RevertReceipt - terminal record of a refunded packet
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct RevertReceipt {
+    pub packet_id: u64,
+    pub tick: u64,
+    pub origin_node: u32,
+    pub tier: MarketTier,
+    pub original_value: f64,
+    /// Value returned to the sender (after demurrage and surge burns)
+    pub refunded_value: f64,
+    pub reason: RevertReason,
+}
+
+// ---------------------------------------------------------------------------
+// RevertLog
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct RevertLog {
+    receipts: VecDeque<RevertReceipt>,
+    /// Reverts so far, by `RevertReason` discriminant
+    pub counts: [u32; RevertReason::COUNT],
+}
+
+impl RevertLog {
+    /// Stamp `packet` with `reason` and keep its receipt.
+    pub fn record(&mut self, packet: &mut SimPacket, tick: u64, reason: RevertReason) {
+        packet.revert_reason = Some(reason);
+        self.counts[reason as usize] += 1;
+        if self.receipts.len() == MAX_REVERT_RECEIPTS {
+            self.receipts.pop_front();
+        }
+        self.receipts.push_back(RevertReceipt {
+            packet_id: packet.id,
+            tick,
+            origin_node: packet.origin_node,
+            tier: packet.tier,
+            original_value: packet.original_value,
+            refunded_value: packet.current_value,
+            reason,
+        });
+    }
+
+    /// Retained receipts, oldest first.
+    pub fn receipts(&self) -> impl Iterator<Item = &RevertReceipt> {
+        self.receipts.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.receipts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.receipts.is_empty()
+    }
+
+    pub fn heap_bytes(&self) -> usize {
+        self.receipts.capacity() * std::mem::size_of::<RevertReceipt>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PacketStatus;
+
+    fn packet(id: u64) -> SimPacket {
+        SimPacket {
+            id, current_value: 90.0, status: PacketStatus::Held, tier: MarketTier::L1,
+            ttl: 500, hop_limit: 20, fee_budget: 2.0,
+            ..SimPacket::test_default(4, 100.0)
+        }
+    }
+
+    #[test]
+    fn test_counts_every_revert_but_keeps_only_recent_receipts() {
+        let mut log = RevertLog::default();
+        for id in 0..MAX_REVERT_RECEIPTS as u64 + 5 {
+            let mut p = packet(id);
+            let reason = if id % 2 == 0 { RevertReason::TtlExpired } else { RevertReason::NoLiquidEgress };
+            log.record(&mut p, id, reason);
+            assert_eq!(p.revert_reason, Some(reason));
+        }
+        assert_eq!(log.counts[RevertReason::TtlExpired as usize], 5003);
+        assert_eq!(log.counts[RevertReason::NoLiquidEgress as usize], 5002);
+        assert_eq!(log.len(), MAX_REVERT_RECEIPTS);
+        let oldest = log.receipts().next().unwrap();
+        assert_eq!(oldest.packet_id, 5);
+        assert_eq!(oldest.refunded_value, 90.0);
+    }
+}
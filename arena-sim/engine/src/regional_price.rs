@@ -0,0 +1,143 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Regional Gold Premiums
+//
+// Gold does not trade at one price everywhere: a country in crisis pays a
+// local premium over the global oracle. A region's local price is the oracle
+// price times (1 + its premium). Settlements are tagged with the regions
+// they leave and enter, and value delivered across regions is priced at the
+// local spread, so the arbitrage pressure behind cross-border flows shows up
+// per region. Nodes outside every region trade at the oracle price.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::corridor::Region;
+
+/// Local price and cross-region flows of one priced region.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct RegionPriceStats {
+    pub region: Region,
+    /// Local premium over the oracle price (0.05 = 5% dearer)
+    pub premium: f64,
+    pub local_price: f64,
+    /// Value delivered at Egress nodes in the region
+    pub value_settled: f64,
+    /// Of that, value sent from outside the region
+    pub value_imported: f64,
+    /// Value sent from the region and delivered elsewhere
+    pub value_exported: f64,
+    /// Local-price gain on imported value over its origin's price, in fiat:
+    /// positive when gold flows from cheaper regions into this one
+    pub arbitrage_gain: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct RegionTotals {
+    value_settled: f64,
+    value_imported: f64,
+    value_exported: f64,
+    arbitrage_gain: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RegionalPrices {
+    regions: Vec<(Region, f64)>,
+    totals: Vec<RegionTotals>,
+}
+
+impl RegionalPrices {
+    /// Price `region` at `premium` over the oracle; re-pricing a region
+    /// keeps its totals.
+    pub fn set_premium(&mut self, region: Region, premium: f64) {
+        let premium = premium.max(-1.0);
+        match self.regions.iter_mut().find(|(r, _)| *r == region) {
+            Some(entry) => entry.1 = premium,
+            None => {
+                self.regions.push((region, premium));
+                self.totals.push(RegionTotals::default());
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.regions.clear();
+        self.totals.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Index of the first region containing `site`.
+    fn region_of(&self, site: u32) -> Option<usize> {
+        self.regions.iter().position(|(r, _)| r.contains(site))
+    }
+
+    pub fn premium(&self, site: u32) -> f64 {
+        self.region_of(site).map_or(0.0, |i| self.regions[i].1)
+    }
+
+    /// Gold price at `site` given the oracle price.
+    pub fn local_price(&self, oracle_price: f64, site: u32) -> f64 {
+        oracle_price * (1.0 + self.premium(site))
+    }
+
+    /// Record `delivered` grams sent from `origin_site` settling at an Egress
+    /// in `egress_site`.
+    pub fn record_settlement(&mut self, origin_site: u32, egress_site: u32, delivered: f64, oracle_price: f64) {
+        let (from, to) = (self.region_of(origin_site), self.region_of(egress_site));
+        if let Some(t) = to.map(|i| &mut self.totals[i]) {
+            t.value_settled += delivered;
+        }
+        if from == to {
+            return;
+        }
+        let spread = self.local_price(oracle_price, egress_site) - self.local_price(oracle_price, origin_site);
+        if let Some(t) = to.map(|i| &mut self.totals[i]) {
+            t.value_imported += delivered;
+            t.arbitrage_gain += delivered * spread;
+        }
+        if let Some(t) = from.map(|i| &mut self.totals[i]) {
+            t.value_exported += delivered;
+        }
+    }
+
+    pub fn stats(&self, oracle_price: f64) -> Vec<RegionPriceStats> {
+        self.regions.iter().zip(&self.totals).map(|(&(region, premium), t)| RegionPriceStats {
+            region,
+            premium,
+            local_price: oracle_price * (1.0 + premium),
+            value_settled: t.value_settled,
+            value_imported: t.value_imported,
+            value_exported: t.value_exported,
+            arbitrage_gain: t.arbitrage_gain,
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cross_region_settlements_capture_the_spread() {
+        let mut prices = RegionalPrices::default();
+        prices.set_premium(Region::new(0, 2), 0.0);
+        prices.set_premium(Region::new(2, 4), 0.10);
+        assert_eq!(prices.local_price(2000.0, 2), 2200.0);
+        assert_eq!(prices.local_price(2000.0, 10), 2000.0, "Outside every region");
+
+        // Site 0 to site 2, then a local settlement in site 3
+        prices.record_settlement(0, 2, 5.0, 2000.0);
+        prices.record_settlement(3, 3, 1.0, 2000.0);
+        let stats = prices.stats(2000.0);
+        assert_eq!((stats[0].value_exported, stats[0].value_settled), (5.0, 0.0));
+        assert_eq!((stats[1].value_settled, stats[1].value_imported), (6.0, 5.0));
+        assert!((stats[1].arbitrage_gain - 5.0 * 200.0).abs() < 1e-9);
+
+        prices.set_premium(Region::new(2, 4), -0.05);
+        assert_eq!(prices.stats(2000.0).len(), 2, "Re-pricing keeps the region");
+        prices.record_settlement(2, 0, 2.0, 2000.0);
+        assert!((prices.stats(2000.0)[0].arbitrage_gain - 2.0 * 100.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Egress Replenishment
+//
+// Settlements draw Egress crypto inventory down and, outside batch netting
+// and provider agents, nothing puts it back, so a long enough run stalls
+// once every Egress is dry. A replenishment policy tops each live Egress up
+// once per tick:
+//
+//   FixedRate      inflow = rate                          (an operator's standing refill)
+//   FeeRecycling   inflow = rate x fees earned this tick  (fees kept on hand as inventory)
+//   ProviderCurve  inflow = rate x max(0, 1 - inventory / reference)
+//
+// The provider curve is outside liquidity that comes in faster the further
+// an Egress has fallen below its reference, the inventory it held when the
+// policy took effect (or when it first became an Egress after that), and
+// stops once it is back there. Recycled fees stay in `total_fees_earned`,
+// which is a running tally rather than a balance. Every inflow is recorded
+// in the asset ledger.
+//
+// Liquidity runway is the ticks the live Egress inventory lasts at the net
+// rate it is drawn down, smoothed draws less smoothed inflow:
+//
+//   runway = sum(inventory_crypto) / (draw_rate - inflow_rate)
+
+use crate::asset_ledger::AssetLedger;
+use crate::egress_queue::DRAW_RATE_ALPHA;
+use crate::types::{NodeRole, SimNode};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReplenishmentPolicy {
+    #[default]
+    None = 0,
+    FixedRate = 1,
+    FeeRecycling = 2,
+    ProviderCurve = 3,
+}
+
+impl ReplenishmentPolicy {
+    /// Map a numeric discriminant (as sent from JS) back to a policy.
+    pub fn from_u32(v: u32) -> Option<Self> {
+        match v {
+            0 => Some(Self::None),
+            1 => Some(Self::FixedRate),
+            2 => Some(Self::FeeRecycling),
+            3 => Some(Self::ProviderCurve),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Replenishment {
+    pub policy: ReplenishmentPolicy,
+    pub rate: f64,
+    /// Inventory put back so far
+    pub replenished: f64,
+    /// Inflow per tick, smoothed like `EgressDrawRates`
+    inflow_rate: f64,
+    /// By node id: cumulative fees when last seen, and the provider curve's
+    /// reference inventory (None until the node is seen as an Egress)
+    last_fees: Vec<f64>,
+    reference: Vec<Option<f64>>,
+}
+
+impl Replenishment {
+    pub fn new(policy: ReplenishmentPolicy, rate: f64) -> Self {
+        Self {
+            policy,
+            rate: rate.max(0.0),
+            replenished: 0.0,
+            inflow_rate: 0.0,
+            last_fees: Vec::new(),
+            reference: Vec::new(),
+        }
+    }
+
+    /// Once per tick, after the node cycle: top up every live Egress.
+    pub fn update(&mut self, nodes: &mut [SimNode], ledger: &mut AssetLedger) {
+        for node in &nodes[self.last_fees.len().min(nodes.len())..] {
+            self.last_fees.push(node.total_fees_earned);
+        }
+        self.reference.resize(nodes.len(), None);
+        let mut inflow = 0.0;
+        for (i, node) in nodes.iter_mut().enumerate() {
+            let earned = node.total_fees_earned - self.last_fees[i];
+            self.last_fees[i] = node.total_fees_earned;
+            if node.role != NodeRole::Egress {
+                self.reference[i] = None;
+                continue;
+            }
+            let reference = *self.reference[i].get_or_insert(node.inventory_crypto.max(0.0));
+            let amount = match self.policy {
+                ReplenishmentPolicy::None => 0.0,
+                ReplenishmentPolicy::FixedRate => self.rate,
+                ReplenishmentPolicy::FeeRecycling => self.rate * earned.max(0.0),
+                ReplenishmentPolicy::ProviderCurve if reference > 0.0 => {
+                    self.rate * (1.0 - node.inventory_crypto / reference).max(0.0)
+                }
+                ReplenishmentPolicy::ProviderCurve => 0.0,
+            };
+            if amount > 0.0 {
+                node.inventory_crypto += amount;
+                ledger.record_replenishment(amount);
+                inflow += amount;
+            }
+        }
+        self.replenished += inflow;
+        self.inflow_rate += DRAW_RATE_ALPHA * (inflow - self.inflow_rate);
+    }
+
+    /// Smoothed inventory put back per tick, across every Egress.
+    pub fn inflow_rate(&self) -> f64 {
+        self.inflow_rate
+    }
+}
+
+/// Ticks the live Egress inventory lasts at `draw_rate` less `inflow_rate`;
+/// None while it is not running down.
+pub fn runway(nodes: &[SimNode], draw_rate: f64, inflow_rate: f64) -> Option<f64> {
+    let net = draw_rate - inflow_rate;
+    let inventory: f64 = nodes.iter()
+        .filter(|n| n.role == NodeRole::Egress)
+        .map(|n| n.inventory_crypto.max(0.0))
+        .sum();
+    (net > f64::EPSILON).then(|| inventory / net)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArenaSimulation;
+
+    #[test]
+    fn test_policies_top_up_live_egress_and_keep_the_ledger() {
+        let mut sim = ArenaSimulation::new(8);
+        let egress: Vec<usize> = sim.nodes.iter()
+            .filter(|n| n.role == NodeRole::Egress)
+            .map(|n| n.id as usize)
+            .collect();
+        let e = egress[0];
+        let mut ledger = AssetLedger::opening(&sim.nodes);
+
+        let mut fixed = Replenishment::new(ReplenishmentPolicy::FixedRate, 10.0);
+        fixed.update(&mut sim.nodes, &mut ledger);
+        assert!((fixed.replenished - 10.0 * egress.len() as f64).abs() < 1e-9);
+
+        // Fees are recycled as they are earned, not from before
+        let mut recycling = Replenishment::new(ReplenishmentPolicy::FeeRecycling, 0.5);
+        sim.nodes[e].total_fees_earned += 100.0;
+        recycling.update(&mut sim.nodes, &mut ledger);
+        sim.nodes[e].total_fees_earned += 40.0;
+        recycling.update(&mut sim.nodes, &mut ledger);
+        assert!((recycling.replenished - 20.0).abs() < 1e-9);
+
+        // The curve refills a quarter-drained Egress at a quarter of the rate,
+        // and a full one not at all
+        let mut curve = Replenishment::new(ReplenishmentPolicy::ProviderCurve, 100.0);
+        curve.update(&mut sim.nodes, &mut ledger);
+        assert_eq!(curve.replenished, 0.0);
+        let before = sim.nodes[e].inventory_crypto;
+        sim.nodes[e].inventory_crypto = 0.75 * before;
+        ledger.record_settlement(0.25 * before);
+        curve.update(&mut sim.nodes, &mut ledger);
+        assert!((curve.replenished - 25.0).abs() < 1e-9);
+
+        assert!(ledger.report(&sim.nodes).crypto.residual < 1e-6);
+        assert_eq!(runway(&sim.nodes, 5.0, 5.0), None);
+        assert!(runway(&sim.nodes, 10.0, 0.0).unwrap() > 0.0);
+    }
+}
@@ -0,0 +1,329 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Node Resources
+//
+// What it costs to run a node. Each node pledges storage (PoSpace) and
+// provisions bandwidth, paid for by the day, and draws energy: a baseline
+// while it is up, plus a little per packet it relays and more per NGauge
+// verification it sells. Costs are in the packets' own value unit and are
+// netted against the fees a node earned into a per-node P&L, so hardware
+// and sustainability figures come from simulated traffic, not assumptions.
+//
+// With the model on, gravity dissolution's "demonstrable capacity" checks a
+// node's pledge and bandwidth against the minimums below; with it off, it
+// stays the bandwidth threshold alone.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::dissolution::DISSOLUTION_TIMEOUT_TICKS;
+use crate::types::{NodeRole, SimNode};
+
+/// Bandwidth (Mbps) a node must provision to count as demonstrable capacity.
+pub const MIN_BANDWIDTH_MBPS: f64 = 10.0;
+/// Storage (GB) a node must pledge to count as demonstrable capacity.
+pub const MIN_STORAGE_PLEDGE_GB: f64 = 100.0;
+
+/// Ticks per day, at the scale that maps the 90-day dissolution timeout to
+/// `DISSOLUTION_TIMEOUT_TICKS`.
+pub const TICKS_PER_DAY: f64 = DISSOLUTION_TIMEOUT_TICKS as f64 / 90.0;
+
+/// Power a live node draws while idle (watts).
+pub const IDLE_WATTS: f64 = 25.0;
+/// Energy to relay or settle one packet (Wh).
+pub const WH_PER_RELAY: f64 = 0.02;
+/// Energy to perform one NGauge verification (Wh).
+pub const WH_PER_VERIFICATION: f64 = 0.5;
+
+/// Storage a node pledges until told otherwise: NGauge verifiers carry the
+/// PoSpace plots, Egress the settlement records.
+pub fn default_storage_pledge_gb(role: NodeRole) -> f64 {
+    match role {
+        NodeRole::NGauge => 2_000.0,
+        NodeRole::Egress => 500.0,
+        NodeRole::Ingress | NodeRole::Transit => 250.0,
+        NodeRole::Disabled => 0.0,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// ResourcePrices
+// ---------------------------------------------------------------------------
+
+/// Unit prices in the packets' value unit.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct ResourcePrices {
+    pub storage_per_gb_day: f64,
+    pub bandwidth_per_mbps_day: f64,
+    pub energy_per_kwh: f64,
+}
+
+impl Default for ResourcePrices {
+    /// Commodity hosting: 0.02 per GB-month, 0.50 per Mbps-month, 0.15 per kWh.
+    fn default() -> Self {
+        Self {
+            storage_per_gb_day: 0.02 / 30.0,
+            bandwidth_per_mbps_day: 0.50 / 30.0,
+            energy_per_kwh: 0.15,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Reports
+// ---------------------------------------------------------------------------
+
+/// One node's resources, running costs and fees earned over the run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct NodePnl {
+    pub node_id: u32,
+    pub role: NodeRole,
+    pub storage_pledged_gb: f64,
+    pub bandwidth_mbps: f64,
+    pub energy_kwh: f64,
+    pub relays: u64,
+    pub verifications: u64,
+    pub storage_cost: f64,
+    pub bandwidth_cost: f64,
+    pub energy_cost: f64,
+    /// Fees earned (`total_fees_earned`)
+    pub revenue: f64,
+    /// Revenue less storage, bandwidth and energy costs
+    pub net: f64,
+}
+
+/// Node P&L summed over one role.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct RolePnl {
+    pub role: NodeRole,
+    pub nodes: u32,
+    /// Nodes whose fees covered their costs
+    pub profitable_nodes: u32,
+    pub storage_pledged_gb: f64,
+    pub energy_kwh: f64,
+    pub cost: f64,
+    pub revenue: f64,
+    pub net: f64,
+}
+
+/// Network-wide resource use and running costs, for hardware and
+/// sustainability figures.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct ResourceSummary {
+    pub prices: ResourcePrices,
+    /// Simulated days the costs cover
+    pub days: f64,
+    pub storage_pledged_gb: f64,
+    pub bandwidth_mbps: f64,
+    pub energy_kwh: f64,
+    pub cost: f64,
+    pub revenue: f64,
+    /// Energy and running cost per settled payment (0 before the first)
+    pub energy_wh_per_settlement: f64,
+    pub cost_per_settlement: f64,
+    /// Live roles in `NodeRole` order
+    pub by_role: Vec<RolePnl>,
+}
+
+// ---------------------------------------------------------------------------
+// ResourceModel
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+struct NodeLedger {
+    // None pledges the role default
+    storage_pledged_gb: Option<f64>,
+    energy_wh: f64,
+    relays: u64,
+    verifications: u64,
+    storage_cost: f64,
+    bandwidth_cost: f64,
+    energy_cost: f64,
+}
+
+/// Running costs per node, indexed by node id.
+#[derive(Debug, Clone, Default)]
+pub struct ResourceModel {
+    prices: ResourcePrices,
+    ledgers: Vec<NodeLedger>,
+    ticks: u64,
+}
+
+impl ResourceModel {
+    pub fn new(prices: ResourcePrices) -> Self {
+        Self { prices, ..Self::default() }
+    }
+
+    pub fn prices(&self) -> ResourcePrices {
+        self.prices
+    }
+
+    /// New prices apply from the next tick; costs so far stand.
+    pub fn set_prices(&mut self, prices: ResourcePrices) {
+        self.prices = prices;
+    }
+
+    fn ledger(&mut self, node_id: u32) -> &mut NodeLedger {
+        let i = node_id as usize;
+        if i >= self.ledgers.len() {
+            self.ledgers.resize(i + 1, NodeLedger::default());
+        }
+        &mut self.ledgers[i]
+    }
+
+    fn add_energy(&mut self, node_id: u32, wh: f64) {
+        let price = self.prices.energy_per_kwh;
+        let ledger = self.ledger(node_id);
+        ledger.energy_wh += wh;
+        ledger.energy_cost += wh / 1000.0 * price;
+    }
+
+    /// Pledge `gb` of storage on `node_id` in place of its role default.
+    pub fn set_storage_pledge(&mut self, node_id: u32, gb: f64) {
+        self.ledger(node_id).storage_pledged_gb = Some(gb.max(0.0));
+    }
+
+    pub fn storage_pledge_gb(&self, node: &SimNode) -> f64 {
+        self.ledgers.get(node.id as usize)
+            .and_then(|l| l.storage_pledged_gb)
+            .unwrap_or_else(|| default_storage_pledge_gb(node.role))
+    }
+
+    /// Whether `node` meets the storage and bandwidth minimums.
+    pub fn demonstrable_capacity(&self, node: &SimNode) -> bool {
+        node.bandwidth >= MIN_BANDWIDTH_MBPS && self.storage_pledge_gb(node) >= MIN_STORAGE_PLEDGE_GB
+    }
+
+    /// One tick of storage, bandwidth and idle power for every live node.
+    pub fn accrue_tick(&mut self, nodes: &[SimNode]) {
+        self.ticks += 1;
+        let idle_wh = IDLE_WATTS * 24.0 / TICKS_PER_DAY;
+        for node in nodes.iter().filter(|n| n.role != NodeRole::Disabled) {
+            let pledge = self.storage_pledge_gb(node);
+            let prices = self.prices;
+            let ledger = self.ledger(node.id);
+            ledger.storage_cost += pledge * prices.storage_per_gb_day / TICKS_PER_DAY;
+            ledger.bandwidth_cost += node.bandwidth * prices.bandwidth_per_mbps_day / TICKS_PER_DAY;
+            self.add_energy(node.id, idle_wh);
+        }
+    }
+
+    /// `node_id` forwarded or settled a packet.
+    pub fn record_relay(&mut self, node_id: u32) {
+        self.ledger(node_id).relays += 1;
+        self.add_energy(node_id, WH_PER_RELAY);
+    }
+
+    /// `node_id` sold an NGauge verification.
+    pub fn record_verification(&mut self, node_id: u32) {
+        self.ledger(node_id).verifications += 1;
+        self.add_energy(node_id, WH_PER_VERIFICATION);
+    }
+
+    /// Every node's P&L, tombstones of removed nodes included.
+    pub fn pnl(&self, nodes: &[SimNode]) -> Vec<NodePnl> {
+        nodes.iter()
+            .map(|n| {
+                let l = self.ledgers.get(n.id as usize).cloned().unwrap_or_default();
+                let cost = l.storage_cost + l.bandwidth_cost + l.energy_cost;
+                NodePnl {
+                    node_id: n.id,
+                    role: n.role,
+                    storage_pledged_gb: self.storage_pledge_gb(n),
+                    bandwidth_mbps: n.bandwidth,
+                    energy_kwh: l.energy_wh / 1000.0,
+                    relays: l.relays,
+                    verifications: l.verifications,
+                    storage_cost: l.storage_cost,
+                    bandwidth_cost: l.bandwidth_cost,
+                    energy_cost: l.energy_cost,
+                    revenue: n.total_fees_earned,
+                    net: n.total_fees_earned - cost,
+                }
+            })
+            .collect()
+    }
+
+    /// Network totals and per-role P&L, with `settlements` payments settled.
+    pub fn summary(&self, nodes: &[SimNode], settlements: u32) -> ResourceSummary {
+        let pnl = self.pnl(nodes);
+        let cost_of = |p: &NodePnl| p.storage_cost + p.bandwidth_cost + p.energy_cost;
+        let by_role = [NodeRole::Ingress, NodeRole::Egress, NodeRole::Transit, NodeRole::NGauge]
+            .into_iter()
+            .filter_map(|role| {
+                let rows: Vec<&NodePnl> = pnl.iter().filter(|p| p.role == role).collect();
+                (!rows.is_empty()).then(|| RolePnl {
+                    role,
+                    nodes: rows.len() as u32,
+                    profitable_nodes: rows.iter().filter(|p| p.net >= 0.0).count() as u32,
+                    storage_pledged_gb: rows.iter().map(|p| p.storage_pledged_gb).sum(),
+                    energy_kwh: rows.iter().map(|p| p.energy_kwh).sum(),
+                    cost: rows.iter().map(|p| cost_of(p)).sum(),
+                    revenue: rows.iter().map(|p| p.revenue).sum(),
+                    net: rows.iter().map(|p| p.net).sum(),
+                })
+            })
+            .collect();
+
+        let live = pnl.iter().filter(|p| p.role != NodeRole::Disabled);
+        let per_settlement = |total: f64| if settlements == 0 { 0.0 } else { total / settlements as f64 };
+        let energy_kwh: f64 = pnl.iter().map(|p| p.energy_kwh).sum();
+        let cost: f64 = pnl.iter().map(cost_of).sum();
+        ResourceSummary {
+            prices: self.prices,
+            days: self.ticks as f64 / TICKS_PER_DAY,
+            storage_pledged_gb: live.clone().map(|p| p.storage_pledged_gb).sum(),
+            bandwidth_mbps: live.map(|p| p.bandwidth_mbps).sum(),
+            energy_kwh,
+            cost,
+            revenue: pnl.iter().map(|p| p.revenue).sum(),
+            energy_wh_per_settlement: per_settlement(energy_kwh * 1000.0),
+            cost_per_settlement: per_settlement(cost),
+            by_role,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::TopologyBuilder;
+
+    #[test]
+    fn test_costs_accrue_by_day_and_by_work() {
+        let nodes = TopologyBuilder::new(4).build_nodes();
+        let mut model = ResourceModel::new(ResourcePrices::default());
+        for _ in 0..TICKS_PER_DAY as u64 {
+            model.accrue_tick(&nodes);
+        }
+        model.record_relay(1);
+        model.record_verification(3);
+
+        let pnl = model.pnl(&nodes);
+        let egress = &pnl[1];
+        // A day's pledge, bandwidth and idle power, less the fraction of a
+        // day the whole ticks fall short by
+        let day = (TICKS_PER_DAY as u64) as f64 / TICKS_PER_DAY;
+        assert!((egress.storage_cost - 500.0 * 0.02 / 30.0 * day).abs() < 1e-12);
+        assert!((egress.bandwidth_cost - 100.0 * 0.5 / 30.0 * day).abs() < 1e-12);
+        assert!((egress.energy_kwh - (IDLE_WATTS * 24.0 * day + WH_PER_RELAY) / 1000.0).abs() < 1e-12);
+        assert_eq!((egress.relays, pnl[3].verifications), (1, 1));
+        assert!(egress.net < 0.0, "no fees earned yet");
+
+        let summary = model.summary(&nodes, 4);
+        assert_eq!(summary.by_role.len(), 4);
+        assert_eq!(summary.storage_pledged_gb, 250.0 + 500.0 + 250.0 + 2_000.0);
+        assert!((summary.cost_per_settlement - summary.cost / 4.0).abs() < 1e-12);
+        assert!((summary.days - day).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_demonstrable_capacity_needs_pledge_and_bandwidth() {
+        let mut nodes = TopologyBuilder::new(4).build_nodes();
+        let mut model = ResourceModel::default();
+        assert!(nodes.iter().all(|n| model.demonstrable_capacity(n)));
+        model.set_storage_pledge(2, MIN_STORAGE_PLEDGE_GB - 1.0);
+        nodes[3].bandwidth = MIN_BANDWIDTH_MBPS / 2.0;
+        let capable: Vec<bool> = nodes.iter().map(|n| model.demonstrable_capacity(n)).collect();
+        assert_eq!(capable, [true, true, false, false]);
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Sender Retries After Revert
+//
+// A reverted packet's value goes back to its sender, who often tries again.
+// With a retry model set, each TTL, orbit-timeout or hop-limit revert is
+// re-sent with `probability` after `delay_ticks`, at the same Ingress and
+// for the refunded value, so the offered load of a sustained-demand run
+// includes its retries. Cancellations are the sender's own choice and are
+// never retried.
+
+use std::collections::VecDeque;
+
+use crate::corridor::CorridorTag;
+use crate::rng::SplitMix64;
+use crate::types::SimPacket;
+
+/// Chance and delay of a sender re-sending a reverted payment. Off by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RetryModel {
+    pub probability: f64,
+    pub delay_ticks: u64,
+}
+
+/// A refunded payment its sender will re-send at `due_tick`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PendingRetry {
+    pub due_tick: u64,
+    pub node_id: u32,
+    pub amount: f64,
+    pub corridor: Option<CorridorTag>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RetryQueue {
+    model: RetryModel,
+    // Ordered by due tick
+    pending: VecDeque<PendingRetry>,
+    // Seeded runs draw each decision; unseeded runs retry a fixed share
+    rng: Option<SplitMix64>,
+    credit: f64,
+    pub scheduled: u32,
+}
+
+impl RetryQueue {
+    /// Set the model; retries already scheduled keep their due tick.
+    pub fn set_model(&mut self, probability: f64, delay_ticks: u64) {
+        self.model = RetryModel { probability: probability.clamp(0.0, 1.0), delay_ticks };
+    }
+
+    /// Draw retry decisions from a generator seeded with `seed`.
+    pub fn seed(&mut self, seed: u64) {
+        self.rng = Some(SplitMix64::new(seed));
+    }
+
+    /// Decide whether the sender of reverted `p` retries, and if so schedule
+    /// its refund for re-sending.
+    pub fn on_revert(&mut self, p: &SimPacket, tick: u64) {
+        if self.model.probability <= 0.0 || p.current_value <= 0.0 {
+            return;
+        }
+        let retry = match self.rng.as_mut() {
+            Some(rng) => rng.next_f64() < self.model.probability,
+            None => {
+                self.credit += self.model.probability;
+                let due = self.credit >= 1.0;
+                if due {
+                    self.credit -= 1.0;
+                }
+                due
+            }
+        };
+        if retry {
+            let due_tick = tick + self.model.delay_ticks.max(1);
+            let at = self.pending.partition_point(|r| r.due_tick <= due_tick);
+            self.pending.insert(at, PendingRetry {
+                due_tick,
+                node_id: p.origin_node,
+                amount: p.current_value,
+                corridor: p.corridor,
+            });
+            self.scheduled += 1;
+        }
+    }
+
+    /// Retries due by `tick`, earliest first.
+    pub fn take_due(&mut self, tick: u64) -> Vec<PendingRetry> {
+        let due = self.pending.iter().take_while(|r| r.due_tick <= tick).count();
+        self.pending.drain(..due).collect()
+    }
+
+    pub fn pending(&self) -> u32 {
+        self.pending.len() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MarketTier, PacketStatus};
+
+    fn reverted(origin_node: u32, value: f64) -> SimPacket {
+        SimPacket {
+            id: 1, current_value: value, status: PacketStatus::Expired, tier: MarketTier::L1,
+            ..SimPacket::test_default(origin_node, 100.0)
+        }
+    }
+
+    #[test]
+    fn test_unseeded_retries_a_fixed_share_after_the_delay() {
+        let mut q = RetryQueue::default();
+        q.on_revert(&reverted(0, 90.0), 5);
+        assert_eq!(q.pending(), 0, "No model, no retries");
+
+        q.set_model(0.5, 10);
+        for tick in 0..4 {
+            q.on_revert(&reverted(4, 90.0), tick);
+        }
+        q.on_revert(&reverted(4, 0.0), 4);
+        assert_eq!((q.scheduled, q.pending()), (2, 2), "Half retried; nothing left to re-send");
+
+        assert!(q.take_due(10).is_empty());
+        let due = q.take_due(11);
+        assert_eq!(due.len(), 1);
+        assert_eq!((due[0].node_id, due[0].amount, due[0].due_tick), (4, 90.0, 11));
+        assert_eq!(q.take_due(100).len(), 1);
+    }
+
+    #[test]
+    fn test_seeded_retries_track_probability() {
+        let mut q = RetryQueue::default();
+        q.set_model(0.3, 1);
+        q.seed(7);
+        for tick in 0..10_000 {
+            q.on_revert(&reverted(0, 1.0), tick);
+        }
+        let share = q.scheduled as f64 / 10_000.0;
+        assert!((share - 0.3).abs() < 0.02, "share {}", share);
+    }
+}
@@ -0,0 +1,283 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Route Caching
+
+use std::collections::BTreeMap;
+
+use crate::corridor::Region;
+use crate::routing::{self, RouteScoring, RoutingMode};
+use crate::types::{NodeRole, SimNode, SimPacket};
+
+/// Change in any neighbor's buffer count (packets) that invalidates a cached
+/// hop; a quarter of the router's buffer capacity.
+pub const CONGESTION_SHIFT: u32 = 5;
+/// Ticks a cached shortest path is followed before it is recomputed under
+/// the network's current congestion.
+pub const PATH_REFRESH_TICKS: u64 = 20;
+
+/// A preferred next hop and the neighbor loads it was scored under.
+#[derive(Debug, Clone)]
+struct CachedHop {
+    target: u32,
+    next_hop: u32,
+    neighbor_loads: Vec<u32>,
+}
+
+// ---------------------------------------------------------------------------
+// RouteCache - preferred next hop per (node, target egress)
+// ---------------------------------------------------------------------------
+
+/// Caches the router's choice per node, packet tier and corridor destination
+/// (None for network-wide traffic), together with the target Egress it was
+/// scored against. An entry is reused while its target is still a liquid
+/// Egress, its next hop is live, and no neighbor's load has moved by
+/// `CONGESTION_SHIFT` or more. The whole cache is dropped when the set of
+/// liquid Egress nodes or the topology changes.
+#[derive(Debug, Clone, Default)]
+pub struct RouteCache {
+    entries: BTreeMap<(u32, u8, Option<Region>), CachedHop>,
+    liquid_egress: Vec<u32>,
+    pub hits: u64,
+    pub misses: u64,
+    pub invalidations: u64,
+}
+
+impl RouteCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop every entry (topology, role, routing mode or scoring changed).
+    pub fn invalidate(&mut self) {
+        if !self.entries.is_empty() {
+            self.invalidations += 1;
+        }
+        self.entries.clear();
+    }
+
+    /// Once per tick: drop the cache when an Egress gained or lost liquidity,
+    /// since that can move the nearest target for any node.
+    pub fn begin_tick(&mut self, nodes: &[SimNode]) {
+        let liquid: Vec<u32> = nodes.iter()
+            .filter(|n| is_liquid_egress(n))
+            .map(|n| n.id)
+            .collect();
+        if liquid != self.liquid_egress {
+            self.invalidate();
+            self.liquid_egress = liquid;
+        }
+    }
+
+    /// `routing::find_next_hop`, served from the cache when the entry is
+    /// still valid.
+    pub fn next_hop(
+        &mut self,
+        nodes: &[SimNode],
+        node_id: u32,
+        packet: &SimPacket,
+        mode: RoutingMode,
+        scoring: RouteScoring,
+    ) -> Option<u32> {
+        let key = (node_id, packet.tier as u8, packet.corridor.map(|c| c.destination));
+        let neighbors = &nodes[node_id as usize].neighbors;
+        if let Some(hop) = self.entries.get(&key) {
+            let still_valid = is_liquid_egress(&nodes[hop.target as usize])
+                && nodes[hop.next_hop as usize].role != NodeRole::Disabled
+                && hop.neighbor_loads.iter().zip(neighbors)
+                    .all(|(&then, &n)| {
+                        then.abs_diff(nodes[n as usize].current_buffer_count) < CONGESTION_SHIFT
+                    });
+            if still_valid {
+                self.hits += 1;
+                return Some(hop.next_hop);
+            }
+        }
+
+        self.misses += 1;
+        match routing::find_route(nodes, node_id, packet, mode, scoring) {
+            Some((target, next_hop)) => {
+                let neighbor_loads = neighbors.iter()
+                    .map(|&n| nodes[n as usize].current_buffer_count)
+                    .collect();
+                self.entries.insert(key, CachedHop { target, next_hop, neighbor_loads });
+                Some(next_hop)
+            }
+            None => {
+                self.entries.remove(&key);
+                None
+            }
+        }
+    }
+
+    /// Share of lookups served from the cache.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups > 0 { self.hits as f64 / lookups as f64 } else { 0.0 }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// PathCache - shortest path per (origin, target egress)
+// ---------------------------------------------------------------------------
+
+/// A least-cost path, its first node the key's origin, and the tick it was
+/// computed.
+#[derive(Debug, Clone)]
+struct CachedPath {
+    path: Vec<u32>,
+    computed_tick: u64,
+}
+
+/// Caches `routing::shortest_path` for `RoutingMode::ShortestPath` per
+/// (origin, target Egress). Every suffix of a computed path is a shortest
+/// path too, so each node along it is cached toward the same target. An
+/// entry is reused for `PATH_REFRESH_TICKS` while every node on it is live;
+/// the whole cache is dropped when nodes are killed, revived, added or
+/// removed, or links are cut or healed.
+#[derive(Debug, Clone, Default)]
+pub struct PathCache {
+    paths: BTreeMap<(u32, u32), CachedPath>,
+    pub hits: u64,
+    pub misses: u64,
+    pub invalidations: u64,
+}
+
+impl PathCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop every path (topology or routing changed).
+    pub fn invalidate(&mut self) {
+        if !self.paths.is_empty() {
+            self.invalidations += 1;
+        }
+        self.paths.clear();
+    }
+
+    /// Next hop from `node_id` on the shortest path to `packet`'s target
+    /// Egress, served from the cache when the path is still valid.
+    pub fn next_hop(&mut self, nodes: &[SimNode], node_id: u32, packet: &SimPacket, tick: u64) -> Option<u32> {
+        let target = routing::target_egress(nodes, &nodes[node_id as usize], packet)?.id;
+        if let Some(cached) = self.paths.get(&(node_id, target)) {
+            let still_valid = tick.saturating_sub(cached.computed_tick) < PATH_REFRESH_TICKS
+                && cached.path.iter().all(|&n| nodes[n as usize].role != NodeRole::Disabled);
+            if still_valid {
+                self.hits += 1;
+                return cached.path.get(1).copied();
+            }
+        }
+
+        self.misses += 1;
+        let Some(path) = routing::shortest_path(nodes, node_id, target) else {
+            self.paths.remove(&(node_id, target));
+            return None;
+        };
+        for i in 0..path.len().saturating_sub(1) {
+            let suffix = CachedPath { path: path[i..].to_vec(), computed_tick: tick };
+            self.paths.insert((path[i], target), suffix);
+        }
+        path.get(1).copied()
+    }
+
+    /// Share of lookups served from the cache.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups > 0 { self.hits as f64 / lookups as f64 } else { 0.0 }
+    }
+
+    /// Paths cached, one per (origin, target Egress).
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+}
+
+fn is_liquid_egress(node: &SimNode) -> bool {
+    node.role == NodeRole::Egress && node.inventory_crypto > 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArenaSimulation;
+
+    const EUCLIDEAN: RoutingMode = RoutingMode::Euclidean;
+    const TRUST: RouteScoring = RouteScoring::TrustWeighted;
+
+    fn packet_at(sim: &mut ArenaSimulation, node_id: u32) -> SimPacket {
+        sim.spawn_packet(node_id, 100.0);
+        sim.node_buffers[&node_id].last().unwrap().clone()
+    }
+
+    #[test]
+    fn test_hit_until_congestion_shift() {
+        let mut sim = ArenaSimulation::new(24);
+        let p = packet_at(&mut sim, 0);
+        let mut cache = RouteCache::new();
+        cache.begin_tick(&sim.nodes);
+
+        let first = cache.next_hop(&sim.nodes, 0, &p, EUCLIDEAN, TRUST);
+        assert_eq!(first, routing::find_next_hop(&sim.nodes, 0, &p, EUCLIDEAN, TRUST));
+        assert_eq!(cache.next_hop(&sim.nodes, 0, &p, EUCLIDEAN, TRUST), first);
+        assert_eq!((cache.hits, cache.misses), (1, 1));
+
+        let hop = first.unwrap() as usize;
+        sim.nodes[hop].current_buffer_count += CONGESTION_SHIFT - 1;
+        cache.next_hop(&sim.nodes, 0, &p, EUCLIDEAN, TRUST);
+        assert_eq!(cache.hits, 2, "Small load changes reuse the entry");
+        sim.nodes[hop].current_buffer_count += 1;
+        cache.next_hop(&sim.nodes, 0, &p, EUCLIDEAN, TRUST);
+        assert_eq!(cache.misses, 2, "A congestion shift forces a rescore");
+    }
+
+    #[test]
+    fn test_liquidity_and_topology_changes_invalidate() {
+        let mut sim = ArenaSimulation::new(24);
+        let p = packet_at(&mut sim, 0);
+        let mut cache = RouteCache::new();
+        cache.begin_tick(&sim.nodes);
+        cache.next_hop(&sim.nodes, 0, &p, EUCLIDEAN, TRUST);
+
+        sim.nodes[1].inventory_crypto = 0.0;
+        cache.begin_tick(&sim.nodes);
+        assert_eq!(cache.invalidations, 1);
+        cache.next_hop(&sim.nodes, 0, &p, EUCLIDEAN, TRUST);
+        assert_eq!(cache.misses, 2);
+
+        cache.invalidate();
+        cache.next_hop(&sim.nodes, 0, &p, EUCLIDEAN, TRUST);
+        assert_eq!((cache.invalidations, cache.misses, cache.hits), (2, 3, 0));
+    }
+
+    #[test]
+    fn test_paths_cache_every_suffix_until_stale() {
+        let mut sim = ArenaSimulation::new(24);
+        let p = packet_at(&mut sim, 0);
+        // Drain the adjacent Egress so the path runs through transit nodes
+        sim.nodes[1].inventory_crypto = 0.0;
+        let target = routing::target_egress(&sim.nodes, &sim.nodes[0], &p).unwrap().id;
+        let path = routing::shortest_path(&sim.nodes, 0, target).unwrap();
+        assert!(path.len() > 2);
+        let mut cache = PathCache::new();
+
+        assert_eq!(cache.next_hop(&sim.nodes, 0, &p, 0), path.get(1).copied());
+        assert_eq!(cache.len(), path.len() - 1, "One entry per node short of the target");
+        for (i, &id) in path.iter().enumerate().take(path.len() - 1) {
+            assert_eq!(cache.next_hop(&sim.nodes, id, &p, 1), Some(path[i + 1]));
+        }
+        assert_eq!((cache.hits, cache.misses), (path.len() as u64 - 1, 1));
+
+        // Paths are recomputed once stale, or when a node on them goes down
+        cache.next_hop(&sim.nodes, 0, &p, PATH_REFRESH_TICKS);
+        assert_eq!(cache.misses, 2);
+        sim.nodes[path[1] as usize].role = NodeRole::Disabled;
+        assert_ne!(cache.next_hop(&sim.nodes, 0, &p, PATH_REFRESH_TICKS), Some(path[1]));
+        assert_eq!(cache.misses, 3);
+        cache.invalidate();
+        assert!(cache.is_empty());
+        assert_eq!(cache.invalidations, 1);
+    }
+}
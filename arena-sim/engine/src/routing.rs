@@ -1,18 +1,80 @@
 // Copyright 2026 Hypermesh Foundation. All rights reserved.
 // Caesar Protocol Simulation Suite ("The Arena") - Capacity-Based Routing
 
-use crate::types::{MarketTier, NodeRole, SimNode, SimPacket};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::types::{MarketTier, NodeRole, OrbitReason, SimNode, SimPacket};
 
 // Geographic/overlay scoring weights (capacity weights now in adapter)
 const W_DISTANCE: f64 = 0.2;
 const W_UPTIME: f64 = 0.05;
+const W_TRUST: f64 = 0.1;
 const W_TRANSIT_FEE: f64 = 0.1;
 const W_TIER_MATCH: f64 = 0.05;
 
-const BUFFER_CAPACITY: f64 = 20.0;
+/// Buffer depth at which a node scores as fully loaded.
+pub const BUFFER_CAPACITY: f64 = 20.0;
 const BANDWIDTH_NORM_CAP: f64 = 1000.0;
 const LATENCY_NORM_CAP: f64 = 500.0;
 
+/// `distance_to_egress` of a node with no live Egress reachable.
+pub const UNREACHABLE: u32 = u32::MAX;
+
+/// Path cost of one hop into an idle node, and the extra cost of a hop
+/// into a node whose buffer is full (`BUFFER_CAPACITY`), in thousandths.
+const HOP_COST: u64 = 1000;
+const CONGESTION_HOP_COST: f64 = 1000.0;
+
+/// What the distance term of a neighbor's score measures.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoutingMode {
+    /// Straight-line distance to the nearest liquid Egress
+    #[default]
+    Euclidean = 0,
+    /// Hop count to the nearest live Egress (`distance_to_egress`), which
+    /// holds for topologies where coordinates mean nothing
+    HopDistance = 1,
+    /// Next hop on the least-cost path to the target Egress over the
+    /// neighbor graph, each hop costing one plus its congestion. Unlike the
+    /// greedy scorings it cannot dead-end in a local minimum.
+    ShortestPath = 2,
+}
+
+impl RoutingMode {
+    /// Map a numeric discriminant (as sent from JS) back to a mode.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Euclidean),
+            1 => Some(Self::HopDistance),
+            2 => Some(Self::ShortestPath),
+            _ => None,
+        }
+    }
+}
+
+/// Whether a neighbor's trust score enters its routing score.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RouteScoring {
+    /// Penalize neighbors by their distrust (`W_TRUST`)
+    #[default]
+    TrustWeighted = 0,
+    /// Leave trust out, as `core_routing` does: capacity, distance, uptime,
+    /// transit fee and tier preference only
+    CapacityOnly = 1,
+}
+
+impl RouteScoring {
+    /// Map a numeric discriminant (as sent from JS) back to a scoring.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::TrustWeighted),
+            1 => Some(Self::CapacityOnly),
+            _ => None,
+        }
+    }
+}
+
 /// Compute the raw capacity score for a single node.
 ///
 /// This score reflects how suitable a node is as a routing candidate
@@ -34,13 +96,34 @@ pub fn score_candidate(node: &SimNode) -> f64 {
 /// 1. Filter neighbors to exclude Disabled nodes
 /// 2. Find the nearest Egress node with sufficient liquidity (>1.0 crypto)
 /// 3. Score each neighbor by capacity metrics, geographic distance,
-///    uptime, transit fee, and tier preference
+///    uptime, trust, transit fee, and tier preference
 /// 4. Return the neighbor with the highest combined score, or None
+///
+/// In `RoutingMode::HopDistance` the distance term uses each neighbor's hop
+/// count to the nearest live Egress instead of its distance to the target;
+/// those counts ignore corridor destinations.
+/// `RouteScoring::CapacityOnly` drops the trust term.
+/// `RoutingMode::ShortestPath` skips scoring and takes the first hop of
+/// `shortest_path` to the target.
 pub fn find_next_hop(
     nodes: &[SimNode],
     node_id: u32,
     packet: &SimPacket,
+    mode: RoutingMode,
+    scoring: RouteScoring,
 ) -> Option<u32> {
+    find_route(nodes, node_id, packet, mode, scoring).map(|(_, hop)| hop)
+}
+
+/// `find_next_hop`, also returning the target Egress the hop was scored
+/// against, as (target egress, next hop).
+pub fn find_route(
+    nodes: &[SimNode],
+    node_id: u32,
+    packet: &SimPacket,
+    mode: RoutingMode,
+    scoring: RouteScoring,
+) -> Option<(u32, u32)> {
     let current = &nodes[node_id as usize];
 
     let neighbors: Vec<u32> = current
@@ -50,59 +133,92 @@ pub fn find_next_hop(
         .copied()
         .collect();
 
-    // Find nearest Egress node with actual liquidity for routing target
-    let target_egress = nodes
-        .iter()
-        .filter(|n| n.role == NodeRole::Egress && n.inventory_crypto > 1.0)
-        .min_by(|a, b| {
-            let da = distance_sq(a.x, a.y, current.x, current.y);
-            let db = distance_sq(b.x, b.y, current.x, current.y);
-            da.partial_cmp(&db).unwrap()
-        });
-
-    let target = match target_egress {
-        Some(t) => t,
-        None => return None, // No Egress with liquidity found - enter orbit
-    };
+    // No Egress with liquidity found - enter orbit
+    let target = target_egress(nodes, current, packet)?;
 
-    let max_dist = compute_max_distance(nodes, &neighbors, target);
+    let distance_norms = match mode {
+        RoutingMode::Euclidean => euclidean_distance_norms(nodes, &neighbors, target),
+        RoutingMode::HopDistance => hop_distance_norms(nodes, &neighbors),
+        RoutingMode::ShortestPath => {
+            let path = shortest_path(nodes, node_id, target.id)?;
+            return path.get(1).map(|&hop| (target.id, hop));
+        }
+    };
 
     let mut best_neighbor: Option<u32> = None;
     let mut best_score = f64::NEG_INFINITY;
 
-    for &n_id in &neighbors {
+    for (&n_id, &distance_norm) in neighbors.iter().zip(&distance_norms) {
         let neighbor = &nodes[n_id as usize];
-        let score = score_neighbor(neighbor, target, max_dist, packet);
+        let score = score_neighbor(neighbor, distance_norm, packet, scoring);
         if score > best_score {
             best_score = score;
             best_neighbor = Some(n_id);
         }
     }
 
-    best_neighbor
+    best_neighbor.map(|hop| (target.id, hop))
+}
+
+/// The liquid Egress nearest `from` (Euclidean), which the router targets.
+pub fn nearest_liquid_egress<'a>(nodes: &'a [SimNode], from: &SimNode) -> Option<&'a SimNode> {
+    nearest(from, nodes.iter().filter(|n| is_liquid_egress(n)))
+}
+
+/// The Egress `packet` is routed toward from `from`: the nearest liquid one
+/// it may settle at.
+pub fn target_egress<'a>(nodes: &'a [SimNode], from: &SimNode, packet: &SimPacket) -> Option<&'a SimNode> {
+    nearest(from, nodes.iter().filter(|n| is_liquid_egress(n) && may_settle_at(packet, n)))
+}
+
+fn nearest<'a>(from: &SimNode, candidates: impl Iterator<Item = &'a SimNode>) -> Option<&'a SimNode> {
+    candidates.min_by(|a, b| {
+        let da = distance_sq(a.x, a.y, from.x, from.y);
+        let db = distance_sq(b.x, b.y, from.x, from.y);
+        da.partial_cmp(&db).unwrap()
+    })
+}
+
+/// Corridor traffic settles only in its destination region; other packets
+/// settle at any Egress.
+pub fn may_settle_at(packet: &SimPacket, node: &SimNode) -> bool {
+    packet.corridor.is_none_or(|c| c.destination.contains(node.site))
+}
+
+/// An Egress holding enough liquidity to be routed toward.
+pub fn is_liquid_egress(node: &SimNode) -> bool {
+    node.role == NodeRole::Egress && node.inventory_crypto > 1.0
+}
+
+/// Why `find_route` found no next hop for `packet`: no liquid Egress it may
+/// settle at, or none of the node's neighbors is live.
+pub fn no_route_reason(nodes: &[SimNode], packet: &SimPacket) -> OrbitReason {
+    if nodes.iter().any(|n| is_liquid_egress(n) && may_settle_at(packet, n)) {
+        OrbitReason::DisabledDestination
+    } else {
+        OrbitReason::NoLiquidEgress
+    }
 }
 
 /// Score a neighbor candidate with all routing factors combined.
+/// `distance_norm` is its distance to Egress normalized into [0, 1].
 fn score_neighbor(
     neighbor: &SimNode,
-    target: &SimNode,
-    max_dist: f64,
+    distance_norm: f64,
     packet: &SimPacket,
+    scoring: RouteScoring,
 ) -> f64 {
     let capacity = score_candidate(neighbor);
 
-    let distance_norm = if max_dist > 0.0 {
-        let dist = distance_sq(neighbor.x, neighbor.y, target.x, target.y).sqrt();
-        (dist / max_dist).min(1.0)
-    } else {
-        0.0
-    };
-
     let uptime_bonus = W_UPTIME * neighbor.uptime.clamp(0.0, 1.0);
+    let trust_penalty = match scoring {
+        RouteScoring::TrustWeighted => W_TRUST * (1.0 - neighbor.trust_score.clamp(0.0, 1.0)),
+        RouteScoring::CapacityOnly => 0.0,
+    };
     let fee_penalty = W_TRANSIT_FEE * neighbor.transit_fee.min(1.0);
     let tier_bonus = tier_match_bonus(neighbor.tier_preference, packet.tier);
 
-    capacity - W_DISTANCE * distance_norm + uptime_bonus - fee_penalty + tier_bonus
+    capacity - W_DISTANCE * distance_norm + uptime_bonus - trust_penalty - fee_penalty + tier_bonus
 }
 
 /// Compute squared Euclidean distance between two points.
@@ -110,16 +226,170 @@ fn distance_sq(x1: f64, y1: f64, x2: f64, y2: f64) -> f64 {
     (x1 - x2).powi(2) + (y1 - y2).powi(2)
 }
 
-/// Compute the maximum distance from any neighbor to the target egress.
-/// Used to normalize distance scores into [0, 1].
-fn compute_max_distance(nodes: &[SimNode], neighbors: &[u32], target: &SimNode) -> f64 {
-    neighbors
+/// Each neighbor's Euclidean distance to the target egress, normalized by
+/// the farthest neighbor's.
+fn euclidean_distance_norms(nodes: &[SimNode], neighbors: &[u32], target: &SimNode) -> Vec<f64> {
+    let dists: Vec<f64> = neighbors
         .iter()
         .map(|&n_id| {
             let n = &nodes[n_id as usize];
             distance_sq(n.x, n.y, target.x, target.y).sqrt()
         })
-        .fold(0.0_f64, f64::max)
+        .collect();
+    let max_dist = dists.iter().copied().fold(0.0_f64, f64::max);
+    dists.iter()
+        .map(|&d| if max_dist > 0.0 { (d / max_dist).min(1.0) } else { 0.0 })
+        .collect()
+}
+
+/// Each neighbor's hop count to the nearest live Egress, normalized by the
+/// farthest reachable neighbor's. Neighbors with no route score as farthest.
+fn hop_distance_norms(nodes: &[SimNode], neighbors: &[u32]) -> Vec<f64> {
+    let hops: Vec<u32> = neighbors.iter()
+        .map(|&n_id| nodes[n_id as usize].distance_to_egress)
+        .collect();
+    let max = hops.iter().copied().filter(|&d| d != UNREACHABLE).max().unwrap_or(0) as f64;
+    hops.iter()
+        .map(|&d| match d {
+            UNREACHABLE => 1.0,
+            _ if max > 0.0 => d as f64 / max,
+            _ => 0.0,
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Hop distance to Egress
+// ---------------------------------------------------------------------------
+
+/// Recompute every node's `distance_to_egress`: hops to the nearest live
+/// Egress over live nodes. Disabled nodes and islands are `UNREACHABLE`.
+pub fn compute_egress_distances(nodes: &mut [SimNode]) {
+    let mut seeds = Vec::new();
+    for node in nodes.iter_mut() {
+        node.distance_to_egress = UNREACHABLE;
+        if node.role == NodeRole::Egress {
+            node.distance_to_egress = 0;
+            seeds.push((0, node.id));
+        }
+    }
+    relax_distances(nodes, seeds);
+}
+
+/// Update `distance_to_egress` after `node_id` changed role (killed, revived
+/// or re-roled), touching only the nodes whose distance can change.
+/// Neighbor lists are assumed symmetric, as in the grid topology.
+pub fn update_egress_distances(nodes: &mut [SimNode], node_id: u32) {
+    // Nodes whose shortest route may have run through `node_id`: everything
+    // reachable from it along edges where the distance grows by exactly one
+    let mut affected = vec![node_id];
+    let mut i = 0;
+    while i < affected.len() {
+        let id = affected[i];
+        let dist = nodes[id as usize].distance_to_egress;
+        if dist != UNREACHABLE {
+            for &n in &nodes[id as usize].neighbors {
+                if nodes[n as usize].distance_to_egress == dist + 1 && !affected.contains(&n) {
+                    affected.push(n);
+                }
+            }
+        }
+        i += 1;
+    }
+    for &id in &affected {
+        nodes[id as usize].distance_to_egress = UNREACHABLE;
+    }
+
+    // Re-seed the affected region from its live Egress and intact border
+    let mut seeds = Vec::new();
+    for &id in &affected {
+        let node = &nodes[id as usize];
+        let dist = match node.role {
+            NodeRole::Disabled => continue,
+            NodeRole::Egress => 0,
+            _ => node.neighbors.iter()
+                .map(|&n| &nodes[n as usize])
+                .filter(|n| n.role != NodeRole::Disabled && n.distance_to_egress != UNREACHABLE)
+                .map(|n| n.distance_to_egress + 1)
+                .min()
+                .unwrap_or(UNREACHABLE),
+        };
+        if dist != UNREACHABLE {
+            nodes[id as usize].distance_to_egress = dist;
+            seeds.push((dist, id));
+        }
+    }
+    relax_distances(nodes, seeds);
+}
+
+/// Propagate distance decreases outward from `seeds` over live nodes.
+fn relax_distances(nodes: &mut [SimNode], seeds: Vec<(u32, u32)>) {
+    let mut heap: BinaryHeap<Reverse<(u32, u32)>> = seeds.into_iter().map(Reverse).collect();
+    while let Some(Reverse((dist, id))) = heap.pop() {
+        if dist > nodes[id as usize].distance_to_egress {
+            continue;
+        }
+        for i in 0..nodes[id as usize].neighbors.len() {
+            let n = nodes[id as usize].neighbors[i];
+            let neighbor = &mut nodes[n as usize];
+            if neighbor.role != NodeRole::Disabled && dist + 1 < neighbor.distance_to_egress {
+                neighbor.distance_to_egress = dist + 1;
+                heap.push(Reverse((dist + 1, n)));
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Shortest path
+// ---------------------------------------------------------------------------
+
+/// Cost of a hop into `node`: `HOP_COST` plus its buffer load's share of
+/// `CONGESTION_HOP_COST`.
+fn hop_cost(node: &SimNode) -> u64 {
+    let load = (node.current_buffer_count as f64 / BUFFER_CAPACITY).min(1.0);
+    HOP_COST + (load * CONGESTION_HOP_COST) as u64
+}
+
+/// Least-cost path from `from` to `to` over live nodes (Dijkstra on
+/// `hop_cost`), both ends included; None when `to` is unreachable.
+pub fn shortest_path(nodes: &[SimNode], from: u32, to: u32) -> Option<Vec<u32>> {
+    let mut cost = vec![u64::MAX; nodes.len()];
+    let mut previous = vec![None; nodes.len()];
+    let mut heap = BinaryHeap::new();
+    cost[from as usize] = 0;
+    heap.push(Reverse((0, from)));
+    while let Some(Reverse((c, id))) = heap.pop() {
+        if id == to {
+            break;
+        }
+        if c > cost[id as usize] {
+            continue;
+        }
+        for &n in &nodes[id as usize].neighbors {
+            let neighbor = &nodes[n as usize];
+            if neighbor.role == NodeRole::Disabled {
+                continue;
+            }
+            let next = c + hop_cost(neighbor);
+            if next < cost[n as usize] {
+                cost[n as usize] = next;
+                previous[n as usize] = Some(id);
+                heap.push(Reverse((next, n)));
+            }
+        }
+    }
+    if cost[to as usize] == u64::MAX {
+        return None;
+    }
+    let mut path = vec![to];
+    let mut at = to;
+    while let Some(id) = previous[at as usize] {
+        path.push(id);
+        at = id;
+    }
+    path.reverse();
+    Some(path)
 }
 
 /// Return tier-match bonus if the node's preference matches the packet tier.
@@ -129,3 +399,120 @@ fn tier_match_bonus(preference: Option<MarketTier>, packet_tier: MarketTier) ->
         _ => 0.0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: u32, role: NodeRole, x: f64, neighbors: Vec<u32>) -> SimNode {
+        SimNode {
+            x, inventory_crypto: 1000.0, neighbors, distance_to_egress: UNREACHABLE,
+            ..SimNode::test_default(id, role)
+        }
+    }
+
+    fn distances(nodes: &[SimNode]) -> Vec<u32> {
+        nodes.iter().map(|n| n.distance_to_egress).collect()
+    }
+
+    #[test]
+    fn test_incremental_update_matches_recompute() {
+        // 5x5 grid, Egress at the two far corners
+        let mut nodes: Vec<SimNode> = (0..25u32)
+            .map(|i| {
+                let (row, col) = (i / 5, i % 5);
+                let mut nb = Vec::new();
+                if col > 0 { nb.push(i - 1); }
+                if col < 4 { nb.push(i + 1); }
+                if row > 0 { nb.push(i - 5); }
+                if row < 4 { nb.push(i + 5); }
+                let role = if i == 0 || i == 24 { NodeRole::Egress } else { NodeRole::Transit };
+                node(i, role, col as f64, nb)
+            })
+            .collect();
+        compute_egress_distances(&mut nodes);
+        assert_eq!(nodes[12].distance_to_egress, 4);
+
+        for (id, role) in [
+            (1, NodeRole::Disabled), (5, NodeRole::Disabled), (24, NodeRole::Disabled),
+            (1, NodeRole::Transit), (12, NodeRole::Egress), (0, NodeRole::Disabled),
+        ] {
+            nodes[id as usize].role = role;
+            update_egress_distances(&mut nodes, id);
+            let mut fresh = nodes.clone();
+            compute_egress_distances(&mut fresh);
+            assert_eq!(distances(&nodes), distances(&fresh), "After node {} -> {:?}", id, role);
+        }
+        assert_eq!(nodes[0].distance_to_egress, UNREACHABLE);
+    }
+
+    #[test]
+    fn test_hop_mode_follows_topology_not_coordinates() {
+        // Node 1 sits next to the Egress on the map but is three hops from it;
+        // node 2 is far away on the map but links to it directly
+        let mut nodes = vec![
+            node(0, NodeRole::Ingress, 0.0, vec![1, 2]),
+            node(1, NodeRole::Transit, 9.0, vec![0, 4]),
+            node(2, NodeRole::Transit, -50.0, vec![0, 3]),
+            node(3, NodeRole::Egress, 10.0, vec![2, 5]),
+            node(4, NodeRole::Transit, -40.0, vec![1, 5]),
+            node(5, NodeRole::Transit, -30.0, vec![4, 3]),
+        ];
+        compute_egress_distances(&mut nodes);
+        let packet = SimPacket::test_default(0, 100.0);
+        let trust = RouteScoring::TrustWeighted;
+        assert_eq!(find_next_hop(&nodes, 0, &packet, RoutingMode::Euclidean, trust), Some(1));
+        assert_eq!(find_next_hop(&nodes, 0, &packet, RoutingMode::HopDistance, trust), Some(2));
+    }
+
+    #[test]
+    fn test_capacity_only_scoring_ignores_trust() {
+        // Two equidistant transits; node 1 has lower trust but more bandwidth
+        let mut nodes = vec![
+            node(0, NodeRole::Ingress, 0.0, vec![1, 2]),
+            node(1, NodeRole::Transit, 5.0, vec![0, 3]),
+            node(2, NodeRole::Transit, 5.0, vec![0, 3]),
+            node(3, NodeRole::Egress, 10.0, vec![1, 2]),
+        ];
+        nodes[1].trust_score = 0.2;
+        nodes[1].bandwidth = 150.0;
+        let packet = SimPacket::test_default(0, 100.0);
+        let mode = RoutingMode::Euclidean;
+        assert_eq!(find_next_hop(&nodes, 0, &packet, mode, RouteScoring::TrustWeighted), Some(2));
+        assert_eq!(find_next_hop(&nodes, 0, &packet, mode, RouteScoring::CapacityOnly), Some(1));
+    }
+
+    #[test]
+    fn test_shortest_path_escapes_dead_ends_and_avoids_congestion() {
+        // Node 1 is nearest the Egress on the map but a dead end; the only
+        // route swings away through 2 and 3
+        let nodes = vec![
+            node(0, NodeRole::Ingress, 0.0, vec![1, 2]),
+            node(1, NodeRole::Transit, 9.0, vec![0]),
+            node(2, NodeRole::Transit, -5.0, vec![0, 3]),
+            node(3, NodeRole::Transit, 0.0, vec![2, 4]),
+            node(4, NodeRole::Egress, 10.0, vec![3]),
+        ];
+        let packet = SimPacket::test_default(0, 100.0);
+        let trust = RouteScoring::TrustWeighted;
+        assert_eq!(find_next_hop(&nodes, 0, &packet, RoutingMode::Euclidean, trust), Some(1));
+        assert_eq!(find_next_hop(&nodes, 0, &packet, RoutingMode::ShortestPath, trust), Some(2));
+        assert_eq!(shortest_path(&nodes, 0, 4), Some(vec![0, 2, 3, 4]));
+        assert_eq!(shortest_path(&nodes, 1, 1), Some(vec![1]));
+
+        // Two equal routes: a full buffer on one tips the path to the other
+        let mut diamond = vec![
+            node(0, NodeRole::Ingress, 0.0, vec![1, 2]),
+            node(1, NodeRole::Transit, 5.0, vec![0, 3]),
+            node(2, NodeRole::Transit, 5.0, vec![0, 3]),
+            node(3, NodeRole::Egress, 10.0, vec![1, 2]),
+        ];
+        assert_eq!(shortest_path(&diamond, 0, 3), Some(vec![0, 1, 3]));
+        diamond[1].current_buffer_count = BUFFER_CAPACITY as u32;
+        assert_eq!(shortest_path(&diamond, 0, 3), Some(vec![0, 2, 3]));
+        diamond[2].role = NodeRole::Disabled;
+        assert_eq!(shortest_path(&diamond, 0, 3), Some(vec![0, 1, 3]), "Congested beats unreachable");
+        diamond[1].role = NodeRole::Disabled;
+        assert_eq!(shortest_path(&diamond, 0, 3), None);
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Hop-Limit and Orbit-Timeout Policy
+//
+// A packet that has bounced past its tier's hop limit is forced into orbit
+// by default, and a Held packet is refunded once it has orbited past its
+// tier's orbit timeout. Both are policy rather than protocol: the defaults
+// reproduce the engine's historical behavior, and the alternatives let a
+// scenario trade held packets for reverts or for delayed re-routes.
+
+use crate::dissolution::DISSOLUTION_TIMEOUT_TICKS;
+use crate::types::MarketTier;
+
+/// What happens to a packet once `hops > hop_limit`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HopExhaustion {
+    /// Hold it in orbit until it settles in place or times out
+    #[default]
+    Orbit = 0,
+    /// Refund it on the spot (`RevertReason::HopLimit`)
+    Revert = 1,
+    /// Hold it for an exponentially growing backoff, then grant another
+    /// tier's worth of hops and re-route
+    Backoff = 2,
+}
+
+impl HopExhaustion {
+    /// Map a numeric discriminant (as sent from JS) back to a policy.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Orbit),
+            1 => Some(Self::Revert),
+            2 => Some(Self::Backoff),
+            _ => None,
+        }
+    }
+}
+
+/// Hop-exhaustion handling and orbit timeouts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoutingPolicy {
+    pub hop_exhaustion: HopExhaustion,
+    /// Backoff before the first re-route; doubles with every renewal
+    pub backoff_base_ticks: u64,
+    pub backoff_max_ticks: u64,
+    /// Orbit timeout of L0-L2 packets as a share of their tier TTL
+    pub orbit_timeout_ttl_fraction: f64,
+    /// Orbit timeout of L3 packets, which may orbit past dissolution
+    pub l3_orbit_timeout_ticks: u64,
+}
+
+impl Default for RoutingPolicy {
+    fn default() -> Self {
+        Self {
+            hop_exhaustion: HopExhaustion::Orbit,
+            backoff_base_ticks: 8,
+            backoff_max_ticks: 256,
+            orbit_timeout_ttl_fraction: 0.5,
+            l3_orbit_timeout_ticks: DISSOLUTION_TIMEOUT_TICKS + 500,
+        }
+    }
+}
+
+impl RoutingPolicy {
+    /// Orbit ticks a Held packet of `tier` may spend before it is refunded.
+    pub fn orbit_timeout(&self, tier: MarketTier) -> u64 {
+        if tier == MarketTier::L3 {
+            self.l3_orbit_timeout_ticks
+        } else {
+            (tier.ttl_ticks() as f64 * self.orbit_timeout_ttl_fraction.max(0.0)) as u64
+        }
+    }
+
+    /// Hop-limit renewals a packet of `tier` has already been granted.
+    pub fn renewals(tier: MarketTier, hop_limit: u32) -> u32 {
+        hop_limit.saturating_sub(tier.hop_limit()) / tier.hop_limit().max(1)
+    }
+
+    /// Orbit ticks to wait before the re-route after `renewals` earlier ones:
+    /// `backoff_base_ticks * 2^renewals`, capped at `backoff_max_ticks`.
+    pub fn backoff_ticks(&self, renewals: u32) -> u64 {
+        self.backoff_base_ticks
+            .saturating_mul(1u64.checked_shl(renewals).unwrap_or(u64::MAX))
+            .min(self.backoff_max_ticks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_timeouts_match_historical_constants() {
+        let policy = RoutingPolicy::default();
+        assert_eq!(policy.orbit_timeout(MarketTier::L0), MarketTier::L0.ttl_ticks() / 2);
+        assert_eq!(policy.orbit_timeout(MarketTier::L2), MarketTier::L2.ttl_ticks() / 2);
+        assert_eq!(policy.orbit_timeout(MarketTier::L3), 5500);
+    }
+
+    #[test]
+    fn test_backoff_doubles_per_renewal_up_to_cap() {
+        let policy = RoutingPolicy { backoff_base_ticks: 10, backoff_max_ticks: 100, ..Default::default() };
+        assert_eq!([0, 1, 2, 3, 4, 64].map(|r| policy.backoff_ticks(r)), [10, 20, 40, 80, 100, 100]);
+
+        let limit = MarketTier::L1.hop_limit();
+        assert_eq!(RoutingPolicy::renewals(MarketTier::L1, limit), 0);
+        assert_eq!(RoutingPolicy::renewals(MarketTier::L1, 3 * limit), 2);
+    }
+}
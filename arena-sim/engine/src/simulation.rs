@@ -1,34 +1,114 @@
 // Copyright 2026 Hypermesh Foundation. All rights reserved.
 // Caesar Protocol Simulation Suite ("The Arena") - Simulation Core
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
 use wasm_bindgen::prelude::*;
 
+use crate::adversary;
+use crate::availability;
+use crate::aggregation;
+use crate::asset_ledger;
+use crate::backlog;
+use crate::batching;
+use crate::capacity_router;
+use crate::checkpoint;
+use crate::cohort;
+use crate::collusion;
+use crate::compaction;
+use crate::core_governor::PressureQuadrant;
+use crate::corridor;
+use crate::concentration;
+use crate::control_loop;
 use crate::conservation;
+use crate::cost;
+use crate::discount;
+use crate::dust;
+use crate::egress_exit;
+use crate::egress_queue;
+use crate::pacing;
+use crate::elasticity;
+use crate::ratelimit;
+use crate::rng;
 use crate::dissolution;
 use crate::engauge;
+use crate::governor;
+use crate::histogram;
+use crate::latency;
+use crate::liquidity_provider;
+use crate::held_sweep;
+use crate::hop_trace;
+use crate::oracle;
+use crate::panic_contagion;
+use crate::panic_dynamics;
+use crate::price_shock;
+use crate::priority_lanes;
+use crate::quote;
+use crate::receipts;
+use crate::rebalancing;
+use crate::replenishment;
+use crate::regional_price;
+use crate::resources;
+use crate::speculation;
+use crate::retry;
+use crate::rebate;
+use crate::route_cache;
 use crate::routing;
+use crate::routing_policy;
+use crate::slippage;
+use crate::stress;
+use crate::throughput;
+use crate::tier_ledger;
+use crate::topology;
+use crate::transit_rewards;
 use crate::types::*;
+use crate::velocity_bonus;
+use crate::verification;
+use crate::volatility;
+
+/// Quadrant transitions retained; `quadrant_transition_count` counts them all.
+pub const MAX_QUADRANT_TRANSITIONS: usize = 10_000;
 
 // ─── ArenaSimulation struct ──────────────────────────────────────────────────
 
+/// The simulated network: nodes, packets in flight and the governor, stepped
+/// a tick at a time. The `_core` methods are the native API the wasm
+/// bindings wrap.
+///
+/// ```
+/// use arena_engine::topology::TopologyBuilder;
+/// use arena_engine::ArenaSimulation;
+///
+/// // A line Ingress 0 - Transit 1 - Egress 2, with no background traffic
+/// let mut sim = ArenaSimulation::from_topology(&TopologyBuilder::new(3)
+///     .edges(vec![0, 1, 1, 2])
+///     .roles(vec![0, 2, 1]));
+/// sim.set_demand_factor(0.0);
+/// for _ in 0..5 {
+///     sim.spawn_packet(0, 100.0);
+/// }
+/// for _ in 0..20 {
+///     sim.tick_core();
+/// }
+/// let stats = sim.get_stats_core();
+/// assert_eq!(stats.settlement_count, 5);
+/// assert!(stats.total_leaked.abs() < 1e-6, "Value is conserved");
+/// ```
 #[wasm_bindgen]
+#[derive(Clone)]
 pub struct ArenaSimulation {
     pub(crate) nodes: Vec<SimNode>,
-    pub(crate) packets: Vec<SimPacket>,
     pub(crate) message_queue: Vec<SimPacket>,
     pub(crate) state: WorldState,
-    pub(crate) node_buffers: HashMap<u32, Vec<SimPacket>>,
+    pub(crate) node_buffers: BTreeMap<u32, Vec<SimPacket>>,
 
-    pub(crate) total_input: f64,
-    pub(crate) total_output: f64,
-    pub(crate) total_burned: f64,
-    pub(crate) total_fees: f64,
+    pub(crate) total_input: conservation::CompensatedSum,
+    pub(crate) total_output: conservation::CompensatedSum,
+    pub(crate) total_burned: conservation::CompensatedSum,
+    pub(crate) total_fees: conservation::CompensatedSum,
     pub(crate) total_rewards_egress: f64,
     pub(crate) total_rewards_transit: f64,
 
     pub(crate) packet_id_counter: u64,
-    pub(crate) max_active_packets: usize,
     pub(crate) last_gold_price: f64,
 
     pub(crate) settlement_count: u32,
@@ -36,21 +116,229 @@ pub struct ArenaSimulation {
     pub(crate) total_settlement_hops: u64,
     pub(crate) total_settlement_time: u64,
 
-    // E11: Rolling volatility window
-    pub(crate) gold_price_history: Vec<f64>,
+    // E11: Gold price history and the estimator reading volatility off it
+    pub(crate) volatility_model: volatility::VolatilityModel,
 
     // Lambda EMA for surge smoothing (10-tick effective window)
     pub(crate) lambda_ema: f64,
 
+    // Monetary velocity: in-flight float EMA (10-tick window) and value settled this tick
+    pub(crate) float_ema: f64,
+    pub(crate) tick_settled_value: f64,
+
     // v0.2: Conservation circuit breaker and NGauge rolling window
     pub(crate) conservation_law: conservation::ConservationLaw,
     pub(crate) engauge_state: engauge::NGaugeState,
 
     // Core governor PID (Decimal-based, vendored from caesar-sim-core)
     pub(crate) core_pid: crate::core_governor::pid::GovernorPid,
+    // Gold price the core governor steers toward
+    pub(crate) peg_target: f64,
 
     // Core conservation law (Decimal-based, vendored from caesar-sim-core)
     pub(crate) core_conservation: crate::core_conservation::ConservationLaw,
+
+    // Effective all-in cost of settlements, per tier
+    pub(crate) settlement_costs: cost::SettlementCostTracker,
+
+    // Settled value per egress node over the current concentration epoch
+    pub(crate) egress_concentration: concentration::EgressConcentration,
+    // Per-epoch forwarded hops by directed link
+    pub(crate) route_concentration: concentration::RouteConcentration,
+    // Smoothed inventory each Egress's settlements draw per tick
+    pub(crate) egress_draws: egress_queue::EgressDrawRates,
+
+    // NGauge verification marketplace
+    pub(crate) verification: verification::VerificationMarket,
+    pub(crate) total_verification_fees: f64,
+    pub(crate) verification_shortfalls: u32,
+
+    // Optional node dynamics: KYC attestations lapse after this many ticks
+    pub(crate) kyc_validity_ticks: Option<u64>,
+
+    // Quadrant of the governor decision in effect, which `governance_quadrant` labels
+    pub(crate) governance_pressure: PressureQuadrant,
+    // Governor quadrant change log
+    pub(crate) quadrant_transitions: Vec<QuadrantTransition>,
+
+    // Optional egress settlement batching: window length (ticks) and inventory ledger
+    pub(crate) settlement_batch_window: Option<u64>,
+    pub(crate) batch_ledger: batching::BatchLedger,
+
+    // Demurrage grace: packets younger than this many ticks do not decay
+    pub(crate) demurrage_grace_ticks: u64,
+
+    // Trust rebates for fully high-trust routes, funded from transit hop fees
+    pub(crate) rebate_pool: rebate::RebatePool,
+
+    // Optional per-origin volume discounts on the base settlement fee
+    pub(crate) volume_discounts: Option<discount::VolumeDiscounts>,
+
+    // Optional anti-spam token buckets per ingress node
+    pub(crate) rate_limiter: Option<ratelimit::IngressRateLimiter>,
+
+    // Dust policy (minimum value, surcharge) and non-dust settlement latency
+    pub(crate) dust_guard: dust::DustGuard,
+
+    // Optional ingress aggregation of sub-threshold payments
+    pub(crate) aggregator: Option<aggregation::DustAggregator>,
+    pub(crate) aggregated_payments_settled: u32,
+
+    // History entries folded away by periodic compaction
+    pub(crate) compacted_entries: u64,
+
+    // Optional bound on route_history entries per packet (route_digest keeps the full path)
+    pub(crate) route_window: Option<usize>,
+
+    // Distance term the router scores neighbors by, and whether trust enters the score
+    pub(crate) routing_mode: routing::RoutingMode,
+    pub(crate) route_scoring: routing::RouteScoring,
+
+    // Core's capacity-based router picking next hops in place of the scoring above (None = legacy)
+    pub(crate) capacity_router: Option<capacity_router::CapacityRouter>,
+
+    // Packets a node may settle or forward per tick per Mbps of bandwidth (0 = bandwidth not enforced)
+    pub(crate) packets_per_mbps: f64,
+    // Tier-first service order for buffers over their cap (None = arrival order)
+    pub(crate) priority_lanes: Option<priority_lanes::PriorityLanes>,
+
+    // Hop-exhaustion handling and orbit timeouts
+    pub(crate) routing_policy: routing_policy::RoutingPolicy,
+
+    // Optional cache of preferred next hops
+    pub(crate) route_cache: Option<route_cache::RouteCache>,
+    // Shortest paths per (origin, target Egress), used in ShortestPath mode
+    pub(crate) path_cache: route_cache::PathCache,
+
+    // How the settlement transit pool is split, and per-packet relay hold times
+    pub(crate) transit_weighting: transit_rewards::TransitWeighting,
+    pub(crate) transit_holds: transit_rewards::HoldLedger,
+
+    // Fast-route bonuses paid from slow-route penalties
+    pub(crate) velocity_bonus: velocity_bonus::VelocityBonus,
+
+    // Core fee modifier per tier from the latest governor cycle, which
+    // packets snapshot at spawn and at each hop
+    pub(crate) tier_fee_modifiers: [f64; 4],
+    // Settlement fee pricing, and how far settlement fees drifted from quotes
+    pub(crate) pricing_mode: quote::PricingMode,
+    pub(crate) quote_drift: f64,
+    pub(crate) quote_mismatches: u32,
+    // Fee charges the clamp cut back to keep a packet within its tier cap
+    pub(crate) fee_cap_breaches: u32,
+
+    // Per-tick deltas behind the stress index
+    pub(crate) stress: stress::StressTracker,
+
+    // Fees, burn and rewards split by the tier that paid them
+    pub(crate) tier_ledger: tier_ledger::TierLedger,
+
+    // Bankrupt Egress exit and re-entry (None = egresses never exit)
+    pub(crate) egress_lifecycle: Option<egress_exit::EgressLifecycle>,
+
+    // Endogenous panic driven by network performance (None = exogenous only)
+    pub(crate) panic_model: Option<panic_dynamics::PanicModel>,
+    // Per-node panic raised by local reverts and spread to neighbors (None = off)
+    pub(crate) panic_contagion: Option<panic_contagion::PanicContagion>,
+
+    // Egress inventory top-ups each tick (None = settlements only drain it)
+    pub(crate) replenishment: Option<replenishment::Replenishment>,
+    // Egress borrowing crypto from linked Egress peers (None = off)
+    pub(crate) rebalancing: Option<rebalancing::Rebalancing>,
+
+    // Spawn volume responding to recently realized all-in cost
+    pub(crate) demand_elasticity: elasticity::DemandElasticity,
+
+    // Spawn cohorts and their outcomes by age
+    pub(crate) cohorts: cohort::CohortTracker,
+
+    // Seeded auto traffic (None = the deterministic tick/id cycle)
+    pub(crate) traffic_rng: Option<rng::SplitMix64>,
+
+    // Seed every stream derives from, once `set_master_seed` is called
+    pub(crate) master_seed: Option<u64>,
+
+    // Optional per-hop latency jitter (max extra ticks), on its own stream
+    pub(crate) latency_jitter_ticks: u64,
+    pub(crate) latency_rng: rng::SplitMix64,
+
+    // Base fee rate and tier modifiers held while fees are frozen
+    pub(crate) frozen_fees: Option<(f64, [f64; 4])>,
+
+    // Per-node hop dwell and fee attribution (None = not tracing)
+    pub(crate) hop_trace: Option<hop_trace::HopTrace>,
+
+    // FX spread charged on egress payouts (None = payouts at the oracle price)
+    pub(crate) payout_slippage: Option<slippage::PayoutSlippage>,
+
+    // Scripted gold price shocks on top of the spot price
+    pub(crate) price_shocks: price_shock::PriceShocks,
+
+    // Revert counts by reason and the most recent revert receipts
+    pub(crate) revert_log: receipts::RevertLog,
+
+    // Spawn corridors and their outcomes (empty = network-wide auto traffic)
+    pub(crate) corridors: corridor::Corridors,
+
+    // Gold price feed the governor steers on, and any scheduled outage
+    pub(crate) oracle: oracle::PriceOracle,
+
+    // Reverted payments their senders will re-send
+    pub(crate) retries: retry::RetryQueue,
+
+    // Optional local gold premiums by region, and the flows between them
+    pub(crate) regional_prices: regional_price::RegionalPrices,
+
+    // Speculator agents round-tripping value, the detector's ground truth
+    pub(crate) speculators: speculation::Speculators,
+
+    // Yield-seeking agents supplying crypto to Egress inventory
+    pub(crate) liquidity_providers: liquidity_provider::LiquidityProviders,
+
+    // Governor control interval, actuation lag and decisions in the pipeline
+    pub(crate) control_loop: control_loop::ControlLoop,
+    // Whether decisions are logged, and this tick's decision when one was
+    pub(crate) log_governor_io: bool,
+    pub(crate) governor_io: Option<control_loop::GovernorIo>,
+
+    // Node updates of the last tick, to flag what changed since
+    pub(crate) last_node_updates: Vec<NodeUpdate>,
+
+    // Ids of nodes taken out by `remove_node`; ids are never reused
+    pub(crate) removed_nodes: BTreeSet<u32>,
+
+    // Real-time pacing for `advance_to`; None runs nothing there
+    pub(crate) pacer: Option<pacing::RealTimePacer>,
+
+    // Directed links cut by `partition_network_core`, restored on heal
+    pub(crate) severed_links: Vec<(u32, u32)>,
+
+    // Keyframe-and-delta checkpoints of the network (None = not taken)
+    pub(crate) checkpoints: Option<checkpoint::CheckpointLog>,
+
+    // Origins whose packets SelectiveCensor nodes refuse
+    pub(crate) censored_origins: BTreeSet<u32>,
+
+    // Nodes colluding to route, vouch and split fees together (None = no cartel)
+    pub(crate) cartel: Option<collusion::Cartel>,
+
+    // Stricter-than-constitutional fee caps on pilot nodes, by node id
+    pub(crate) node_fee_caps: BTreeMap<u32, governor::FeeCaps>,
+
+    // Spawn-to-settlement latency by tier, for service-level checks
+    pub(crate) settlement_latency: latency::SettlementLatency,
+
+    // What the construction-time topology check found, before the first tick
+    pub(crate) topology_issues: Vec<topology::TopologyIssue>,
+
+    // Network description the simulation was built from; `reset` rebuilds it
+    pub(crate) topology: topology::TopologyBuilder,
+
+    // Node running costs (storage, bandwidth, energy); None accounts nothing
+    pub(crate) resources: Option<resources::ResourceModel>,
+
+    // Node fiat and crypto inventories by flow, and the dissolution payout path
+    pub(crate) asset_ledger: asset_ledger::AssetLedger,
 }
 
 // ─── Internal Logic (Testable, pure Rust) ────────────────────────────────────
@@ -60,17 +348,35 @@ impl ArenaSimulation {
         self.state.current_tick += 1;
         let current_tick = self.state.current_tick;
 
-        // E11: Update gold price history (rolling window of 20)
-        self.gold_price_history.push(self.state.gold_price);
-        if self.gold_price_history.len() > 20 {
-            self.gold_price_history.remove(0);
+        // Scripted shocks move gold before anything prices off it
+        self.state.gold_price = self.price_shocks.apply(self.state.gold_price, current_tick);
+        self.oracle.publish(self.state.gold_price, current_tick);
+        self.state.oracle_price = self.oracle.price();
+        self.state.oracle_staleness_ticks = self.oracle.staleness(current_tick);
+
+        // Refill ingress rate-limit buckets before this tick's spawns
+        if let Some(limiter) = self.rate_limiter.as_mut() {
+            limiter.refill();
+        }
+
+        // Node attribute dynamics (KYC expiry)
+        self.apply_node_dynamics(current_tick);
+
+        // Bankrupt Egress nodes exit; recapitalized ones re-enter
+        self.apply_egress_lifecycle(current_tick);
+
+        // Rolling availability feeds trust and routing
+        availability::update_availability(&mut self.nodes);
+        if let Some(cartel) = self.cartel.as_mut() {
+            cartel.retain_live(&self.nodes);
+            cartel.inflate_trust(&mut self.nodes);
         }
 
         // S1: Deliver in-transit packets from message queue
         self.deliver_message_queue(current_tick);
 
-        // E11: Proper volatility via rolling window (coefficient of variation)
-        let volatility = compute_rolling_volatility(&self.gold_price_history);
+        // E11: Volatility from the price history, by the selected estimator
+        let volatility = self.volatility_model.update(self.state.gold_price);
         self.state.volatility = volatility;
         self.last_gold_price = self.state.gold_price;
 
@@ -88,6 +394,7 @@ impl ArenaSimulation {
         // Exponential moving average — 10-tick effective window
         self.lambda_ema = self.lambda_ema * 0.9 + raw_lambda * 0.1;
         let lambda = self.lambda_ema;
+        self.state.liquidity_lambda = lambda;
 
         // E8: Surge only in Bottleneck quadrant: sustained low lambda AND market stress
         let surge_multiplier = if lambda < 0.5
@@ -103,65 +410,17 @@ impl ArenaSimulation {
         self.state.ngauge_activity_index =
             engauge::update_ngauge_activity(&mut self.nodes, self.state.demand_factor);
 
-        // 1. The Caesar Governor Logic (core PID, Decimal-based)
-        let core_metrics = crate::adapter::world_to_metrics(&self.state, volatility, lambda);
-        let core_params = self.core_pid.recalculate(&core_metrics);
-
-        // Convert core GovernanceParams back to Arena GovernorOutput
-        let fee_rate = crate::adapter::params_to_fee_rate(&core_params);
-        let quadrant = match core_params.pressure {
-            crate::core_governor::params::PressureQuadrant::GoldenEra => "D: GOLDEN ERA",
-            crate::core_governor::params::PressureQuadrant::Bubble => "A: BUBBLE",
-            crate::core_governor::params::PressureQuadrant::Crash => "B: CRASH",
-            crate::core_governor::params::PressureQuadrant::Stagnation => "C: STAGNATION",
-            crate::core_governor::params::PressureQuadrant::Bottleneck => "E: BOTTLENECK",
-            crate::core_governor::params::PressureQuadrant::Vacuum => "F: VACUUM",
-        };
-        let status = match core_params.pressure {
-            crate::core_governor::params::PressureQuadrant::GoldenEra => "STABLE",
-            crate::core_governor::params::PressureQuadrant::Bubble => "OVER-PEG: VENTING",
-            crate::core_governor::params::PressureQuadrant::Crash => "UNDER-PEG: EMERGENCY BRAKE",
-            crate::core_governor::params::PressureQuadrant::Stagnation => "UNDER-PEG: STIMULUS",
-            crate::core_governor::params::PressureQuadrant::Bottleneck => "CONGESTED: THROTTLING",
-            crate::core_governor::params::PressureQuadrant::Vacuum => "LOW ACTIVITY: INCENTIVIZING",
-        };
-        // Health score normalized to 0..1 for complexity mapping
-        let health = crate::adapter::from_decimal(core_params.health_score) / 10.0;
-        let verification_complexity = (1.0 + (1.0 - health) * 4.0).round() as u64;
-
-        let mut gov = GovernorOutput {
-            fee_rate,
-            demurrage: match core_params.pressure {
-                crate::core_governor::params::PressureQuadrant::Bubble => 0.10,
-                crate::core_governor::params::PressureQuadrant::Crash => 0.0,
-                crate::core_governor::params::PressureQuadrant::Stagnation => 0.001,
-                crate::core_governor::params::PressureQuadrant::Bottleneck => 0.005 * 1.5,
-                crate::core_governor::params::PressureQuadrant::Vacuum => 0.005 * 0.5,
-                crate::core_governor::params::PressureQuadrant::GoldenEra => 0.005,
-            },
-            quadrant: quadrant.to_string(),
-            status: status.to_string(),
-            verification_complexity,
-        };
-
-        // Legacy overrides (preserve simulation behavior)
-        if self.state.panic_level > 0.7 {
-            gov.fee_rate = gov.fee_rate.max(0.05);
-            gov.demurrage *= 0.5;
-        }
-        if self.state.ngauge_activity_index > 0.5 {
-            gov.fee_rate *= 0.8;
-        }
-        let organic_ratio = if self.state.network_velocity > 100.0 {
-            self.state.ngauge_activity_index / (self.state.network_velocity / 1000.0).max(0.1)
-        } else {
-            1.0
-        };
-        if organic_ratio < 0.3 {
-            gov.fee_rate *= 1.5;
-        }
-        if lambda < 0.5 {
-            gov.fee_rate *= surge_multiplier;
+        // 1. The Caesar Governor decides on its control interval; decisions
+        // reach the network after the actuation lag
+        self.governor_io = None;
+        let decided = self.control_loop.is_due(current_tick)
+            .then(|| self.decide_governance(volatility, lambda, surge_multiplier));
+        let control_loop::GovernorDecision { output: mut gov, tier_modifiers: mut mods, pressure } =
+            self.control_loop.actuate(current_tick, decided);
+        // Frozen fees hold the rate and tier modifiers of the freeze tick
+        if let Some((fee_rate, frozen_mods)) = self.frozen_fees {
+            gov.fee_rate = fee_rate;
+            mods = frozen_mods;
         }
 
         // E7: Organic ratio (also computed inside governor, but store separately)
@@ -184,19 +443,16 @@ impl ArenaSimulation {
             ),
         );
 
+        self.record_quadrant(pressure, &gov.quadrant, current_tick);
         self.state.governance_quadrant = gov.quadrant.clone();
         self.state.governance_status = gov.status.clone();
         self.state.current_demurrage_rate = gov.demurrage;
         self.state.current_fee_rate = gov.fee_rate;
+        self.control_loop.record_fee_rate(gov.fee_rate);
         // Compute per-tier effective fee rates from core fee modifiers
         {
             let caps = [0.05_f64, 0.02, 0.005, 0.001];
-            let mods = [
-                crate::adapter::from_decimal(core_params.fee_modifiers.l0),
-                crate::adapter::from_decimal(core_params.fee_modifiers.l1),
-                crate::adapter::from_decimal(core_params.fee_modifiers.l2),
-                crate::adapter::from_decimal(core_params.fee_modifiers.l3),
-            ];
+            self.tier_fee_modifiers = mods;
             self.state.tier_fee_rates = [
                 (gov.fee_rate * mods[0]).min(caps[0]).max(0.0),
                 (gov.fee_rate * mods[1]).min(caps[1]).max(0.0),
@@ -204,25 +460,110 @@ impl ArenaSimulation {
                 (gov.fee_rate * mods[3]).min(caps[3]).max(0.0),
             ];
         }
-        // Recompute peg_deviation same way governor does internally
-        let effective_rate = self.state.gold_price * (1.0 - gov.fee_rate);
+        // Payout FX spread at this tick's volatility
+        self.state.payout_spread = self.payout_slippage.as_ref()
+            .map_or(0.0, |fx| fx.spread(self.state.volatility));
+        // Recompute peg_deviation same way governor does internally, net of
+        // the beneficiary's conversion spread
+        let effective_rate = self.state.gold_price * (1.0 - gov.fee_rate)
+            * (1.0 - self.state.payout_spread);
         let peg_deviation = (effective_rate - self.state.gold_price) / self.state.gold_price;
         self.state.peg_deviation = peg_deviation - (self.state.panic_level * 0.15);
         self.state.verification_complexity = gov.verification_complexity;
 
         let demurrage = gov.demurrage;
 
+        // Route ingress aggregates whose wait is over
+        self.release_aggregates(current_tick);
+
+        // Senders re-send reverted payments whose retry delay is over
+        self.respawn_retries(current_tick);
+
+        // Speculators re-send what came back to them
+        self.spawn_speculator_orders();
+
         // S2: Auto Traffic Generation
         self.auto_spawn_traffic(current_tick);
 
         // 4. Node Execution Cycle (Sovereign Routing)
+        if let Some(cache) = self.route_cache.as_mut() {
+            cache.begin_tick(&self.nodes);
+        }
+        if let Some(router) = self.capacity_router.as_mut() {
+            router.begin_tick(&self.nodes);
+        }
         let settled_count = self.execute_node_cycle(current_tick, demurrage);
 
         // E12: Compute per-node liquidity pressure
         self.compute_node_pressure();
 
+        // Keep long-horizon runs flat
+        if current_tick.is_multiple_of(compaction::COMPACTION_INTERVAL_TICKS) {
+            self.compact_memory();
+        }
+
         // 5. Finalize Stats
-        self.finalize_stats(settled_count, current_tick)
+        let result = self.finalize_stats(settled_count, current_tick);
+
+        // Checkpoint the network as this tick leaves it
+        if self.checkpoints.as_ref().is_some_and(|c| c.is_due(current_tick)) {
+            let checkpoint = self.capture_checkpoint();
+            if let Some(log) = self.checkpoints.as_mut() {
+                log.record(checkpoint);
+            }
+        }
+        result
+    }
+
+    /// Nodes, non-empty buffers, in-flight packets and world state as they
+    /// stand now.
+    pub(crate) fn capture_checkpoint(&self) -> checkpoint::Checkpoint {
+        checkpoint::Checkpoint {
+            tick: self.state.current_tick,
+            nodes: self.nodes.clone(),
+            node_buffers: self.node_buffers.iter()
+                .filter(|(_, buf)| !buf.is_empty())
+                .map(|(&id, buf)| (id, buf.clone()))
+                .collect(),
+            message_queue: self.message_queue.clone(),
+            state: self.state.clone(),
+        }
+    }
+
+    /// Fold long route and fee histories, drop any terminal packet still held
+    /// in a container, and release buffer capacity left over from spikes.
+    pub(crate) fn compact_memory(&mut self) {
+        let mut dropped = 0;
+        for buf in self.node_buffers.values_mut() {
+            buf.retain(|p| !p.status.is_terminal());
+            dropped += buf.iter_mut().map(compaction::compact_packet).sum::<usize>();
+            buf.shrink_to_fit();
+        }
+        self.message_queue.retain(|p| !p.status.is_terminal());
+        dropped += self.message_queue.iter_mut().map(compaction::compact_packet).sum::<usize>();
+        self.message_queue.shrink_to_fit();
+        self.compacted_entries += dropped as u64;
+
+        // Holds of packets that reverted or dissolved are never taken
+        if !self.transit_holds.is_empty() {
+            let live: std::collections::BTreeSet<u64> = self.node_buffers.values()
+                .flatten()
+                .chain(&self.message_queue)
+                .map(|p| p.id)
+                .collect();
+            self.transit_holds.retain_live(|id| live.contains(&id));
+        }
+    }
+
+    /// Governor pricing a packet of `tier` gets this tick.
+    fn governor_snapshot(&self, tier: MarketTier) -> GovernorSnapshot {
+        quote::snapshot(
+            self.state.current_tick,
+            self.state.current_fee_rate,
+            &self.tier_fee_modifiers,
+            &self.state.governance_quadrant,
+            tier,
+        )
     }
 
     /// Deliver in-transit packets whose arrival tick has been reached.
@@ -254,6 +595,7 @@ impl ArenaSimulation {
                     } else {
                         p.status = PacketStatus::Held;
                         p.orbit_start_tick = Some(current_tick);
+                        p.orbit_reason = Some(OrbitReason::DisabledDestination);
                         let origin = p.origin_node;
                         self.node_buffers.entry(origin).or_default().push(p);
                     }
@@ -265,11 +607,110 @@ impl ArenaSimulation {
         }
     }
 
-    /// S2: Auto traffic generation based on demand and panic.
+    /// One governor decision from this tick's measurements: the core PID
+    /// (Decimal-based) and the legacy overrides.
+    fn decide_governance(
+        &mut self,
+        volatility: f64,
+        lambda: f64,
+        surge_multiplier: f64,
+    ) -> control_loop::GovernorDecision {
+        let core_metrics = crate::adapter::world_to_metrics(&self.state, volatility, lambda, self.peg_target);
+        let core_params = self.core_pid.recalculate(&core_metrics);
+
+        // Convert core GovernanceParams back to Arena GovernorOutput
+        let fee_rate = crate::adapter::params_to_fee_rate(&core_params);
+        let pressure = core_params.pressure;
+        let quadrant = match core_params.pressure {
+            crate::core_governor::params::PressureQuadrant::GoldenEra => "D: GOLDEN ERA",
+            crate::core_governor::params::PressureQuadrant::Bubble => "A: BUBBLE",
+            crate::core_governor::params::PressureQuadrant::Crash => "B: CRASH",
+            crate::core_governor::params::PressureQuadrant::Stagnation => "C: STAGNATION",
+            crate::core_governor::params::PressureQuadrant::Bottleneck => "E: BOTTLENECK",
+            crate::core_governor::params::PressureQuadrant::Vacuum => "F: VACUUM",
+        };
+        let status = match core_params.pressure {
+            crate::core_governor::params::PressureQuadrant::GoldenEra => "STABLE",
+            crate::core_governor::params::PressureQuadrant::Bubble => "OVER-PEG: VENTING",
+            crate::core_governor::params::PressureQuadrant::Crash => "UNDER-PEG: EMERGENCY BRAKE",
+            crate::core_governor::params::PressureQuadrant::Stagnation => "UNDER-PEG: STIMULUS",
+            crate::core_governor::params::PressureQuadrant::Bottleneck => "CONGESTED: THROTTLING",
+            crate::core_governor::params::PressureQuadrant::Vacuum => "LOW ACTIVITY: INCENTIVIZING",
+        };
+        // Health score normalized to 0..1 for complexity mapping
+        let health = crate::adapter::from_decimal(core_params.health_score) / 10.0;
+        let mut verification_complexity = (1.0 + (1.0 - health) * 4.0).round() as u64;
+        // A stale price could be hiding a shock: verify harder until it is fresh
+        if self.core_pid.is_oracle_stale(&core_metrics) {
+            verification_complexity += oracle::STALE_VERIFICATION_COMPLEXITY;
+        }
+
+        let mut gov = GovernorOutput {
+            fee_rate,
+            demurrage: match core_params.pressure {
+                crate::core_governor::params::PressureQuadrant::Bubble => 0.10,
+                crate::core_governor::params::PressureQuadrant::Crash => 0.0,
+                crate::core_governor::params::PressureQuadrant::Stagnation => 0.001,
+                crate::core_governor::params::PressureQuadrant::Bottleneck => 0.005 * 1.5,
+                crate::core_governor::params::PressureQuadrant::Vacuum => 0.005 * 0.5,
+                crate::core_governor::params::PressureQuadrant::GoldenEra => 0.005,
+            },
+            quadrant: quadrant.to_string(),
+            status: status.to_string(),
+            verification_complexity,
+        };
+
+        // Legacy overrides (preserve simulation behavior)
+        if self.state.panic_level > 0.7 {
+            gov.fee_rate = gov.fee_rate.max(0.05);
+            gov.demurrage *= 0.5;
+        }
+        if self.state.ngauge_activity_index > 0.5 {
+            gov.fee_rate *= 0.8;
+        }
+        let organic_ratio = engauge::compute_organic_ratio(
+            self.state.ngauge_activity_index,
+            self.state.network_velocity,
+        );
+        if organic_ratio < 0.3 {
+            gov.fee_rate *= 1.5;
+        }
+        if lambda < 0.5 {
+            gov.fee_rate *= surge_multiplier;
+        }
+        let mods = [
+            crate::adapter::from_decimal(core_params.fee_modifiers.l0),
+            crate::adapter::from_decimal(core_params.fee_modifiers.l1),
+            crate::adapter::from_decimal(core_params.fee_modifiers.l2),
+            crate::adapter::from_decimal(core_params.fee_modifiers.l3),
+        ];
+        if self.log_governor_io {
+            self.governor_io = Some(control_loop::GovernorIo {
+                tick: self.state.current_tick,
+                metrics: core_metrics,
+                params: core_params,
+                output: gov.clone(),
+                tier_modifiers: mods,
+            });
+        }
+        control_loop::GovernorDecision { output: gov, tier_modifiers: mods, pressure }
+    }
+
+    /// S2: Auto traffic generation based on demand and panic, or along
+    /// each corridor's own demand curve once corridors are defined.
     fn auto_spawn_traffic(&mut self, current_tick: u64) {
+        if !self.corridors.is_empty() {
+            for index in 0..self.corridors.len() as u32 {
+                self.spawn_corridor_traffic(index, current_tick);
+            }
+            return;
+        }
         let spawn_rate = self.state.demand_factor * 5.0
             * if self.state.panic_level > 0.5 { 1.0 + self.state.panic_level } else { 1.0 };
-        let packets_to_spawn = spawn_rate as u32;
+        let packets_to_spawn = match self.traffic_rng.as_mut() {
+            Some(rng) => rng.poisson(spawn_rate),
+            None => spawn_rate as u32,
+        };
         let ingress_nodes: Vec<u32> = self.nodes.iter()
             .filter(|n| n.role == NodeRole::Ingress)
             .map(|n| n.id)
@@ -277,58 +718,250 @@ impl ArenaSimulation {
         if !ingress_nodes.is_empty() {
             let tier_base = self.packet_id_counter;
             for i in 0..packets_to_spawn {
-                let node_idx = (current_tick as usize + i as usize) % ingress_nodes.len();
-                let node_id = ingress_nodes[node_idx];
-                // Generate diverse tier traffic
-                let tier_selector = (tier_base + i as u64) % 4;
-                let amount = match tier_selector {
-                    0 => 1.0 + ((current_tick + i as u64) % 9) as f64,           // L0: 1-9g
-                    1 => 50.0 + ((current_tick + i as u64) % 950) as f64,        // L1: 50-999g
-                    2 => 1000.0 + ((current_tick + i as u64) % 99000) as f64,    // L2: 1000-99999g
-                    _ => 100000.0 + ((current_tick + i as u64) % 900000) as f64, // L3: 100000-999999g
+                // Seeded traffic draws each choice; otherwise they cycle with tick and id
+                let (draw, tier_selector, check) = match self.traffic_rng.as_mut() {
+                    Some(rng) => (rng.next_u64(), rng.next_u64() % 4, rng.next_f64()),
+                    None => (
+                        current_tick + i as u64,
+                        (tier_base + i as u64) % 4,
+                        (((self.packet_id_counter * 7 + i as u64) % 100) as f64 + 0.5) / 100.0,
+                    ),
                 };
+                let node_id = ingress_nodes[(draw % ingress_nodes.len() as u64) as usize];
+                self.spawn_auto_packet(
+                    node_id, tier_amount(tier_selector, draw), check, None, current_tick);
+            }
+        }
+    }
 
-                // E4: Demand destruction
-                if self.state.current_fee_rate > 0.10 {
-                    let cancel_prob =
-                        ((self.state.current_fee_rate - 0.10) * 5.0).min(1.0);
-                    let check = ((self.packet_id_counter * 7 + i as u64) % 100)
-                        as f64 / 100.0;
-                    if check < cancel_prob {
-                        continue;
-                    }
+    /// Re-send reverted payments that are due. A retry is the sender's
+    /// decision already made, so demand elasticity does not cancel it;
+    /// admission still applies.
+    fn respawn_retries(&mut self, current_tick: u64) {
+        for r in self.retries.take_due(current_tick) {
+            self.state.retry_count += 1;
+            self.spawn_auto_packet(r.node_id, r.amount, 1.0, r.corridor, current_tick);
+        }
+    }
+
+    /// Send each speculator's order for this tick. Speculators bypass
+    /// aggregation; admission still applies.
+    fn spawn_speculator_orders(&mut self) {
+        if self.speculators.is_empty() {
+            return;
+        }
+        for order in self.speculators.orders(self.state.volatility) {
+            match self.admit_spawn(order.node_id, order.amount) {
+                Some(surcharge) => {
+                    let id = self.mint_packet(order.node_id, order.amount, surcharge, Vec::new(), None);
+                    self.speculators.sent(order, id);
                 }
+                None => self.speculators.unsent(order),
+            }
+        }
+    }
 
-                self.packet_id_counter += 1;
-                let tier = MarketTier::from_value(amount);
-                let ttl = current_tick + tier.ttl_ticks();
-                let hop_limit = tier.hop_limit();
-                let fee_budget = tier.fee_cap() * amount;
-                let packet = SimPacket {
-                    id: self.packet_id_counter,
-                    original_value: amount,
-                    current_value: amount,
-                    arrival_tick: current_tick,
-                    status: PacketStatus::Minted,
-                    origin_node: node_id,
-                    target_node: None,
-                    hops: 0,
-                    route_history: vec![node_id],
-                    orbit_start_tick: None,
-                    tier,
-                    ttl,
-                    hop_limit,
-                    fee_budget,
-                    fees_consumed: 0.0,
-                    fee_schedule: Vec::new(),
-                    spawn_tick: current_tick,
-                };
-                self.node_buffers.entry(node_id).or_default().push(packet);
-                self.nodes[node_id as usize].current_buffer_count += 1;
-                self.total_input += amount;
-                self.state.spawn_count += 1;
+    /// Auto traffic of one corridor: its demand curve sets the volume, its
+    /// tier mix the amounts, and packets enter at its origin region's Ingress.
+    fn spawn_corridor_traffic(&mut self, index: u32, current_tick: u64) {
+        let Some(c) = self.corridors.get(index) else {
+            return;
+        };
+        let spawn_rate = c.demand.rate(current_tick);
+        let origin = c.origin;
+        let ingress_nodes: Vec<u32> = self.nodes.iter()
+            .filter(|n| n.role == NodeRole::Ingress && origin.contains(n.site))
+            .map(|n| n.id)
+            .collect();
+        if ingress_nodes.is_empty() {
+            return;
+        }
+        let packets_to_spawn = match self.traffic_rng.as_mut() {
+            Some(rng) => rng.poisson(spawn_rate),
+            None => spawn_rate as u32,
+        };
+        let tier_base = self.packet_id_counter;
+        for i in 0..packets_to_spawn {
+            let (draw, tier_draw, check) = match self.traffic_rng.as_mut() {
+                Some(rng) => (rng.next_u64(), rng.next_f64(), rng.next_f64()),
+                None => (
+                    current_tick + i as u64,
+                    (((tier_base + i as u64) % 100) as f64 + 0.5) / 100.0,
+                    (((self.packet_id_counter * 7 + i as u64) % 100) as f64 + 0.5) / 100.0,
+                ),
+            };
+            let tier_selector = self.corridors.get(index).map_or(0, |c| c.pick_tier(tier_draw));
+            let node_id = ingress_nodes[(draw % ingress_nodes.len() as u64) as usize];
+            let tag = self.corridors.tag(index);
+            self.spawn_auto_packet(
+                node_id, tier_amount(tier_selector, draw), check, Some(tag), current_tick);
+        }
+    }
+
+    /// Mint one auto traffic packet unless demand elasticity cancels it
+    /// (`check` below the cancel probability) or admission turns it away.
+    /// Corridor payments are never aggregated: an aggregate has no single
+    /// destination.
+    fn spawn_auto_packet(
+        &mut self,
+        node_id: u32,
+        amount: f64,
+        check: f64,
+        corridor: Option<corridor::CorridorTag>,
+        current_tick: u64,
+    ) {
+        // E4: Demand destruction along the elasticity curve
+        let cancel_prob = 1.0 - self.demand_elasticity.retention();
+        if check < cancel_prob {
+            return;
+        }
+
+        let Some(surcharge) = self.admit_spawn(node_id, amount) else {
+            return;
+        };
+        if corridor.is_none() {
+            if let Some(agg) = self.aggregator.as_mut() {
+                if agg.offer(node_id, amount, surcharge, current_tick) {
+                    return;
+                }
+            }
+        }
+
+        self.mint_packet(node_id, amount, surcharge, Vec::new(), corridor);
+        if let Some(tag) = corridor {
+            self.corridors.record_spawn(tag.corridor, amount);
+        }
+        self.state.spawn_count += 1;
+    }
+
+    /// Spawn-time admission shared by auto traffic and `spawn_packet`: the
+    /// ingress rate limit, then the dust policy. Returns the dust surcharge to
+    /// take from the packet, or `None` when it is rejected.
+    pub(crate) fn admit_spawn(&mut self, node_id: u32, amount: f64) -> Option<f64> {
+        if let Some(limiter) = self.rate_limiter.as_mut() {
+            if !limiter.try_admit(node_id) {
+                return None;
             }
         }
+        self.dust_guard.admit(amount)
+    }
+
+    /// A packet of `amount` minted at `node_id` this tick with `surcharge`
+    /// taken up front, booked as input but not yet placed anywhere.
+    fn new_packet(
+        &mut self,
+        node_id: u32,
+        amount: f64,
+        surcharge: f64,
+        manifest: Vec<f64>,
+        corridor: Option<corridor::CorridorTag>,
+    ) -> SimPacket {
+        // Pre-increment so ids never collide
+        self.packet_id_counter += 1;
+        let tick = self.state.current_tick;
+        let tier = MarketTier::from_value(amount);
+        let p = SimPacket {
+            id: self.packet_id_counter, original_value: amount, current_value: amount - surcharge,
+            arrival_tick: tick, status: PacketStatus::Minted,
+            origin_node: node_id, target_node: None, hops: 0,
+            route_history: vec![node_id],
+            orbit_start_tick: None,
+            orbit_reason: None,
+            revert_reason: None,
+            tier,
+            ttl: tick + tier.ttl_ticks(),
+            hop_limit: tier.hop_limit(),
+            fee_budget: tier.fee_cap() * amount + surcharge,
+            fees_consumed: surcharge,
+            fee_schedule: Vec::new(),
+            spawn_tick: tick,
+            cohort: self.cohorts.cohort_of(tick),
+            manifest,
+            route_digest: compaction::digest_route(&[node_id]),
+            route_visits: vec![(node_id, 1)],
+            governor_snapshots: vec![self.governor_snapshot(tier)],
+            corridor,
+        };
+        self.total_input += amount;
+        self.total_fees += surcharge;
+        self.tier_ledger.record_fee(tier, surcharge);
+        self.cohorts.record_spawn(p.cohort);
+        p
+    }
+
+    /// Mint a packet of `amount` at `node_id`, taking `surcharge` up front,
+    /// into the node's buffer. Returns the packet id.
+    pub(crate) fn mint_packet(
+        &mut self,
+        node_id: u32,
+        amount: f64,
+        surcharge: f64,
+        manifest: Vec<f64>,
+        corridor: Option<corridor::CorridorTag>,
+    ) -> u64 {
+        let p = self.new_packet(node_id, amount, surcharge, manifest, corridor);
+        let p_id = p.id;
+        self.node_buffers.entry(node_id).or_default().push(p);
+        self.nodes[node_id as usize].current_buffer_count += 1;
+        p_id
+    }
+
+    /// Pre-load `spec`'s packets before the first tick, round-robin over the
+    /// active Ingress nodes: buffered at the Ingress, or on the link to its
+    /// first active neighbor and arriving on tick 1. Admission policies are
+    /// bypassed. Returns the packets seeded; none once the clock has started
+    /// or when no Ingress is active.
+    pub fn seed_backlog_core(&mut self, spec: &backlog::BacklogSpec) -> u32 {
+        if self.state.current_tick > 0 {
+            return 0;
+        }
+        let ingress: Vec<u32> = self.nodes.iter()
+            .filter(|n| n.role == NodeRole::Ingress)
+            .map(|n| n.id)
+            .collect();
+        if ingress.is_empty() {
+            return 0;
+        }
+        let planned = spec.packets();
+        for (i, b) in planned.iter().enumerate() {
+            let node_id = ingress[i % ingress.len()];
+            let first_hop = self.nodes[node_id as usize].neighbors.iter().copied()
+                .find(|&n| self.nodes[n as usize].role != NodeRole::Disabled);
+            let mut p = self.new_packet(node_id, b.value, 0.0, Vec::new(), None);
+            // Demurrage borne while aged, past the grace window
+            let decayed_ticks = b.age_ticks.saturating_sub(self.demurrage_grace_ticks);
+            p.current_value = b.value * (-b.tier.demurrage_lambda() * decayed_ticks as f64).exp();
+            p.ttl = b.tier.ttl_ticks().saturating_sub(b.age_ticks).max(1);
+            self.total_burned += b.value - p.current_value;
+            self.tier_ledger.record_burn(b.tier, b.value - p.current_value);
+            self.state.spawn_count += 1;
+            match first_hop {
+                Some(target) if b.in_transit => {
+                    let snapshot = p.governor_snapshots[0];
+                    p.status = PacketStatus::InTransit;
+                    p.target_node = Some(target);
+                    p.hops = 1;
+                    p.arrival_tick = 1;
+                    compaction::push_hop(&mut p, node_id, snapshot, self.route_window);
+                    self.message_queue.push(p);
+                }
+                _ => {
+                    self.node_buffers.entry(node_id).or_default().push(p);
+                    self.nodes[node_id as usize].current_buffer_count += 1;
+                }
+            }
+        }
+        planned.len() as u32
+    }
+
+    /// Mint one packet per ingress aggregate that is ready to route.
+    fn release_aggregates(&mut self, current_tick: u64) {
+        let Some(agg) = self.aggregator.as_mut() else {
+            return;
+        };
+        for a in agg.release(current_tick) {
+            self.mint_packet(a.node_id, a.amount, a.surcharge, a.manifest, None);
+        }
     }
 
     /// Process all node buffers: demurrage, orbit timeout, settlement, routing.
@@ -342,27 +975,54 @@ impl ArenaSimulation {
         let mut _reverted_count: u32 = 0;
         let node_indices: Vec<u32> = self.node_buffers.keys().cloned().collect();
         let current_volatility = self.state.volatility;
+        self.verification.begin_tick(&self.nodes);
+        // Some(true) on the tick a batching window closes, None when batching is off
+        let batch_close = self.settlement_batch_window
+            .map(|w| current_tick.is_multiple_of(w));
+        // Governor pricing this tick, by tier, for hop snapshots
+        let tier_snapshots = [MarketTier::L0, MarketTier::L1, MarketTier::L2, MarketTier::L3]
+            .map(|tier| self.governor_snapshot(tier));
+        self.state.throughput_deferred = 0;
 
         for node_id in node_indices {
             let node_role = self.nodes[node_id as usize].role;
-            let node_strategy = self.nodes[node_id as usize].strategy;
+            let (node_strategy, local_panic) = match &self.panic_contagion {
+                Some(c) => (c.strategy(node_id, self.nodes[node_id as usize].strategy), c.level(node_id)),
+                None => (self.nodes[node_id as usize].strategy, 0.0),
+            };
             if node_role == NodeRole::Disabled {
                 continue;
             }
 
-            let buf = match self.node_buffers.get_mut(&node_id) {
-                Some(b) => b,
-                None => continue,
+            // Out of the map for the node's turn, so refunds can borrow the sim
+            let Some(mut buf) = self.node_buffers.remove(&node_id) else {
+                continue;
             };
+            let approved_corridors = if node_role == NodeRole::Egress && batch_close == Some(true) {
+                batching::approve_corridors(&self.nodes, &buf, self.nodes[node_id as usize].inventory_crypto)
+            } else {
+                Default::default()
+            };
+            // Settlements and forwards this node may make this tick, and those made
+            let capacity = throughput::capacity(&self.nodes[node_id as usize], self.packets_per_mbps);
+            if let (Some(lanes), Some(c)) = (&self.priority_lanes, capacity) {
+                lanes.order(&mut buf, c, current_tick);
+            }
+            let mut processed: u32 = 0;
+            let mut deferred: u32 = 0;
             let mut j = 0;
             while j < buf.len() {
                 let mut p = buf.remove(j);
 
-                // E1: Per-tier exponential demurrage V_t = V_0 * e^(-lambda * dt)
-                let lambda = p.tier.demurrage_lambda();
-                let old_v = p.current_value;
-                p.current_value *= (-lambda).exp(); // dt=1 tick
-                self.total_burned += old_v - p.current_value;
+                // E1: Per-tier exponential demurrage V_t = V_0 * e^(-lambda * dt),
+                // starting once the packet is past its grace window
+                if current_tick.saturating_sub(p.spawn_tick) >= self.demurrage_grace_ticks {
+                    let lambda = p.tier.demurrage_lambda();
+                    let old_v = p.current_value;
+                    p.current_value *= (-lambda).exp(); // dt=1 tick
+                    self.total_burned += old_v - p.current_value;
+                    self.tier_ledger.record_burn(p.tier, old_v - p.current_value);
+                }
 
                 // E8: Surge pricing per packet (escalating cost for orbiting >10 ticks)
                 if let Some(orbit_start) = p.orbit_start_tick {
@@ -372,15 +1032,18 @@ impl ArenaSimulation {
                             * ((orbit_ticks - 10) as f64 * 0.01).min(0.5);
                         p.current_value -= surge_burn;
                         self.total_burned += surge_burn;
+                        self.tier_ledger.record_burn(p.tier, surge_burn);
                     }
                 }
 
                 // TTL expiry check - uses per-tier TTL set at minting
                 if p.ttl > 0 && current_tick >= p.ttl {
-                    p.status = PacketStatus::Expired;
-                    self.total_output += p.current_value;
+                    self.refund(&mut p, current_tick, RevertReason::TtlExpired);
+                    self.retries.on_revert(&p, current_tick);
+                    if let Some(c) = self.panic_contagion.as_mut() {
+                        c.on_revert(node_id);
+                    }
                     _reverted_count += 1;
-                    self.revert_count += 1;
                     self.nodes[node_id as usize].current_buffer_count =
                         self.nodes[node_id as usize].current_buffer_count
                             .saturating_sub(1);
@@ -392,36 +1055,20 @@ impl ArenaSimulation {
                 if p.status == PacketStatus::Held {
                     let total_age = current_tick.saturating_sub(p.spawn_tick);
                     if dissolution::is_eligible_ticks(total_age) && p.current_value > 0.0 {
-                        let qualifications: Vec<dissolution::GravityQualification> =
-                            self.nodes.iter()
-                                .filter(|n| n.role != NodeRole::Disabled)
-                                .map(|n| dissolution::GravityQualification {
-                                    node_id: n.id,
-                                    upi_active: n.upi_active,
-                                    engauge_active: n.ngauge_running,
-                                    kyc_attested: n.kyc_valid,
-                                    caesar_active: n.role != NodeRole::Disabled,
-                                    demonstrable_capacity: n.bandwidth >= 10.0,
-                                    active_routing_current_epoch:
-                                        n.current_buffer_count > 0
-                                        || n.total_fees_earned > 0.0,
-                                })
-                                .collect();
-                        let shard_holders: Vec<u32> = p.route_history.clone();
+                        let qualifications = dissolution::node_qualifications(&self.nodes, self.resources.as_ref());
+                        let shard_holders = compaction::visited_nodes(&p);
                         if let Ok(result) = dissolution::dissolve(
                             p.current_value,
                             &qualifications,
                             &shard_holders,
                         ) {
-                            for dist in &result.distributions {
-                                if let Some(node) =
-                                    self.nodes.get_mut(dist.node_id as usize)
-                                {
-                                    node.inventory_fiat += dist.amount;
-                                }
-                            }
+                            self.asset_ledger.pay_dissolution(&mut self.nodes, &result);
                             p.status = PacketStatus::Dissolved;
                             self.total_output += p.current_value;
+                            self.cohorts.record_outcome(
+                                p.cohort, total_age, cohort::CohortOutcome::Dissolved);
+                            self.corridors.record_exit(&p, cohort::CohortOutcome::Dissolved);
+                            self.speculators.on_exit(p.id, p.current_value);
                             self.state.dissolved_count += 1;
                             self.nodes[node_id as usize].current_buffer_count =
                                 self.nodes[node_id as usize].current_buffer_count
@@ -437,18 +1084,14 @@ impl ArenaSimulation {
                         p.orbit_start_tick = Some(current_tick);
                     }
                     let orbit_ticks = current_tick - p.orbit_start_tick.unwrap();
-                    // L3 packets can orbit past dissolution threshold (5000 ticks)
-                    // Other tiers use TTL/2 as orbit limit
-                    let orbit_limit = if p.tier == MarketTier::L3 {
-                        dissolution::DISSOLUTION_TIMEOUT_TICKS + 500 // 5500: beyond dissolution
-                    } else {
-                        p.tier.ttl_ticks() / 2
-                    };
-                    if orbit_ticks > orbit_limit {
-                        p.status = PacketStatus::Refunded;
-                        self.total_output += p.current_value;
+                    if orbit_ticks > self.routing_policy.orbit_timeout(p.tier) {
+                        let reason = RevertReason::from_orbit(p.orbit_reason);
+                        self.refund(&mut p, current_tick, reason);
+                        self.retries.on_revert(&p, current_tick);
+                        if let Some(c) = self.panic_contagion.as_mut() {
+                            c.on_revert(node_id);
+                        }
                         _reverted_count += 1;
-                        self.revert_count += 1;
                         self.nodes[node_id as usize].current_buffer_count =
                             self.nodes[node_id as usize].current_buffer_count
                                 .saturating_sub(1);
@@ -456,9 +1099,10 @@ impl ArenaSimulation {
                     }
                 }
 
-                // E9: RiskAverse strategy - buffer packets during high volatility
+                // E9: RiskAverse strategy - buffer packets during high volatility,
+                // as felt through the node's local panic
                 if node_strategy == NodeStrategy::RiskAverse
-                    && current_volatility > 0.1
+                    && current_volatility + local_panic > 0.1
                     && node_role != NodeRole::Egress
                 {
                     buf.insert(j, p);
@@ -466,134 +1110,347 @@ impl ArenaSimulation {
                     continue;
                 }
 
-                // Egress settlement (inlined to avoid borrow conflict with buf)
-                if node_role == NodeRole::Egress && p.current_value > 0.0 {
-                    if self.nodes[node_id as usize].inventory_crypto >= p.current_value {
-                        // S5 + E3: 80/20 reward split with velocity bonus
-                        let total_fee = crate::adapter::calculate_fee_via_core(
-                            &self.core_pid,
-                            &p.tier,
-                            self.state.current_fee_rate,
-                            p.original_value,
-                        ).min(p.current_value);
-                        p.route_history.push(node_id);
-
-                        let velocity_bonus = if p.hops <= 3 { 1.2 }
-                            else if p.hops <= 6 { 1.0 }
-                            else { 0.8 };
-
-                        // E9: Greedy fee modifier
-                        let strategy_fee_mod = match node_strategy {
-                            NodeStrategy::Greedy => 1.5,
-                            _ => 1.0,
-                        };
-                        let adjusted_fee = total_fee * strategy_fee_mod;
-                        // Cost certainty: cap settlement fee to remaining budget
-                        let remaining_budget = (p.fee_budget - p.fees_consumed).max(0.0);
-                        let capped_fee = adjusted_fee.min(p.current_value).min(remaining_budget);
-                        p.fees_consumed += capped_fee;
-
-                        // Fee distribution via core's Decimal-based 80/20 splitter
-                        let transit_node_ids: Vec<u32> = p.route_history.iter()
-                            .filter(|&&n| {
-                                n != node_id
-                                    && self.nodes.get(n as usize)
-                                        .map(|node| node.role != NodeRole::Ingress)
-                                        .unwrap_or(false)
-                            })
-                            .copied()
-                            .collect();
-                        let (core_egress_amt, core_per_transit) =
-                            crate::adapter::distribute_fee_via_core(
-                                capped_fee, node_id, &transit_node_ids,
-                            );
-
-                        // Apply velocity_bonus as arena-specific overlay
-                        let egress_reward = core_egress_amt * velocity_bonus;
-                        self.nodes[node_id as usize].total_fees_earned += egress_reward;
-                        self.total_rewards_egress += core_egress_amt;
-
-                        // Transit distribution
-                        if !transit_node_ids.is_empty() {
-                            let per_transit = core_per_transit * velocity_bonus;
-                            for &tn in &transit_node_ids {
-                                if let Some(node) = self.nodes.get_mut(tn as usize) {
-                                    node.total_fees_earned += per_transit;
-                                }
-                            }
-                        }
-                        self.total_rewards_transit += capped_fee - core_egress_amt;
-
-                        let settlement_val = (p.current_value - capped_fee).max(0.0);
-                        self.nodes[node_id as usize].inventory_crypto -= p.current_value;
-                        self.total_output += settlement_val;
-                        self.total_fees += capped_fee;
-                        self.settlement_count += 1;
-                        self.total_settlement_hops += p.hops as u64;
-                        self.total_settlement_time +=
-                            current_tick.saturating_sub(p.arrival_tick);
-                        self.nodes[node_id as usize].current_buffer_count =
-                            self.nodes[node_id as usize].current_buffer_count
-                                .saturating_sub(1);
+                // Batching: settleable packets wait at the egress for the window to close
+                if batch_close == Some(false)
+                    && node_role == NodeRole::Egress
+                    && routing::may_settle_at(&p, &self.nodes[node_id as usize])
+                    && p.current_value > 0.0
+                    && self.nodes[node_id as usize].inventory_crypto >= p.current_value
+                {
+                    buf.insert(j, p);
+                    j += 1;
+                    continue;
+                }
 
-                        // Conservation verify at settlement
-                        // fees_consumed already includes capped_fee (added at line 428)
-                        let demurrage_burned =
-                            p.original_value - p.current_value - p.fees_consumed;
-                        self.conservation_law.verify_settlement(
-                            p.original_value,
-                            settlement_val,
-                            p.fees_consumed,
-                            demurrage_burned.max(0.0),
-                        );
+                // Adversaries: a dropper discards what it is handed and a
+                // censor refuses its censored origins; the sender is refunded
+                if let Some(reason) = adversary::refusal(node_strategy, &p, &self.censored_origins) {
+                    self.refund(&mut p, current_tick, reason);
+                    self.retries.on_revert(&p, current_tick);
+                    if let Some(c) = self.panic_contagion.as_mut() {
+                        c.on_revert(node_id);
+                    }
+                    match reason {
+                        RevertReason::Dropped => self.state.dropped_packets += 1,
+                        _ => self.state.censored_packets += 1,
+                    }
+                    _reverted_count += 1;
+                    self.nodes[node_id as usize].current_buffer_count =
+                        self.nodes[node_id as usize].current_buffer_count
+                            .saturating_sub(1);
+                    continue;
+                }
+
+                // Throughput cap: past it, the packet waits its turn next tick
+                if capacity.is_some_and(|c| processed >= c) {
+                    deferred += 1;
+                    buf.insert(j, p);
+                    j += 1;
+                    continue;
+                }
 
-                        // Core conservation cross-check (Decimal-based, parallel validation)
-                        let _core_conservation_result = crate::adapter::verify_settlement_via_core(
-                            &mut self.core_conservation,
-                            p.original_value,
-                            settlement_val,
-                            p.fees_consumed,
-                            demurrage_burned.max(0.0),
+                // Egress settlement (inlined to avoid borrow conflict with buf);
+                // corridor traffic passes through Egress nodes outside its destination
+                if node_role == NodeRole::Egress
+                    && routing::may_settle_at(&p, &self.nodes[node_id as usize])
+                    && p.current_value > 0.0
+                    && match batch_close {
+                        None => self.nodes[node_id as usize].inventory_crypto >= p.current_value,
+                        Some(_) => approved_corridors.contains(&batching::corridor(&self.nodes, &p)),
+                    }
+                {
+                    // S5 + E3: 80/20 reward split with velocity bonus
+                    let spot_fee = crate::adapter::calculate_fee_via_core(
+                        &self.core_pid,
+                        &p.tier,
+                        self.state.current_fee_rate,
+                        p.original_value,
+                    );
+                    // Cost certainty: optionally honor the fee quoted at spawn
+                    let quoted_fee = quote::spawn_quote(&p)
+                        .map(|q| quote::quoted_fee(q, p.tier, p.original_value));
+                    let priced_fee = match (self.pricing_mode, quoted_fee) {
+                        (quote::PricingMode::Quoted, Some(quoted)) => quoted,
+                        _ => spot_fee,
+                    };
+                    if let Some(quoted) = quoted_fee {
+                        let drift = (priced_fee - quoted).abs();
+                        self.quote_drift += drift;
+                        if drift > quote::QUOTE_TOLERANCE {
+                            self.quote_mismatches += 1;
+                        }
+                    }
+                    let total_fee = priced_fee.min(p.current_value);
+                    let snapshot = tier_snapshots[p.tier as usize];
+                    compaction::push_hop(&mut p, node_id, snapshot, self.route_window);
+
+                    // E9: Greedy fee modifier, and a gouger's
+                    let strategy_fee_mod = match node_strategy {
+                        NodeStrategy::Greedy => 1.5,
+                        NodeStrategy::FeeGouger => adversary::GOUGE_MULTIPLIER,
+                        _ => 1.0,
+                    };
+                    // Volume discount on the base fee for high-volume origins
+                    let discount_rate = self.volume_discounts.as_ref()
+                        .map_or(0.0, |d| d.rate_for(p.origin_node));
+                    let adjusted_fee = total_fee * (1.0 - discount_rate) * strategy_fee_mod;
+                    // Constitutional clamp: whatever surge, quote and greedy
+                    // modifiers stacked up, never charge past the tier cap,
+                    // or the node's own where a pilot tightens it, neither
+                    // on its own nor with the fees the packet already paid
+                    let fee_caps = self.node_fee_caps.get(&node_id).cloned().unwrap_or_default();
+                    let headroom = fee_caps.headroom(p.tier, p.fees_consumed, p.original_value);
+                    if adjusted_fee > headroom + governor::FEE_CAP_TOLERANCE {
+                        self.fee_cap_breaches += 1;
+                    }
+                    // Cost certainty: cap settlement fee to remaining budget
+                    let remaining_budget = (p.fee_budget - p.fees_consumed).max(0.0);
+                    let capped_fee = fee_caps.clamp_fee(p.tier, adjusted_fee, p.original_value)
+                        .min(headroom).min(p.current_value).min(remaining_budget);
+                    let discount_forgone = fee_caps.clamp_fee(p.tier, total_fee * strategy_fee_mod, p.original_value)
+                        .min(headroom).min(p.current_value).min(remaining_budget) - capped_fee;
+                    p.fees_consumed += capped_fee;
+
+                    // Fee distribution via core's Decimal-based 80/20 splitter
+                    let transit_node_ids: Vec<u32> = compaction::route_visits(&p)
+                        .filter(|&n| {
+                            n != node_id
+                                && self.nodes.get(n as usize)
+                                    .map(|node| node.role != NodeRole::Ingress)
+                                    .unwrap_or(false)
+                        })
+                        .collect();
+                    let holds = self.transit_holds.take(p.id);
+                    let transit_weights = transit_rewards::transit_weights(
+                        self.transit_weighting, &self.nodes, &transit_node_ids, &holds,
+                    );
+                    let (core_egress_amt, core_transit_amts) =
+                        crate::adapter::distribute_weighted_fee_via_core(
+                            capped_fee, node_id, &transit_weights,
                         );
 
-                        settled_count += 1;
-                        continue;
+                    // Trust rebate: a route of only high-trust nodes gets part of its
+                    // fees back from the transit-funded rebate pool
+                    let rebate = self.rebate_pool.claim(&self.nodes, &compaction::visited_nodes(&p), p.fees_consumed);
+                    p.fees_consumed -= rebate;
+
+                    // Velocity bonus as arena-specific overlay, funded by the
+                    // slow-route penalties held in its treasury
+                    let velocity_bonus = self.velocity_bonus.settle(p.hops, capped_fee);
+                    let egress_reward = core_egress_amt * velocity_bonus;
+                    self.nodes[node_id as usize].total_fees_earned += egress_reward;
+                    self.total_rewards_egress += core_egress_amt;
+
+                    // NGauge marketplace: the egress buys verification of the settlement
+                    let purchase = self.verification.purchase(&self.nodes, node_id);
+                    if purchase.seller().is_none() {
+                        self.verification_shortfalls += 1;
+                    }
+                    if let Some(resources) = &mut self.resources {
+                        resources.record_relay(node_id);
+                        if let Some(seller) = purchase.seller() {
+                            resources.record_verification(seller);
+                        }
+                    }
+                    self.total_verification_fees += verification::pay_verifier(
+                        &mut self.nodes, node_id, purchase, egress_reward,
+                    );
+
+                    // Transit distribution
+                    for (&tn, &amount) in transit_node_ids.iter().zip(&core_transit_amts) {
+                        if let Some(node) = self.nodes.get_mut(tn as usize) {
+                            node.total_fees_earned += amount * velocity_bonus;
+                        }
+                    }
+                    self.total_rewards_transit += capped_fee - core_egress_amt;
+                    self.tier_ledger.record_rewards(p.tier, capped_fee);
+
+                    let settlement_val = (p.current_value - capped_fee + rebate).max(0.0);
+                    // Payout FX: the beneficiary converts at the oracle price less the spread
+                    let delivered_val = match self.payout_slippage.as_mut() {
+                        Some(fx) => fx.pay_out(settlement_val, self.state.payout_spread),
+                        None => settlement_val,
+                    };
+                    self.nodes[node_id as usize].inventory_crypto -= p.current_value;
+                    self.asset_ledger.record_settlement(p.current_value);
+                    self.egress_draws.record(node_id, p.current_value);
+                    self.batch_ledger.record(
+                        &self.nodes, node_id, p.origin_node, p.current_value, batch_close.is_some(),
+                    );
+                    self.total_output += settlement_val;
+                    self.tick_settled_value += settlement_val;
+                    self.cohorts.record_outcome(
+                        p.cohort, current_tick.saturating_sub(p.spawn_tick), cohort::CohortOutcome::Settled);
+                    self.corridors.record_settlement(
+                        &p, delivered_val, current_tick.saturating_sub(p.spawn_tick));
+                    self.speculators.on_settle(p.id, settlement_val);
+                    if !self.regional_prices.is_empty() {
+                        let origin_site = batching::site(&self.nodes, p.origin_node);
+                        self.regional_prices.record_settlement(
+                            origin_site, self.nodes[node_id as usize].site, delivered_val, self.oracle.price());
+                    }
+                    self.total_fees += capped_fee - rebate;
+                    self.tier_ledger.record_fee(p.tier, capped_fee - rebate);
+                    // Fan-out: an aggregate settles each payment in its manifest
+                    let payments = p.manifest.len().max(1) as u32;
+                    if !p.manifest.is_empty() {
+                        self.aggregated_payments_settled += payments;
                     }
+                    self.settlement_count += payments;
+                    self.settlement_costs.record(p.tier, p.original_value, delivered_val);
+                    self.settlement_latency.record(
+                        p.tier, current_tick.saturating_sub(p.spawn_tick), payments);
+                    self.demand_elasticity.record(p.original_value, delivered_val);
+                    self.egress_concentration.record(node_id, settlement_val);
+                    if let Some(d) = self.volume_discounts.as_mut() {
+                        d.record(p.origin_node, settlement_val, discount_forgone);
+                    }
+                    self.total_settlement_hops += p.hops as u64 * payments as u64;
+                    self.total_settlement_time +=
+                        current_tick.saturating_sub(p.arrival_tick) * payments as u64;
+                    if p.manifest.is_empty() {
+                        self.dust_guard.record_settlement(
+                            p.original_value, current_tick.saturating_sub(p.spawn_tick));
+                    }
+                    self.nodes[node_id as usize].current_buffer_count =
+                        self.nodes[node_id as usize].current_buffer_count
+                            .saturating_sub(1);
+
+                    // Conservation verify at settlement
+                    // fees_consumed already includes capped_fee (added at line 428)
+                    let demurrage_burned =
+                        p.original_value - p.current_value - p.fees_consumed;
+                    self.conservation_law.verify_settlement(
+                        p.original_value,
+                        settlement_val,
+                        p.fees_consumed,
+                        demurrage_burned.max(0.0),
+                    );
+
+                    // Core conservation cross-check (Decimal-based, parallel validation)
+                    let _core_conservation_result = crate::adapter::verify_settlement_via_core(
+                        &mut self.core_conservation,
+                        p.original_value,
+                        settlement_val,
+                        p.fees_consumed,
+                        demurrage_burned.max(0.0),
+                    );
+
+                    settled_count += payments;
+                    processed += 1;
+                    continue;
                 }
 
-                // Force orbit if packet has bounced too many times (hop limit)
+                // Hop limit exhausted: orbit, refund, or back off and re-route
                 if p.hops > p.hop_limit {
-                    p.status = PacketStatus::Held;
+                    let policy = self.routing_policy;
+                    if policy.hop_exhaustion == routing_policy::HopExhaustion::Revert {
+                        self.refund(&mut p, current_tick, RevertReason::HopLimit);
+                        self.retries.on_revert(&p, current_tick);
+                        if let Some(c) = self.panic_contagion.as_mut() {
+                            c.on_revert(node_id);
+                        }
+                        _reverted_count += 1;
+                        self.nodes[node_id as usize].current_buffer_count =
+                            self.nodes[node_id as usize].current_buffer_count
+                                .saturating_sub(1);
+                        continue;
+                    }
                     if p.orbit_start_tick.is_none() {
                         p.orbit_start_tick = Some(current_tick);
                     }
-                    buf.insert(j, p);
-                    j += 1;
-                    continue;
+                    let renewals = routing_policy::RoutingPolicy::renewals(p.tier, p.hop_limit);
+                    let backed_off = policy.hop_exhaustion == routing_policy::HopExhaustion::Backoff
+                        && current_tick - p.orbit_start_tick.unwrap() >= policy.backoff_ticks(renewals);
+                    if backed_off {
+                        p.hop_limit += p.tier.hop_limit();
+                    } else {
+                        p.status = PacketStatus::Held;
+                        p.orbit_reason = Some(OrbitReason::HopLimit);
+                        buf.insert(j, p);
+                        j += 1;
+                        continue;
+                    }
                 }
 
                 // Routing: find path to Egress (skip Disabled nodes)
-                let next_hop = routing::find_next_hop(&self.nodes, node_id, &p);
+                let next_hop = if let Some(router) = &self.capacity_router {
+                    router.next_hop(&self.nodes, node_id, &p, self.routing_mode, self.route_scoring)
+                } else if self.routing_mode == routing::RoutingMode::ShortestPath {
+                    self.path_cache.next_hop(&self.nodes, node_id, &p, current_tick)
+                } else {
+                    match self.route_cache.as_mut() {
+                        Some(cache) => cache.next_hop(&self.nodes, node_id, &p, self.routing_mode, self.route_scoring),
+                        None => routing::find_next_hop(&self.nodes, node_id, &p, self.routing_mode, self.route_scoring),
+                    }
+                };
+
+                // A cartel member keeps the packet among its own
+                let next_hop = match (next_hop, self.cartel.as_mut()) {
+                    (Some(honest), Some(cartel)) => Some(cartel.steer(&self.nodes, node_id, &p, honest)),
+                    (hop, _) => hop,
+                };
+
+                // A liar sends the packet the wrong way
+                let next_hop = match next_hop {
+                    Some(honest) if node_strategy == NodeStrategy::RouteLiar => {
+                        let lie = adversary::misroute(&self.nodes, node_id, &p, honest);
+                        if lie != honest {
+                            self.state.misrouted_packets += 1;
+                        }
+                        Some(lie)
+                    }
+                    hop => hop,
+                };
 
                 if let Some(target) = next_hop {
-                    // Charge transit fee for this hop
-                    let transit_fee =
-                        self.nodes[target as usize].transit_fee * p.current_value;
+                    // Charge transit fee for this hop, as the hop prices it
+                    let transit_fee = self.nodes[target as usize].transit_fee * p.current_value
+                        * adversary::hop_fee_multiplier(self.nodes[target as usize].strategy);
                     let remaining_budget = (p.fee_budget - p.fees_consumed).max(0.0);
+                    // Clamped per hop, and so hops and settlement together
+                    // stay within the tier cap
+                    let transit_caps = self.node_fee_caps.get(&target).cloned().unwrap_or_default();
+                    let headroom = transit_caps.headroom(p.tier, p.fees_consumed, p.original_value);
+                    if transit_fee > headroom + governor::FEE_CAP_TOLERANCE {
+                        self.fee_cap_breaches += 1;
+                    }
                     let capped_transit_fee = transit_fee
-                        .min(p.current_value * p.tier.fee_cap())
+                        .min(p.current_value * transit_caps.cap_for(p.tier))
+                        .min(headroom)
                         .min(remaining_budget);
                     p.current_value -= capped_transit_fee;
                     p.fees_consumed += capped_transit_fee;
                     p.fee_schedule.push(capped_transit_fee);
                     self.total_fees += capped_transit_fee;
-                    self.nodes[target as usize].total_fees_earned += capped_transit_fee;
+                    self.tier_ledger.record_fee(p.tier, capped_transit_fee);
+                    let hop_earned = capped_transit_fee - self.rebate_pool.fund(capped_transit_fee);
+                    self.nodes[target as usize].total_fees_earned += hop_earned;
+                    self.route_concentration.record(node_id, target);
+
+                    // NGauge marketplace: the next hop buys verification for this hop;
+                    // scarce local capacity raises its effective complexity
+                    let purchase = self.verification.purchase(&self.nodes, target);
+                    if purchase.seller().is_none() {
+                        self.verification_shortfalls += 1;
+                    }
+                    if let Some(resources) = &mut self.resources {
+                        resources.record_relay(node_id);
+                        if let Some(seller) = purchase.seller() {
+                            resources.record_verification(seller);
+                        }
+                    }
+                    self.total_verification_fees += verification::pay_verifier(
+                        &mut self.nodes, target, purchase, hop_earned,
+                    );
 
                     p.status = PacketStatus::InTransit;
                     p.target_node = Some(target);
                     p.hops += 1;
-                    p.route_history.push(node_id);
+                    let snapshot = tier_snapshots[p.tier as usize];
+                    compaction::push_hop(&mut p, node_id, snapshot, self.route_window);
                     p.orbit_start_tick = None;
+                    p.orbit_reason = None;
 
                     // E10: Variable latency based on distance
                     let distance = (
@@ -603,10 +1460,27 @@ impl ArenaSimulation {
                             - self.nodes[target as usize].y).powi(2)
                     ).sqrt();
                     let base_latency = 1 + (distance as u64);
-                    p.arrival_tick =
-                        current_tick + base_latency + self.state.verification_complexity;
+                    let jitter = if self.latency_jitter_ticks > 0 {
+                        self.latency_rng.next_u64() % (self.latency_jitter_ticks + 1)
+                    } else { 0 };
+                    let received_tick = p.arrival_tick;
+                    p.arrival_tick = current_tick
+                        + base_latency
+                        + jitter
+                        + self.state.verification_complexity
+                        + purchase.extra_complexity();
+                    // Custody runs from receipt until the next hop receives it
+                    if self.transit_weighting == transit_rewards::TransitWeighting::HoldingTime {
+                        self.transit_holds.record(
+                            p.id, node_id, p.arrival_tick.saturating_sub(received_tick));
+                    }
+                    if let Some(trace) = self.hop_trace.as_mut() {
+                        trace.record_hop(
+                            node_id, p.arrival_tick.saturating_sub(received_tick), target, hop_earned);
+                    }
 
                     self.message_queue.push(p);
+                    processed += 1;
                     self.nodes[node_id as usize].current_buffer_count =
                         self.nodes[node_id as usize].current_buffer_count
                             .saturating_sub(1);
@@ -615,15 +1489,111 @@ impl ArenaSimulation {
                     if p.orbit_start_tick.is_none() {
                         p.orbit_start_tick = Some(current_tick);
                     }
+                    p.orbit_reason = Some(routing::no_route_reason(&self.nodes, &p));
                     buf.insert(j, p);
                     j += 1;
                 }
             }
+            self.node_buffers.insert(node_id, buf);
+            self.nodes[node_id as usize].queue_latency = throughput::queue_latency(deferred, capacity);
+            self.state.throughput_deferred += deferred;
+        }
+        if batch_close == Some(true) {
+            for (egress_id, credit) in self.batch_ledger.close_window() {
+                self.nodes[egress_id as usize].inventory_crypto += credit;
+                self.asset_ledger.record_netting(credit);
+            }
         }
 
         settled_count
     }
 
+    /// Refund `p` to its sender at its current value: Expired at TTL, else
+    /// Refunded, and counted by every tracker that follows reverts. Retries
+    /// and panic contagion only react to reverts inside the node cycle,
+    /// which reports those itself.
+    pub(crate) fn refund(&mut self, p: &mut SimPacket, tick: u64, reason: RevertReason) {
+        p.status = if reason == RevertReason::TtlExpired { PacketStatus::Expired } else { PacketStatus::Refunded };
+        self.revert_log.record(p, tick, reason);
+        self.total_output += p.current_value;
+        self.cohorts.record_outcome(
+            p.cohort, tick.saturating_sub(p.spawn_tick), cohort::CohortOutcome::Reverted);
+        self.corridors.record_exit(p, cohort::CohortOutcome::Reverted);
+        self.speculators.on_exit(p.id, p.current_value);
+        self.revert_count += 1;
+    }
+
+    /// Count a tick in `pressure`, labelled `quadrant`, and log a transition
+    /// if it differs from the previous tick's. The trigger is the metric
+    /// behind whichever side of the change is not Golden Era (the condition
+    /// that fired or cleared).
+    fn record_quadrant(&mut self, pressure: PressureQuadrant, quadrant: &str, current_tick: u64) {
+        self.state.quadrant_ticks[pressure.index()] += 1;
+        let previous = std::mem::replace(&mut self.governance_pressure, pressure);
+        if previous == pressure {
+            return;
+        }
+        let driver = if pressure == PressureQuadrant::GoldenEra { previous } else { pressure };
+        let (trigger_metric, trigger_value) = match driver {
+            PressureQuadrant::Stagnation => ("network_velocity", self.state.network_velocity),
+            PressureQuadrant::Vacuum => ("settled_value_per_tick", self.state.settled_value_per_tick),
+            _ => ("gold_deviation", (self.state.gold_price - self.peg_target) / self.peg_target),
+        };
+        let previous = &self.state.governance_quadrant;
+        if self.quadrant_transitions.len() == MAX_QUADRANT_TRANSITIONS {
+            self.quadrant_transitions.remove(0);
+        }
+        self.quadrant_transitions.push(QuadrantTransition {
+            tick: current_tick,
+            from: previous.clone(),
+            to: quadrant.to_string(),
+            trigger_metric: trigger_metric.to_string(),
+            trigger_value,
+        });
+        self.state.quadrant_transition_count += 1;
+    }
+
+    /// Lapse KYC attestations older than the configured validity window.
+    fn apply_node_dynamics(&mut self, current_tick: u64) {
+        let Some(validity) = self.kyc_validity_ticks else {
+            return;
+        };
+        for node in self.nodes.iter_mut().filter(|n| n.kyc_valid) {
+            if current_tick.saturating_sub(node.kyc_attested_tick) >= validity {
+                node.kyc_valid = false;
+            }
+        }
+    }
+
+    fn apply_egress_lifecycle(&mut self, current_tick: u64) {
+        let Some(lifecycle) = self.egress_lifecycle.as_mut() else {
+            return;
+        };
+        let changes = lifecycle.update(&self.nodes, current_tick);
+        let exit_role = lifecycle.exit_role;
+        for node_id in changes.exits {
+            if exit_role == NodeRole::Disabled {
+                self.kill_node(node_id);
+            } else {
+                self.set_live_role(node_id, exit_role);
+            }
+        }
+        for node_id in changes.reentries {
+            if self.nodes[node_id as usize].role == NodeRole::Disabled {
+                self.revive_node(node_id, NodeRole::Egress as u32);
+            } else {
+                self.set_live_role(node_id, NodeRole::Egress);
+            }
+        }
+    }
+
+    /// Re-role a live node in place; its buffered packets route on from it.
+    fn set_live_role(&mut self, node_id: u32, role: NodeRole) {
+        self.nodes[node_id as usize].role = role;
+        routing::update_egress_distances(&mut self.nodes, node_id);
+        self.invalidate_route_cache();
+    }
+
     /// E12: Compute per-node liquidity pressure.
     fn compute_node_pressure(&mut self) {
         for node in self.nodes.iter_mut() {
@@ -647,44 +1617,175 @@ impl ArenaSimulation {
     }
 
     /// Finalize tick statistics and build the TickResult.
-    fn finalize_stats(&mut self, settled_count: u32, _current_tick: u64) -> TickResult {
-        self.state.network_velocity = settled_count as f64 * 100.0;
+    fn finalize_stats(&mut self, _settled_count: u32, current_tick: u64) -> TickResult {
         self.state.total_rewards_egress = self.total_rewards_egress;
         self.state.total_rewards_transit = self.total_rewards_transit;
-        self.state.total_fees_collected = self.total_fees;
-        self.state.total_demurrage_burned = self.total_burned;
+        self.state.total_fees_collected = self.total_fees.value();
+        self.state.total_demurrage_burned = self.total_burned.value();
         self.state.settlement_count = self.settlement_count;
         self.state.revert_count = self.revert_count;
-        self.state.total_input = self.total_input;
-        self.state.total_output = self.total_output;
+        self.state.total_input = self.total_input.value();
+        self.state.total_output = self.total_output.value();
 
-        let active_val: f64 = self.node_buffers.values().flatten()
-            .map(|p| p.current_value).sum::<f64>()
-            + self.message_queue.iter().map(|p| p.current_value).sum::<f64>();
+        let active_val = self.active_value();
         self.state.active_value = active_val;
+        self.state.in_transit_float = conservation::compensated_sum(
+            self.message_queue.iter().map(|p| p.current_value));
+
+        // Velocity of money: settled value this tick over the average in-flight
+        // float; refunds and dissolutions return value but do not move it
+        let settled_value = std::mem::take(&mut self.tick_settled_value);
+        self.float_ema = self.float_ema * 0.9 + active_val * 0.1;
+        self.state.settled_value_per_tick = settled_value;
+        self.state.network_velocity = compute_monetary_velocity(settled_value, self.float_ema);
+        self.state.speculative_value_share = self.speculators.end_tick(
+            settled_value, self.state.organic_ratio < engauge::SPECULATIVE_THRESHOLD);
+
+        self.state.verification_fees_paid = self.total_verification_fees;
+        self.state.verification_shortfalls = self.verification_shortfalls;
+        self.state.ngauge_utilization = self.verification.utilization();
+        if let Some(resources) = &mut self.resources {
+            resources.accrue_tick(&self.nodes);
+        }
+
+        self.state.settlement_batches = self.batch_ledger.batches;
+        self.state.egress_inventory_drawn = self.batch_ledger.drawn;
+        self.state.batch_inventory_saved = self.batch_ledger.saved();
+
+        self.state.trust_rebates_paid = self.rebate_pool.paid;
+        self.state.trusted_settlements = self.rebate_pool.trusted_settlements;
+        self.state.rebate_pool_balance = self.rebate_pool.balance;
+        self.state.velocity_bonus_paid = self.velocity_bonus.paid;
+        self.state.velocity_bonus_treasury = self.velocity_bonus.treasury;
+        self.state.quote_drift = self.quote_drift;
+        self.state.quote_mismatches = self.quote_mismatches;
+        self.state.fee_cap_breaches = self.fee_cap_breaches;
+        self.state.revert_reasons = self.revert_log.counts;
+        self.state.pending_retries = self.retries.pending();
+        self.state.governor_decisions = self.control_loop.decisions;
+        self.state.fee_rate_reversals = self.control_loop.fee_reversals;
+        self.state.payout_slippage = self.payout_slippage.as_ref().map_or(0.0, |fx| fx.slippage);
+
+        self.state.dust_rejected = self.dust_guard.rejected;
+        self.state.dust_surcharges = self.dust_guard.surcharges;
+        self.state.non_dust_settle_ticks = self.dust_guard.non_dust_settle_ticks();
+        if let Some(agg) = &self.aggregator {
+            self.state.payments_aggregated = agg.payments_aggregated;
+            self.state.aggregate_packets = agg.aggregate_packets;
+        }
+        self.state.aggregated_payments_settled = self.aggregated_payments_settled;
+        if let Some(cache) = &self.route_cache {
+            self.state.route_cache_hit_rate = cache.hit_rate();
+            self.state.route_cache_invalidations = cache.invalidations as u32;
+        }
+        self.state.path_cache_hit_rate = self.path_cache.hit_rate();
+        self.state.path_cache_invalidations = self.path_cache.invalidations as u32;
+
+        if let Some(d) = self.volume_discounts.as_mut() {
+            d.end_tick();
+            self.state.volume_discount_forgone = d.forgone;
+            self.state.discounted_settlements = d.discounted_settlements;
+            self.state.discounted_value_share = d.discounted_share();
+        }
+
+        // Cartel members split this tick's earnings off the books
+        if let Some(cartel) = self.cartel.as_mut() {
+            cartel.split_fees(&self.nodes);
+            self.state.cartel_steered_hops = cartel.steered_hops;
+            self.state.cartel_fee_split = cartel.split_volume;
+        }
+
+        // Egress, route and fee concentration, refreshed once per epoch
+        if current_tick.is_multiple_of(concentration::EPOCH_TICKS) {
+            let shares = self.egress_concentration.close_epoch();
+            self.state.egress_top1_share = shares.top1;
+            self.state.egress_top3_share = shares.top3;
+            self.state.egress_concentration_alert = shares.alert();
+            self.state.route_concentration = self.route_concentration.close_epoch();
+            let earnings: Vec<f64> = self.nodes.iter()
+                .filter(|n| n.role != NodeRole::Disabled)
+                .map(|n| n.total_fees_earned)
+                .collect();
+            self.state.fee_share_gini = concentration::gini(&earnings);
+        }
+
         self.state.total_value_leaked = conservation::compute_conservation(
-            self.total_input,
-            self.total_output,
-            self.total_burned,
-            self.total_fees,
+            self.total_input.value(),
+            self.total_output.value(),
+            self.total_burned.value(),
+            self.total_fees.value(),
             active_val,
         );
 
         // Circuit breaker check
         let conservation_result = self.conservation_law.verify_tick(
-            self.total_input,
-            self.total_output,
-            self.total_fees,
-            self.total_burned,
+            self.total_input.value(),
+            self.total_output.value(),
+            self.total_fees.value(),
+            self.total_burned.value(),
             active_val,
         );
         self.state.circuit_breaker_active = conservation_result.circuit_breaker_tripped;
 
-        // Count orbiting packets
-        let orbit_count: u32 = self.node_buffers.values().flatten()
+        // Users react to this tick's reverts, latency and breaker trips
+        if let Some(model) = self.panic_model.as_mut() {
+            self.state.panic_level = model.update(
+                self.state.panic_level,
+                self.settlement_count,
+                self.revert_count,
+                self.total_settlement_time,
+                conservation_result.circuit_breaker_tripped,
+            );
+        }
+        // Local panic spreads from where this tick's reverts happened
+        if let Some(c) = self.panic_contagion.as_mut() {
+            c.end_tick(&self.nodes);
+            self.state.peak_local_panic = c.peak();
+            self.state.panicked_nodes = c.panicked_count();
+        }
+        // Liquidity providers follow fee yield into and out of Egress inventory
+        if !self.liquidity_providers.is_empty() {
+            self.liquidity_providers.update(&mut self.nodes, &mut self.asset_ledger, current_tick);
+        }
+        // Replenishment tops every live Egress back up
+        if let Some(r) = self.replenishment.as_mut() {
+            r.update(&mut self.nodes, &mut self.asset_ledger);
+            self.state.egress_replenished = r.replenished;
+        }
+        // Short Egress borrow inventory from linked peers with crypto to spare
+        if let Some(r) = self.rebalancing.as_mut() {
+            let mut queued = vec![0.0; self.nodes.len()];
+            for (&id, buf) in &self.node_buffers {
+                if let Some(q) = queued.get_mut(id as usize) {
+                    *q = buf.iter().map(|p| p.current_value).sum();
+                }
+            }
+            r.update(&mut self.nodes, &queued, &mut self.asset_ledger, current_tick);
+            self.state.rebalanced_volume = r.rebalanced;
+            self.state.rebalancing_fees = r.fees;
+            self.state.rebalancing_in_flight = r.in_flight_value();
+        }
+
+        // Count orbiting packets, by reason
+        let mut orbit_reasons = [0u32; 3];
+        for p in self.node_buffers.values().flatten()
             .filter(|p| p.status == PacketStatus::Held)
-            .count() as u32;
+        {
+            // Packets restored from a snapshot predating reasons had no route
+            orbit_reasons[p.orbit_reason.unwrap_or(OrbitReason::NoLiquidEgress) as usize] += 1;
+        }
+        let orbit_count: u32 = orbit_reasons.iter().sum();
         self.state.orbit_count = orbit_count;
+        self.state.orbit_no_liquid_egress = orbit_reasons[OrbitReason::NoLiquidEgress as usize];
+        self.state.orbit_hop_limit = orbit_reasons[OrbitReason::HopLimit as usize];
+        self.state.orbit_disabled_destination =
+            orbit_reasons[OrbitReason::DisabledDestination as usize];
+
+        if let Some(lifecycle) = &self.egress_lifecycle {
+            self.state.egress_exits = lifecycle.exits;
+            self.state.egress_reentries = lifecycle.reentries;
+            self.state.exited_egress_count = lifecycle.exited_count();
+        }
 
         // Track tier distribution
         let mut tier_dist = [0u32; 4];
@@ -699,6 +1800,16 @@ impl ArenaSimulation {
             }
         }
         self.state.tier_distribution = tier_dist;
+        self.state.tier_effective_cost = self.settlement_costs.mean_costs();
+        self.demand_elasticity.end_tick();
+        self.egress_draws.end_tick();
+        self.state.liquidity_runway_ticks = replenishment::runway(
+            &self.nodes,
+            self.egress_draws.total_rate(),
+            self.replenishment.as_ref().map_or(0.0, |r| r.inflow_rate()),
+        );
+        self.state.realized_cost = self.demand_elasticity.realized_cost;
+        self.state.demand_retention = self.demand_elasticity.retention();
 
         // Count held packets
         self.state.held_count = self.node_buffers.values().flatten()
@@ -710,7 +1821,7 @@ impl ArenaSimulation {
             + self.message_queue.len() as f64;
         // Network fee component: average fee per active packet as fraction of gold price
         self.state.network_fee_component = if total_active_count > 0.0 && self.state.gold_price > 0.0 {
-            (self.total_fees / total_active_count) / self.state.gold_price
+            (self.total_fees.value() / total_active_count) / self.state.gold_price
         } else {
             0.0
         };
@@ -729,8 +1840,8 @@ impl ArenaSimulation {
             0.0
         };
         // Float component: in-flight value as fraction of total input (capped)
-        self.state.float_component = if self.total_input > 0.0 {
-            (active_val / self.total_input * 0.001).min(0.05)
+        self.state.float_component = if self.total_input.value() > 0.0 {
+            (active_val / self.total_input.value() * 0.001).min(0.05)
         } else {
             0.0
         };
@@ -739,45 +1850,533 @@ impl ArenaSimulation {
                 + self.state.speculation_component
                 + self.state.float_component);
 
+        // Composite liquidity stress (formula in `stress`)
+        self.state.stress_index = self.stress.update(
+            &self.nodes,
+            self.lambda_ema,
+            orbit_count,
+            total_active_count as usize,
+            self.settlement_count,
+            self.revert_count,
+        ).index();
+
         let mut active_packets = self.message_queue.clone();
         for b in self.node_buffers.values() {
             active_packets.extend(b.clone());
         }
 
+        let histograms = histogram::compute_histograms(
+            active_packets.iter(),
+            &self.nodes,
+            self.state.current_tick,
+        );
+
         TickResult {
             state: self.state.clone(),
             active_packets,
-            node_updates: self.nodes.iter().map(|n| NodeUpdate {
-                id: n.id,
-                buffer_count: n.current_buffer_count,
-                inventory_fiat: n.inventory_fiat,
-                inventory_crypto: n.inventory_crypto,
-            }).collect(),
+            histograms,
+            node_updates: self.node_updates(),
+        }
+    }
+
+    /// This tick's node updates, flagged against the previous tick's.
+    fn node_updates(&mut self) -> Vec<NodeUpdate> {
+        let updates: Vec<NodeUpdate> = self.nodes.iter()
+            .map(|n| NodeUpdate::new(n, self.last_node_updates.get(n.id as usize)))
+            .collect();
+        self.last_node_updates.clone_from(&updates);
+        updates
+    }
+
+    /// Run the ticks the pacer says are due at host time `now_ms`.
+    pub fn advance_to_core(&mut self, now_ms: f64) -> BatchSummary {
+        let due = self.pacer.as_mut().map_or(0, |pacer| pacer.due(now_ms));
+        self.run_batch_core(due)
+    }
+
+    /// Milliseconds of host time until `advance_to_core` next has a tick to
+    /// run, for hosts that sleep between calls. None while pacing is off.
+    pub fn until_next_tick_ms(&self, now_ms: f64) -> Option<f64> {
+        self.pacer.as_ref().map(|pacer| pacer.until_next_ms(now_ms))
+    }
+
+    pub fn get_pacing_stats_core(&self) -> Option<pacing::PacingStats> {
+        self.pacer.as_ref().map(pacing::RealTimePacer::stats)
+    }
+
+    /// Advance `ticks` ticks, folding each tick's state into a `BatchSummary`.
+    pub fn run_batch_core(&mut self, ticks: u32) -> BatchSummary {
+        let start_settlements = self.settlement_count;
+        let start_reverts = self.revert_count;
+        let mut summary = BatchSummary {
+            ticks,
+            start_tick: self.state.current_tick,
+            end_tick: self.state.current_tick,
+            settlements: 0,
+            reverts: 0,
+            min_fee_rate: if ticks > 0 { f64::INFINITY } else { 0.0 },
+            max_fee_rate: 0.0,
+            max_conservation_error: 0.0,
+            quadrant_occupancy: [0; 6],
+        };
+
+        for _ in 0..ticks {
+            self.tick_core();
+            let s = &self.state;
+            summary.min_fee_rate = summary.min_fee_rate.min(s.current_fee_rate);
+            summary.max_fee_rate = summary.max_fee_rate.max(s.current_fee_rate);
+            summary.max_conservation_error =
+                summary.max_conservation_error.max(s.total_value_leaked.abs());
+            summary.quadrant_occupancy[self.governance_pressure.index()] += 1;
+        }
+
+        summary.end_tick = self.state.current_tick;
+        summary.settlements = self.settlement_count - start_settlements;
+        summary.reverts = self.revert_count - start_reverts;
+        summary
+    }
+
+    /// Tick until `metric` is stationary: over the trailing `window` ticks its
+    /// spread (max - min) is within `tolerance` relative to the window mean.
+    /// Gives up after `max_ticks`, reporting `converged: false`.
+    pub fn run_until_converged_core(
+        &mut self,
+        metric: ConvergenceMetric,
+        tolerance: f64,
+        window: usize,
+        max_ticks: u64,
+    ) -> ConvergenceResult {
+        let window = window.max(2);
+        let mut samples: std::collections::VecDeque<f64> =
+            std::collections::VecDeque::with_capacity(window);
+        let mut ticks_used = 0;
+        let mut converged = false;
+
+        while ticks_used < max_ticks {
+            self.tick_core();
+            ticks_used += 1;
+
+            if samples.len() == window {
+                samples.pop_front();
+            }
+            samples.push_back(self.sample_metric(metric));
+
+            if samples.len() == window {
+                let (lo, hi) = samples.iter()
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+                let mean = samples.iter().sum::<f64>() / window as f64;
+                if hi - lo <= tolerance * mean.abs() {
+                    converged = true;
+                    break;
+                }
+            }
+        }
+
+        ConvergenceResult {
+            converged,
+            ticks_used,
+            final_value: self.sample_metric(metric),
+            stats: self.get_stats_core(),
+        }
+    }
+
+    fn sample_metric(&self, metric: ConvergenceMetric) -> f64 {
+        match metric {
+            ConvergenceMetric::FeeRate => self.state.current_fee_rate,
+            ConvergenceMetric::SurgeMultiplier => self.state.surge_multiplier,
+            ConvergenceMetric::ActiveValue => self.state.active_value,
+            ConvergenceMetric::HeldCount => self.state.held_count as f64,
+            ConvergenceMetric::Volatility => self.state.volatility,
+            ConvergenceMetric::PegDeviation => self.state.peg_deviation,
+            ConvergenceMetric::SettlementRate => {
+                if self.packet_id_counter > 0 {
+                    self.settlement_count as f64 / self.packet_id_counter as f64
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    /// End-of-run sweep: classify every Held packet and resolve it under
+    /// `policy`, outside the tick. Refunds count as reverts for the orbit
+    /// reason and are never retried; dissolutions ignore the age threshold.
+    pub fn sweep_held_core(&mut self, policy: held_sweep::SweepPolicy) -> held_sweep::HeldSweepReport {
+        use held_sweep::SweepPolicy;
+
+        let tick = self.state.current_tick;
+        let mut report = held_sweep::HeldSweepReport::new(policy, tick);
+        let qualifications = dissolution::node_qualifications(&self.nodes, self.resources.as_ref());
+        let node_ids: Vec<u32> = self.node_buffers.keys().cloned().collect();
+
+        for node_id in node_ids {
+            let Some(mut buf) = self.node_buffers.remove(&node_id) else {
+                continue;
+            };
+            let mut kept = Vec::with_capacity(buf.len());
+            for mut p in buf.drain(..) {
+                if p.status != PacketStatus::Held {
+                    kept.push(p);
+                    continue;
+                }
+                report.record_held(&p);
+                let age = tick.saturating_sub(p.spawn_tick);
+                let dissolved = matches!(policy, SweepPolicy::Dissolve | SweepPolicy::DissolveOrRefund)
+                    && dissolution::dissolve(p.current_value, &qualifications, &compaction::visited_nodes(&p))
+                        .map(|result| self.asset_ledger.pay_dissolution(&mut self.nodes, &result))
+                        .is_ok();
+                if dissolved {
+                    p.status = PacketStatus::Dissolved;
+                    self.total_output += p.current_value;
+                    self.cohorts.record_outcome(p.cohort, age, cohort::CohortOutcome::Dissolved);
+                    self.corridors.record_exit(&p, cohort::CohortOutcome::Dissolved);
+                    self.speculators.on_exit(p.id, p.current_value);
+                    self.state.dissolved_count += 1;
+                    report.record_dissolved(&p);
+                } else if matches!(policy, SweepPolicy::Refund | SweepPolicy::DissolveOrRefund) {
+                    let reason = RevertReason::from_orbit(p.orbit_reason);
+                    self.refund(&mut p, tick, reason);
+                    report.record_refunded(&p);
+                } else {
+                    report.record_unresolved(&p);
+                    kept.push(p);
+                    continue;
+                }
+                let node = &mut self.nodes[node_id as usize];
+                node.current_buffer_count = node.current_buffer_count.saturating_sub(1);
+            }
+            self.node_buffers.insert(node_id, kept);
+        }
+
+        // Bring the state snapshot up to date with what the sweep resolved
+        self.state.held_count = report.unresolved;
+        self.state.orbit_count = report.unresolved;
+        self.state.orbit_no_liquid_egress = report.unresolved_by_reason[OrbitReason::NoLiquidEgress as usize];
+        self.state.orbit_hop_limit = report.unresolved_by_reason[OrbitReason::HopLimit as usize];
+        self.state.orbit_disabled_destination =
+            report.unresolved_by_reason[OrbitReason::DisabledDestination as usize];
+        self.state.revert_count = self.revert_count;
+        self.state.revert_reasons = self.revert_log.counts;
+        self.state.total_output = self.total_output.value();
+        self.state.active_value = self.active_value();
+        report
+    }
+
+    /// Sever every link between `group_a` and `group_b`, in both
+    /// directions, islanding the groups from each other. Packets already on
+    /// a link still land. Returns the number of directed links cut.
+    pub fn partition_network_core(&mut self, group_a: &[u32], group_b: &[u32]) -> u32 {
+        let len = self.nodes.len() as u32;
+        let mut cut = 0;
+        for (from_group, to_group) in [(group_a, group_b), (group_b, group_a)] {
+            for &from in from_group.iter().filter(|&&id| id < len) {
+                let node = &mut self.nodes[from as usize];
+                let before = node.neighbors.len();
+                node.neighbors.retain(|n| {
+                    let severed = to_group.contains(n);
+                    if severed {
+                        self.severed_links.push((from, *n));
+                    }
+                    !severed
+                });
+                cut += (before - node.neighbors.len()) as u32;
+            }
+        }
+        if cut > 0 {
+            routing::compute_egress_distances(&mut self.nodes);
+            self.invalidate_route_cache();
+        }
+        cut
+    }
+
+    /// Restore every link cut by `partition_network_core` between nodes that
+    /// are still in the network. Returns the number of directed links restored.
+    pub fn heal_partition_core(&mut self) -> u32 {
+        let mut restored = 0;
+        for (from, to) in std::mem::take(&mut self.severed_links) {
+            if self.removed_nodes.contains(&from) || self.removed_nodes.contains(&to) {
+                continue;
+            }
+            let neighbors = &mut self.nodes[from as usize].neighbors;
+            if !neighbors.contains(&to) {
+                neighbors.push(to);
+                restored += 1;
+            }
+        }
+        if restored > 0 {
+            routing::compute_egress_distances(&mut self.nodes);
+            self.invalidate_route_cache();
+        }
+        restored
+    }
+
+    pub fn get_governor_state_core(&self) -> GovernorState {
+        use crate::adapter::from_decimal;
+        let (kp, ki, kd) = self.core_pid.gains();
+        let plan = self.core_pid.mpc().and_then(|m| m.last_plan());
+        GovernorState {
+            kp: from_decimal(kp),
+            ki: from_decimal(ki),
+            kd: from_decimal(kd),
+            peg_target: self.peg_target,
+            integral_error: from_decimal(self.core_pid.integral_error()),
+            last_error: from_decimal(self.core_pid.last_error()),
+            last_adjustment: from_decimal(self.core_pid.last_params().recommended_fee_adjustment),
+            mpc_horizon: self.core_pid.mpc().map_or(0, |m| m.model().horizon),
+            mpc_predicted_peg_error: plan.map_or(0.0, |p| from_decimal(p.predicted_peg_error)),
+            mpc_predicted_backlog: plan.map_or(0.0, |p| from_decimal(p.predicted_backlog)),
+        }
+    }
+
+    pub fn get_quadrant_transitions_core(&self) -> &[QuadrantTransition] {
+        &self.quadrant_transitions
+    }
+
+    pub fn get_settlement_costs_core(&self) -> Vec<cost::TierCostStats> {
+        self.settlement_costs.summary(self.state.gold_price)
+    }
+
+    pub fn get_settlement_latency_core(&self) -> Vec<latency::TierLatencyStats> {
+        self.settlement_latency.stats()
+    }
+
+    pub fn get_asset_ledger_core(&self) -> asset_ledger::AssetLedgerReport {
+        self.asset_ledger.report(&self.nodes)
+    }
+
+    pub fn get_node_pnl_core(&self) -> Vec<resources::NodePnl> {
+        self.resources.as_ref().map_or_else(Vec::new, |r| r.pnl(&self.nodes))
+    }
+
+    pub fn get_resource_summary_core(&self) -> Option<resources::ResourceSummary> {
+        self.resources.as_ref().map(|r| r.summary(&self.nodes, self.settlement_count))
+    }
+
+    /// Issues the topology check found when the network was built.
+    pub fn get_topology_issues_core(&self) -> &[topology::TopologyIssue] {
+        &self.topology_issues
+    }
+
+    /// Role coverage and reachability of the network as it stands now,
+    /// after any kills, removals or partitions.
+    pub fn check_topology_core(&self) -> Vec<topology::TopologyIssue> {
+        topology::check_nodes(&self.nodes)
+    }
+
+    /// The checkpoint taken at `tick`, rebuilt from its keyframe and the
+    /// deltas since; None if checkpoints are off or none was taken then.
+    pub fn get_checkpoint_core(&self, tick: u64) -> Option<checkpoint::Checkpoint> {
+        self.checkpoints.as_ref()?.restore(tick)
+    }
+
+    pub fn get_checkpoint_stats_core(&self) -> Option<checkpoint::CheckpointStats> {
+        self.checkpoints.as_ref().map(|c| c.stats())
+    }
+
+    /// Spawn cohorts with their settled / reverted / dissolved counts by age.
+    pub fn get_cohorts_core(&self) -> Vec<cohort::CohortStats> {
+        self.cohorts.cohorts()
+    }
+
+    /// The most recent revert receipts, oldest first.
+    pub fn get_revert_receipts_core(&self) -> Vec<receipts::RevertReceipt> {
+        self.revert_log.receipts().cloned().collect()
+    }
+
+    /// What the governor saw and decided this tick; None on ticks it did not
+    /// decide or with logging off.
+    pub fn governor_io_core(&self) -> Option<&control_loop::GovernorIo> {
+        self.governor_io.as_ref()
+    }
+
+    /// The cartel, with its live members and split ledger; None without one.
+    pub fn get_cartel_core(&self) -> Option<&collusion::Cartel> {
+        self.cartel.as_ref()
+    }
+
+    /// Hop dwell and hop fees by node, while hop tracing is on.
+    pub fn get_hop_stats_core(&self) -> Vec<hop_trace::NodeHopStats> {
+        self.hop_trace.as_ref().map_or_else(Vec::new, |t| t.node_stats())
+    }
+
+    pub fn get_stats_core(&self) -> SimStats {
+        let orbit_count = self.node_buffers.values().flatten()
+            .filter(|p| p.status == PacketStatus::Held)
+            .count() as u32;
+        let active_val = self.active_value();
+        SimStats {
+            total_input: self.total_input.value(),
+            total_output: self.total_output.value(),
+            total_burned: self.total_burned.value(),
+            total_fees: self.total_fees.value(),
+            total_leaked: conservation::compute_conservation(
+                self.total_input.value(),
+                self.total_output.value(),
+                self.total_burned.value(),
+                self.total_fees.value(),
+                active_val,
+            ),
+            settlement_count: self.settlement_count,
+            revert_count: self.revert_count,
+            orbit_count,
+            avg_hops: if self.settlement_count > 0 {
+                self.total_settlement_hops as f64 / self.settlement_count as f64
+            } else { 0.0 },
+            avg_time_to_settle: if self.settlement_count > 0 {
+                self.total_settlement_time as f64 / self.settlement_count as f64
+            } else { 0.0 },
+            rate_limited_count: self.rate_limiter.as_ref().map_or(0, |l| l.rejected),
+            tier_fees_collected: self.tier_ledger.fees_collected,
+            tier_demurrage_burned: self.tier_ledger.demurrage_burned,
+            tier_rewards_distributed: self.tier_ledger.rewards_distributed,
+            corridors: self.corridors.stats(
+                &self.nodes, self.node_buffers.values().flatten().chain(&self.message_queue)),
+            regional_prices: self.regional_prices.stats(self.oracle.price()),
+            speculation: self.speculators.stats(),
+            liquidity: self.liquidity_providers.stats(),
+            tier_latency: self.settlement_latency.stats(),
+        }
+    }
+
+    /// Drop cached next hops and paths after a topology or routing change.
+    pub(crate) fn invalidate_route_cache(&mut self) {
+        if let Some(cache) = self.route_cache.as_mut() {
+            cache.invalidate();
+        }
+        self.path_cache.invalidate();
+    }
+
+    /// Value held by packets buffered at nodes or in flight.
+    pub(crate) fn active_value(&self) -> f64 {
+        conservation::compensated_sum(
+            self.node_buffers.values().flatten()
+                .chain(self.message_queue.iter())
+                .map(|p| p.current_value),
+        )
+    }
+
+    pub fn get_memory_footprint_core(&self) -> MemoryFootprint {
+        let packet_size = std::mem::size_of::<SimPacket>();
+        let packets: Vec<&SimPacket> = self.node_buffers.values().flatten()
+            .chain(self.message_queue.iter())
+            .collect();
+        let container_bytes = self.node_buffers.values()
+            .map(|b| b.capacity() * packet_size)
+            .sum::<usize>()
+            + self.message_queue.capacity() * packet_size;
+        let heap_bytes: usize = packets.iter().map(|p| compaction::packet_heap_bytes(p)).sum();
+        let transition_bytes =
+            self.quadrant_transitions.capacity() * std::mem::size_of::<QuadrantTransition>();
+        let receipt_bytes = self.revert_log.heap_bytes();
+        MemoryFootprint {
+            packets: packets.len() as u32,
+            route_entries: packets.iter().map(|p| p.route_history.len() as u64).sum(),
+            fee_schedule_entries: packets.iter().map(|p| p.fee_schedule.len() as u64).sum(),
+            quadrant_transitions: self.quadrant_transitions.len() as u32,
+            revert_receipts: self.revert_log.len() as u32,
+            compacted_entries: self.compacted_entries,
+            estimated_bytes: (container_bytes + heap_bytes + transition_bytes + receipt_bytes) as u64,
         }
     }
 
-    pub fn get_total_output(&self) -> f64 { self.total_output }
+    pub fn get_total_output(&self) -> f64 { self.total_output.value() }
     pub fn get_total_value_leaked(&self) -> f64 { self.state.total_value_leaked }
+    /// Payments admitted so far, whether routed alone or held for aggregation.
+    pub fn get_payments_accepted(&self) -> u64 {
+        let (aggregated, aggregates) = self.aggregator.as_ref()
+            .map_or((0, 0), |a| (a.payments_aggregated, a.aggregate_packets));
+        self.packet_id_counter + aggregated as u64 - aggregates as u64
+    }
     pub fn get_node_pressure(&self, node_id: usize) -> f64 {
         self.nodes.get(node_id).map_or(0.0, |n| n.pressure)
     }
+
+    /// Nodes matching an optional role and minimum pressure, paginated by
+    /// `offset`/`limit` over the filtered set (in node-id order).
+    pub fn get_nodes_filtered_core(
+        &self,
+        role: Option<NodeRole>,
+        min_pressure: Option<f64>,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<&SimNode> {
+        self.nodes.iter()
+            .filter(|n| role.is_none_or(|r| n.role == r))
+            .filter(|n| min_pressure.is_none_or(|p| n.pressure >= p))
+            .skip(offset)
+            .take(limit)
+            .collect()
+    }
+
+    /// Value and tier mix of the packets heading to Egress `node_id` and how
+    /// long its inventory lasts at its current draw rate. None unless the
+    /// node is an Egress.
+    pub fn get_egress_queue_core(&self, node_id: u32) -> Option<egress_queue::EgressQueue> {
+        let egress = self.nodes.get(node_id as usize)
+            .filter(|n| n.role == NodeRole::Egress)?;
+        let nearest = egress_queue::nearest_liquid_egress(&self.nodes);
+        // Buffered packets head from the node holding them, in-flight ones
+        // from their next hop; corridor traffic only to its destination region
+        let buffered = self.node_buffers.iter()
+            .flat_map(|(&id, b)| b.iter().map(move |p| (id, p)));
+        let in_flight = self.message_queue.iter()
+            .filter_map(|p| p.target_node.map(|id| (id, p)));
+        let heading = buffered.chain(in_flight)
+            .filter(|&(at, p)| match p.corridor {
+                None => nearest[at as usize] == Some(node_id),
+                Some(_) => routing::target_egress(&self.nodes, &self.nodes[at as usize], p)
+                    .is_some_and(|e| e.id == node_id),
+            })
+            .map(|(_, p)| p);
+        Some(egress_queue::EgressQueue::collect(
+            node_id, egress.inventory_crypto, self.egress_draws.rate(node_id), heading,
+        ))
+    }
+
+    /// Active packets matching optional status, tier and location filters,
+    /// ordered by packet id and capped at `limit`. A buffered packet is
+    /// located at the node holding it; an in-flight packet at its target.
+    pub fn get_active_packets_core(
+        &self,
+        status: Option<PacketStatus>,
+        tier: Option<MarketTier>,
+        node_id: Option<u32>,
+        limit: usize,
+    ) -> Vec<&SimPacket> {
+        let buffered = self.node_buffers.iter()
+            .filter(|(&id, _)| node_id.is_none_or(|n| n == id))
+            .flat_map(|(_, b)| b.iter());
+        let in_flight = self.message_queue.iter()
+            .filter(|p| node_id.is_none_or(|n| p.target_node == Some(n)));
+
+        let mut matches: Vec<&SimPacket> = buffered.chain(in_flight)
+            .filter(|p| status.is_none_or(|s| p.status == s))
+            .filter(|p| tier.is_none_or(|t| p.tier == t))
+            .collect();
+        matches.sort_unstable_by_key(|p| p.id);
+        matches.truncate(limit);
+        matches
+    }
 }
 
-// ─── Rolling Volatility ──────────────────────────────────────────────────────
+// ─── Traffic and Velocity Helpers ────────────────────────────────────────────
 
-/// E11: Compute coefficient of variation from rolling price window
-pub(crate) fn compute_rolling_volatility(history: &[f64]) -> f64 {
-    if history.len() < 2 {
-        return 0.0;
+/// Auto traffic amount for a tier (0..4), spread over the tier's range by `draw`.
+fn tier_amount(tier_selector: u64, draw: u64) -> f64 {
+    match tier_selector {
+        0 => 1.0 + (draw % 9) as f64,           // L0: 1-9g
+        1 => 50.0 + (draw % 950) as f64,        // L1: 50-999g
+        2 => 1000.0 + (draw % 99000) as f64,    // L2: 1000-99999g
+        _ => 100000.0 + (draw % 900000) as f64, // L3: 100000-999999g
     }
-    let n = history.len() as f64;
-    let mean = history.iter().sum::<f64>() / n;
-    if mean.abs() < 1e-12 {
-        return 0.0;
+}
+
+/// Turnover of the in-flight float per tick: value settled this tick divided by
+/// the average float. Zero when nothing is in flight.
+pub(crate) fn compute_monetary_velocity(settled_value: f64, avg_float: f64) -> f64 {
+    if avg_float > 1e-9 {
+        settled_value.max(0.0) / avg_float
+    } else {
+        0.0
     }
-    let variance = history.iter()
-        .map(|&p| (p - mean).powi(2))
-        .sum::<f64>() / n;
-    let std_dev = variance.sqrt();
-    std_dev / mean
 }
@@ -0,0 +1,64 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Egress Payout FX Slippage
+
+// ---------------------------------------------------------------------------
+// PayoutSlippage - conversion cost between settled crypto and the payout
+// ---------------------------------------------------------------------------
+
+/// The beneficiary converts settled crypto at the oracle price less a
+/// spread: `base_spread` in a calm market, widening by
+/// `volatility_coefficient` per unit of rolling gold volatility, capped at
+/// `max_spread`. Slippage is the beneficiary's cost; the protocol's own
+/// accounting still settles at the oracle price.
+#[derive(Debug, Clone)]
+pub struct PayoutSlippage {
+    base_spread: f64,
+    volatility_coefficient: f64,
+    max_spread: f64,
+    /// Value lost to the spread over all payouts
+    pub slippage: f64,
+    pub payouts: u32,
+}
+
+impl PayoutSlippage {
+    pub fn new(base_spread: f64, volatility_coefficient: f64, max_spread: f64) -> Self {
+        Self {
+            base_spread: base_spread.max(0.0),
+            volatility_coefficient: volatility_coefficient.max(0.0),
+            max_spread: max_spread.clamp(0.0, 1.0),
+            slippage: 0.0,
+            payouts: 0,
+        }
+    }
+
+    /// Spread (0..1) at rolling volatility `volatility`.
+    pub fn spread(&self, volatility: f64) -> f64 {
+        (self.base_spread + self.volatility_coefficient * volatility.max(0.0)).min(self.max_spread)
+    }
+
+    /// Pay out `value` at `spread`, returning what the beneficiary receives.
+    pub fn pay_out(&mut self, value: f64, spread: f64) -> f64 {
+        let lost = value * spread;
+        self.slippage += lost;
+        self.payouts += 1;
+        value - lost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spread_widens_with_volatility_up_to_cap() {
+        let mut fx = PayoutSlippage::new(0.002, 0.5, 0.03);
+        assert_eq!(fx.spread(0.0), 0.002);
+        assert!((fx.spread(0.02) - 0.012).abs() < 1e-12);
+        assert_eq!(fx.spread(1.0), 0.03);
+
+        let delivered = fx.pay_out(100.0, fx.spread(0.0));
+        assert!((delivered - 99.8).abs() < 1e-9);
+        assert!((fx.slippage - 0.2).abs() < 1e-9);
+        assert_eq!(fx.payouts, 1);
+    }
+}
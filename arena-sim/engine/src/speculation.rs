@@ -0,0 +1,236 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Speculator Agents
+//
+// Speculators farm volatility by round-tripping value as fast as the network
+// lets them: each tick every speculator sends `aggressiveness` of its idle
+// bankroll from its Ingress, more when gold is volatile, and re-sends
+// whatever comes back once a packet settles or is refunded. They do no
+// NGauge work, so they raise velocity without raising activity, the pattern
+// the organic-ratio detector (`engauge::NGaugeState`) looks for. Their
+// packets are known, which makes them a ground truth to score it against.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+/// Grams each speculator starts with.
+pub const SPECULATOR_BANKROLL: f64 = 20_000.0;
+/// Largest single packet: speculators slice orders to stay in the fast,
+/// cheap L1 tier rather than wait out L2/L3 liquidity.
+pub const ORDER_SLICE: f64 = 1_000.0;
+/// Extra share of the bankroll sent per unit of rolling gold volatility.
+pub const VOLATILITY_GAIN: f64 = 10.0;
+/// Smallest order a speculator bothers to send (grams).
+pub const MIN_ORDER: f64 = 1.0;
+/// Share of a tick's settled value moved by speculators at or above which
+/// the tick counts as speculative for scoring the detector.
+pub const SPECULATIVE_SHARE: f64 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Speculator {
+    ingress: u32,
+    idle: f64,
+}
+
+/// A speculator's order for this tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Order {
+    pub speculator: usize,
+    pub node_id: u32,
+    pub amount: f64,
+}
+
+/// What speculators did and how the organic-ratio detector scored against
+/// them, tick by tick.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct SpeculationStats {
+    pub speculators: u32,
+    pub aggressiveness: f64,
+    pub packets_sent: u32,
+    pub value_sent: f64,
+    /// Value returned to speculators by settlements and refunds
+    pub value_returned: f64,
+    /// Bankroll idle or in flight, against `speculators * SPECULATOR_BANKROLL`
+    pub bankroll: f64,
+    /// Ticks by whether speculators moved `SPECULATIVE_SHARE` of settled
+    /// value and whether the organic ratio was under its speculative threshold
+    pub true_positive_ticks: u32,
+    pub false_positive_ticks: u32,
+    pub false_negative_ticks: u32,
+    pub true_negative_ticks: u32,
+    /// Share of flagged ticks that were speculative, and of speculative
+    /// ticks that were flagged (0 with none to score)
+    pub precision: f64,
+    pub recall: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Speculators {
+    agents: Vec<Speculator>,
+    aggressiveness: f64,
+    // Packet id -> speculator and value sent, while the packet is live
+    owners: BTreeMap<u64, (usize, f64)>,
+    in_flight: f64,
+    // Value speculators' packets settled this tick
+    tick_output: f64,
+    stats: SpeculationStats,
+}
+
+impl Speculators {
+    /// Replace the population with `count` speculators spread round-robin
+    /// over `ingress_nodes`. Packets of the old population stay tracked.
+    pub fn configure(&mut self, count: u32, aggressiveness: f64, ingress_nodes: &[u32]) {
+        if ingress_nodes.is_empty() {
+            return;
+        }
+        self.agents = (0..count as usize)
+            .map(|i| Speculator { ingress: ingress_nodes[i % ingress_nodes.len()], idle: SPECULATOR_BANKROLL })
+            .collect();
+        self.aggressiveness = aggressiveness.clamp(0.0, 1.0);
+        self.stats.speculators = count;
+        self.stats.aggressiveness = self.aggressiveness;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.agents.is_empty()
+    }
+
+    /// This tick's orders at rolling gold `volatility`, in `ORDER_SLICE`
+    /// packets; their value leaves the idle bankrolls until `sent` or
+    /// `unsent` says what became of it.
+    pub fn orders(&mut self, volatility: f64) -> Vec<Order> {
+        let share = (self.aggressiveness * (1.0 + VOLATILITY_GAIN * volatility.max(0.0))).min(1.0);
+        let mut orders = Vec::new();
+        for (speculator, a) in self.agents.iter_mut().enumerate() {
+            let mut remaining = a.idle * share;
+            while remaining >= MIN_ORDER {
+                let amount = remaining.min(ORDER_SLICE);
+                remaining -= amount;
+                a.idle -= amount;
+                orders.push(Order { speculator, node_id: a.ingress, amount });
+            }
+        }
+        orders
+    }
+
+    /// `order` was minted as packet `id`.
+    pub fn sent(&mut self, order: Order, id: u64) {
+        self.owners.insert(id, (order.speculator, order.amount));
+        self.in_flight += order.amount;
+        self.stats.packets_sent += 1;
+        self.stats.value_sent += order.amount;
+    }
+
+    /// `order` was turned away at admission.
+    pub fn unsent(&mut self, order: Order) {
+        if let Some(a) = self.agents.get_mut(order.speculator) {
+            a.idle += order.amount;
+        }
+    }
+
+    /// Packet `id` settled, `returned` going back to its speculator and
+    /// counting toward this tick's speculative settlements.
+    pub fn on_settle(&mut self, id: u64, returned: f64) {
+        if self.owners.contains_key(&id) {
+            self.tick_output += returned;
+        }
+        self.on_exit(id, returned);
+    }
+
+    /// Packet `id` settled, was refunded or dissolved, `returned` going
+    /// back to its speculator.
+    pub fn on_exit(&mut self, id: u64, returned: f64) {
+        let Some((speculator, sent)) = self.owners.remove(&id) else {
+            return;
+        };
+        self.in_flight -= sent;
+        self.stats.value_returned += returned;
+        if let Some(a) = self.agents.get_mut(speculator) {
+            a.idle += returned;
+        }
+    }
+
+    /// Score the detector's `flagged` verdict against the share of this
+    /// tick's `settled_value` that speculators' settlements made up; returns
+    /// that share.
+    pub fn end_tick(&mut self, settled_value: f64, flagged: bool) -> f64 {
+        let share = if settled_value > 0.0 { (self.tick_output / settled_value).min(1.0) } else { 0.0 };
+        self.tick_output = 0.0;
+        let s = &mut self.stats;
+        match (share >= SPECULATIVE_SHARE, flagged) {
+            (true, true) => s.true_positive_ticks += 1,
+            (false, true) => s.false_positive_ticks += 1,
+            (true, false) => s.false_negative_ticks += 1,
+            (false, false) => s.true_negative_ticks += 1,
+        }
+        share
+    }
+
+    pub fn stats(&self) -> SpeculationStats {
+        let s = &self.stats;
+        let ratio = |hits: u32, misses: u32| {
+            if hits + misses > 0 { hits as f64 / (hits + misses) as f64 } else { 0.0 }
+        };
+        SpeculationStats {
+            bankroll: self.agents.iter().map(|a| a.idle).sum::<f64>() + self.in_flight,
+            precision: ratio(s.true_positive_ticks, s.false_positive_ticks),
+            recall: ratio(s.true_positive_ticks, s.false_negative_ticks),
+            ..s.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orders_round_trip_the_bankroll() {
+        let mut s = Speculators::default();
+        s.configure(3, 0.5, &[0, 4]);
+        let orders = s.orders(0.0);
+        let per_agent = (SPECULATOR_BANKROLL * 0.5 / ORDER_SLICE) as usize;
+        assert_eq!(orders.len(), 3 * per_agent);
+        assert!(orders.iter().all(|o| o.amount == ORDER_SLICE));
+        assert_eq!([0, per_agent, 2 * per_agent].map(|i| orders[i].node_id), [0, 4, 0]);
+
+        s.sent(orders[0], 10);
+        s.sent(orders[per_agent], 11);
+        for &order in &orders[per_agent + 1..] {
+            s.unsent(order);
+        }
+        s.on_exit(10, 900.0);
+        s.on_exit(11, 1000.0);
+        s.on_exit(12, 1.0);
+        let stats = s.stats();
+        assert_eq!((stats.packets_sent, stats.value_returned), (2, 1900.0));
+        // The first agent's other orders are still out of its idle bankroll
+        let withheld = (per_agent - 1) as f64 * ORDER_SLICE;
+        assert_eq!(stats.bankroll, 3.0 * SPECULATOR_BANKROLL - 100.0 - withheld);
+
+        // Volatility makes them send more, never more than they hold
+        let calm = Speculators { agents: vec![Speculator { ingress: 0, idle: 100.0 }], aggressiveness: 0.1, ..Default::default() };
+        assert_eq!(calm.clone().orders(0.0)[0].amount, 10.0);
+        assert_eq!(calm.clone().orders(0.1)[0].amount, 20.0);
+        assert_eq!(calm.clone().orders(1.0)[0].amount, 100.0);
+    }
+
+    #[test]
+    fn test_detector_scored_against_speculative_share() {
+        let mut s = Speculators::default();
+        s.configure(1, 1.0, &[0]);
+        let order = s.orders(0.0)[0];
+        s.sent(order, 1);
+        s.on_settle(1, 60.0);
+        assert_eq!(s.end_tick(100.0, true), 0.6);
+        // Refunds return value without settling it
+        s.sent(order, 2);
+        s.on_exit(2, 60.0);
+        assert_eq!(s.end_tick(100.0, true), 0.0);
+        assert_eq!(s.end_tick(0.0, false), 0.0);
+        let stats = s.stats();
+        assert_eq!((stats.true_positive_ticks, stats.false_positive_ticks, stats.true_negative_ticks), (1, 1, 1));
+        assert_eq!((stats.precision, stats.recall), (0.5, 1.0));
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Liquidity Stress Index
+//
+// stress_index = (liquidity + occupancy + orbit_growth + revert_rate) / 4
+//
+// Every component is clamped to [0, 1], 0 being calm:
+//   liquidity    = 1 - lambda_ema                 (lambda >= 1 covers the float)
+//   occupancy    = p90 live-node buffer count / routing::BUFFER_CAPACITY
+//   orbit_growth = new orbiting (Held) packets this tick / active packets
+//   revert_rate  = reverts / (settlements + reverts) this tick
+
+use crate::routing::BUFFER_CAPACITY;
+use crate::types::{NodeRole, SimNode};
+
+/// Buffer-occupancy percentile the index tracks.
+pub const OCCUPANCY_PERCENTILE: f64 = 0.9;
+
+/// The four stress components, each in [0, 1].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StressComponents {
+    pub liquidity: f64,
+    pub occupancy: f64,
+    pub orbit_growth: f64,
+    pub revert_rate: f64,
+}
+
+impl StressComponents {
+    /// Equal-weight mean of the components.
+    pub fn index(&self) -> f64 {
+        (self.liquidity + self.occupancy + self.orbit_growth + self.revert_rate) / 4.0
+    }
+}
+
+/// `OCCUPANCY_PERCENTILE` of live nodes' buffer counts (0 with no live nodes).
+pub fn buffer_occupancy_percentile(nodes: &[SimNode]) -> f64 {
+    let mut counts: Vec<u32> = nodes.iter()
+        .filter(|n| n.role != NodeRole::Disabled)
+        .map(|n| n.current_buffer_count)
+        .collect();
+    if counts.is_empty() {
+        return 0.0;
+    }
+    let rank = ((counts.len() - 1) as f64 * OCCUPANCY_PERCENTILE).round() as usize;
+    *counts.select_nth_unstable(rank).1 as f64
+}
+
+// ---------------------------------------------------------------------------
+// StressTracker - per-tick deltas the rate components need
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct StressTracker {
+    last_orbit_count: u32,
+    last_settlements: u32,
+    last_reverts: u32,
+}
+
+impl StressTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Components for this tick from the cumulative settlement and revert
+    /// counts and the current orbit and active packet counts.
+    pub fn update(
+        &mut self,
+        nodes: &[SimNode],
+        lambda_ema: f64,
+        orbit_count: u32,
+        active_packets: usize,
+        settlements: u32,
+        reverts: u32,
+    ) -> StressComponents {
+        let new_orbits = orbit_count.saturating_sub(self.last_orbit_count);
+        let settled = settlements.saturating_sub(self.last_settlements);
+        let reverted = reverts.saturating_sub(self.last_reverts);
+        self.last_orbit_count = orbit_count;
+        self.last_settlements = settlements;
+        self.last_reverts = reverts;
+
+        let finished = settled + reverted;
+        StressComponents {
+            liquidity: (1.0 - lambda_ema).clamp(0.0, 1.0),
+            occupancy: (buffer_occupancy_percentile(nodes) / BUFFER_CAPACITY).min(1.0),
+            orbit_growth: (new_orbits as f64 / active_packets.max(1) as f64).min(1.0),
+            revert_rate: if finished == 0 { 0.0 } else { reverted as f64 / finished as f64 },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stress_components_track_tick_deltas() {
+        let mut tracker = StressTracker::new();
+        let calm = tracker.update(&[], 2.0, 0, 10, 5, 0);
+        assert_eq!(calm.index(), 0.0);
+
+        // Half the float uncovered, 5 of 10 active packets newly orbiting,
+        // 1 of 4 finished packets reverted
+        let stressed = tracker.update(&[], 0.5, 5, 10, 8, 1);
+        assert_eq!(stressed, StressComponents {
+            liquidity: 0.5, occupancy: 0.0, orbit_growth: 0.5, revert_rate: 0.25,
+        });
+        assert!((stressed.index() - 0.3125).abs() < 1e-12);
+
+        // Orbits draining is not growth
+        assert_eq!(tracker.update(&[], 1.0, 2, 10, 8, 1).orbit_growth, 0.0);
+    }
+}
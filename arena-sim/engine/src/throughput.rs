@@ -0,0 +1,58 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Node Throughput Limits
+//
+// A node settles or forwards at most `capacity` packets a tick: the smaller
+// of its own `max_packets_per_tick` (0 = no cap) and what its bandwidth
+// carries, `bandwidth x packets_per_mbps` (0 = bandwidth not enforced).
+// Packets past the capacity wait in the node's buffer, in order, for the
+// next tick. Expiry, refunds and dissolution are bookkeeping and take none
+// of it.
+//
+// Queue latency is the ticks the waiting packets need to clear at that
+// capacity, deferred / capacity: what a packet arriving now waits first.
+
+use crate::types::SimNode;
+
+/// Packets `node` may settle or forward this tick; None when uncapped.
+pub fn capacity(node: &SimNode, packets_per_mbps: f64) -> Option<u32> {
+    let own = (node.max_packets_per_tick > 0).then_some(node.max_packets_per_tick);
+    let carried = (packets_per_mbps > 0.0).then(|| (node.bandwidth * packets_per_mbps).floor().max(0.0) as u32);
+    match (own, carried) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Ticks `deferred` waiting packets need to clear at `capacity`.
+pub fn queue_latency(deferred: u32, capacity: Option<u32>) -> f64 {
+    match capacity {
+        Some(_) if deferred == 0 => 0.0,
+        Some(0) => f64::INFINITY,
+        Some(c) => f64::from(deferred) / f64::from(c),
+        None => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArenaSimulation;
+
+    #[test]
+    fn test_capacity_is_the_tighter_of_cap_and_bandwidth() {
+        let mut node = ArenaSimulation::new(4).nodes[0].clone();
+        node.bandwidth = 100.0;
+        assert_eq!(capacity(&node, 0.0), None);
+        assert_eq!(capacity(&node, 0.05), Some(5));
+        node.max_packets_per_tick = 3;
+        assert_eq!(capacity(&node, 0.0), Some(3));
+        assert_eq!(capacity(&node, 0.05), Some(3));
+        node.bandwidth = 10.0;
+        assert_eq!(capacity(&node, 0.05), Some(0));
+
+        assert_eq!(queue_latency(6, Some(3)), 2.0);
+        assert_eq!(queue_latency(6, None), 0.0);
+        assert_eq!(queue_latency(0, Some(0)), 0.0);
+        assert!(queue_latency(1, Some(0)).is_infinite());
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Per-Tier Revenue and Burn
+
+use crate::types::MarketTier;
+
+// ---------------------------------------------------------------------------
+// TierLedger - which tier funds the network
+// ---------------------------------------------------------------------------
+
+/// Per-tier split of the global fee, burn and reward totals, indexed L0..L3.
+/// Each array sums to its global counterpart: `fees_collected` to total_fees,
+/// `demurrage_burned` to total_burned (surge burn included), and
+/// `rewards_distributed` to the egress plus transit settlement rewards.
+#[derive(Debug, Clone, Default)]
+pub struct TierLedger {
+    pub fees_collected: [f64; 4],
+    pub demurrage_burned: [f64; 4],
+    pub rewards_distributed: [f64; 4],
+}
+
+impl TierLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_fee(&mut self, tier: MarketTier, amount: f64) {
+        self.fees_collected[tier as usize] += amount;
+    }
+
+    pub fn record_burn(&mut self, tier: MarketTier, amount: f64) {
+        self.demurrage_burned[tier as usize] += amount;
+    }
+
+    pub fn record_rewards(&mut self, tier: MarketTier, amount: f64) {
+        self.rewards_distributed[tier as usize] += amount;
+    }
+}
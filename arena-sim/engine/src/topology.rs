@@ -0,0 +1,743 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Network Topology
+//
+// How the network is wired and who plays which part in it. The default is
+// the original 6-wide grid with roles and strategies dealt cyclically
+// (`i % 4`, `i % 3`), exactly as `ArenaSimulation::new` has always built it.
+// Rings, small-world (Watts-Strogatz) and scale-free (Barabasi-Albert)
+// graphs are undirected; an explicit adjacency list is taken as given.
+// Random graphs draw from a seeded generator, so a builder always yields
+// the same network.
+//
+// A built network is checked before its first tick (`check`): Ingress with
+// no route to an Egress, Egress no Ingress can reach, missing roles and
+// malformed explicit links are reported as structured issues rather than
+// surfacing later as a mysteriously idle network.
+//
+// Nodes are grouped into sites, the unit corridor batching, corridors and
+// regional prices work in. Cyclic roles deal one of each role to every run
+// of SITE_SIZE ids, and that run is a site. Under any other role assignment
+// a node joins the site of the Egress fewest links away (ties to the lower
+// Egress id), sites numbered in Egress id order; nodes no Egress reaches
+// share the site after the last. Explicit sites override both.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::inventory;
+use crate::rng::SplitMix64;
+use crate::routing;
+use crate::types::{NodeRole, NodeStrategy, SimNode};
+
+/// Columns and rows of the default grid. Rows past the last still link up
+/// and sideways, so larger networks grow downwards.
+pub const GRID_WIDTH: u32 = 6;
+pub const GRID_HEIGHT: u32 = 4;
+/// Ids per site under cyclic roles, one of each role.
+pub const SITE_SIZE: u32 = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Topology {
+    Grid { width: u32, height: u32 },
+    /// Each node linked to its `k` nearest nodes on either side
+    Ring { k: u32 },
+    /// A `k` ring with each link rewired to a random node with `rewire_probability`
+    SmallWorld { k: u32, rewire_probability: f64 },
+    /// Preferential attachment: each node after the first `m + 1` (a clique)
+    /// links to `m` existing nodes with probability proportional to degree
+    ScaleFree { m: u32 },
+    /// Neighbor ids per node; the list length sets the node count
+    Adjacency(Vec<Vec<u32>>),
+}
+
+impl Default for Topology {
+    fn default() -> Self {
+        Self::Grid { width: GRID_WIDTH, height: GRID_HEIGHT }
+    }
+}
+
+/// How roles are dealt once the graph is built. Explicit lists shorter than
+/// the network fall back to `Cyclic` for the remaining nodes.
+#[derive(Debug, Clone, Default)]
+pub enum RoleAssignment {
+    /// Ingress, Egress, Transit, NGauge by `id % 4`
+    #[default]
+    Cyclic,
+    Explicit(Vec<NodeRole>),
+    /// Role from a node's id and neighbor ids
+    Custom(fn(u32, &[u32]) -> NodeRole),
+}
+
+/// How strategies are dealt, as `RoleAssignment`.
+#[derive(Debug, Clone, Default)]
+pub enum StrategyAssignment {
+    /// RiskAverse, Greedy, Passive by `id % 3`
+    #[default]
+    Cyclic,
+    Explicit(Vec<NodeStrategy>),
+    Custom(fn(u32, &[u32]) -> NodeStrategy),
+}
+
+impl RoleAssignment {
+    fn role(&self, id: u32, neighbors: &[u32]) -> NodeRole {
+        match self {
+            Self::Explicit(roles) if (id as usize) < roles.len() => roles[id as usize],
+            Self::Custom(f) => f(id, neighbors),
+            _ => match id % 4 {
+                0 => NodeRole::Ingress,
+                1 => NodeRole::Egress,
+                2 => NodeRole::Transit,
+                _ => NodeRole::NGauge,
+            },
+        }
+    }
+}
+
+impl StrategyAssignment {
+    fn strategy(&self, id: u32, neighbors: &[u32]) -> NodeStrategy {
+        match self {
+            Self::Explicit(strategies) if (id as usize) < strategies.len() => strategies[id as usize],
+            Self::Custom(f) => f(id, neighbors),
+            _ => match id % 3 {
+                0 => NodeStrategy::RiskAverse,
+                1 => NodeStrategy::Greedy,
+                _ => NodeStrategy::Passive,
+            },
+        }
+    }
+}
+
+/// Describes a network for `ArenaSimulation::from_topology`.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct TopologyBuilder {
+    node_count: u32,
+    topology: Topology,
+    roles: RoleAssignment,
+    strategies: StrategyAssignment,
+    /// Explicit site per node id; nodes past the list keep the derived one
+    sites: Vec<u32>,
+    seed: u64,
+}
+
+#[wasm_bindgen]
+impl TopologyBuilder {
+    /// `node_count` nodes on the default grid, roles and strategies cyclic.
+    #[wasm_bindgen(constructor)]
+    pub fn new(node_count: u32) -> Self {
+        Self {
+            node_count,
+            topology: Topology::default(),
+            roles: RoleAssignment::default(),
+            strategies: StrategyAssignment::default(),
+            sites: Vec::new(),
+            seed: 0,
+        }
+    }
+
+    /// Grid `width` columns wide; rows past `height` only link up and sideways.
+    pub fn grid(mut self, width: u32, height: u32) -> Self {
+        self.topology = Topology::Grid { width: width.max(1), height: height.max(1) };
+        self
+    }
+
+    pub fn ring(mut self, k: u32) -> Self {
+        self.topology = Topology::Ring { k: k.max(1) };
+        self
+    }
+
+    pub fn small_world(mut self, k: u32, rewire_probability: f64) -> Self {
+        self.topology = Topology::SmallWorld { k: k.max(1), rewire_probability: rewire_probability.clamp(0.0, 1.0) };
+        self
+    }
+
+    pub fn scale_free(mut self, m: u32) -> Self {
+        self.topology = Topology::ScaleFree { m: m.max(1) };
+        self
+    }
+
+    /// Undirected links as a flat list of id pairs `[a0, b0, a1, b1, ..]`
+    /// over the builder's nodes. Self-links, repeats and pairs naming
+    /// unknown nodes are dropped when the network is built (and reported by
+    /// `check`).
+    pub fn edges(mut self, pairs: Vec<u32>) -> Self {
+        let mut adjacency = vec![Vec::new(); self.node_count as usize];
+        for pair in pairs.chunks_exact(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if a < self.node_count {
+                adjacency[a as usize].push(b);
+            }
+            if b < self.node_count && a != b {
+                adjacency[b as usize].push(a);
+            }
+        }
+        self.topology = Topology::Adjacency(adjacency);
+        self
+    }
+
+    /// Roles by `NodeRole` discriminant, per node id. Unknown values and
+    /// nodes past the list are dealt cyclically.
+    pub fn roles(mut self, roles: Vec<u32>) -> Self {
+        let roles = roles.iter().enumerate()
+            .map(|(i, &r)| NodeRole::from_u32(r).unwrap_or_else(|| RoleAssignment::Cyclic.role(i as u32, &[])))
+            .collect();
+        self.roles = RoleAssignment::Explicit(roles);
+        self
+    }
+
+    /// Strategies by `NodeStrategy` discriminant, as `roles`.
+    pub fn strategies(mut self, strategies: Vec<u32>) -> Self {
+        let strategies = strategies.iter().enumerate()
+            .map(|(i, &s)| NodeStrategy::from_u32(s).unwrap_or_else(|| StrategyAssignment::Cyclic.strategy(i as u32, &[])))
+            .collect();
+        self.strategies = StrategyAssignment::Explicit(strategies);
+        self
+    }
+
+    /// Site per node id, in place of the derived sites. Nodes past the
+    /// list keep theirs.
+    pub fn sites(mut self, sites: Vec<u32>) -> Self {
+        self.sites = sites;
+        self
+    }
+
+    /// Seed for the random graphs.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+impl TopologyBuilder {
+    /// Neighbor ids per node as given; the list length sets the node count.
+    pub fn adjacency(mut self, adjacency: Vec<Vec<u32>>) -> Self {
+        self.node_count = adjacency.len() as u32;
+        self.topology = Topology::Adjacency(adjacency);
+        self
+    }
+
+    pub fn role_assignment(mut self, roles: RoleAssignment) -> Self {
+        self.roles = roles;
+        self
+    }
+
+    pub fn strategy_assignment(mut self, strategies: StrategyAssignment) -> Self {
+        self.strategies = strategies;
+        self
+    }
+
+    pub fn node_count(&self) -> u32 {
+        self.node_count
+    }
+
+    /// Neighbor ids per node, ascending except on the grid, which keeps its
+    /// original order. Self-links, duplicates and unknown ids in an explicit
+    /// adjacency are dropped.
+    pub fn neighbors(&self) -> Vec<Vec<u32>> {
+        let n = self.node_count;
+        let mut rng = SplitMix64::new(self.seed);
+        let mut adjacency = match &self.topology {
+            Topology::Grid { width, height } => grid(n, *width, *height),
+            Topology::Ring { k } => ring(n, *k),
+            Topology::SmallWorld { k, rewire_probability } => small_world(n, *k, *rewire_probability, &mut rng),
+            Topology::ScaleFree { m } => scale_free(n, *m, &mut rng),
+            Topology::Adjacency(adjacency) => adjacency.clone(),
+        };
+        adjacency.resize(n as usize, Vec::new());
+        if !matches!(self.topology, Topology::Grid { .. }) {
+            for (i, list) in adjacency.iter_mut().enumerate() {
+                list.retain(|&j| j != i as u32 && j < n);
+                list.sort_unstable();
+                list.dedup();
+            }
+        }
+        adjacency
+    }
+
+    /// The network's nodes with starting inventory, before any tick.
+    pub fn build_nodes(&self) -> Vec<SimNode> {
+        let adjacency = self.neighbors();
+        let roles: Vec<NodeRole> = (0..self.node_count)
+            .map(|i| self.roles.role(i, &adjacency[i as usize]))
+            .collect();
+        let egress_count = roles.iter().filter(|&&r| r == NodeRole::Egress).count() as u32;
+        let grid_width = match self.topology {
+            Topology::Grid { width, .. } => Some(width),
+            _ => None,
+        };
+
+        let mut nodes: Vec<SimNode> = adjacency.into_iter().zip(roles).enumerate()
+            .map(|(i, (neighbors, role))| {
+                let id = i as u32;
+                let position = match grid_width {
+                    Some(width) => ((id % width) as f64, (id / width) as f64),
+                    None => circle_position(id, self.node_count),
+                };
+                let strategy = self.strategies.strategy(id, &neighbors);
+                new_node(id, role, strategy, neighbors, position, self.node_count, egress_count)
+            })
+            .collect();
+        let sites = match self.roles {
+            RoleAssignment::Cyclic => (0..self.node_count).map(|id| id / SITE_SIZE).collect(),
+            _ => nearest_egress_sites(&nodes),
+        };
+        for (node, site) in nodes.iter_mut().zip(sites) {
+            node.site = self.sites.get(node.id as usize).copied().unwrap_or(site);
+        }
+        nodes
+    }
+
+    /// Everything wrong with the network this builder describes:
+    /// malformed explicit links, then `check_nodes` on the built network.
+    pub fn check(&self) -> Vec<TopologyIssue> {
+        let mut nodes = self.build_nodes();
+        routing::compute_egress_distances(&mut nodes);
+        let mut issues = self.link_issues();
+        issues.extend(check_nodes(&nodes));
+        issues
+    }
+
+    /// Self-links, repeated links and unknown neighbor ids in an explicit
+    /// adjacency, all of which `neighbors` drops. Generated graphs have none.
+    pub fn link_issues(&self) -> Vec<TopologyIssue> {
+        let Topology::Adjacency(adjacency) = &self.topology else {
+            return Vec::new();
+        };
+        let (mut self_links, mut duplicates, mut unknown) = (Vec::new(), Vec::new(), Vec::new());
+        for (i, list) in adjacency.iter().enumerate().take(self.node_count as usize) {
+            let id = i as u32;
+            if list.contains(&id) {
+                self_links.push(id);
+            }
+            if list.iter().any(|&j| j >= self.node_count) {
+                unknown.push(id);
+            }
+            let mut sorted = list.clone();
+            sorted.sort_unstable();
+            if sorted.windows(2).any(|w| w[0] == w[1] && w[0] != id) {
+                duplicates.push(id);
+            }
+        }
+        [
+            (TopologyIssueKind::SelfLink, self_links),
+            (TopologyIssueKind::DuplicateEdge, duplicates),
+            (TopologyIssueKind::UnknownNeighbor, unknown),
+        ]
+        .into_iter()
+        .filter(|(_, nodes)| !nodes.is_empty())
+        .map(|(kind, nodes)| TopologyIssue::new(kind, nodes))
+        .collect()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Sanity checks
+// ---------------------------------------------------------------------------
+
+/// Errors leave part of the network unable to settle at all; warnings
+/// waste nodes or links but every payment still has a way out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+pub enum IssueSeverity {
+    Warning = 0,
+    Error = 1,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+pub enum TopologyIssueKind {
+    NoIngress = 0,           // no node spawns traffic
+    NoEgress = 1,            // no node settles it
+    IngressCannotSettle = 2, // Ingress with no route to any live Egress
+    UnreachableEgress = 3,   // Egress no live Ingress can route to
+    IsolatedNode = 4,        // a live node with no links to live nodes
+    SelfLink = 5,            // explicit adjacency lists a node as its own neighbor
+    DuplicateEdge = 6,       // explicit adjacency lists a neighbor twice
+    UnknownNeighbor = 7,     // explicit adjacency names an id past the network
+}
+
+impl TopologyIssueKind {
+    pub fn severity(self) -> IssueSeverity {
+        match self {
+            Self::NoIngress | Self::NoEgress | Self::IngressCannotSettle => IssueSeverity::Error,
+            _ => IssueSeverity::Warning,
+        }
+    }
+}
+
+/// One problem with a network, and the nodes it concerns (empty for
+/// network-wide problems such as a missing role).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct TopologyIssue {
+    pub severity: IssueSeverity,
+    pub kind: TopologyIssueKind,
+    pub nodes: Vec<u32>,
+    pub message: String,
+}
+
+impl TopologyIssue {
+    fn new(kind: TopologyIssueKind, nodes: Vec<u32>) -> Self {
+        let what = match kind {
+            TopologyIssueKind::NoIngress => "no Ingress node: nothing spawns traffic",
+            TopologyIssueKind::NoEgress => "no Egress node: nothing can settle",
+            TopologyIssueKind::IngressCannotSettle => "Ingress with no route to any Egress",
+            TopologyIssueKind::UnreachableEgress => "Egress no Ingress can reach",
+            TopologyIssueKind::IsolatedNode => "nodes with no live links",
+            TopologyIssueKind::SelfLink => "self-links dropped",
+            TopologyIssueKind::DuplicateEdge => "repeated links dropped",
+            TopologyIssueKind::UnknownNeighbor => "links to unknown nodes dropped",
+        };
+        let message = if nodes.is_empty() { what.to_string() } else { format!("{} (nodes {:?})", what, nodes) };
+        Self { severity: kind.severity(), kind, nodes, message }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == IssueSeverity::Error
+    }
+}
+
+/// Role coverage and reachability of live (non-Disabled) nodes, which must
+/// have current `distance_to_egress`. Links are followed as listed, so a
+/// one-way link counts only in its own direction.
+pub fn check_nodes(nodes: &[SimNode]) -> Vec<TopologyIssue> {
+    let live = |role: NodeRole| role != NodeRole::Disabled;
+    let ids_with = |f: &dyn Fn(&SimNode) -> bool| -> Vec<u32> {
+        nodes.iter().filter(|n| live(n.role) && f(n)).map(|n| n.id).collect()
+    };
+    let mut issues = Vec::new();
+
+    let ingress = ids_with(&|n| n.role == NodeRole::Ingress);
+    if ingress.is_empty() {
+        issues.push(TopologyIssue::new(TopologyIssueKind::NoIngress, Vec::new()));
+    }
+    if ids_with(&|n| n.role == NodeRole::Egress).is_empty() {
+        issues.push(TopologyIssue::new(TopologyIssueKind::NoEgress, Vec::new()));
+    } else {
+        // Stranded Ingress; with no Egress at all, NoEgress already says so
+        let stranded = ids_with(&|n| n.role == NodeRole::Ingress && n.distance_to_egress == routing::UNREACHABLE);
+        if !stranded.is_empty() {
+            issues.push(TopologyIssue::new(TopologyIssueKind::IngressCannotSettle, stranded));
+        }
+    }
+
+    // Egress outside everything live Ingress can reach
+    if !ingress.is_empty() {
+        let mut reached = vec![false; nodes.len()];
+        let mut queue = ingress.clone();
+        for &id in &queue {
+            reached[id as usize] = true;
+        }
+        while let Some(id) = queue.pop() {
+            for &n in &nodes[id as usize].neighbors {
+                if (n as usize) < nodes.len() && !reached[n as usize] && live(nodes[n as usize].role) {
+                    reached[n as usize] = true;
+                    queue.push(n);
+                }
+            }
+        }
+        let unreached = ids_with(&|n| n.role == NodeRole::Egress && !reached[n.id as usize]);
+        if !unreached.is_empty() {
+            issues.push(TopologyIssue::new(TopologyIssueKind::UnreachableEgress, unreached));
+        }
+    }
+
+    if nodes.len() > 1 {
+        let isolated = ids_with(&|n| n.neighbors.iter().all(|&j| !live(nodes[j as usize].role)));
+        if !isolated.is_empty() {
+            issues.push(TopologyIssue::new(TopologyIssueKind::IsolatedNode, isolated));
+        }
+    }
+    issues
+}
+
+/// Each node's site by the Egress fewest links away: that Egress's rank in id
+/// order, or the rank after the last for nodes no Egress reaches. Sources
+/// are expanded in id order, so ties go to the lower Egress id.
+fn nearest_egress_sites(nodes: &[SimNode]) -> Vec<u32> {
+    let mut sites = vec![None; nodes.len()];
+    let mut queue = VecDeque::new();
+    let mut egress_count = 0;
+    for node in nodes.iter().filter(|n| n.role == NodeRole::Egress) {
+        sites[node.id as usize] = Some(egress_count);
+        egress_count += 1;
+        queue.push_back(node.id as usize);
+    }
+    while let Some(at) = queue.pop_front() {
+        for &next in &nodes[at].neighbors {
+            let next = next as usize;
+            if next < nodes.len() && sites[next].is_none() {
+                sites[next] = sites[at];
+                queue.push_back(next);
+            }
+        }
+    }
+    sites.into_iter().map(|s| s.unwrap_or(egress_count)).collect()
+}
+
+/// A fresh node, stocked with the network-scaled inventory for a network of
+/// `node_count` nodes, `egress_count` of them Egress. It sits in site 0
+/// until placed.
+pub fn new_node(
+    id: u32, role: NodeRole, strategy: NodeStrategy, neighbors: Vec<u32>,
+    (x, y): (f64, f64), node_count: u32, egress_count: u32,
+) -> SimNode {
+    // Scale initial node inventory with network size
+    let (inventory_fiat, inventory_crypto) = inventory::initial_inventory(
+        inventory::InventoryPolicy::NetworkScaled, role, node_count, egress_count,
+        inventory::ExpectedDemand::auto_traffic(0.2),
+    );
+    SimNode {
+        id, role, x, y,
+        inventory_fiat, inventory_crypto,
+        current_buffer_count: 0,
+        strategy,
+        neighbors, distance_to_egress: u32::MAX,
+        total_fees_earned: 0.0, accumulated_work: 0.0,
+        pressure: 0.0,
+        // v0.2 fields
+        transit_fee: 0.01,
+        bandwidth: 100.0,
+        latency: 1.0,
+        uptime: 1.0,
+        tier_preference: None,
+        upi_active: true,
+        ngauge_running: true,
+        kyc_valid: true,
+        kyc_attested_tick: 0,
+        trust_score: 1.0,
+        max_packets_per_tick: 0,
+        queue_latency: 0.0,
+        site: 0,
+    }
+}
+
+// The original grid, one-directional links included: a node in the last
+// row links down only while the grid has room.
+fn grid(n: u32, width: u32, height: u32) -> Vec<Vec<u32>> {
+    (0..n)
+        .map(|i| {
+            let mut neighbors = Vec::new();
+            let row = i / width;
+            let col = i % width;
+            if col > 0 { neighbors.push(i - 1); }
+            if col < width - 1 && i + 1 < n { neighbors.push(i + 1); }
+            if row > 0 { neighbors.push(i - width); }
+            if row < height - 1 && i + width < n { neighbors.push(i + width); }
+            neighbors
+        })
+        .collect()
+}
+
+fn ring(n: u32, k: u32) -> Vec<Vec<u32>> {
+    let mut adjacency = vec![Vec::new(); n as usize];
+    for i in 0..n {
+        for j in 1..=k.min(n.saturating_sub(1) / 2).max(1) {
+            let to = (i + j) % n;
+            link(&mut adjacency, i, to);
+        }
+    }
+    adjacency
+}
+
+fn small_world(n: u32, k: u32, rewire_probability: f64, rng: &mut SplitMix64) -> Vec<Vec<u32>> {
+    let mut adjacency = ring(n, k);
+    if n < 3 {
+        return adjacency;
+    }
+    for i in 0..n {
+        for j in 1..=k.min((n - 1) / 2) {
+            let to = (i + j) % n;
+            if rng.next_f64() >= rewire_probability || !adjacency[i as usize].contains(&to) {
+                continue;
+            }
+            // Rewire to a node not already linked; keep the link if none is free
+            if adjacency[i as usize].len() + 1 >= n as usize {
+                continue;
+            }
+            let target = loop {
+                let t = (rng.next_u64() % n as u64) as u32;
+                if t != i && !adjacency[i as usize].contains(&t) {
+                    break t;
+                }
+            };
+            adjacency[i as usize].retain(|&x| x != to);
+            adjacency[to as usize].retain(|&x| x != i);
+            link(&mut adjacency, i, target);
+        }
+    }
+    adjacency
+}
+
+fn scale_free(n: u32, m: u32, rng: &mut SplitMix64) -> Vec<Vec<u32>> {
+    let mut adjacency = vec![Vec::new(); n as usize];
+    let seed_nodes = (m + 1).min(n);
+    // Every link end, so a uniform pick is proportional to degree
+    let mut ends = Vec::new();
+    for i in 0..seed_nodes {
+        for j in 0..i {
+            link(&mut adjacency, i, j);
+            ends.extend([i, j]);
+        }
+    }
+    for i in seed_nodes..n {
+        let mut targets: Vec<u32> = Vec::new();
+        while (targets.len() as u32) < m.min(i) {
+            let t = if ends.is_empty() { (rng.next_u64() % i as u64) as u32 } else { ends[(rng.next_u64() % ends.len() as u64) as usize] };
+            if !targets.contains(&t) {
+                targets.push(t);
+            }
+        }
+        for t in targets {
+            link(&mut adjacency, i, t);
+            ends.extend([i, t]);
+        }
+    }
+    adjacency
+}
+
+fn link(adjacency: &mut [Vec<u32>], a: u32, b: u32) {
+    if a != b && !adjacency[a as usize].contains(&b) {
+        adjacency[a as usize].push(b);
+        adjacency[b as usize].push(a);
+    }
+}
+
+// Nodes of non-grid networks sit on a circle with the default grid's extent
+fn circle_position(id: u32, n: u32) -> (f64, f64) {
+    let angle = std::f64::consts::TAU * id as f64 / n.max(1) as f64;
+    let (cx, cy) = ((GRID_WIDTH - 1) as f64 / 2.0, (GRID_HEIGHT - 1) as f64 / 2.0);
+    (cx + cx * angle.cos(), cy + cy * angle.sin())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn degrees(adjacency: &[Vec<u32>]) -> Vec<usize> {
+        adjacency.iter().map(Vec::len).collect()
+    }
+
+    fn symmetric(adjacency: &[Vec<u32>]) -> bool {
+        adjacency.iter().enumerate()
+            .all(|(i, list)| list.iter().all(|&j| adjacency[j as usize].contains(&(i as u32))))
+    }
+
+    #[test]
+    fn test_default_is_the_original_grid() {
+        let adjacency = TopologyBuilder::new(30).neighbors();
+        assert_eq!(adjacency[0], [1, 6]);
+        assert_eq!(adjacency[7], [6, 8, 1, 13]);
+        // Row 3 is the grid's last: it links up but not down to row 4
+        assert_eq!(adjacency[19], [18, 20, 13]);
+        assert_eq!(adjacency[25], [24, 26, 19]);
+
+        let nodes = TopologyBuilder::new(8).build_nodes();
+        let roles: Vec<NodeRole> = nodes.iter().map(|n| n.role).collect();
+        assert_eq!(roles[..5], [NodeRole::Ingress, NodeRole::Egress, NodeRole::Transit, NodeRole::NGauge, NodeRole::Ingress]);
+        assert_eq!(nodes[4].strategy, NodeStrategy::Greedy);
+        assert_eq!((nodes[7].x, nodes[7].y), (1.0, 1.0));
+    }
+
+    #[test]
+    fn test_ring_and_small_world_keep_the_link_count() {
+        let ring = TopologyBuilder::new(20).ring(2).neighbors();
+        assert!(degrees(&ring).iter().all(|&d| d == 4));
+        assert_eq!(ring[0], [1, 2, 18, 19]);
+
+        let builder = TopologyBuilder::new(50).small_world(2, 0.3).seed(9);
+        let rewired = builder.neighbors();
+        assert_eq!(rewired, builder.neighbors(), "same seed, same graph");
+        assert!(symmetric(&rewired));
+        assert_eq!(degrees(&rewired).iter().sum::<usize>(), 50 * 4);
+        assert_ne!(rewired, TopologyBuilder::new(50).ring(2).neighbors());
+        assert_eq!(TopologyBuilder::new(50).small_world(2, 0.0).neighbors(), TopologyBuilder::new(50).ring(2).neighbors());
+    }
+
+    #[test]
+    fn test_scale_free_grows_hubs() {
+        let adjacency = TopologyBuilder::new(500).scale_free(2).seed(1).neighbors();
+        assert!(symmetric(&adjacency));
+        let degrees = degrees(&adjacency);
+        // A 3-clique, then two links per node
+        assert_eq!(degrees.iter().sum::<usize>(), 2 * (3 + 2 * 497));
+        assert!(degrees.iter().all(|&d| d >= 2));
+        assert!(*degrees.iter().max().unwrap() > 20, "preferential attachment makes hubs: {:?}", degrees.iter().max());
+    }
+
+    #[test]
+    fn test_explicit_adjacency_and_pluggable_roles() {
+        let builder = TopologyBuilder::new(4)
+            .edges(vec![0, 1, 1, 2, 2, 2, 2, 9, 3, 0])
+            .roles(vec![1, 0, 7])
+            .strategies(vec![2]);
+        assert_eq!(builder.neighbors(), [vec![1, 3], vec![0, 2], vec![1], vec![0]]);
+        let nodes = builder.build_nodes();
+        // Unknown role 7 and node 3 past the list are dealt cyclically
+        let roles: Vec<NodeRole> = nodes.iter().map(|n| n.role).collect();
+        assert_eq!(roles, [NodeRole::Egress, NodeRole::Ingress, NodeRole::Transit, NodeRole::NGauge]);
+        assert_eq!(nodes[0].strategy, NodeStrategy::Passive);
+
+        let hubs = TopologyBuilder::new(0)
+            .adjacency(vec![vec![1, 2, 3], vec![0], vec![0], vec![0]])
+            .role_assignment(RoleAssignment::Custom(|_, neighbors| {
+                if neighbors.len() > 1 { NodeRole::Egress } else { NodeRole::Ingress }
+            }))
+            .build_nodes();
+        assert_eq!(hubs.len(), 4);
+        assert_eq!(hubs.iter().filter(|n| n.role == NodeRole::Egress).count(), 1);
+        assert_eq!(hubs[0].inventory_crypto, inventory::initial_inventory(
+            inventory::InventoryPolicy::NetworkScaled, NodeRole::Egress, 4, 1,
+            inventory::ExpectedDemand::auto_traffic(0.2)).1);
+    }
+
+    #[test]
+    fn test_sites_follow_the_nearest_egress() {
+        use NodeRole::*;
+        let sites = |nodes: Vec<SimNode>| -> Vec<u32> { nodes.iter().map(|n| n.site).collect() };
+        assert_eq!(sites(TopologyBuilder::new(8).build_nodes()), [0, 0, 0, 0, 1, 1, 1, 1]);
+
+        // Egress 2 and 6 on a ring of 8: nodes 0 and 4 are two hops from both
+        let mut roles = vec![Transit; 8];
+        roles[2] = Egress;
+        roles[6] = Egress;
+        let ring = TopologyBuilder::new(8).ring(1).role_assignment(RoleAssignment::Explicit(roles));
+        assert_eq!(sites(ring.clone().build_nodes()), [0, 0, 0, 0, 0, 1, 1, 1]);
+        assert_eq!(sites(ring.sites(vec![5, 5]).build_nodes()), [5, 5, 0, 0, 0, 1, 1, 1]);
+
+        // Nodes no Egress reaches share the site after the last
+        let cut = TopologyBuilder::new(0)
+            .adjacency(vec![vec![1], vec![0], vec![3], vec![2]])
+            .role_assignment(RoleAssignment::Explicit(vec![Ingress, Egress, Ingress, Transit]));
+        assert_eq!(sites(cut.build_nodes()), [0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn test_check_flags_stranded_ingress_and_malformed_links() {
+        use NodeRole::*;
+        let roles = [Ingress, Egress, Ingress, Transit, Egress, Transit];
+        // {0, 1} settles; {2, 3} has no Egress; Egress 4 and node 5 are cut off
+        let builder = TopologyBuilder::new(0)
+            .adjacency(vec![vec![1, 1], vec![0], vec![3, 2], vec![2, 9], vec![], vec![]])
+            .role_assignment(RoleAssignment::Explicit(roles.to_vec()));
+        let kinds = |issues: &[TopologyIssue]| -> Vec<(TopologyIssueKind, Vec<u32>)> {
+            issues.iter().map(|i| (i.kind, i.nodes.clone())).collect()
+        };
+        let issues = builder.check();
+        assert_eq!(kinds(&issues), [
+            (TopologyIssueKind::SelfLink, vec![2]),
+            (TopologyIssueKind::DuplicateEdge, vec![0]),
+            (TopologyIssueKind::UnknownNeighbor, vec![3]),
+            (TopologyIssueKind::IngressCannotSettle, vec![2]),
+            (TopologyIssueKind::UnreachableEgress, vec![4]),
+            (TopologyIssueKind::IsolatedNode, vec![4, 5]),
+        ]);
+        assert_eq!(issues.iter().filter(|i| i.is_error()).count(), 1);
+        assert_eq!(issues[3].message, "Ingress with no route to any Egress (nodes [2])");
+
+        let no_egress = TopologyBuilder::new(3).ring(1)
+            .role_assignment(RoleAssignment::Explicit(vec![Ingress, Transit, Transit]));
+        assert_eq!(kinds(&no_egress.check()), [(TopologyIssueKind::NoEgress, vec![])]);
+        assert!(TopologyBuilder::new(24).check().is_empty());
+        assert!(TopologyBuilder::new(200).scale_free(2).seed(3).check().is_empty());
+    }
+}
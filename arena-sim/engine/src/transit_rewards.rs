@@ -0,0 +1,118 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Transit Reward Weighting
+
+use std::collections::BTreeMap;
+
+use crate::types::SimNode;
+
+/// Fixed-point scale applied to bandwidth so capacity weights survive the
+/// integer weights the core splitter takes.
+const CAPACITY_WEIGHT_SCALE: f64 = 1_000.0;
+
+/// How the settlement fee's transit pool is split among the route's relays.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TransitWeighting {
+    /// Every relay on the route gets the same share
+    #[default]
+    Equal = 0,
+    /// Shares follow the ticks each relay had custody of the packet, from
+    /// receipt until the next hop received it, plus one for forwarding it;
+    /// queueing and scarce verification capacity lengthen custody
+    HoldingTime = 1,
+    /// Shares follow each relay's bandwidth
+    Capacity = 2,
+}
+
+impl TransitWeighting {
+    /// Map a numeric discriminant (as sent from JS) back to a weighting.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Equal),
+            1 => Some(Self::HoldingTime),
+            2 => Some(Self::Capacity),
+            _ => None,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// HoldLedger - custody ticks of each relay for each live packet
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct HoldLedger {
+    holds: BTreeMap<u64, BTreeMap<u32, u64>>,
+}
+
+impl HoldLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `node_id` had custody of packet `packet_id` for `ticks`.
+    pub fn record(&mut self, packet_id: u64, node_id: u32, ticks: u64) {
+        *self.holds.entry(packet_id).or_default().entry(node_id).or_insert(0) += ticks;
+    }
+
+    /// Remove and return a packet's holds once it settles.
+    pub fn take(&mut self, packet_id: u64) -> BTreeMap<u32, u64> {
+        self.holds.remove(&packet_id).unwrap_or_default()
+    }
+
+    /// Drop holds of packets that are no longer live (reverted or dissolved).
+    pub fn retain_live(&mut self, is_live: impl Fn(u64) -> bool) {
+        self.holds.retain(|&id, _| is_live(id));
+    }
+
+    pub fn len(&self) -> usize {
+        self.holds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.holds.is_empty()
+    }
+}
+
+/// Weight of each relay in `transit_ids` under `weighting`. `Equal` yields
+/// all zeros, which the core splitter treats as an equal split.
+pub fn transit_weights(
+    weighting: TransitWeighting,
+    nodes: &[SimNode],
+    transit_ids: &[u32],
+    holds: &BTreeMap<u32, u64>,
+) -> Vec<(u32, u64)> {
+    transit_ids.iter()
+        .map(|&id| {
+            let weight = match weighting {
+                TransitWeighting::Equal => 0,
+                TransitWeighting::HoldingTime => 1 + holds.get(&id).copied().unwrap_or(0),
+                TransitWeighting::Capacity => nodes.get(id as usize)
+                    .map_or(0, |n| (n.bandwidth.max(0.0) * CAPACITY_WEIGHT_SCALE).round() as u64),
+            };
+            (id, weight)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_holding_time_weights_follow_ledger() {
+        let mut ledger = HoldLedger::new();
+        ledger.record(7, 2, 3);
+        ledger.record(7, 2, 1);
+        ledger.record(7, 6, 0);
+        ledger.record(8, 2, 9);
+
+        let holds = ledger.take(7);
+        let weights = transit_weights(TransitWeighting::HoldingTime, &[], &[2, 6, 10], &holds);
+        assert_eq!(weights, vec![(2, 5), (6, 1), (10, 1)]);
+        assert_eq!(transit_weights(TransitWeighting::Equal, &[], &[2, 6], &holds),
+            vec![(2, 0), (6, 0)]);
+
+        ledger.retain_live(|id| id != 8);
+        assert!(ledger.is_empty());
+    }
+}
@@ -1,22 +1,29 @@
 // Copyright 2026 Hypermesh Foundation. All rights reserved.
 // Caesar Protocol Simulation Suite ("The Arena") - Type Definitions
+//
+// Every serialized type derives `Tsify`, so wasm-pack emits matching
+// TypeScript interfaces into the generated `.d.ts` alongside the wasm package.
 
 use serde::{Serialize, Deserialize};
+use tsify::Tsify;
+
+use crate::corridor::{CorridorStats, CorridorTag};
+use crate::regional_price::RegionPriceStats;
+use crate::speculation::SpeculationStats;
+use crate::latency::TierLatencyStats;
+use crate::liquidity_provider::LiquidityStats;
 
 // ─── Market Tier (v0.2) ─────────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Tsify)]
 pub enum MarketTier {
+    #[default]
     L0 = 0,
     L1 = 1,
     L2 = 2,
     L3 = 3,
 }
 
-impl Default for MarketTier {
-    fn default() -> Self { MarketTier::L0 }
-}
-
 impl MarketTier {
     pub fn fee_cap(&self) -> f64 {
         match self {
@@ -65,6 +72,17 @@ impl MarketTier {
         }
     }
 
+    /// Map a numeric discriminant (as sent from JS) back to a tier.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::L0),
+            1 => Some(Self::L1),
+            2 => Some(Self::L2),
+            3 => Some(Self::L3),
+            _ => None,
+        }
+    }
+
     pub fn from_value(value: f64) -> Self {
         if value <= 10.0 {
             Self::L0
@@ -80,7 +98,7 @@ impl MarketTier {
 
 // ─── Node Role ───────────────────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Tsify)]
 pub enum NodeRole {
     Ingress = 0,
     Egress = 1,
@@ -89,22 +107,61 @@ pub enum NodeRole {
     Disabled = 4,
 }
 
+impl NodeRole {
+    /// Map a numeric discriminant (as sent from JS) back to a role.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Ingress),
+            1 => Some(Self::Egress),
+            2 => Some(Self::Transit),
+            3 => Some(Self::NGauge),
+            4 => Some(Self::Disabled),
+            _ => None,
+        }
+    }
+}
+
 // ─── Node Strategy (E9) ─────────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Tsify)]
 pub enum NodeStrategy {
     RiskAverse = 0,
     Greedy = 1,
     Passive = 2,
+    // Adversaries (see `adversary`)
+    FeeGouger = 3,
+    PacketDropper = 4,
+    RouteLiar = 5,
+    SelectiveCensor = 6,
+}
+
+impl NodeStrategy {
+    /// Map a numeric discriminant (as sent from JS) back to a strategy.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::RiskAverse),
+            1 => Some(Self::Greedy),
+            2 => Some(Self::Passive),
+            3 => Some(Self::FeeGouger),
+            4 => Some(Self::PacketDropper),
+            5 => Some(Self::RouteLiar),
+            6 => Some(Self::SelectiveCensor),
+            _ => None,
+        }
+    }
 }
 
 pub fn default_strategy() -> NodeStrategy {
     NodeStrategy::Passive
 }
 
+pub fn default_trust() -> f64 {
+    1.0
+}
+
 // ─── Packet Status (canonical: matches core PacketState) ─────────────────────
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Tsify)]
 pub enum PacketStatus {
     Minted = 0,       // just created at ingress
     InTransit = 1,    // moving through mesh
@@ -120,6 +177,23 @@ pub enum PacketStatus {
 }
 
 impl PacketStatus {
+    /// Map a numeric discriminant (as sent from JS) back to a status.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Minted),
+            1 => Some(Self::InTransit),
+            2 => Some(Self::Delivered),
+            3 => Some(Self::Settling),
+            4 => Some(Self::Settled),
+            5 => Some(Self::Held),
+            6 => Some(Self::Stalled),
+            7 => Some(Self::Dispersed),
+            8 => Some(Self::Expired),
+            9 => Some(Self::Refunded),
+            10 => Some(Self::Dissolved),
+            _ => None,
+        }
+    }
     pub fn is_terminal(&self) -> bool {
         matches!(self, Self::Settled | Self::Refunded | Self::Dissolved)
     }
@@ -128,9 +202,69 @@ impl PacketStatus {
     }
 }
 
+// ─── Orbit Reason ────────────────────────────────────────────────────────────
+
+/// Why a packet was put in orbit (Held) on its last routing attempt.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Tsify)]
+pub enum OrbitReason {
+    NoLiquidEgress = 0,      // no Egress anywhere holds liquidity to route toward
+    HopLimit = 1,            // bounced past the tier's hop limit
+    DisabledDestination = 2, // every next hop (or the hop in flight) is Disabled
+}
+
+impl OrbitReason {
+    /// Liquidity failures, as opposed to topology failures.
+    pub fn is_liquidity(&self) -> bool {
+        matches!(self, Self::NoLiquidEgress)
+    }
+}
+
+// ─── Revert Reason ───────────────────────────────────────────────────────────
+
+/// Why a packet was refunded to its sender.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Tsify)]
+pub enum RevertReason {
+    TtlExpired = 0,     // reached its tier TTL
+    OrbitTimeout = 1,   // orbited past its limit for topology reasons
+    NoLiquidEgress = 2, // orbited past its limit with no liquid Egress to route to
+    Cancelled = 3,      // withdrawn by its sender (`cancel_packet`)
+    CircuitBreaker = 4, // halted by the conservation circuit breaker; reserved,
+                        // as a trip does not yet stop settlement
+    HopLimit = 5,       // exhausted its hops under `HopExhaustion::Revert`
+    Dropped = 6,        // discarded by a PacketDropper
+    Censored = 7,       // refused by a SelectiveCensor
+    NodeRemoved = 8,    // buffered at a node `remove_node` took out, with no live neighbor to move to
+}
+
+impl RevertReason {
+    pub const COUNT: usize = 9;
+
+    /// Reason for an orbit timeout, from why the packet was orbiting.
+    pub fn from_orbit(reason: Option<OrbitReason>) -> Self {
+        match reason {
+            Some(OrbitReason::NoLiquidEgress) => Self::NoLiquidEgress,
+            _ => Self::OrbitTimeout,
+        }
+    }
+}
+
+// ─── GovernorSnapshot ────────────────────────────────────────────────────────
+
+/// Governor pricing in force when a packet was minted or took a hop, enough
+/// to re-derive the fee it was quoted then.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct GovernorSnapshot {
+    pub tick: u64,
+    pub fee_rate: f64,
+    // Core fee modifier of the packet's tier
+    pub tier_modifier: f64,
+    // Quadrant letter (A-F) of `governance_quadrant`
+    pub quadrant: char,
+}
+
 // ─── SimPacket ───────────────────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
 pub struct SimPacket {
     pub id: u64,
     pub original_value: f64,
@@ -143,6 +277,12 @@ pub struct SimPacket {
     pub route_history: Vec<u32>,
     #[serde(default)]
     pub orbit_start_tick: Option<u64>,
+    // Why the packet is orbiting (None while it is not Held)
+    #[serde(default)]
+    pub orbit_reason: Option<OrbitReason>,
+    // Why the packet was refunded (None unless it reverted)
+    #[serde(default)]
+    pub revert_reason: Option<RevertReason>,
     // v0.2 fields
     #[serde(default)]
     pub tier: MarketTier,
@@ -158,11 +298,48 @@ pub struct SimPacket {
     pub fee_schedule: Vec<f64>,
     #[serde(default)]
     pub spawn_tick: u64,
+    // Spawn cohort label (spawn tick / cohort width) for vintage analysis
+    #[serde(default)]
+    pub cohort: u32,
+    // Component payment values of an ingress aggregate (empty for a single payment)
+    #[serde(default)]
+    pub manifest: Vec<f64>,
+    // FNV-1a digest of the full route, including hops no longer in route_history
+    #[serde(default)]
+    pub route_digest: u64,
+    // Each node on the route with its visit count, in first-visit order;
+    // never compacted, unlike route_history
+    #[serde(default)]
+    pub route_visits: Vec<(u32, u32)>,
+    // Governor pricing at spawn, then at each hop; aligned with route_history
+    #[serde(default)]
+    pub governor_snapshots: Vec<GovernorSnapshot>,
+    // Spawn corridor and the region it settles in (None for network-wide traffic)
+    #[serde(default)]
+    pub corridor: Option<CorridorTag>,
+}
+
+#[cfg(test)]
+impl SimPacket {
+    /// A Minted packet of `value` at `origin` on tick 0, tiered by value,
+    /// with no budget, TTL or hop limit; tests override what they exercise.
+    pub(crate) fn test_default(origin: u32, value: f64) -> Self {
+        Self {
+            id: 0, original_value: value, current_value: value,
+            arrival_tick: 0, status: PacketStatus::Minted,
+            origin_node: origin, target_node: None, hops: 0,
+            route_history: vec![origin], orbit_start_tick: None, orbit_reason: None, revert_reason: None,
+            tier: MarketTier::from_value(value), ttl: 0, hop_limit: 0,
+            fee_budget: 0.0, fees_consumed: 0.0, fee_schedule: vec![],
+            spawn_tick: 0, cohort: 0, manifest: vec![], route_digest: 0,
+            route_visits: vec![(origin, 1)], governor_snapshots: vec![], corridor: None,
+        }
+    }
 }
 
 // ─── SimNode ─────────────────────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
 pub struct SimNode {
     pub id: u32,
     pub role: NodeRole,
@@ -196,119 +373,754 @@ pub struct SimNode {
     pub ngauge_running: bool,
     #[serde(default)]
     pub kyc_valid: bool,
+    // Tick of the last KYC attestation (expiry clock origin)
+    #[serde(default)]
+    pub kyc_attested_tick: u64,
+    // Availability-weighted reputation in [0, 1]; see `availability`
+    #[serde(default = "default_trust")]
+    pub trust_score: f64,
+    // Packets settled or forwarded per tick at most; 0 = no cap (see `throughput`)
+    #[serde(default)]
+    pub max_packets_per_tick: u32,
+    // Ticks of deferred work waiting behind the throughput cap, last tick
+    #[serde(default)]
+    pub queue_latency: f64,
+    // Site the node belongs to, for batching, corridors and regions; see `topology`
+    #[serde(default)]
+    pub site: u32,
+}
+
+#[cfg(test)]
+impl SimNode {
+    /// A live, fully trusted Passive node at the origin with no links,
+    /// inventory or fees; tests override what they exercise.
+    pub(crate) fn test_default(id: u32, role: NodeRole) -> Self {
+        Self {
+            id, role, x: 0.0, y: 0.0,
+            inventory_fiat: 0.0, inventory_crypto: 0.0,
+            current_buffer_count: 0,
+            neighbors: vec![], distance_to_egress: 0,
+            total_fees_earned: 0.0, accumulated_work: 0.0,
+            strategy: NodeStrategy::Passive,
+            pressure: 0.0,
+            transit_fee: 0.0,
+            bandwidth: 100.0,
+            latency: 1.0,
+            uptime: 1.0,
+            tier_preference: None,
+            upi_active: true,
+            ngauge_running: true,
+            kyc_valid: true,
+            kyc_attested_tick: 0,
+            trust_score: 1.0,
+            max_packets_per_tick: 0,
+            queue_latency: 0.0,
+            site: 0,
+        }
+    }
 }
 
 // ─── WorldState ──────────────────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Engine state after each tick. Every field's doc ends in an `@field`
+/// annotation (unit, kind, formula) that build.rs compiles into
+/// `field_dictionary`; a field without one fails the build.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
 pub struct WorldState {
+    /// Ticks run so far
+    /// @field unit: ticks; kind: cumulative; formula: simulation::tick_core
     pub current_tick: u64,
+    /// Oracle gold price after any active price shocks
+    /// @field unit: price; kind: instantaneous; formula: price_shock::PriceShocks::apply
     pub gold_price: f64,
+    /// Deviation of the effective payout rate (net of fee and payout spread)
+    /// from the gold price, less 0.15 x panic level
+    /// @field unit: fraction; kind: instantaneous; formula: WP §5.1 gold peg
     pub peg_deviation: f64,
+    /// Velocity of money: value settled this tick over the average in-flight
+    /// float (EMA). A turnover rate, not a running total
+    /// @field unit: 1/tick; kind: instantaneous; formula: simulation::compute_monetary_velocity
     pub network_velocity: f64,
+    /// Offered demand level driving auto traffic
+    /// @field unit: index; kind: instantaneous; formula: set_demand_factor
     pub demand_factor: f64,
+    /// User panic level in [0, 1], scenario-set or endogenous
+    /// @field unit: index; kind: instantaneous; formula: panic_dynamics::PanicModel::update
     pub panic_level: f64,
+    /// Governor quadrant label this tick
+    /// @field unit: label; kind: instantaneous; formula: WP §5.1 governor quadrants
     pub governance_quadrant: String,
+    /// Governor status label this tick
+    /// @field unit: label; kind: instantaneous; formula: governor::GovernorOutput
     pub governance_status: String,
 
     // Thermodynamic Stats
+    /// Settlement rewards paid to Egress nodes so far
+    /// @field unit: value; kind: cumulative; formula: core_fee_distribution 80/20 split
     pub total_rewards_egress: f64,
+    /// Settlement rewards paid to Transit nodes so far
+    /// @field unit: value; kind: cumulative; formula: core_fee_distribution 80/20 split
     pub total_rewards_transit: f64,
+    /// Fees charged so far
+    /// @field unit: value; kind: cumulative; formula: sum(dust surcharges + hop fees + settlement fees) - trust rebates
     pub total_fees_collected: f64,
+    /// Value burned by demurrage and surge so far
+    /// @field unit: value; kind: cumulative; formula: sum(V x (1 - e^(-lambda))) per tick past grace + surge burns
     pub total_demurrage_burned: f64,
+    /// Governor base fee rate this tick
+    /// @field unit: fraction; kind: instantaneous; formula: adapter::params_to_fee_rate
     pub current_fee_rate: f64,
+    /// Governor demurrage rate this tick
+    /// @field unit: fraction/tick; kind: instantaneous; formula: governor::GovernorOutput
     pub current_demurrage_rate: f64,
+    /// Governor verification complexity this tick
+    /// @field unit: level; kind: instantaneous; formula: governor::GovernorOutput
     pub verification_complexity: u64,
+    /// NGauge work level in [0, 1] this tick
+    /// @field unit: index; kind: instantaneous; formula: engauge::update_ngauge_activity
     pub ngauge_activity_index: f64,
 
+    /// Conservation leakage: |input - output - burned - fees - active value|
+    /// @field unit: value; kind: instantaneous; formula: conservation::compute_conservation
     pub total_value_leaked: f64,
+    /// Reserved; always 0
+    /// @field unit: value; kind: instantaneous; formula: none
     pub total_network_utility: f64,
 
+    /// Gold price volatility from the selected `VolatilityEstimator` over its
+    /// window (rolling coefficient of variation by default)
+    /// @field unit: ratio; kind: instantaneous; formula: volatility::VolatilityModel::update
     #[serde(default)]
     pub volatility: f64,
+    /// Packets settled so far (aggregated payments count individually)
+    /// @field unit: packets; kind: cumulative; formula: simulation::tick_core
     #[serde(default)]
     pub settlement_count: u32,
+    /// Packets refunded so far (see `revert_reasons`)
+    /// @field unit: packets; kind: cumulative; formula: receipts::RevertLog
     #[serde(default)]
     pub revert_count: u32,
+    /// Packets orbiting (Held) this tick
+    /// @field unit: packets; kind: instantaneous; formula: OrbitReason
     #[serde(default)]
     pub orbit_count: u32,
+    /// Value minted into packets so far
+    /// @field unit: value; kind: cumulative; formula: sum(original_value) at mint
     #[serde(default)]
     pub total_input: f64,
+    /// Value paid out at Egress or refunded so far
+    /// @field unit: value; kind: cumulative; formula: sum(current_value) at settlement, refund or dissolution
     #[serde(default)]
     pub total_output: f64,
+    /// Value of every packet still buffered or in flight
+    /// @field unit: value; kind: instantaneous; formula: simulation::active_value
     #[serde(default)]
     pub active_value: f64,
+    /// Packets minted so far
+    /// @field unit: packets; kind: cumulative; formula: simulation::mint_packet
     #[serde(default)]
     pub spawn_count: u32,
 
     // E7: Organic ratio
+    /// NGauge work over scaled velocity on a rolling window, clamped to this
+    /// tick's value; low means speculative churn
+    /// @field unit: ratio; kind: instantaneous; formula: engauge::compute_organic_ratio
     #[serde(default)]
     pub organic_ratio: f64,
     // E8: Surge multiplier
+    /// Demurrage multiplier in the Bottleneck quadrant, 1 elsewhere
+    /// @field unit: multiplier; kind: instantaneous; formula: 1 / lambda capped at 3 when lambda < 0.5 under stress
     #[serde(default)]
     pub surge_multiplier: f64,
+    /// Smoothed liquidity coefficient (Egress capacity / in-flight value, EMA)
+    /// @field unit: ratio; kind: instantaneous; formula: 10-tick EMA of Egress crypto / in-flight value
+    #[serde(default)]
+    pub liquidity_lambda: f64,
 
     // v0.2 fields
+    /// Conservation circuit breaker tripped
+    /// @field unit: flag; kind: instantaneous; formula: core_conservation::ConservationLaw
     #[serde(default)]
     pub circuit_breaker_active: bool,
+    /// Reserved; always 0
+    /// @field unit: fraction; kind: instantaneous; formula: none
     #[serde(default)]
     pub ingress_throttle: f64,
+    /// Packets dissolved by demurrage so far
+    /// @field unit: packets; kind: cumulative; formula: dissolution::dissolve
     #[serde(default)]
     pub dissolved_count: u32,
+    /// Packets Held at nodes this tick
+    /// @field unit: packets; kind: instantaneous; formula: PacketStatus::Held
     #[serde(default)]
     pub held_count: u32,
+    /// Active packets per tier (L0..L3) this tick
+    /// @field unit: packets; kind: instantaneous; formula: MarketTier
     #[serde(default)]
     pub tier_distribution: [u32; 4],
+    /// Gold price x (1 + fee + speculation + float components)
+    /// @field unit: price; kind: instantaneous; formula: simulation::tick_core effective price composite
     #[serde(default)]
     pub effective_price_composite: f64,
+    /// Fees so far per active packet, as a fraction of the gold price
+    /// @field unit: fraction; kind: instantaneous; formula: total_fees_collected / active packets / gold_price
     #[serde(default)]
     pub network_fee_component: f64,
+    /// Ingress/Egress buffer imbalance premium, capped at 0.05
+    /// @field unit: fraction; kind: instantaneous; formula: 0.001 x (ingress / egress buffers - 1)
     #[serde(default)]
     pub speculation_component: f64,
+    /// In-flight value over total input premium, capped at 0.05
+    /// @field unit: fraction; kind: instantaneous; formula: 0.001 x active_value / total_input
     #[serde(default)]
     pub float_component: f64,
+    /// Effective fee rate per tier (L0..L3) after modifiers and tier caps
+    /// @field unit: fraction; kind: instantaneous; formula: core_governor tier fee modifiers
     #[serde(default)]
     pub tier_fee_rates: [f64; 4],
+    /// Mean all-in cost (fees + demurrage) per settled packet, per tier, as a
+    /// fraction of original value
+    /// @field unit: fraction; kind: cumulative; formula: cost::SettlementCostTracker::mean_costs
+    #[serde(default)]
+    pub tier_effective_cost: [f64; 4],
+    /// Value settled at Egress this tick, refunds and dissolutions aside
+    /// (numerator of network_velocity)
+    /// @field unit: value/tick; kind: instantaneous; formula: simulation::tick_core
+    #[serde(default)]
+    pub settled_value_per_tick: f64,
+    /// Share of last epoch's settled value through the top-1 Egress node
+    /// @field unit: fraction; kind: instantaneous; formula: concentration::EgressConcentration
+    #[serde(default)]
+    pub egress_top1_share: f64,
+    /// Share of last epoch's settled value through the top-3 Egress nodes
+    /// @field unit: fraction; kind: instantaneous; formula: concentration::EgressConcentration
+    #[serde(default)]
+    pub egress_top3_share: f64,
+    /// Last epoch's Egress concentration crossed its alert threshold
+    /// @field unit: flag; kind: instantaneous; formula: concentration::EgressConcentration
+    #[serde(default)]
+    pub egress_concentration_alert: bool,
+    /// Fees paid to NGauge verifiers so far
+    /// @field unit: value; kind: cumulative; formula: verification::VerificationMarket
+    #[serde(default)]
+    pub verification_fees_paid: f64,
+    /// Hops that found no NGauge capacity so far
+    /// @field unit: hops; kind: cumulative; formula: verification::VerificationMarket
+    #[serde(default)]
+    pub verification_shortfalls: u32,
+    /// Share of this tick's NGauge capacity sold
+    /// @field unit: fraction; kind: instantaneous; formula: verification::VerificationMarket
+    #[serde(default)]
+    pub ngauge_utilization: f64,
+    /// Ticks spent in each governor quadrant (A..F)
+    /// @field unit: ticks; kind: cumulative; formula: WP §5.1 governor quadrants
+    #[serde(default)]
+    pub quadrant_ticks: [u32; 6],
+    /// Governor quadrant changes so far
+    /// @field unit: transitions; kind: cumulative; formula: simulation::record_quadrant
+    #[serde(default)]
+    pub quadrant_transition_count: u32,
+    /// Corridor batches settled at Egress so far
+    /// @field unit: batches; kind: cumulative; formula: batching::BatchLedger
+    #[serde(default)]
+    pub settlement_batches: u32,
+    /// Egress inventory drawn by all settlements so far
+    /// @field unit: value; kind: cumulative; formula: batching::BatchLedger
+    #[serde(default)]
+    pub egress_inventory_drawn: f64,
+    /// Inventory saved by netting vs per-packet settlement so far
+    /// @field unit: value; kind: cumulative; formula: batching::BatchLedger
+    #[serde(default)]
+    pub batch_inventory_saved: f64,
+    /// Fees refunded to fully high-trust routes so far
+    /// @field unit: value; kind: cumulative; formula: rebate::RebatePool
+    #[serde(default)]
+    pub trust_rebates_paid: f64,
+    /// Settlements over fully high-trust routes so far
+    /// @field unit: packets; kind: cumulative; formula: rebate::route_is_trusted
+    #[serde(default)]
+    pub trusted_settlements: u32,
+    /// Unspent transit-funded rebate pool
+    /// @field unit: value; kind: instantaneous; formula: rebate::RebatePool
+    #[serde(default)]
+    pub rebate_pool_balance: f64,
+    /// Fee revenue forgone to volume discounts so far
+    /// @field unit: value; kind: cumulative; formula: discount::VolumeDiscounts
+    #[serde(default)]
+    pub volume_discount_forgone: f64,
+    /// Discounted settlements so far
+    /// @field unit: packets; kind: cumulative; formula: discount::VolumeDiscounts
+    #[serde(default)]
+    pub discounted_settlements: u32,
+    /// Share of all settled value so far from discounted origins
+    /// @field unit: fraction; kind: cumulative; formula: discount::VolumeDiscounts
+    #[serde(default)]
+    pub discounted_value_share: f64,
+    /// Spawns rejected by the dust policy so far
+    /// @field unit: packets; kind: cumulative; formula: dust::DustGuard
+    #[serde(default)]
+    pub dust_rejected: u32,
+    /// Dust surcharges collected so far
+    /// @field unit: value; kind: cumulative; formula: dust::DustGuard
+    #[serde(default)]
+    pub dust_surcharges: f64,
+    /// Mean spawn-to-settlement ticks of all non-dust packets settled so far
+    /// @field unit: ticks; kind: cumulative; formula: dust::DustGuard
+    #[serde(default)]
+    pub non_dust_settle_ticks: f64,
+    /// Payments coalesced at ingress so far
+    /// @field unit: payments; kind: cumulative; formula: aggregation::DustAggregator
+    #[serde(default)]
+    pub payments_aggregated: u32,
+    /// Aggregate packets routed so far
+    /// @field unit: packets; kind: cumulative; formula: aggregation::DustAggregator
+    #[serde(default)]
+    pub aggregate_packets: u32,
+    /// Aggregated payments settled so far (fanned out from their manifests)
+    /// @field unit: payments; kind: cumulative; formula: aggregation::DustAggregator
+    #[serde(default)]
+    pub aggregated_payments_settled: u32,
+    /// Share of all next-hop lookups so far served from the route cache
+    /// @field unit: fraction; kind: cumulative; formula: route_cache::RouteCache
+    #[serde(default)]
+    pub route_cache_hit_rate: f64,
+    /// Full route cache drops so far
+    /// @field unit: drops; kind: cumulative; formula: route_cache::RouteCache
+    #[serde(default)]
+    pub route_cache_invalidations: u32,
+    /// Share of shortest-path lookups so far served from the path cache
+    /// @field unit: fraction; kind: cumulative; formula: route_cache::PathCache
+    #[serde(default)]
+    pub path_cache_hit_rate: f64,
+    /// Full path cache drops so far
+    /// @field unit: drops; kind: cumulative; formula: route_cache::PathCache
+    #[serde(default)]
+    pub path_cache_invalidations: u32,
+    /// Fast-route bonuses paid so far
+    /// @field unit: value; kind: cumulative; formula: velocity_bonus::VelocityBonus
+    #[serde(default)]
+    pub velocity_bonus_paid: f64,
+    /// Slow-route penalties collected and not yet paid out
+    /// @field unit: value; kind: instantaneous; formula: velocity_bonus::VelocityBonus
+    #[serde(default)]
+    pub velocity_bonus_treasury: f64,
+    /// Total |settlement fee - spawn-quoted fee| so far
+    /// @field unit: value; kind: cumulative; formula: quote::quoted_fee
+    #[serde(default)]
+    pub quote_drift: f64,
+    /// Settlements whose fee differed from their quote so far
+    /// @field unit: packets; kind: cumulative; formula: quote::quoted_fee
+    #[serde(default)]
+    pub quote_mismatches: u32,
+    /// Composite liquidity stress in [0, 1]: mean of uncovered float
+    /// (1 - lambda), p90 buffer occupancy, orbit growth and revert rate
+    /// @field unit: index; kind: instantaneous; formula: stress::StressComponents::index
+    #[serde(default)]
+    pub stress_index: f64,
+    /// Packets orbiting this tick for lack of a liquid Egress
+    /// @field unit: packets; kind: instantaneous; formula: OrbitReason::NoLiquidEgress
+    #[serde(default)]
+    pub orbit_no_liquid_egress: u32,
+    /// Packets orbiting this tick past their hop limit
+    /// @field unit: packets; kind: instantaneous; formula: OrbitReason::HopLimit
+    #[serde(default)]
+    pub orbit_hop_limit: u32,
+    /// Packets orbiting this tick with no live next hop
+    /// @field unit: packets; kind: instantaneous; formula: OrbitReason::DisabledDestination
+    #[serde(default)]
+    pub orbit_disabled_destination: u32,
+    /// Bankrupt Egress exits so far
+    /// @field unit: nodes; kind: cumulative; formula: egress_exit::EgressLifecycle
+    #[serde(default)]
+    pub egress_exits: u32,
+    /// Recapitalized Egress re-entries so far
+    /// @field unit: nodes; kind: cumulative; formula: egress_exit::EgressLifecycle
+    #[serde(default)]
+    pub egress_reentries: u32,
+    /// Exited Egress nodes still out
+    /// @field unit: nodes; kind: instantaneous; formula: egress_exit::EgressLifecycle
+    #[serde(default)]
+    pub exited_egress_count: u32,
+    /// Value in flight between hops (the message queue), the float the
+    /// governor weighs for Bottleneck pressure
+    /// @field unit: value; kind: instantaneous; formula: message_queue value (conservation::compensated_sum)
+    #[serde(default)]
+    pub in_transit_float: f64,
+    /// Recent realized all-in cost (EMA), the elasticity curve's input
+    /// @field unit: fraction; kind: instantaneous; formula: elasticity::DemandElasticity
+    #[serde(default)]
+    pub realized_cost: f64,
+    /// Share of offered demand the realized cost leaves spawning
+    /// @field unit: fraction; kind: instantaneous; formula: elasticity::demand_retention
+    #[serde(default)]
+    pub demand_retention: f64,
+    /// Hop and settlement charges that would have taken a packet's combined
+    /// fees past its tier cap, each clamped to the cap at the charging site
+    /// @field unit: charges; kind: cumulative; formula: governor::FeeCaps::headroom
+    #[serde(default)]
+    pub fee_cap_breaches: u32,
+    /// This tick's payout conversion spread at Egress (0 with slippage off)
+    /// @field unit: fraction; kind: instantaneous; formula: slippage::PayoutSlippage::spread
+    #[serde(default)]
+    pub payout_spread: f64,
+    /// Value beneficiaries have lost to payout spreads so far
+    /// @field unit: value; kind: cumulative; formula: slippage::PayoutSlippage::pay_out
+    #[serde(default)]
+    pub payout_slippage: f64,
+    /// Reverts so far by `RevertReason` discriminant
+    /// @field unit: packets; kind: cumulative; formula: RevertReason
+    #[serde(default)]
+    pub revert_reasons: [u32; RevertReason::COUNT],
+    /// Gold price the oracle last published, which the governor steers on
+    /// @field unit: price; kind: instantaneous; formula: oracle::PriceOracle::price
+    #[serde(default)]
+    pub oracle_price: f64,
+    /// Ticks since the oracle last published a price (0 while the feed is live)
+    /// @field unit: ticks; kind: instantaneous; formula: oracle::PriceOracle::staleness
+    #[serde(default)]
+    pub oracle_staleness_ticks: u64,
+    /// Reverted payments re-sent by their senders so far; included in
+    /// `spawn_count` once admitted
+    /// @field unit: packets; kind: cumulative; formula: retry::RetryQueue::take_due
+    #[serde(default)]
+    pub retry_count: u32,
+    /// Reverted payments waiting out their retry delay
+    /// @field unit: packets; kind: instantaneous; formula: retry::RetryQueue::pending
+    #[serde(default)]
+    pub pending_retries: u32,
+    /// Share of this tick's settled value returned to speculator agents
+    /// @field unit: ratio; kind: instantaneous; formula: speculation::Speculators::end_tick
+    #[serde(default)]
+    pub speculative_value_share: f64,
+    /// Governor decisions taken so far, one per control interval
+    /// @field unit: decisions; kind: cumulative; formula: control_loop::ControlLoop::actuate
+    #[serde(default)]
+    pub governor_decisions: u32,
+    /// Direction reversals of the actuated fee rate so far
+    /// @field unit: reversals; kind: cumulative; formula: control_loop::ControlLoop::record_fee_rate
+    #[serde(default)]
+    pub fee_rate_reversals: u32,
+    /// Highest per-node panic level (0 with contagion off)
+    /// @field unit: index; kind: instantaneous; formula: panic_contagion::PanicContagion::end_tick
+    #[serde(default)]
+    pub peak_local_panic: f64,
+    /// Nodes panicked enough to turn RiskAverse
+    /// @field unit: nodes; kind: instantaneous; formula: panic_contagion::RISK_AVERSE_LEVEL
+    #[serde(default)]
+    pub panicked_nodes: u32,
+    /// Packets held over this tick by node throughput caps
+    /// @field unit: packets; kind: instantaneous; formula: throughput::capacity
+    #[serde(default)]
+    pub throughput_deferred: u32,
+    /// Crypto put back into Egress inventory by the replenishment policy
+    /// @field unit: value; kind: cumulative; formula: replenishment::Replenishment::update
+    #[serde(default)]
+    pub egress_replenished: f64,
+    /// Ticks live Egress inventory lasts at its smoothed net draw; None
+    /// while inflow keeps up with settlements
+    /// @field unit: ticks; kind: instantaneous; formula: replenishment::runway
+    #[serde(default)]
+    pub liquidity_runway_ticks: Option<f64>,
+    /// Crypto landed with short Egress from linked peers, fees included
+    /// @field unit: value; kind: cumulative; formula: rebalancing::Rebalancing::update
+    #[serde(default)]
+    pub rebalanced_volume: f64,
+    /// Rebalancing fees short Egress paid back to their lenders
+    /// @field unit: value; kind: cumulative; formula: rebalanced x fee_rate
+    #[serde(default)]
+    pub rebalancing_fees: f64,
+    /// Rebalancing crypto sent and not yet landed
+    /// @field unit: value; kind: instantaneous; formula: rebalancing::Rebalancing::in_flight_value
+    #[serde(default)]
+    pub rebalancing_in_flight: f64,
+    /// Packets PacketDropper nodes discarded
+    /// @field unit: packets; kind: cumulative; formula: adversary::refusal
+    #[serde(default)]
+    pub dropped_packets: u32,
+    /// Packets SelectiveCensor nodes refused
+    /// @field unit: packets; kind: cumulative; formula: adversary::refusal
+    #[serde(default)]
+    pub censored_packets: u32,
+    /// Hops RouteLiar nodes sent away from the target Egress
+    /// @field unit: hops; kind: cumulative; formula: adversary::misroute
+    #[serde(default)]
+    pub misrouted_packets: u32,
+    /// Herfindahl index of the last epoch's forwarded hops over directed links
+    /// @field unit: ratio; kind: instantaneous; formula: sum((hops_link / hops)^2)
+    #[serde(default)]
+    pub route_concentration: f64,
+    /// Gini coefficient of fees earned to date across live nodes, as of the last epoch
+    /// @field unit: ratio; kind: instantaneous; formula: concentration::gini
+    #[serde(default)]
+    pub fee_share_gini: f64,
+    /// Hops cartel members steered to each other
+    /// @field unit: hops; kind: cumulative; formula: collusion::Cartel::steer
+    #[serde(default)]
+    pub cartel_steered_hops: u32,
+    /// Fees moved between cartel members by their off-protocol split
+    /// @field unit: value; kind: cumulative; formula: sum(|earned - share|) / 2
+    #[serde(default)]
+    pub cartel_fee_split: f64,
 }
 
 // ─── TickResult ──────────────────────────────────────────────────────────────
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Tsify)]
 pub struct TickResult {
     pub state: WorldState,
     pub active_packets: Vec<SimPacket>,
     pub node_updates: Vec<NodeUpdate>,
+    pub histograms: crate::histogram::TickHistograms,
 }
 
 // ─── NodeUpdate ──────────────────────────────────────────────────────────────
 
-#[derive(Debug, Serialize)]
+/// `NodeUpdate::changed` bits, one per group of fields.
+pub const NODE_CHANGED_BUFFER: u32 = 1;
+pub const NODE_CHANGED_INVENTORY: u32 = 1 << 1;
+pub const NODE_CHANGED_TRUST: u32 = 1 << 2;
+pub const NODE_CHANGED_PRESSURE: u32 = 1 << 3;
+pub const NODE_CHANGED_FEES: u32 = 1 << 4;
+pub const NODE_CHANGED_QUEUE: u32 = 1 << 5;
+pub const NODE_CHANGED_ALL: u32 = (1 << 6) - 1;
+
+/// Per-tick view of a node: enough to drive a node view from `TickResult`
+/// alone, with `changed` flagging what moved since the previous tick.
+#[derive(Debug, Clone, Serialize, Tsify)]
 pub struct NodeUpdate {
     pub id: u32,
     pub buffer_count: u32,
     pub inventory_fiat: f64,
     pub inventory_crypto: f64,
+    pub trust_score: f64,
+    pub pressure: f64,
+    pub total_fees_earned: f64,
+    /// Ticks of work queued behind the node's throughput cap
+    pub queue_latency: f64,
+    /// `NODE_CHANGED_*` bits of the fields that differ from the previous
+    /// tick's update; all set for a node with none
+    pub changed: u32,
+}
+
+impl NodeUpdate {
+    /// Update for `node`, flagged against `previous`.
+    pub fn new(node: &SimNode, previous: Option<&NodeUpdate>) -> Self {
+        let mut update = NodeUpdate {
+            id: node.id,
+            buffer_count: node.current_buffer_count,
+            inventory_fiat: node.inventory_fiat,
+            inventory_crypto: node.inventory_crypto,
+            trust_score: node.trust_score,
+            pressure: node.pressure,
+            total_fees_earned: node.total_fees_earned,
+            queue_latency: node.queue_latency,
+            changed: NODE_CHANGED_ALL,
+        };
+        if let Some(p) = previous {
+            let flag = |differs: bool, bit: u32| if differs { bit } else { 0 };
+            update.changed = flag(p.buffer_count != update.buffer_count, NODE_CHANGED_BUFFER)
+                | flag(p.inventory_fiat != update.inventory_fiat
+                    || p.inventory_crypto != update.inventory_crypto, NODE_CHANGED_INVENTORY)
+                | flag(p.trust_score != update.trust_score, NODE_CHANGED_TRUST)
+                | flag(p.pressure != update.pressure, NODE_CHANGED_PRESSURE)
+                | flag(p.total_fees_earned != update.total_fees_earned, NODE_CHANGED_FEES)
+                | flag(p.queue_latency != update.queue_latency, NODE_CHANGED_QUEUE);
+        }
+        update
+    }
+}
+
+// ─── BatchSummary ────────────────────────────────────────────────────────────
+
+/// Aggregate of a `run_batch` fast-forward. Counts are deltas over the batch.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct BatchSummary {
+    pub ticks: u32,
+    pub start_tick: u64,
+    pub end_tick: u64,
+    pub settlements: u32,
+    pub reverts: u32,
+    pub min_fee_rate: f64,
+    pub max_fee_rate: f64,
+    pub max_conservation_error: f64,
+    /// Ticks spent in each governor quadrant, indexed A..F
+    /// (Bubble, Crash, Stagnation, Golden Era, Bottleneck, Vacuum).
+    pub quadrant_occupancy: [u32; 6],
+}
+
+// ─── Quadrant Transitions ────────────────────────────────────────────────────
+
+/// A change of governor quadrant and the metric that drove it.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct QuadrantTransition {
+    pub tick: u64,
+    pub from: String,
+    pub to: String,
+    /// `gold_deviation`, `network_velocity` or `settled_value_per_tick`
+    pub trigger_metric: String,
+    pub trigger_value: f64,
+}
+
+// ─── Governor State ──────────────────────────────────────────────────────────
+
+/// The core PID governor's tuning and internal state.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Tsify)]
+pub struct GovernorState {
+    /// Base gains, used in quadrants without scheduled gains
+    pub kp: f64,
+    pub ki: f64,
+    pub kd: f64,
+    /// Gold price (USD) the governor steers toward
+    pub peg_target: f64,
+    /// Relative gold deviation, summed over every fresh-oracle cycle
+    pub integral_error: f64,
+    /// Relative gold deviation at the last fresh-oracle cycle
+    pub last_error: f64,
+    /// Fee adjustment the governor last recommended, after clamping
+    pub last_adjustment: f64,
+    /// Cycles the model-predictive planner looks ahead (0 while the PID steers)
+    pub mpc_horizon: u32,
+    /// Peg error and active packets the planner last predicted at the end of
+    /// its horizon (0 while the PID steers)
+    pub mpc_predicted_peg_error: f64,
+    pub mpc_predicted_backlog: f64,
+}
+
+// ─── Convergence ─────────────────────────────────────────────────────────────
+
+/// Metric watched by `run_until_converged`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Tsify)]
+#[serde(rename_all = "snake_case")]
+pub enum ConvergenceMetric {
+    FeeRate,
+    SurgeMultiplier,
+    ActiveValue,
+    HeldCount,
+    Volatility,
+    PegDeviation,
+    /// Cumulative settlements / packets minted
+    SettlementRate,
+}
+
+impl ConvergenceMetric {
+    /// Parse the snake_case name used on the JS side (e.g. `"fee_rate"`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "fee_rate" => Some(Self::FeeRate),
+            "surge_multiplier" => Some(Self::SurgeMultiplier),
+            "active_value" => Some(Self::ActiveValue),
+            "held_count" => Some(Self::HeldCount),
+            "volatility" => Some(Self::Volatility),
+            "peg_deviation" => Some(Self::PegDeviation),
+            "settlement_rate" => Some(Self::SettlementRate),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+pub struct ConvergenceResult {
+    pub converged: bool,
+    pub ticks_used: u64,
+    pub final_value: f64,
+    pub stats: SimStats,
 }
 
 // ─── SimStats ────────────────────────────────────────────────────────────────
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Run totals; fields carry `@field` annotations like `WorldState`.
+#[derive(Debug, Serialize, Deserialize, Tsify)]
 pub struct SimStats {
+    /// Value minted into packets so far
+    /// @field unit: value; kind: cumulative; formula: same as WorldState::total_input
     pub total_input: f64,
+    /// Value paid out at Egress or refunded so far
+    /// @field unit: value; kind: cumulative; formula: same as WorldState::total_output
     pub total_output: f64,
+    /// Value burned by demurrage and surge so far
+    /// @field unit: value; kind: cumulative; formula: same as WorldState::total_demurrage_burned
     pub total_burned: f64,
+    /// Fees charged so far
+    /// @field unit: value; kind: cumulative; formula: same as WorldState::total_fees_collected
     pub total_fees: f64,
+    /// Conservation leakage: |input - output - burned - fees - active value|
+    /// @field unit: value; kind: instantaneous; formula: conservation::compute_conservation
     pub total_leaked: f64,
+    /// Packets settled so far
+    /// @field unit: packets; kind: cumulative; formula: simulation::tick_core
     pub settlement_count: u32,
+    /// Packets refunded so far
+    /// @field unit: packets; kind: cumulative; formula: receipts::RevertLog
     pub revert_count: u32,
+    /// Packets orbiting (Held) now
+    /// @field unit: packets; kind: instantaneous; formula: OrbitReason
     pub orbit_count: u32,
+    /// Mean hops of every settlement so far
+    /// @field unit: hops; kind: cumulative; formula: settlement hops / settlement_count
     pub avg_hops: f64,
+    /// Mean arrival-to-settlement ticks of every settlement so far
+    /// @field unit: ticks; kind: cumulative; formula: settlement ticks / settlement_count
     pub avg_time_to_settle: f64,
+    /// Spawns rejected by the ingress rate limiter (auto traffic and API)
+    /// @field unit: packets; kind: cumulative; formula: ratelimit::IngressRateLimiter
+    #[serde(default)]
+    pub rate_limited_count: u32,
+    /// Fees collected so far, by the tier of the packet that paid them (L0..L3)
+    /// @field unit: value; kind: cumulative; formula: tier_ledger::TierLedger
+    #[serde(default)]
+    pub tier_fees_collected: [f64; 4],
+    /// Demurrage (and surge) burned so far, by packet tier
+    /// @field unit: value; kind: cumulative; formula: tier_ledger::TierLedger
+    #[serde(default)]
+    pub tier_demurrage_burned: [f64; 4],
+    /// Settlement rewards distributed so far, by packet tier
+    /// @field unit: value; kind: cumulative; formula: tier_ledger::TierLedger
+    #[serde(default)]
+    pub tier_rewards_distributed: [f64; 4],
+    /// Outcomes, pricing and destination liquidity per spawn corridor
+    /// @field unit: mixed; kind: cumulative; formula: corridor::Corridors::stats
+    #[serde(default)]
+    pub corridors: Vec<CorridorStats>,
+    /// Local gold price and cross-region flows per priced region
+    /// @field unit: mixed; kind: cumulative; formula: regional_price::RegionalPrices::stats
+    #[serde(default)]
+    pub regional_prices: Vec<RegionPriceStats>,
+    /// Speculator activity and the organic-ratio detector's score against it
+    /// @field unit: mixed; kind: cumulative; formula: speculation::Speculators::stats
+    #[serde(default)]
+    pub speculation: SpeculationStats,
+    /// Liquidity providers' supply, withdrawals and fee yield earned
+    /// @field unit: mixed; kind: cumulative; formula: liquidity_provider::LiquidityProviders::stats
+    #[serde(default)]
+    pub liquidity: LiquidityStats,
+    /// Spawn-to-settlement latency per tier (L0..L3)
+    /// @field unit: ticks; kind: cumulative; formula: latency::SettlementLatency::stats
+    #[serde(default)]
+    pub tier_latency: Vec<TierLatencyStats>,
+}
+
+// ─── MemoryFootprint ─────────────────────────────────────────────────────────
+
+/// Estimated size of the simulation's growing containers.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+pub struct MemoryFootprint {
+    /// Packets buffered at nodes or in flight
+    pub packets: u32,
+    pub route_entries: u64,
+    pub fee_schedule_entries: u64,
+    pub quadrant_transitions: u32,
+    /// Revert receipts retained (the most recent `receipts::MAX_REVERT_RECEIPTS`)
+    pub revert_receipts: u32,
+    /// Route and fee entries folded away by compaction so far
+    pub compacted_entries: u64,
+    /// Allocated bytes of the packet containers, per-packet histories,
+    /// transition log and revert receipts (nodes are fixed-size and not counted)
+    pub estimated_bytes: u64,
 }
 
 // ─── GovernorOutput (v0.2) ───────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
 pub struct GovernorOutput {
     pub fee_rate: f64,
     pub demurrage: f64,
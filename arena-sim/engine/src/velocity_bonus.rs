@@ -0,0 +1,88 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Velocity Bonus Treasury
+
+/// Routes of at most this many hops earn the fast-route bonus.
+pub const DEFAULT_FAST_HOPS: u32 = 3;
+/// Routes of more than this many hops pay the slow-route penalty.
+pub const DEFAULT_SLOW_HOPS: u32 = 6;
+/// Share of a settlement's fee payouts added (fast) or withheld (slow).
+pub const DEFAULT_BONUS_RATE: f64 = 0.2;
+
+// ---------------------------------------------------------------------------
+// VelocityBonus - fast-route bonuses funded by slow-route penalties
+// ---------------------------------------------------------------------------
+
+/// Rewards fast routes without minting: penalties withheld from slow-route
+/// payouts fund a treasury, and fast-route bonuses are paid from it only
+/// while the balance lasts.
+#[derive(Debug, Clone)]
+pub struct VelocityBonus {
+    pub fast_hops: u32,
+    pub slow_hops: u32,
+    pub rate: f64,
+    pub treasury: f64,
+    pub paid: f64,
+    pub withheld: f64,
+}
+
+impl Default for VelocityBonus {
+    fn default() -> Self {
+        Self::new(DEFAULT_FAST_HOPS, DEFAULT_SLOW_HOPS, DEFAULT_BONUS_RATE)
+    }
+}
+
+impl VelocityBonus {
+    pub fn new(fast_hops: u32, slow_hops: u32, rate: f64) -> Self {
+        Self {
+            fast_hops,
+            slow_hops: slow_hops.max(fast_hops),
+            rate: rate.max(0.0),
+            treasury: 0.0,
+            paid: 0.0,
+            withheld: 0.0,
+        }
+    }
+
+    /// Multiplier on the fee payouts of a settlement that took `hops` to
+    /// distribute `payout`. A slow route's cut goes into the treasury; a fast
+    /// route's bonus comes out of it, capped at the balance.
+    pub fn settle(&mut self, hops: u32, payout: f64) -> f64 {
+        if payout <= 0.0 {
+            return 1.0;
+        }
+        if hops <= self.fast_hops {
+            let bonus = (payout * self.rate).min(self.treasury);
+            self.treasury -= bonus;
+            self.paid += bonus;
+            1.0 + bonus / payout
+        } else if hops > self.slow_hops {
+            let cut = (payout * self.rate).min(payout);
+            self.treasury += cut;
+            self.withheld += cut;
+            1.0 - cut / payout
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bonus_is_funded_by_penalties() {
+        let mut bonus = VelocityBonus::default();
+        assert_eq!(bonus.settle(2, 10.0), 1.0, "Empty treasury pays no bonus");
+
+        assert!((bonus.settle(8, 10.0) - 0.8).abs() < 1e-12);
+        assert!((bonus.treasury - 2.0).abs() < 1e-12);
+        assert_eq!(bonus.settle(5, 10.0), 1.0);
+
+        // Full bonus, then whatever is left
+        assert!((bonus.settle(1, 5.0) - 1.2).abs() < 1e-12);
+        assert!((bonus.settle(3, 10.0) - 1.1).abs() < 1e-12);
+        assert_eq!(bonus.treasury, 0.0);
+        assert!((bonus.paid - bonus.withheld).abs() < 1e-12);
+    }
+}
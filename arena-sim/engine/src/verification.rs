@@ -0,0 +1,186 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - NGauge Verification Marketplace
+
+use crate::types::{NodeRole, SimNode};
+
+/// Verification units an NGauge node can sell per tick, per unit of bandwidth.
+pub const CAPACITY_PER_BANDWIDTH: f64 = 0.5;
+/// Share of the fee earned on a hop or settlement paid to the verifier.
+pub const VERIFICATION_FEE_SHARE: f64 = 0.05;
+/// Extra ticks of hop latency when verification is bought outside the neighborhood.
+pub const REMOTE_COMPLEXITY: u64 = 1;
+/// Extra ticks of hop latency when no NGauge capacity is left anywhere.
+pub const SHORTFALL_COMPLEXITY: u64 = 2;
+
+// ---------------------------------------------------------------------------
+// Purchase outcome
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Purchase {
+    /// Bought from an NGauge node in the buyer's neighborhood.
+    Local(u32),
+    /// Local capacity exhausted; bought from a distant NGauge node.
+    Remote(u32),
+    /// No NGauge capacity left in the network this tick.
+    Shortfall,
+}
+
+impl Purchase {
+    pub fn seller(&self) -> Option<u32> {
+        match *self {
+            Purchase::Local(id) | Purchase::Remote(id) => Some(id),
+            Purchase::Shortfall => None,
+        }
+    }
+
+    /// Added verification complexity (ticks) for the buyer's hop.
+    pub fn extra_complexity(&self) -> u64 {
+        match self {
+            Purchase::Local(_) => 0,
+            Purchase::Remote(_) => REMOTE_COMPLEXITY,
+            Purchase::Shortfall => SHORTFALL_COMPLEXITY,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// VerificationMarket - per-tick NGauge capacity
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct VerificationMarket {
+    remaining: Vec<f64>,
+    capacity: f64,
+    sold: f64,
+    // Round-robin cursor for remote purchases
+    cursor: usize,
+}
+
+impl VerificationMarket {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refill capacity from every running, non-disabled NGauge node.
+    pub fn begin_tick(&mut self, nodes: &[SimNode]) {
+        self.remaining = nodes.iter()
+            .map(|n| {
+                if n.role == NodeRole::NGauge && n.ngauge_running {
+                    (n.bandwidth * CAPACITY_PER_BANDWIDTH).max(0.0)
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        self.capacity = self.remaining.iter().map(|c| c.floor()).sum();
+        self.sold = 0.0;
+        self.cursor = 0;
+    }
+
+    /// Buy one unit of verification for `buyer`, preferring the NGauge node
+    /// with the most spare capacity among the buyer and its neighbors, then
+    /// the next node with spare capacity anywhere (round-robin).
+    pub fn purchase(&mut self, nodes: &[SimNode], buyer: u32) -> Purchase {
+        let Some(node) = nodes.get(buyer as usize) else {
+            return Purchase::Shortfall;
+        };
+        let local = std::iter::once(buyer)
+            .chain(node.neighbors.iter().copied())
+            .filter(|&id| self.available(id))
+            .max_by(|&a, &b| self.spare(a).total_cmp(&self.spare(b)).then(b.cmp(&a)));
+        if let Some(seller) = local {
+            self.consume(seller);
+            return Purchase::Local(seller);
+        }
+
+        if self.sold >= self.capacity {
+            return Purchase::Shortfall;
+        }
+        let n = self.remaining.len();
+        let remote = (0..n)
+            .map(|k| ((self.cursor + k) % n) as u32)
+            .find(|&id| self.available(id));
+        match remote {
+            Some(seller) => {
+                self.cursor = seller as usize;
+                self.consume(seller);
+                Purchase::Remote(seller)
+            }
+            None => Purchase::Shortfall,
+        }
+    }
+
+    /// Fraction of this tick's NGauge capacity sold so far.
+    pub fn utilization(&self) -> f64 {
+        if self.capacity > 0.0 { self.sold / self.capacity } else { 0.0 }
+    }
+
+    fn spare(&self, id: u32) -> f64 {
+        self.remaining.get(id as usize).copied().unwrap_or(0.0)
+    }
+
+    fn available(&self, id: u32) -> bool {
+        self.spare(id) >= 1.0
+    }
+
+    fn consume(&mut self, id: u32) {
+        self.remaining[id as usize] -= 1.0;
+        self.sold += 1.0;
+    }
+}
+
+/// Pay the verifier its share of the fee the buyer just earned. Moves value
+/// between node balances only, so network conservation is unaffected.
+/// Returns the amount paid.
+pub fn pay_verifier(nodes: &mut [SimNode], buyer: u32, purchase: Purchase, fee_earned: f64) -> f64 {
+    let Some(seller) = purchase.seller() else {
+        return 0.0;
+    };
+    let payment = (fee_earned * VERIFICATION_FEE_SHARE).max(0.0);
+    nodes[buyer as usize].total_fees_earned -= payment;
+    nodes[seller as usize].total_fees_earned += payment;
+    nodes[seller as usize].accumulated_work += 1.0;
+    payment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: u32, role: NodeRole, neighbors: Vec<u32>, bandwidth: f64) -> SimNode {
+        SimNode { neighbors, bandwidth, ..SimNode::test_default(id, role) }
+    }
+
+    #[test]
+    fn test_local_then_remote_then_shortfall() {
+        // 0 (transit) - 1 (ngauge, 1 unit); 2 (ngauge, 1 unit) is not a neighbor
+        let nodes = vec![
+            node(0, NodeRole::Transit, vec![1], 100.0),
+            node(1, NodeRole::NGauge, vec![0], 2.0),
+            node(2, NodeRole::NGauge, vec![], 2.0),
+        ];
+        let mut market = VerificationMarket::new();
+        market.begin_tick(&nodes);
+
+        assert_eq!(market.purchase(&nodes, 0), Purchase::Local(1));
+        assert_eq!(market.purchase(&nodes, 0), Purchase::Remote(2));
+        assert_eq!(market.purchase(&nodes, 0), Purchase::Shortfall);
+        assert_eq!(market.utilization(), 1.0);
+
+        market.begin_tick(&nodes);
+        assert_eq!(market.utilization(), 0.0);
+        assert_eq!(market.purchase(&nodes, 0), Purchase::Local(1));
+    }
+
+    #[test]
+    fn test_stopped_ngauge_sells_nothing() {
+        let mut stopped = node(1, NodeRole::NGauge, vec![0], 100.0);
+        stopped.ngauge_running = false;
+        let nodes = vec![node(0, NodeRole::Egress, vec![1], 100.0), stopped];
+        let mut market = VerificationMarket::new();
+        market.begin_tick(&nodes);
+        assert_eq!(market.purchase(&nodes, 0), Purchase::Shortfall);
+        assert_eq!(Purchase::Shortfall.extra_complexity(), SHORTFALL_COMPLEXITY);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2026 Hypermesh Foundation. All rights reserved.
+// Caesar Protocol Simulation Suite ("The Arena") - Volatility Estimators
+//
+// The governor reads one volatility number a tick. The default is the
+// coefficient of variation of the last `window` gold prices, which lags a
+// regime change by most of the window and swings with the price level near
+// zero. The alternatives work on log returns r = ln(P_t / P_t-1), which
+// carry no price scale:
+//
+//   RollingCv      std(P) / mean(P) over the window
+//   EwmaLogReturn  var_t = l x var_t-1 + (1 - l) x r^2,  l = 1 - 2 / (window + 1)
+//   Parkinson      var = sum(ln(H / L)^2) / (4 ln 2 x bars x (PARKINSON_BAR_TICKS - 1)),
+//                  over PARKINSON_BAR_TICKS-tick high/low bars in the window
+//   GarchLite      var_t = w + a x r^2 + b x var_t-1,  w = (1 - a - b) x the window's
+//                  sample return variance (a = GARCH_ALPHA, b = GARCH_BETA)
+//
+// The return-based estimates are per tick; each is reported over the
+// window's horizon, sqrt(var x window), so they sit on roughly the scale of
+// the coefficient of variation for a random walk and the governor's
+// thresholds keep their meaning.
+
+use std::collections::VecDeque;
+
+/// Window of the default estimator, in prices.
+pub const DEFAULT_WINDOW: u32 = 20;
+/// Ticks per high/low bar of the Parkinson estimator.
+pub const PARKINSON_BAR_TICKS: usize = 5;
+/// Weight of the latest squared return and of the last variance in GARCH-lite.
+pub const GARCH_ALPHA: f64 = 0.1;
+pub const GARCH_BETA: f64 = 0.85;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VolatilityEstimator {
+    #[default]
+    RollingCv = 0,
+    EwmaLogReturn = 1,
+    Parkinson = 2,
+    GarchLite = 3,
+}
+
+impl VolatilityEstimator {
+    /// Map a numeric discriminant (as sent from JS) back to an estimator.
+    pub fn from_u32(v: u32) -> Option<Self> {
+        match v {
+            0 => Some(Self::RollingCv),
+            1 => Some(Self::EwmaLogReturn),
+            2 => Some(Self::Parkinson),
+            3 => Some(Self::GarchLite),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VolatilityModel {
+    pub estimator: VolatilityEstimator,
+    pub window: u32,
+    /// The last `window` prices, oldest first
+    prices: VecDeque<f64>,
+    /// Per-tick return variance carried by EWMA and GARCH-lite
+    variance: Option<f64>,
+}
+
+impl VolatilityModel {
+    /// A model whose history starts at `initial_price`.
+    pub fn new(estimator: VolatilityEstimator, window: u32, initial_price: f64) -> Self {
+        Self {
+            estimator,
+            window: window.max(2),
+            prices: VecDeque::from([initial_price]),
+            variance: None,
+        }
+    }
+
+    /// Switch estimator and window, keeping the price history.
+    pub fn configure(&mut self, estimator: VolatilityEstimator, window: u32) {
+        self.estimator = estimator;
+        self.window = window.max(2);
+        self.variance = None;
+        while self.prices.len() > self.window as usize {
+            self.prices.pop_front();
+        }
+    }
+
+    /// Take this tick's price and return the volatility estimate.
+    pub fn update(&mut self, price: f64) -> f64 {
+        let last = self.prices.back().copied();
+        self.prices.push_back(price);
+        if self.prices.len() > self.window as usize {
+            self.prices.pop_front();
+        }
+        let r = last.and_then(|prev| log_return(prev, price));
+        let window = f64::from(self.window);
+        let prices: &[f64] = self.prices.make_contiguous();
+        match self.estimator {
+            VolatilityEstimator::RollingCv => rolling_cv(prices),
+            VolatilityEstimator::EwmaLogReturn => {
+                let lambda = 1.0 - 2.0 / (window + 1.0);
+                if let Some(r) = r {
+                    let prev = self.variance.unwrap_or(r * r);
+                    self.variance = Some(lambda * prev + (1.0 - lambda) * r * r);
+                }
+                horizon(self.variance.unwrap_or(0.0), window)
+            }
+            VolatilityEstimator::Parkinson => horizon(parkinson_variance(prices), window),
+            VolatilityEstimator::GarchLite => {
+                let long_run = return_variance(prices);
+                if let Some(r) = r {
+                    let prev = self.variance.unwrap_or(long_run);
+                    let omega = (1.0 - GARCH_ALPHA - GARCH_BETA) * long_run;
+                    self.variance = Some(omega + GARCH_ALPHA * r * r + GARCH_BETA * prev);
+                }
+                horizon(self.variance.unwrap_or(0.0), window)
+            }
+        }
+    }
+}
+
+/// A per-tick variance as volatility over `window` ticks.
+fn horizon(variance: f64, window: f64) -> f64 {
+    (variance.max(0.0) * window).sqrt()
+}
+
+fn log_return(prev: f64, price: f64) -> Option<f64> {
+    (prev > 0.0 && price > 0.0).then(|| (price / prev).ln())
+}
+
+/// E11: Coefficient of variation of `history`; 0 with fewer than two prices
+/// or a zero mean.
+pub fn rolling_cv(history: &[f64]) -> f64 {
+    if history.len() < 2 {
+        return 0.0;
+    }
+    let n = history.len() as f64;
+    let mean = history.iter().sum::<f64>() / n;
+    if mean.abs() < 1e-12 {
+        return 0.0;
+    }
+    let variance = history.iter()
+        .map(|&p| (p - mean).powi(2))
+        .sum::<f64>() / n;
+    let std_dev = variance.sqrt();
+    std_dev / mean
+}
+
+/// Sample variance of the log returns in `prices`.
+fn return_variance(prices: &[f64]) -> f64 {
+    let returns: Vec<f64> = prices.windows(2).filter_map(|w| log_return(w[0], w[1])).collect();
+    if returns.len() < 2 {
+        return 0.0;
+    }
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
+    returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0)
+}
+
+/// Parkinson's range variance per tick, from high/low bars of `prices`.
+fn parkinson_variance(prices: &[f64]) -> f64 {
+    let ranges: Vec<f64> = prices.chunks(PARKINSON_BAR_TICKS)
+        .filter(|bar| bar.len() > 1)
+        .filter_map(|bar| {
+            let high = bar.iter().copied().fold(f64::MIN, f64::max);
+            let low = bar.iter().copied().fold(f64::MAX, f64::min);
+            (low > 0.0).then(|| (high / low).ln().powi(2))
+        })
+        .collect();
+    if ranges.is_empty() {
+        return 0.0;
+    }
+    // A bar's range spans its ticks; scale back to one tick
+    let per_bar = ranges.iter().sum::<f64>() / (4.0 * std::f64::consts::LN_2 * ranges.len() as f64);
+    per_bar / (PARKINSON_BAR_TICKS - 1) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(estimator: VolatilityEstimator, prices: &[f64]) -> Vec<f64> {
+        let mut model = VolatilityModel::new(estimator, DEFAULT_WINDOW, prices[0]);
+        prices[1..].iter().map(|&p| model.update(p)).collect()
+    }
+
+    #[test]
+    fn test_return_estimators_track_regime_changes_and_ignore_scale() {
+        // 60 calm ticks, then 20 of +/-3% swings
+        let prices: Vec<f64> = (0..80)
+            .map(|t| 2600.0 * if t >= 60 && t % 2 == 1 { 1.03 } else { 1.0 })
+            .collect();
+        let estimators = [
+            VolatilityEstimator::RollingCv,
+            VolatilityEstimator::EwmaLogReturn,
+            VolatilityEstimator::Parkinson,
+            VolatilityEstimator::GarchLite,
+        ];
+        let mut after_5 = Vec::new();
+        for estimator in estimators {
+            let v = run(estimator, &prices);
+            assert_eq!(v[58], 0.0, "{estimator:?} calm");
+            assert!(v[78] > 0.0, "{estimator:?} stressed");
+            // Five ticks into the shift, as a share of where it settles
+            after_5.push(v[63] / v[78]);
+
+            // The same path at a thousandth of the price gives the same estimate
+            let scaled: Vec<f64> = prices.iter().map(|p| p / 1000.0).collect();
+            let w = run(estimator, &scaled);
+            assert!((w[78] - v[78]).abs() < 1e-9, "{estimator:?} scale");
+        }
+        // EWMA and GARCH-lite react faster than the rolling window
+        assert!(after_5[1] > after_5[0] && after_5[3] > after_5[0], "{after_5:?}");
+    }
+}
@@ -1,5 +1,6 @@
 import { useEffect, useState, useRef, useCallback } from 'react';
 import init, { ArenaSimulation } from './engine/arena_engine.js';
+import type { NodeUpdate, SimNode } from './engine/arena_engine.js';
 import { LineChart, Line, XAxis, YAxis, Tooltip, ResponsiveContainer, CartesianGrid } from 'recharts';
 import { Play, Pause, Activity, DollarSign, Zap, ShieldAlert, Trash2, TrendingDown, Info, Package, Plus, Download, Search } from 'lucide-react';
 import './App.css';
@@ -17,6 +18,11 @@ const ROLE_COLORS: Record<number, string> = {
   [NodeRole.Transit]: '#64748b', [NodeRole.NGauge]: 'var(--accent-green)',
 };
 
+// NodeUpdate.changed bits, as NODE_CHANGED_* in the engine's types.rs
+const NodeChanged = {
+  Buffer: 1, Inventory: 1 << 1, Trust: 1 << 2, Pressure: 1 << 3, Fees: 1 << 4, Queue: 1 << 5,
+} as const;
+
 const TIER_LABELS: Record<number, string> = { 0: 'L0', 1: 'L1', 2: 'L2', 3: 'L3' };
 const TIER_COLORS: Record<number, string> = { 0: '#06b6d4', 1: '#f1f5f9', 2: '#f59e0b', 3: '#a855f7' };
 const TIER_FEE_CAPS: Record<number, number> = { 0: 5.0, 1: 2.0, 2: 0.5, 3: 0.1 };
@@ -27,7 +33,10 @@ const PACKET_STATUS_LABELS: Record<number, string> = {
   8: 'Expired', 9: 'Refunded', 10: 'Dissolved',
 };
 
-interface Node {
+// Node fields that arrived with NodeUpdate, typed from the engine's SimNode
+type NodeUpdateFields = Pick<SimNode, 'trust_score' | 'queue_latency'>;
+
+interface Node extends Partial<NodeUpdateFields> {
   id: number; role: NodeRole; inventory_fiat: number; inventory_crypto: number;
   current_buffer_count: number; neighbors: number[]; x?: number; y?: number;
   total_fees_earned?: number; accumulated_work?: number;
@@ -45,7 +54,6 @@ interface Node {
 }
 
 interface TickResult { state: WorldState; active_packets: Packet[]; node_updates: NodeUpdate[]; }
-interface NodeUpdate { id: number; buffer_count: number; inventory_fiat: number; inventory_crypto: number; }
 interface Packet {
   id: number; status: number; current_value: number; origin_node: number;
   target_node?: number; arrival_tick: number; original_value?: number;
@@ -459,11 +467,16 @@ function App() {
 
         result.node_updates.forEach(u => {
           const n = nodesRef.current[u.id];
-          if (n) {
-            n.current_buffer_count = u.buffer_count;
+          if (!n) return;
+          if (u.changed & NodeChanged.Buffer) n.current_buffer_count = u.buffer_count;
+          if (u.changed & NodeChanged.Inventory) {
             n.inventory_fiat = u.inventory_fiat;
             n.inventory_crypto = u.inventory_crypto;
           }
+          if (u.changed & NodeChanged.Trust) n.trust_score = u.trust_score;
+          if (u.changed & NodeChanged.Pressure) n.pressure = u.pressure;
+          if (u.changed & NodeChanged.Fees) n.total_fees_earned = u.total_fees_earned;
+          if (u.changed & NodeChanged.Queue) n.queue_latency = u.queue_latency;
         });
 
         // B2: Halt on leak check